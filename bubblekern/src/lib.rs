@@ -1,54 +1,71 @@
 #![allow(unstable_name_collisions)]
-mod dist;
 mod glyph;
 
-use dist::_determine_kern;
 use glyph::BubbleBuffer;
-use harfbuzz_wasm::{debug, Font};
+use harfbuzz_wasm::geometry::OutlineCache;
+use harfbuzz_wasm::{debug, FeatureList, Font, ShaperCache};
+use outline_distance::{kern_to_distance, Outline};
+use std::cell::RefCell;
 use std::collections::BTreeMap;
+use std::rc::Rc;
 
 use wasm_bindgen::prelude::*;
 
+// Each glyph's bubble, if it has one. Finding the bubble means
+// looking up names and reading outlines, so they are kept from one
+// call to the next until the font's size or variation changes.
+#[derive(Default)]
+struct Bubbles {
+    outlines: OutlineCache<Outline>,
+    ids: RefCell<BTreeMap<u32, Option<u32>>>,
+}
+
+impl Bubbles {
+    fn get(&self, font: &Font, glyph: u32) -> Option<Rc<Outline>> {
+        let id = *self.ids.borrow_mut().entry(glyph).or_insert_with(|| {
+            let this_name = font.get_glyph_name(glyph);
+            font.get_glyph_from_name(&(this_name + ".bubble"))
+        });
+        Some(self.outlines.get(font, id?))
+    }
+}
+
+thread_local! {
+    static BUBBLES: ShaperCache<Bubbles> = const { ShaperCache::new() };
+}
+
 #[wasm_bindgen]
 pub fn shape(
     _shape_plan: u32,
     font_ref: u32,
     buf_ref: u32,
-    _features: u32,
-    _num_features: u32,
+    features: u32,
+    num_features: u32,
 ) -> i32 {
     let font = Font::from_ref(font_ref);
-    font.shape_with(buf_ref, "ot");
-    let face = font.get_face();
-    let (x_scale, _y_scale) = font.get_scale();
-    let upem = face.get_upem();
-    let scale_factor: f32 = x_scale as f32 / upem as f32;
+    let features = FeatureList::from_ref(features, num_features);
+    font.shape_with_features(buf_ref, "ot", &features.features);
     let mut buffer = BubbleBuffer::from_ref(buf_ref);
-    let names_map: BTreeMap<String, u32> = (0..60_u32)
-        .map(|id| (font.get_glyph_name(id), id))
-        .collect();
-    // Fill in bubble paths and total advance
-    let mut total_advance = 0;
-    for item in buffer.glyphs.iter_mut() {
-        item.x_total_advance = total_advance;
-        total_advance += item.x_advance;
-
-        let this_name = font.get_glyph_name(item.codepoint);
-        if let Some(&bubble_id) = names_map.get(&(this_name + ".bubble")) {
-            item.bubble_paths = Some(font.get_outline(bubble_id))
-        }
+    // Fill in bubble outlines and pen positions
+    let bubbles = BUBBLES.with(|cache| cache.get_or_insert_with_scale(&font, Bubbles::default));
+    let pens = buffer.pen_positions();
+    for (item, pen) in buffer.glyphs.iter_mut().zip(pens) {
+        item.pen = pen;
+        item.bubble = bubbles.get(&font, item.codepoint);
     }
 
     // Do the kern
     for ix in 0..(buffer.glyphs.len() - 1) {
-        if let Some(left_paths) = buffer.glyphs[ix].positioned_bubble_paths(0.0) {
-            // We push the right-hand glyphs outwards slightly, simply because the
-            // algorithm does not do well if the bubbles start by overlapping.
-            if let Some(right_paths) =
-                buffer.glyphs[ix + 1].positioned_bubble_paths(200.0 * scale_factor)
-            {
-                let kern = 200.0 * scale_factor
-                    + _determine_kern(&left_paths, &right_paths, 0.0 * scale_factor, scale_factor);
+        let (c1, c2) = (buffer.glyphs[ix].cluster, buffer.glyphs[ix + 1].cluster);
+        if features.is_disabled("kern", c1.min(c2)..c1.max(c2) + 1) {
+            continue;
+        }
+        // Move the right-hand glyph until the bubbles just touch,
+        // whether they started apart or overlapping.
+        let left = buffer.glyphs[ix].placed_bubble();
+        let right = buffer.glyphs[ix + 1].placed_bubble();
+        if let (Some(left), Some(right)) = (left, right) {
+            if let Some(kern) = kern_to_distance(&[left], &[right], 0.0) {
                 debug(&format!("Kerning by {} at {}", kern, ix));
                 buffer.glyphs[ix].x_advance += kern as i32;
             }
@@ -2,6 +2,17 @@ use harfbuzz_wasm::{debug, Font, Glyph, GlyphBuffer};
 use regex::Regex;
 use wasm_bindgen::prelude::*;
 
+// Turn Arabic-Indic and Extended Arabic-Indic digits into ASCII ones.
+fn ascii_digits(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            '٠'..='٩' => char::from_u32(c as u32 - 0x0660 + '0' as u32).unwrap(),
+            '۰'..='۹' => char::from_u32(c as u32 - 0x06F0 + '0' as u32).unwrap(),
+            _ => c,
+        })
+        .collect()
+}
+
 #[wasm_bindgen]
 pub fn shape(
     _shape_plan: u32,
@@ -13,38 +24,38 @@ pub fn shape(
     let font = Font::from_ref(font_ref);
     let mut buffer = GlyphBuffer::from_ref(buf_ref);
     // Get buffer as string
-    let buf_u8: Vec<u8> = buffer.glyphs.iter().map(|g| g.codepoint as u8).collect();
-    let str_buf = String::from_utf8_lossy(&buf_u8);
+    let text = buffer.text_map();
+    let str_buf = &text.text;
 
-    let re = Regex::new(r"(\d+(?:\.\d+)?)\s*([\+-/\*])\s*(\d+(?:\.\d+)?)\s*=").unwrap();
-    if let Some(captures) = re.captures(&str_buf) {
+    let re = Regex::new(
+        r"([0-9٠-٩۰-۹]+(?:\.[0-9٠-٩۰-۹]+)?)\s*([\+\-/\*×÷])\s*([0-9٠-٩۰-۹]+(?:\.[0-9٠-٩۰-۹]+)?)\s*=",
+    )
+    .unwrap();
+    if let Some(captures) = re.captures(str_buf) {
         debug(&format!("{:?}", captures));
-        let left = captures[1].parse::<f32>().unwrap();
-        let right = captures[3].parse::<f32>().unwrap();
+        let left = ascii_digits(&captures[1]).parse::<f32>().unwrap();
+        let right = ascii_digits(&captures[3]).parse::<f32>().unwrap();
         let res = match &captures[2] {
             "+" => left + right,
             "-" => left - right,
-            "*" => left * right,
-            "/" => left / right,
+            "*" | "×" => left * right,
+            "/" | "÷" => left / right,
             _ => panic!(),
         };
         let res_str = format!("{}", res);
-        buffer.glyphs = res_str
+        let result = res_str
             .chars()
-            .enumerate()
-            .map(|(ix, x)| Glyph {
+            .map(|x| Glyph {
                 codepoint: x as u32,
-                flags: 0,
-                x_advance: 0,
-                y_advance: 0,
-                cluster: ix as u32,
-                x_offset: 0,
-                y_offset: 0,
+                ..Default::default()
             })
             .collect();
+        // The answer replaces the sum, so it becomes one cluster.
+        let sum = captures.get(0).unwrap().range();
+        buffer.replace_range(text.glyph_range(sum), result);
     } else {
         debug("No match");
-        debug(&str_buf);
+        debug(str_buf);
     }
 
     for mut item in buffer.glyphs.iter_mut() {
@@ -1,54 +1,28 @@
-// We don't use the usual harfbuzz-wasm for this, because
-// we are doing evil.
-mod hbwasm;
-use crate::hbwasm::{
-    buffer_set_contents, Buffer, BufferItem, CBufferContents, CGlyphInfo, CGlyphPosition, Font,
-    Glyph, GlyphBuffer,
-};
+use harfbuzz_wasm::{FeatureList, Font, Glyph, GlyphBuffer};
 
 use wasm_bindgen::prelude::*;
 
-fn putback(buffer: &mut GlyphBuffer) {
-    let mut positions: Vec<CGlyphPosition>;
-    let mut infos: Vec<CGlyphInfo>;
-    let glyphs = std::mem::take(&mut buffer.glyphs);
-    (infos, positions) = glyphs.into_iter().map(|g| g.to_c()).unzip();
-    let c_contents = CBufferContents {
-        length: positions.len() as u32,
-        info: infos[..].as_mut_ptr(),
-        position: positions[..].as_mut_ptr(),
-    };
-    unsafe {
-        buffer_set_contents(buffer._ptr, &c_contents);
-    }
-}
-
 #[wasm_bindgen]
 pub fn shape(
     _shape_plan: u32,
     font_ref: u32,
     buf_ref: u32,
-    _features: u32,
-    _num_features: u32,
+    features: u32,
+    num_features: u32,
 ) -> i32 {
     let font = Font::from_ref(font_ref);
-    let mut buffer: GlyphBuffer = Buffer::from_ref(buf_ref);
-
-    // Get buffer as string
-    let buf_u8: Vec<u8> = buffer.glyphs.iter().map(|g| g.codepoint as u8).collect();
-    let str_buf = String::from_utf8_lossy(&buf_u8);
+    let features = FeatureList::from_ref(features, num_features);
+    let mut buffer = GlyphBuffer::from_ref(buf_ref);
 
-    if !str_buf.starts_with("debug: ") {
-        font.shape_with(buf_ref, "ot");
-        std::mem::forget(buffer);
+    let debugging = buffer.text().starts_with("debug: ");
+    if debugging {
+        buffer.glyphs.drain(..7);
+    }
+    // Shape the text, and if we're not debugging, that's all.
+    if font.shape_buffer(&mut buffer, "ot", &features.features).is_err() || !debugging {
         return 1;
     }
-    buffer.glyphs = buffer.glyphs[7..].to_vec();
-    putback(&mut buffer);
-    font.shape_with(buf_ref, "ot");
-    std::mem::forget(buffer);
 
-    let mut buffer = GlyphBuffer::from_ref(buf_ref);
     let names: String = buffer
         .glyphs
         .iter()
@@ -68,6 +42,7 @@ pub fn shape(
             cluster: ix as u32,
             x_offset: 0,
             y_offset: 0,
+            ..Default::default()
         })
         .collect();
 
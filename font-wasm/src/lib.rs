@@ -0,0 +1,88 @@
+#![warn(missing_docs)]
+//! Put WASM shapers into fonts, and check them on the way in.
+//!
+//! Harfbuzz looks for a shaper in a font's `Wasm` table. Before
+//! adding one, this checks that Harfbuzz will be able to run it:
+//! that it exports a `shape` function taking five `i32`s and
+//! returning an `i32`, and that everything it imports is a function
+//! Harfbuzz provides (see [`harfbuzz_wasm::abi`]). A shaper which
+//! fails these checks would otherwise only show up as text quietly
+//! being shaped without it.
+//!
+//! The `font-wasm` binary wraps this for use in Makefiles:
+//!
+//! ```text
+//! font-wasm add Font.ttf pkg/shaper_bg.wasm -o Font-Wasm.ttf
+//! font-wasm check pkg/shaper_bg.wasm
+//! font-wasm extract Font-Wasm.ttf -o shaper.wasm
+//! font-wasm info Font-Wasm.ttf
+//! ```
+use std::fmt;
+
+pub mod sfnt;
+pub mod wasm;
+
+pub use sfnt::{checksum, Font};
+pub use wasm::{Module, Problem};
+
+/// The tag of the table Harfbuzz loads shapers from.
+pub const WASM_TAG: &str = "Wasm";
+
+/// Things that can go wrong reading fonts and shapers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The font file couldn't be read
+    BadFont(String),
+    /// The WASM module couldn't be read
+    BadWasm(String),
+    /// The font has no `Wasm` table
+    NoWasmTable,
+    /// Harfbuzz wouldn't be able to run the shaper
+    Problems(Vec<Problem>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::BadFont(message) => write!(f, "Couldn't read font: {}", message),
+            Error::BadWasm(message) => write!(f, "Couldn't read WASM module: {}", message),
+            Error::NoWasmTable => write!(f, "The font has no Wasm table"),
+            Error::Problems(problems) => {
+                write!(f, "Harfbuzz wouldn't be able to run this shaper:")?;
+                for problem in problems {
+                    write!(f, "\n  {}", problem)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Parse a shaper and check that Harfbuzz could run it.
+pub fn check_shaper(wasm: &[u8]) -> Result<Module, Error> {
+    let module = Module::parse(wasm)?;
+    let problems = module.problems();
+    if !problems.is_empty() {
+        return Err(Error::Problems(problems));
+    }
+    Ok(module)
+}
+
+/// Check a shaper and put it in a font's `Wasm` table, replacing
+/// any shaper already there. Returns the new font file.
+pub fn add_shaper(font: &[u8], wasm: &[u8]) -> Result<Vec<u8>, Error> {
+    check_shaper(wasm)?;
+    let mut font = Font::parse(font)?;
+    font.set_table(WASM_TAG, wasm.to_vec());
+    Ok(font.to_bytes())
+}
+
+/// Get the shaper out of a font's `Wasm` table.
+pub fn extract_shaper(font: &[u8]) -> Result<Vec<u8>, Error> {
+    Font::parse(font)?
+        .table(WASM_TAG)
+        .map(|t| t.to_vec())
+        .ok_or(Error::NoWasmTable)
+}
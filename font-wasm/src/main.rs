@@ -0,0 +1,102 @@
+use std::path::Path;
+use std::process::ExitCode;
+
+use font_wasm::{add_shaper, check_shaper, checksum, extract_shaper, Error, Font, Module};
+
+const USAGE: &str = "Put WASM shapers into fonts, and check them on the way in
+
+Usage:
+  font-wasm add FONT WASM -o OUTPUT   Check a shaper and add it to a font's Wasm table
+  font-wasm check WASM                Check a shaper without adding it to a font
+  font-wasm extract FONT -o WASM      Copy the shaper out of a font's Wasm table
+  font-wasm info FONT|WASM            Summarize a font's shaper, or a WASM file";
+
+fn read(path: &str) -> Result<Vec<u8>, String> {
+    std::fs::read(path).map_err(|e| format!("{}: {}", path, e))
+}
+
+fn write(path: &str, data: &[u8]) -> Result<(), String> {
+    std::fs::write(path, data).map_err(|e| format!("{}: {}", path, e))
+}
+
+// The positional arguments, and the value given with `-o`.
+fn split_args(args: &[String]) -> Result<(Vec<&str>, Option<&str>), String> {
+    let mut positional = vec![];
+    let mut output = None;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "-o" {
+            output = Some(iter.next().ok_or("-o needs a file name")?.as_str());
+        } else {
+            positional.push(arg.as_str());
+        }
+    }
+    Ok((positional, output))
+}
+
+fn describe(wasm: &[u8], module: &Module) -> String {
+    format!(
+        "{} bytes, {} imports, table checksum 0x{:08x}",
+        wasm.len(),
+        module.imports.len(),
+        checksum(wasm)
+    )
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let (command, rest) = args.split_first().ok_or(USAGE)?;
+    let (positional, output) = split_args(rest)?;
+    let err = |e: Error| e.to_string();
+    match (command.as_str(), positional.as_slice(), output) {
+        ("add", [font, wasm], Some(output)) => {
+            let wasm_data = read(wasm)?;
+            let module = check_shaper(&wasm_data).map_err(|e| format!("{}: {}", wasm, e))?;
+            let font_data = add_shaper(&read(font)?, &wasm_data).map_err(err)?;
+            write(output, &font_data)?;
+            println!(
+                "Added {} to {}: {}",
+                wasm,
+                output,
+                describe(&wasm_data, &module)
+            );
+        }
+        ("check", [wasm], None) => {
+            let wasm_data = read(wasm)?;
+            let module = check_shaper(&wasm_data).map_err(|e| format!("{}: {}", wasm, e))?;
+            println!("{}: {}", wasm, describe(&wasm_data, &module));
+        }
+        ("extract", [font], Some(output)) => {
+            write(output, &extract_shaper(&read(font)?).map_err(err)?)?;
+        }
+        ("info", [file], None) => {
+            let data = read(file)?;
+            let wasm = if data.starts_with(b"\0asm") {
+                data
+            } else {
+                let font = Font::parse(&data).map_err(err)?;
+                let tags: Vec<String> = font.tags().collect();
+                println!("Tables: {}", tags.join(" "));
+                extract_shaper(&data).map_err(err)?
+            };
+            println!("Checksum: 0x{:08x}", checksum(&wasm));
+            print!("{}", Module::parse(&wasm).map_err(err)?.summary());
+        }
+        _ => return Err(USAGE.to_string()),
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            let name = std::env::args().next().unwrap_or_default();
+            let name = Path::new(&name)
+                .file_name()
+                .map_or("font-wasm".into(), |n| n.to_string_lossy());
+            eprintln!("{}: {}", name, e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//! Reading and writing the tables of an OpenType font file.
+use std::collections::BTreeMap;
+
+use crate::Error;
+
+/// The checksum of a table, as stored in the font's table directory:
+/// the sum of its contents as big-endian 32-bit numbers, padded
+/// with zeros.
+pub fn checksum(data: &[u8]) -> u32 {
+    data.chunks(4).fold(0u32, |sum, chunk| {
+        let mut word = [0; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        sum.wrapping_add(u32::from_be_bytes(word))
+    })
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// The tables of a font, by tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Font {
+    sfnt_version: u32,
+    tables: BTreeMap<[u8; 4], Vec<u8>>,
+}
+
+fn tag_bytes(tag: &str) -> [u8; 4] {
+    let mut bytes = [b' '; 4];
+    for (b, c) in bytes.iter_mut().zip(tag.bytes()) {
+        *b = c;
+    }
+    bytes
+}
+
+impl Font {
+    /// Read the tables of a font file. Collections and web fonts
+    /// aren't supported.
+    pub fn parse(data: &[u8]) -> Result<Self, Error> {
+        let malformed = || Error::BadFont("The table directory is malformed".to_string());
+        let sfnt_version = read_u32(data, 0).ok_or_else(malformed)?;
+        match &sfnt_version.to_be_bytes() {
+            [0, 1, 0, 0] | b"OTTO" | b"true" => {}
+            b"ttcf" => return Err(Error::BadFont("Font collections aren't supported".into())),
+            b"wOFF" | b"wOF2" => return Err(Error::BadFont("Web fonts aren't supported".into())),
+            _ => return Err(Error::BadFont("Not a font file".into())),
+        }
+        let num_tables = read_u16(data, 4).ok_or_else(malformed)? as usize;
+        let mut tables = BTreeMap::new();
+        for ix in 0..num_tables {
+            let record = 12 + ix * 16;
+            let tag = data.get(record..record + 4).ok_or_else(malformed)?;
+            let offset = read_u32(data, record + 8).ok_or_else(malformed)? as usize;
+            let length = read_u32(data, record + 12).ok_or_else(malformed)? as usize;
+            let table = data
+                .get(offset..offset.saturating_add(length))
+                .ok_or_else(malformed)?;
+            tables.insert(tag.try_into().unwrap(), table.to_vec());
+        }
+        Ok(Self {
+            sfnt_version,
+            tables,
+        })
+    }
+
+    /// The contents of a table, if the font has it.
+    pub fn table(&self, tag: &str) -> Option<&[u8]> {
+        self.tables.get(&tag_bytes(tag)).map(|t| t.as_slice())
+    }
+
+    /// Add a table, replacing any the font already has with that tag.
+    pub fn set_table(&mut self, tag: &str, data: Vec<u8>) {
+        self.tables.insert(tag_bytes(tag), data);
+    }
+
+    /// The tags of the font's tables, in order.
+    pub fn tags(&self) -> impl Iterator<Item = String> + '_ {
+        self.tables
+            .keys()
+            .map(|t| t.iter().map(|&b| b as char).collect())
+    }
+
+    /// Write the font out again, with its table directory and
+    /// checksums recalculated.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let num_tables = self.tables.len() as u16;
+        let entry_selector = if num_tables == 0 {
+            0
+        } else {
+            15 - num_tables.leading_zeros() as u16
+        };
+        let search_range = (1u16 << entry_selector) * 16;
+        let mut out = vec![];
+        out.extend(self.sfnt_version.to_be_bytes());
+        for value in [
+            num_tables,
+            search_range,
+            entry_selector,
+            num_tables * 16 - search_range.min(num_tables * 16),
+        ] {
+            out.extend(value.to_be_bytes());
+        }
+
+        let mut offset = 12 + 16 * self.tables.len();
+        let mut head_offset = None;
+        let mut body = vec![];
+        for (tag, data) in &self.tables {
+            let mut data = data.clone();
+            // The head table's checksum is worked out with the
+            // whole-font adjustment set to zero.
+            if tag == b"head" && data.len() >= 12 {
+                data[8..12].fill(0);
+                head_offset = Some(offset);
+            }
+            out.extend(tag);
+            out.extend(checksum(&data).to_be_bytes());
+            out.extend((offset as u32).to_be_bytes());
+            out.extend((data.len() as u32).to_be_bytes());
+            body.extend(&data);
+            while body.len() % 4 != 0 {
+                body.push(0);
+            }
+            offset = 12 + 16 * self.tables.len() + body.len();
+        }
+        out.extend(body);
+        if let Some(head) = head_offset {
+            let adjustment = 0xB1B0AFBAu32.wrapping_sub(checksum(&out));
+            out[head + 8..head + 12].copy_from_slice(&adjustment.to_be_bytes());
+        }
+        out
+    }
+}
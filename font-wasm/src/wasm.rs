@@ -0,0 +1,594 @@
+//! Just enough of the WASM binary format to check a shaper and
+//! summarize what is in it.
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Write;
+
+use harfbuzz_wasm::abi::{host_function, AbiFunction, HOST_MODULE, SHAPE_EXPORT};
+
+use crate::Error;
+
+/// The type of a WASM value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValType {
+    /// 32-bit integer
+    I32,
+    /// 64-bit integer
+    I64,
+    /// 32-bit float
+    F32,
+    /// 64-bit float
+    F64,
+    /// 128-bit vector
+    V128,
+    /// Function reference
+    FuncRef,
+    /// External reference
+    ExternRef,
+}
+
+impl fmt::Display for ValType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ValType::I32 => "i32",
+            ValType::I64 => "i64",
+            ValType::F32 => "f32",
+            ValType::F64 => "f64",
+            ValType::V128 => "v128",
+            ValType::FuncRef => "funcref",
+            ValType::ExternRef => "externref",
+        };
+        f.write_str(name)
+    }
+}
+
+/// The parameters and results of a function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuncType {
+    /// Parameter types
+    pub params: Vec<ValType>,
+    /// Result types
+    pub results: Vec<ValType>,
+}
+
+impl FuncType {
+    /// Does this type match a function in the Harfbuzz ABI?
+    pub fn matches(&self, abi: &AbiFunction) -> bool {
+        let results: &[ValType] = if abi.returns { &[ValType::I32] } else { &[] };
+        self.params == vec![ValType::I32; abi.params] && self.results == results
+    }
+}
+
+impl fmt::Display for FuncType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = |types: &[ValType]| {
+            types
+                .iter()
+                .map(|t| t.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        write!(f, "({})", list(&self.params))?;
+        match self.results.as_slice() {
+            [] => Ok(()),
+            [result] => write!(f, " -> {}", result),
+            results => write!(f, " -> ({})", list(results)),
+        }
+    }
+}
+
+/// What kind of thing is imported or exported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExternKind {
+    /// A function, with the index of its type
+    Function(u32),
+    /// A table
+    Table,
+    /// A memory
+    Memory,
+    /// A global variable
+    Global,
+    /// An exception tag
+    Tag,
+}
+
+impl ExternKind {
+    fn name(&self) -> &'static str {
+        match self {
+            ExternKind::Function(_) => "function",
+            ExternKind::Table => "table",
+            ExternKind::Memory => "memory",
+            ExternKind::Global => "global",
+            ExternKind::Tag => "tag",
+        }
+    }
+}
+
+/// Something the module needs from its host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Import {
+    /// The module it comes from
+    pub module: String,
+    /// Its name
+    pub name: String,
+    /// What it is
+    pub kind: ExternKind,
+}
+
+/// Something the module provides to its host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Export {
+    /// Its name
+    pub name: String,
+    /// What it is; for functions, the index of the function rather
+    /// than its type
+    pub kind: ExternKind,
+}
+
+/// A section of the module, and how big it is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// The section's ID
+    pub id: u8,
+    /// The name of a custom section
+    pub name: Option<String>,
+    /// The size of its contents in bytes
+    pub size: usize,
+}
+
+impl Section {
+    /// What the section is called.
+    pub fn describe(&self) -> String {
+        let name = match self.id {
+            0 => return format!("custom \"{}\"", self.name.as_deref().unwrap_or("")),
+            1 => "type",
+            2 => "import",
+            3 => "function",
+            4 => "table",
+            5 => "memory",
+            6 => "global",
+            7 => "export",
+            8 => "start",
+            9 => "element",
+            10 => "code",
+            11 => "data",
+            12 => "data count",
+            13 => "tag",
+            _ => "unknown",
+        };
+        name.to_string()
+    }
+}
+
+/// Something which stops Harfbuzz running a module as a shaper.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// There is no `shape` function
+    NoShapeExport,
+    /// `shape` has the wrong type
+    ShapeSignature(FuncType),
+    /// Harfbuzz doesn't provide this import
+    UnknownImport(String, String),
+    /// The import has a different type from the one Harfbuzz provides
+    ImportSignature(String, FuncType),
+    /// The module imports something which isn't a function
+    NonFunctionImport(String, String, &'static str),
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::NoShapeExport => write!(f, "No function called 'shape' is exported"),
+            Problem::ShapeSignature(t) => write!(
+                f,
+                "'shape' has type {}, but should take five i32s and return an i32",
+                t
+            ),
+            Problem::UnknownImport(module, name) => {
+                write!(
+                    f,
+                    "Imports {}.{}, which Harfbuzz doesn't provide",
+                    module, name
+                )
+            }
+            Problem::ImportSignature(name, t) => write!(
+                f,
+                "Imports {} with type {}, which isn't how Harfbuzz provides it",
+                name, t
+            ),
+            Problem::NonFunctionImport(module, name, kind) => write!(
+                f,
+                "Imports a {} {}.{}, but Harfbuzz only provides functions",
+                kind, module, name
+            ),
+        }
+    }
+}
+
+/// A parsed WASM module.
+#[derive(Debug, Clone, Default)]
+pub struct Module {
+    /// The size of the whole module in bytes
+    pub size: usize,
+    /// Each section, in order
+    pub sections: Vec<Section>,
+    /// Function types
+    pub types: Vec<FuncType>,
+    /// Imports, in order
+    pub imports: Vec<Import>,
+    /// The type index of each function defined in the module
+    pub functions: Vec<u32>,
+    /// Exports, in order
+    pub exports: Vec<Export>,
+    /// The size of the body of each function defined in the module
+    pub code_sizes: Vec<usize>,
+    /// Function names from the `name` section, by function index
+    pub function_names: BTreeMap<u32, String>,
+}
+
+// Reads values from a WASM binary, keeping track of where it is so
+// errors can say where things went wrong.
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    // Where `data` starts in the whole module
+    base: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8], base: usize) -> Self {
+        Self { data, pos: 0, base }
+    }
+
+    fn error(&self, message: &str) -> Error {
+        Error::BadWasm(format!("{} at byte {}", message, self.base + self.pos))
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        let bytes = self
+            .data
+            .get(self.pos..self.pos.saturating_add(len))
+            .ok_or_else(|| self.error("Unexpected end of data"))?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn byte(&mut self) -> Result<u8, Error> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    // An unsigned LEB128 number.
+    fn leb(&mut self) -> Result<u64, Error> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(self.error("Number too long"))
+    }
+
+    fn u32(&mut self) -> Result<u32, Error> {
+        let value = self.leb()?;
+        u32::try_from(value).map_err(|_| self.error("Number too large"))
+    }
+
+    fn len(&mut self) -> Result<usize, Error> {
+        Ok(self.u32()? as usize)
+    }
+
+    fn name(&mut self) -> Result<String, Error> {
+        let len = self.len()?;
+        let bytes = self.bytes(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| self.error("Name isn't UTF-8"))
+    }
+
+    fn val_type(&mut self) -> Result<ValType, Error> {
+        Ok(match self.byte()? {
+            0x7f => ValType::I32,
+            0x7e => ValType::I64,
+            0x7d => ValType::F32,
+            0x7c => ValType::F64,
+            0x7b => ValType::V128,
+            0x70 => ValType::FuncRef,
+            0x6f => ValType::ExternRef,
+            _ => return Err(self.error("Unknown value type")),
+        })
+    }
+
+    fn val_types(&mut self) -> Result<Vec<ValType>, Error> {
+        (0..self.len()?).map(|_| self.val_type()).collect()
+    }
+
+    fn limits(&mut self) -> Result<(), Error> {
+        let flags = self.byte()?;
+        self.leb()?;
+        if flags & 1 != 0 {
+            self.leb()?;
+        }
+        Ok(())
+    }
+
+    fn extern_kind(&mut self, import: bool) -> Result<ExternKind, Error> {
+        let kind = self.byte()?;
+        if !import {
+            let index = self.u32()?;
+            return Ok(match kind {
+                0 => ExternKind::Function(index),
+                1 => ExternKind::Table,
+                2 => ExternKind::Memory,
+                3 => ExternKind::Global,
+                4 => ExternKind::Tag,
+                _ => return Err(self.error("Unknown export kind")),
+            });
+        }
+        Ok(match kind {
+            0 => ExternKind::Function(self.u32()?),
+            1 => {
+                self.val_type()?;
+                self.limits()?;
+                ExternKind::Table
+            }
+            2 => {
+                self.limits()?;
+                ExternKind::Memory
+            }
+            3 => {
+                self.val_type()?;
+                self.byte()?;
+                ExternKind::Global
+            }
+            4 => {
+                self.byte()?;
+                self.u32()?;
+                ExternKind::Tag
+            }
+            _ => return Err(self.error("Unknown import kind")),
+        })
+    }
+}
+
+impl Module {
+    /// Parse a WASM module.
+    pub fn parse(data: &[u8]) -> Result<Self, Error> {
+        let mut reader = Reader::new(data, 0);
+        if reader.bytes(4).ok() != Some(b"\0asm") {
+            return Err(Error::BadWasm("Not a WASM module".to_string()));
+        }
+        if reader.bytes(4)? != [1, 0, 0, 0] {
+            return Err(reader.error("Unsupported WASM version"));
+        }
+        let mut module = Module {
+            size: data.len(),
+            ..Default::default()
+        };
+        while !reader.at_end() {
+            let id = reader.byte()?;
+            let size = reader.len()?;
+            let base = reader.pos;
+            let mut section = Reader::new(reader.bytes(size)?, base);
+            let name = if id == 0 { Some(section.name()?) } else { None };
+            match (id, name.as_deref()) {
+                (0, Some("name")) => module.read_names(&mut section)?,
+                (1, _) => module.read_types(&mut section)?,
+                (2, _) => module.read_imports(&mut section)?,
+                (3, _) => {
+                    for _ in 0..section.len()? {
+                        module.functions.push(section.u32()?);
+                    }
+                }
+                (7, _) => {
+                    for _ in 0..section.len()? {
+                        let name = section.name()?;
+                        let kind = section.extern_kind(false)?;
+                        module.exports.push(Export { name, kind });
+                    }
+                }
+                (10, _) => {
+                    for _ in 0..section.len()? {
+                        let len = section.len()?;
+                        section.bytes(len)?;
+                        module.code_sizes.push(len);
+                    }
+                }
+                _ => {}
+            }
+            module.sections.push(Section { id, name, size });
+        }
+        Ok(module)
+    }
+
+    fn read_types(&mut self, section: &mut Reader) -> Result<(), Error> {
+        for _ in 0..section.len()? {
+            if section.byte()? != 0x60 {
+                return Err(section.error("Unsupported type"));
+            }
+            let params = section.val_types()?;
+            let results = section.val_types()?;
+            self.types.push(FuncType { params, results });
+        }
+        Ok(())
+    }
+
+    fn read_imports(&mut self, section: &mut Reader) -> Result<(), Error> {
+        for _ in 0..section.len()? {
+            let module = section.name()?;
+            let name = section.name()?;
+            let kind = section.extern_kind(true)?;
+            self.imports.push(Import { module, name, kind });
+        }
+        Ok(())
+    }
+
+    // The function names subsection of the `name` section. The other
+    // subsections are skipped.
+    fn read_names(&mut self, section: &mut Reader) -> Result<(), Error> {
+        while !section.at_end() {
+            let id = section.byte()?;
+            let size = section.len()?;
+            let base = section.base + section.pos;
+            let mut sub = Reader::new(section.bytes(size)?, base);
+            if id == 1 {
+                for _ in 0..sub.len()? {
+                    let index = sub.u32()?;
+                    let name = sub.name()?;
+                    self.function_names.insert(index, name);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Imported functions, which come before the functions defined
+    /// in the module when they are numbered.
+    fn imported_functions(&self) -> impl Iterator<Item = (&Import, u32)> {
+        self.imports.iter().filter_map(|i| match i.kind {
+            ExternKind::Function(t) => Some((i, t)),
+            _ => None,
+        })
+    }
+
+    /// The type of a function, by its index.
+    pub fn function_type(&self, index: u32) -> Option<&FuncType> {
+        let imported = self.imported_functions().count();
+        let type_index = match index as usize {
+            ix if ix < imported => self.imported_functions().nth(ix)?.1,
+            ix => *self.functions.get(ix - imported)?,
+        };
+        self.types.get(type_index as usize)
+    }
+
+    /// The type of an exported function.
+    pub fn export_type(&self, name: &str) -> Option<&FuncType> {
+        self.exports.iter().find_map(|e| match e.kind {
+            ExternKind::Function(index) if e.name == name => self.function_type(index),
+            _ => None,
+        })
+    }
+
+    /// Check that Harfbuzz can run this module as a shaper: it
+    /// exports `shape` with the right type, and only imports
+    /// functions Harfbuzz provides.
+    pub fn problems(&self) -> Vec<Problem> {
+        let mut problems = vec![];
+        match self.export_type(SHAPE_EXPORT.name) {
+            None => problems.push(Problem::NoShapeExport),
+            Some(t) if !t.matches(&SHAPE_EXPORT) => {
+                problems.push(Problem::ShapeSignature(t.clone()))
+            }
+            _ => {}
+        }
+        for import in &self.imports {
+            let ExternKind::Function(type_index) = import.kind else {
+                problems.push(Problem::NonFunctionImport(
+                    import.module.clone(),
+                    import.name.clone(),
+                    import.kind.name(),
+                ));
+                continue;
+            };
+            let host = host_function(&import.name).filter(|_| import.module == HOST_MODULE);
+            let Some(host) = host else {
+                problems.push(Problem::UnknownImport(
+                    import.module.clone(),
+                    import.name.clone(),
+                ));
+                continue;
+            };
+            match self.types.get(type_index as usize) {
+                Some(t) if t.matches(host) => {}
+                t => problems.push(Problem::ImportSignature(
+                    import.name.clone(),
+                    t.cloned().unwrap_or(FuncType {
+                        params: vec![],
+                        results: vec![],
+                    }),
+                )),
+            }
+        }
+        problems
+    }
+
+    /// A description of the module: its sections, imports and
+    /// exports, and its largest functions.
+    pub fn summary(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "Size: {} bytes", self.size);
+        let _ = writeln!(out, "Sections:");
+        for section in &self.sections {
+            let _ = writeln!(
+                out,
+                "  {:<24} {:>9} bytes",
+                section.describe(),
+                section.size
+            );
+        }
+        let _ = writeln!(out, "Imports ({}):", self.imports.len());
+        for import in &self.imports {
+            let _ = match import.kind {
+                ExternKind::Function(t) => writeln!(
+                    out,
+                    "  {}.{}{}",
+                    import.module,
+                    import.name,
+                    self.types
+                        .get(t as usize)
+                        .map_or("(?)".to_string(), |t| t.to_string())
+                ),
+                kind => writeln!(out, "  {}.{} ({})", import.module, import.name, kind.name()),
+            };
+        }
+        let _ = writeln!(out, "Exports ({}):", self.exports.len());
+        for export in &self.exports {
+            let _ = match export.kind {
+                ExternKind::Function(index) => writeln!(
+                    out,
+                    "  {}{}",
+                    export.name,
+                    self.function_type(index)
+                        .map_or("(?)".to_string(), |t| t.to_string())
+                ),
+                kind => writeln!(out, "  {} ({})", export.name, kind.name()),
+            };
+        }
+        let code: usize = self.code_sizes.iter().sum();
+        let _ = writeln!(
+            out,
+            "Functions: {} defined, {} bytes of code",
+            self.code_sizes.len(),
+            code
+        );
+        let imported = self.imported_functions().count();
+        let mut largest: Vec<(usize, usize)> =
+            self.code_sizes.iter().copied().enumerate().collect();
+        largest.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        if !largest.is_empty() {
+            let _ = writeln!(out, "Largest functions:");
+        }
+        for (ix, size) in largest.into_iter().take(10) {
+            let index = (imported + ix) as u32;
+            let name = self
+                .function_names
+                .get(&index)
+                .cloned()
+                .unwrap_or_else(|| format!("function {}", index));
+            let _ = writeln!(out, "  {:>9} bytes  {}", size, name);
+        }
+        let problems = self.problems();
+        if problems.is_empty() {
+            let _ = writeln!(out, "No problems found");
+        } else {
+            let _ = writeln!(out, "Problems:");
+            for problem in problems {
+                let _ = writeln!(out, "  {}", problem);
+            }
+        }
+        out
+    }
+}
@@ -0,0 +1,166 @@
+use font_wasm::{add_shaper, checksum, extract_shaper, Error, Font, Module, Problem};
+
+fn open_sans() -> Vec<u8> {
+    std::fs::read(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../base-fonts/OpenSans-Regular.ttf"
+    ))
+    .unwrap()
+}
+
+fn section(id: u8, contents: &[u8]) -> Vec<u8> {
+    let mut out = vec![id, contents.len() as u8];
+    out.extend(contents);
+    out
+}
+
+fn name(s: &str) -> Vec<u8> {
+    let mut out = vec![s.len() as u8];
+    out.extend(s.as_bytes());
+    out
+}
+
+// A module importing one function from `module`, and exporting a
+// function called `export` whose type has `params` i32 parameters
+// and returns an i32.
+fn build_module(module: &str, import: &str, export: &str, params: u8) -> Vec<u8> {
+    let mut wasm = b"\0asm\x01\0\0\0".to_vec();
+    let mut types = vec![2, 0x60, params];
+    types.extend(vec![0x7f; params as usize]);
+    types.extend([1, 0x7f, 0x60, 1, 0x7f, 1, 0x7f]);
+    wasm.extend(section(1, &types));
+    let mut imports = vec![1];
+    imports.extend(name(module));
+    imports.extend(name(import));
+    imports.extend([0, 1]);
+    wasm.extend(section(2, &imports));
+    wasm.extend(section(3, &[1, 0]));
+    let mut exports = vec![1];
+    exports.extend(name(export));
+    exports.extend([0, 1]);
+    wasm.extend(section(7, &exports));
+    // One body: no locals, `i32.const 1`, `end`
+    wasm.extend(section(10, &[1, 4, 0, 0x41, 1, 0x0b]));
+    let mut names = name("name");
+    let mut function_names = vec![1, 1];
+    function_names.extend(name("shape_it"));
+    names.push(1);
+    names.push(function_names.len() as u8);
+    names.extend(function_names);
+    wasm.extend(section(0, &names));
+    wasm
+}
+
+fn shaper() -> Vec<u8> {
+    build_module("env", "font_get_face", "shape", 5)
+}
+
+#[test]
+fn shapers_are_checked() {
+    let parsed = Module::parse(&shaper()).unwrap();
+    assert!(parsed.problems().is_empty());
+    assert_eq!(
+        parsed.export_type("shape").unwrap().to_string(),
+        "(i32, i32, i32, i32, i32) -> i32"
+    );
+    assert_eq!(parsed.function_names.get(&1).unwrap(), "shape_it");
+    assert!(parsed.summary().contains("env.font_get_face(i32) -> i32"));
+
+    let problems = |wasm: Vec<u8>| Module::parse(&wasm).unwrap().problems();
+    assert_eq!(
+        problems(build_module("env", "malloc", "shape", 5)),
+        vec![Problem::UnknownImport("env".into(), "malloc".into())]
+    );
+    // Harfbuzz provides this, even though harfbuzz-wasm doesn't use it
+    assert!(problems(build_module(
+        "env",
+        "script_get_horizontal_direction",
+        "shape",
+        5
+    ))
+    .is_empty());
+    assert_eq!(
+        problems(build_module("env", "buffer_get_flags", "shape", 5)),
+        vec![Problem::UnknownImport(
+            "env".into(),
+            "buffer_get_flags".into()
+        )]
+    );
+    assert_eq!(
+        problems(build_module("wbg", "font_get_face", "shape", 5)),
+        vec![Problem::UnknownImport("wbg".into(), "font_get_face".into())]
+    );
+    assert!(matches!(
+        problems(build_module("env", "font_get_glyph", "shape", 5)).as_slice(),
+        [Problem::ImportSignature(name, _)] if name == "font_get_glyph"
+    ));
+    assert!(matches!(
+        problems(build_module("env", "font_get_face", "shape", 4)).as_slice(),
+        [Problem::ShapeSignature(_)]
+    ));
+    assert_eq!(
+        problems(build_module("env", "font_get_face", "shaper", 5)),
+        vec![Problem::NoShapeExport]
+    );
+
+    assert!(matches!(Module::parse(b"\0asm"), Err(Error::BadWasm(_))));
+    assert!(matches!(
+        Module::parse(&open_sans()),
+        Err(Error::BadWasm(_))
+    ));
+}
+
+#[test]
+fn shapers_are_embedded_and_extracted() {
+    let font = open_sans();
+    assert_eq!(extract_shaper(&font), Err(Error::NoWasmTable));
+
+    let wasm = shaper();
+    let with_shaper = add_shaper(&font, &wasm).unwrap();
+    assert_eq!(extract_shaper(&with_shaper).unwrap(), wasm);
+    // The whole font sums to the magic number once the head table's
+    // adjustment is filled in.
+    assert_eq!(checksum(&with_shaper), 0xB1B0AFBA);
+
+    let face = ttf_parser::Face::parse(&with_shaper, 0).unwrap();
+    let original = ttf_parser::Face::parse(&font, 0).unwrap();
+    assert_eq!(face.number_of_glyphs(), original.number_of_glyphs());
+    assert_eq!(
+        face.raw_face().table(ttf_parser::Tag::from_bytes(b"Wasm")),
+        Some(wasm.as_slice())
+    );
+    let tags: Vec<String> = Font::parse(&with_shaper).unwrap().tags().collect();
+    assert!(tags.windows(2).all(|w| w[0] < w[1]));
+
+    // Adding a shaper again replaces the old one.
+    let replaced = add_shaper(
+        &with_shaper,
+        &build_module("env", "face_get_upem", "shape", 5),
+    )
+    .unwrap();
+    assert_ne!(extract_shaper(&replaced).unwrap(), wasm);
+    assert_eq!(Font::parse(&replaced).unwrap().tags().count(), tags.len());
+
+    assert!(matches!(
+        add_shaper(&font, &build_module("env", "malloc", "shape", 5)),
+        Err(Error::Problems(_))
+    ));
+}
+
+#[test]
+fn checksums() {
+    assert_eq!(checksum(&[]), 0);
+    assert_eq!(checksum(&[0, 0, 1, 0, 1]), 0x0100_0100);
+}
+
+#[test]
+fn existing_shapers_pass() {
+    let font = std::fs::read(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../nastaliq/NotoNastaliq-Wasm.ttf"
+    ))
+    .unwrap();
+    let module = Module::parse(&extract_shaper(&font).unwrap()).unwrap();
+    assert_eq!(module.problems(), vec![]);
+    assert!(module.imports.iter().any(|i| i.name == "shape_with"));
+}
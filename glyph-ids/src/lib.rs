@@ -0,0 +1,338 @@
+#![warn(missing_docs)]
+//! Glyph IDs for shapers, looked up in the font when the shaper is
+//! built.
+//!
+//! Shapers which add glyphs of their own (a space, a dot, a shadow
+//! layer) need to know their IDs, but writing the numbers into the
+//! code means that reordering the glyphs in the font source quietly
+//! breaks the shaper. Instead, look them up by name from a build
+//! script, which fails the build if any of them has gone missing:
+//!
+//! ```rust,ignore
+//! // build.rs
+//! fn main() {
+//!     glyph_ids::GlyphIds::from_makefile(&["BASE_FONT"])
+//!         .glyphs(&["space", "dot"])
+//!         .suffix("shadow")
+//!         .build("glyphs.rs");
+//! }
+//! ```
+//!
+//! This writes a module of constants to `OUT_DIR`, which the shaper
+//! includes:
+//!
+//! ```rust,ignore
+//! mod glyphs {
+//!     include!(concat!(env!("OUT_DIR"), "/glyphs.rs"));
+//! }
+//!
+//! buffer.push(Glyph { codepoint: glyphs::DOT, ..Default::default() });
+//! let shadow = glyphs::lookup(glyphs::SHADOW, glyph);
+//! ```
+//!
+//! The font can also be a Glyphs source (a `.glyphs` file), which is
+//! better when the font is built from it by the Makefile: the build
+//! script then doesn't depend on a font which is built from the
+//! shaper. Glyph IDs are worked out as fontmake would number them.
+//!
+//! Each glyph becomes a `u32` constant named after it in capitals,
+//! with anything other than letters and digits turned into
+//! underscores (so `A.shadow` is `A_SHADOW`). Each suffix becomes a
+//! list of `(glyph, variant)` pairs, sorted by glyph ID, pairing
+//! every glyph with its variant of that name (`A` with `A.shadow`).
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+mod source;
+
+/// Things that can stop the glyph IDs being generated.
+#[derive(Debug)]
+pub enum Error {
+    /// A file couldn't be read or written
+    Io(PathBuf, std::io::Error),
+    /// None of the Makefile variables named a font file which exists
+    NoFont(Vec<String>),
+    /// The font file couldn't be parsed
+    FontMalformed(PathBuf),
+    /// The glyph order couldn't be read from a Glyphs source
+    SourceUnsupported(PathBuf, String),
+    /// The font has no glyphs with these names
+    MissingGlyphs(PathBuf, Vec<String>),
+    /// The font has no glyphs with this suffix
+    MissingSuffix(PathBuf, String),
+    /// Two glyphs or suffixes would both be given this constant name
+    DuplicateConstant(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+            Error::NoFont(vars) => write!(
+                f,
+                "None of the Makefile variables {} names a font file which exists",
+                vars.join(", ")
+            ),
+            Error::FontMalformed(path) => write!(f, "Couldn't parse font {}", path.display()),
+            Error::SourceUnsupported(path, why) => write!(
+                f,
+                "Couldn't read the glyph order of {}: {}",
+                path.display(),
+                why
+            ),
+            Error::MissingGlyphs(path, names) => write!(
+                f,
+                "{} has no glyphs named {}",
+                path.display(),
+                names.join(", ")
+            ),
+            Error::MissingSuffix(path, suffix) => {
+                write!(f, "{} has no glyphs ending in .{}", path.display(), suffix)
+            }
+            Error::DuplicateConstant(name) => {
+                write!(f, "More than one constant would be called {}", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The glyphs a shaper needs from a font, and where to find the font.
+#[derive(Debug, Clone)]
+pub struct GlyphIds {
+    font: Result<PathBuf, Vec<String>>,
+    makefile: Option<PathBuf>,
+    // Constant name and glyph name
+    glyphs: Vec<(String, String)>,
+    suffixes: Vec<String>,
+}
+
+/// Turn a glyph name or suffix into the name of a Rust constant.
+pub fn constant_name(name: &str) -> String {
+    let mut constant: String = name
+        .trim_start_matches(|c: char| !c.is_ascii_alphanumeric())
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if !constant.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        constant.insert_str(0, "GLYPH_");
+    }
+    constant
+}
+
+// The value of each `NAME = value` line of a Makefile.
+fn makefile_variables(makefile: &str) -> BTreeMap<String, String> {
+    makefile
+        .lines()
+        .filter(|line| !line.starts_with(char::is_whitespace))
+        .filter_map(|line| {
+            let (name, value) = line.split_once('=')?;
+            let name = name.trim_end_matches([':', '?', '+']).trim();
+            if name.is_empty() || name.contains(char::is_whitespace) {
+                return None;
+            }
+            Some((name.to_string(), value.trim().to_string()))
+        })
+        .collect()
+}
+
+// The ID of each named glyph in a font file or Glyphs source.
+fn glyph_names(path: &Path) -> Result<BTreeMap<String, u16>, Error> {
+    let data = std::fs::read(path).map_err(|e| Error::Io(path.to_path_buf(), e))?;
+    if path.extension().is_some_and(|e| e == "glyphs") {
+        let order = source::glyph_order(&String::from_utf8_lossy(&data))
+            .map_err(|why| Error::SourceUnsupported(path.to_path_buf(), why))?;
+        return Ok(order.into_iter().zip(0..).collect());
+    }
+    let face =
+        ttf_parser::Face::parse(&data, 0).map_err(|_| Error::FontMalformed(path.to_path_buf()))?;
+    Ok((0..face.number_of_glyphs())
+        .filter_map(|gid| {
+            let name = face.glyph_name(ttf_parser::GlyphId(gid))?;
+            Some((name.to_string(), gid))
+        })
+        .collect())
+}
+
+impl GlyphIds {
+    /// Look glyphs up in the given font file or Glyphs source.
+    pub fn new(font: impl Into<PathBuf>) -> Self {
+        Self {
+            font: Ok(font.into()),
+            makefile: None,
+            glyphs: vec![],
+            suffixes: vec![],
+        }
+    }
+
+    /// Look glyphs up in the font named by a variable in the crate's
+    /// `Makefile`, such as `BASE_FONT`, or in the Glyphs source the
+    /// font is built from.
+    ///
+    /// If more than one variable is given, the first one which names
+    /// a file that exists is used. Don't name the font the Makefile
+    /// builds by adding the shaper, since that doesn't exist until
+    /// the shaper has been built.
+    pub fn from_makefile(variables: &[&str]) -> Self {
+        let dir = PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").unwrap_or_default());
+        Self::from_makefile_at(dir.join("Makefile"), variables)
+    }
+
+    /// Look glyphs up in the font named by a variable in the given
+    /// Makefile. Paths are relative to the Makefile's directory.
+    pub fn from_makefile_at(makefile: impl AsRef<Path>, variables: &[&str]) -> Self {
+        let makefile = makefile.as_ref();
+        let dir = makefile.parent().unwrap_or(Path::new(""));
+        let values = std::fs::read_to_string(makefile)
+            .map(|contents| makefile_variables(&contents))
+            .unwrap_or_default();
+        let font = variables
+            .iter()
+            .filter_map(|v| values.get(*v))
+            .map(|path| dir.join(path))
+            .find(|path| path.exists())
+            .ok_or_else(|| variables.iter().map(|v| v.to_string()).collect());
+        Self {
+            font,
+            makefile: Some(makefile.to_path_buf()),
+            glyphs: vec![],
+            suffixes: vec![],
+        }
+    }
+
+    /// The font glyphs will be looked up in.
+    pub fn font(&self) -> Result<&Path, Error> {
+        self.font
+            .as_deref()
+            .map_err(|vars| Error::NoFont(vars.clone()))
+    }
+
+    /// Add a glyph, which must be in the font.
+    pub fn glyph(self, name: &str) -> Self {
+        self.glyph_as(&constant_name(name), name)
+    }
+
+    /// Add several glyphs, which must all be in the font.
+    pub fn glyphs(self, names: &[&str]) -> Self {
+        names.iter().fold(self, |ids, name| ids.glyph(name))
+    }
+
+    /// Add a glyph under a constant name of your choosing.
+    pub fn glyph_as(mut self, constant: &str, name: &str) -> Self {
+        self.glyphs.push((constant.to_string(), name.to_string()));
+        self
+    }
+
+    /// Add a map from each glyph to its variant with this suffix
+    /// (without the dot). The font must have at least one.
+    pub fn suffix(mut self, suffix: &str) -> Self {
+        self.suffixes.push(suffix.to_string());
+        self
+    }
+
+    /// Read the font and return the Rust source of the module.
+    pub fn generate(&self) -> Result<String, Error> {
+        let path = self.font()?;
+        let names = glyph_names(path)?;
+
+        let mut constants = BTreeMap::new();
+        let mut claim = |constant: &str| match constants.insert(constant.to_string(), ()) {
+            Some(_) => Err(Error::DuplicateConstant(constant.to_string())),
+            None => Ok(()),
+        };
+
+        let mut out = format!(
+            "// Generated by glyph-ids from {}. Do not edit.\n",
+            path.display()
+        );
+        let mut missing = vec![];
+        for (constant, name) in &self.glyphs {
+            claim(constant)?;
+            match names.get(name) {
+                Some(gid) => {
+                    let _ = write!(
+                        out,
+                        "\n/// `{}`\npub const {}: u32 = {};\n",
+                        name, constant, gid
+                    );
+                }
+                None => missing.push(name.clone()),
+            }
+        }
+        if !missing.is_empty() {
+            return Err(Error::MissingGlyphs(path.to_path_buf(), missing));
+        }
+
+        for suffix in &self.suffixes {
+            let constant = constant_name(suffix);
+            claim(&constant)?;
+            let ending = format!(".{}", suffix);
+            let mut pairs: Vec<(u16, u16)> = names
+                .iter()
+                .filter_map(|(name, &variant)| {
+                    let base = name.strip_suffix(&ending)?;
+                    Some((*names.get(base)?, variant))
+                })
+                .collect();
+            if pairs.is_empty() {
+                return Err(Error::MissingSuffix(path.to_path_buf(), suffix.clone()));
+            }
+            pairs.sort();
+            let _ = write!(
+                out,
+                "\n/// Each glyph with a `.{}` variant, and the variant, sorted by glyph ID.\npub const {}: &[(u32, u32)] = &[\n",
+                suffix, constant
+            );
+            for (base, variant) in pairs {
+                let _ = writeln!(out, "    ({}, {}),", base, variant);
+            }
+            out.push_str("];\n");
+        }
+        if !self.suffixes.is_empty() {
+            out.push_str(
+                "
+/// Find a glyph's variant in one of the maps above.
+#[allow(dead_code)]
+pub fn lookup(map: &[(u32, u32)], glyph: u32) -> Option<u32> {
+    let ix = map.binary_search_by_key(&glyph, |&(base, _)| base).ok()?;
+    Some(map[ix].1)
+}
+",
+            );
+        }
+        Ok(out)
+    }
+
+    /// Generate the module and write it to a file in `OUT_DIR`,
+    /// asking Cargo to run the build script again if the font or the
+    /// Makefile changes.
+    pub fn write(&self, file_name: &str) -> Result<(), Error> {
+        let source = self.generate()?;
+        let out_dir = PathBuf::from(std::env::var_os("OUT_DIR").unwrap_or_default());
+        let out = out_dir.join(file_name);
+        std::fs::write(&out, source).map_err(|e| Error::Io(out, e))?;
+        println!("cargo:rerun-if-changed={}", self.font()?.display());
+        if let Some(makefile) = &self.makefile {
+            println!("cargo:rerun-if-changed={}", makefile.display());
+        }
+        Ok(())
+    }
+
+    /// Like [`GlyphIds::write`], but fails the build with a readable
+    /// message if anything goes wrong. Call this from `build.rs`.
+    pub fn build(&self, file_name: &str) {
+        if let Err(e) = self.write(file_name) {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Reading the glyph order from a Glyphs source file.
+//!
+//! fontmake numbers the glyphs of the fonts it builds in the order
+//! they appear in the source, with `.notdef` moved to the front (or
+//! added there if the source hasn't got one) and glyphs which aren't
+//! exported left out. Reading the same order here means a shaper's
+//! glyph IDs can be generated before the font is built.
+
+/// The names of the glyphs fontmake would build from a Glyphs
+/// source, in glyph ID order.
+pub(crate) fn glyph_order(source: &str) -> Result<Vec<String>, String> {
+    // Glyphs are the dictionaries at the top level of the top-level
+    // `glyphs` list, so their keys are three levels deep.
+    let mut names = vec![];
+    let mut in_glyphs = false;
+    let mut glyph: Option<(Option<String>, bool)> = None;
+    for (depth, line) in lines_with_depth(source) {
+        let line = line.trim();
+        if depth == 1 {
+            in_glyphs = line.starts_with("glyphs = (");
+            continue;
+        }
+        if line == "name = glyphOrder;" {
+            return Err("its glyphOrder custom parameter isn't supported".to_string());
+        }
+        if !in_glyphs {
+            continue;
+        }
+        if depth == 2 && line.starts_with('{') {
+            if let Some((name, true)) = glyph.take() {
+                names.push(name.ok_or("a glyph has no name")?);
+            }
+            glyph = Some((None, true));
+        } else if depth == 3 {
+            let Some((name, export)) = glyph.as_mut() else {
+                continue;
+            };
+            if let Some(value) = line.strip_prefix("glyphname = ") {
+                *name = Some(unquote(value));
+            } else if line == "export = 0;" {
+                *export = false;
+            }
+        }
+    }
+    if let Some((name, true)) = glyph {
+        names.push(name.ok_or("a glyph has no name")?);
+    }
+    if names.is_empty() {
+        return Err("it has no glyphs".to_string());
+    }
+    names.retain(|name| name != ".notdef");
+    names.insert(0, ".notdef".to_string());
+    Ok(names)
+}
+
+// Each line of the source, with how deeply nested in brackets and
+// braces it starts. Brackets inside strings don't count.
+fn lines_with_depth(source: &str) -> Vec<(usize, &str)> {
+    let mut lines = vec![];
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for line in source.split('\n') {
+        lines.push((depth, line));
+        for c in line.chars() {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' if in_string => escaped = true,
+                '"' => in_string = !in_string,
+                '{' | '(' if !in_string => depth += 1,
+                '}' | ')' if !in_string => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+    }
+    lines
+}
+
+fn unquote(value: &str) -> String {
+    let value = value.trim_end_matches(';');
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value)
+        .to_string()
+}
@@ -0,0 +1,122 @@
+use glyph_ids::{constant_name, Error, GlyphIds};
+
+fn shadow() -> GlyphIds {
+    GlyphIds::from_makefile_at(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../shadow/Makefile"),
+        &["BASE_FONT"],
+    )
+}
+
+#[test]
+fn constant_names() {
+    assert_eq!(constant_name("space"), "SPACE");
+    assert_eq!(constant_name("A.shadow"), "A_SHADOW");
+    assert_eq!(constant_name(".notdef"), "NOTDEF");
+    assert_eq!(constant_name("uni000D"), "UNI000D");
+    assert_eq!(constant_name("1.half"), "GLYPH_1_HALF");
+}
+
+#[test]
+fn font_from_the_makefile() {
+    let ids = shadow();
+    assert!(ids
+        .font()
+        .unwrap()
+        .ends_with("base-fonts/DrShadow-Regular.ttf"));
+
+    // The first variable naming a file which exists wins.
+    let ids = GlyphIds::from_makefile_at(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../handwriting/Makefile"),
+        &["BASE_FONT", "TARGET_FONT"],
+    );
+    assert!(ids.font().unwrap().ends_with("Hand-Wasm.ttf"));
+
+    let ids = GlyphIds::from_makefile_at(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../shadow/Makefile"),
+        &["NO_SUCH_FONT"],
+    );
+    assert!(matches!(ids.font(), Err(Error::NoFont(_))));
+    assert!(matches!(ids.generate(), Err(Error::NoFont(_))));
+}
+
+#[test]
+fn constants_and_suffix_maps() {
+    let source = shadow()
+        .glyphs(&["space", "A"])
+        .glyph_as("NULL", ".null")
+        .suffix("shadow")
+        .generate()
+        .unwrap();
+    assert!(source.contains("pub const SPACE: u32 = 3;"));
+    assert!(source.contains("pub const A: u32 = 4;"));
+    assert!(source.contains("pub const NULL: u32 = 1;"));
+    assert!(source.contains("pub const SHADOW: &[(u32, u32)] = &[\n    (4, 30),\n"));
+    assert!(source.contains("    (29, 55),\n];"));
+    assert!(source.contains("pub fn lookup("));
+}
+
+#[test]
+fn missing_glyphs_are_errors() {
+    match shadow().glyphs(&["space", "dot", "comma"]).generate() {
+        Err(Error::MissingGlyphs(_, names)) => assert_eq!(names, vec!["dot", "comma"]),
+        other => panic!("{:?}", other),
+    }
+    assert!(matches!(
+        shadow().suffix("half").generate(),
+        Err(Error::MissingSuffix(_, s)) if s == "half"
+    ));
+    assert!(matches!(
+        shadow().glyph("A").glyph_as("A", "B").generate(),
+        Err(Error::DuplicateConstant(c)) if c == "A"
+    ));
+}
+
+// Every glyph's constant, as generated from `path`.
+fn all_constants(path: &str, font: &str) -> String {
+    let data = std::fs::read(font).unwrap();
+    let face = ttf_parser::Face::parse(&data, 0).unwrap();
+    let names: Vec<String> = (0..face.number_of_glyphs())
+        .map(|gid| {
+            face.glyph_name(ttf_parser::GlyphId(gid))
+                .unwrap()
+                .to_string()
+        })
+        .collect();
+    let mut ids = GlyphIds::new(path);
+    for (gid, name) in names.iter().enumerate() {
+        ids = ids.glyph_as(&format!("G{}", gid), name);
+    }
+    ids.generate().unwrap()
+}
+
+#[test]
+fn glyph_order_from_the_source() {
+    for (source, font) in [
+        (
+            "/../inception/Inception.glyphs",
+            "/../inception/Inception-VF.ttf",
+        ),
+        (
+            "/../handwriting/Hand.glyphs",
+            "/../handwriting/Hand-Wasm.ttf",
+        ),
+    ] {
+        let dir = env!("CARGO_MANIFEST_DIR");
+        let from_source = all_constants(&format!("{}{}", dir, source), &format!("{}{}", dir, font));
+        let from_font = all_constants(&format!("{}{}", dir, font), &format!("{}{}", dir, font));
+        assert_eq!(
+            from_source.lines().skip(1).collect::<Vec<_>>(),
+            from_font.lines().skip(1).collect::<Vec<_>>(),
+            "{}",
+            source
+        );
+    }
+
+    let ids = GlyphIds::from_makefile_at(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../inception/Makefile"),
+        &["TARGET_SOURCE"],
+    );
+    let source = ids.glyphs(&["space", "pixel3"]).generate().unwrap();
+    assert!(source.contains("pub const SPACE: u32 = 1;"));
+    assert!(source.contains("pub const PIXEL3: u32 = 4;"));
+}
@@ -0,0 +1,7 @@
+// Look up the glyphs the shaper adds, in the source the Makefile
+// builds the font from.
+fn main() {
+    glyph_ids::GlyphIds::from_makefile(&["SOURCE"])
+        .glyphs(&["space", "dot"])
+        .build("glyphs.rs");
+}
@@ -1,14 +1,31 @@
 #![allow(unstable_name_collisions)]
-use harfbuzz_wasm::{debug, Font, Glyph, GlyphBuffer};
+use harfbuzz_wasm::geometry::pen_positions;
+use harfbuzz_wasm::{debug, FeatureList, Font, Glyph, GlyphBuffer};
 use itertools::Itertools;
 use kurbo::{Affine, BezPath, ParamCurve, ParamCurveArclen, PathEl, Point};
 
 use wasm_bindgen::prelude::*;
 
-const SPACE_ID: u32 = 116;
-const DOT_ID: u32 = 119;
+// The IDs of the space and dot glyphs, generated from the font by
+// build.rs. These are the glyphs we add, unless the font names
+// others in its Wcfg table (as space_glyph and dot_glyph).
+mod glyphs {
+    include!(concat!(env!("OUT_DIR"), "/glyphs.rs"));
+}
+
+#[derive(Clone, Copy)]
+struct DotGlyphs {
+    space: u32,
+    dot: u32,
+}
 
-fn dot_sequence(glyphs: Vec<Glyph>, buffer: &mut Vec<Glyph>, font: &Font, dot_spacing: f64) {
+fn dot_sequence(
+    glyphs: Vec<Glyph>,
+    buffer: &mut Vec<Glyph>,
+    font: &Font,
+    ids: DotGlyphs,
+    dot_spacing: f64,
+) {
     if glyphs.is_empty() {
         return;
     }
@@ -22,11 +39,11 @@ fn dot_sequence(glyphs: Vec<Glyph>, buffer: &mut Vec<Glyph>, font: &Font, dot_sp
     ));
     let mut lines: Vec<BezPath> = vec![];
     let mut this_line: Vec<PathEl> = vec![];
-    let mut total_advance = 0;
+    let pens = pen_positions(&glyphs);
     let mut dot_positions: Vec<Point> = vec![];
-    for g in glyphs.iter() {
+    for (g, pen) in glyphs.iter().zip(&pens) {
         for (ix, mut p) in font.get_outline(g.codepoint).into_iter().enumerate() {
-            p.apply_affine(Affine::translate((total_advance as f64, 0.0)));
+            p.apply_affine(Affine::translate(pen.to_vec2()));
             let mut start_pt: Option<Point> = None;
 
             for el in p.elements().iter() {
@@ -54,7 +71,6 @@ fn dot_sequence(glyphs: Vec<Glyph>, buffer: &mut Vec<Glyph>, font: &Font, dot_sp
                 }
             }
         }
-        total_advance += g.x_advance;
     }
     lines.push(BezPath::from_vec(this_line));
 
@@ -100,25 +116,27 @@ fn dot_sequence(glyphs: Vec<Glyph>, buffer: &mut Vec<Glyph>, font: &Font, dot_sp
                 }
                 dot_positions.push(pt);
                 buffer.push(Glyph {
-                    codepoint: DOT_ID,
+                    codepoint: ids.dot,
                     cluster: glyphs[0].cluster,
                     x_advance: 0,
                     y_advance: 0,
                     x_offset: pt.x as i32,
                     y_offset: pt.y as i32,
                     flags: 0,
+                    ..Default::default()
                 })
             }
         }
     }
     buffer.push(Glyph {
-        codepoint: SPACE_ID,
+        codepoint: ids.space,
         cluster: glyphs[0].cluster,
-        x_advance: total_advance,
+        x_advance: pens[glyphs.len()].x as i32,
         y_advance: 0,
         x_offset: 0,
         y_offset: 0,
         flags: 0,
+        ..Default::default()
     })
 }
 
@@ -127,11 +145,12 @@ pub fn shape(
     _shape_plan: u32,
     font_ref: u32,
     buf_ref: u32,
-    _features: u32,
-    _num_features: u32,
+    features: u32,
+    num_features: u32,
 ) -> i32 {
     let font = Font::from_ref(font_ref);
-    font.shape_with(buf_ref, "ot");
+    let features = FeatureList::from_ref(features, num_features);
+    font.shape_with_features(buf_ref, "ot", &features.features);
     let face = font.get_face();
     // let mut paths = font.get_outline(glyph);
     let (x_scale, _y_scale) = font.get_scale();
@@ -141,10 +160,16 @@ pub fn shape(
     let old_buffer = std::mem::take(&mut buffer.glyphs);
     let mut cur_sequence: Vec<Glyph> = vec![];
 
-    let mut dot_width = font.get_glyph_extents(DOT_ID).width as f32 * 1.5 / scale_factor;
+    let config = font.config();
+    let ids = DotGlyphs {
+        space: config.glyph(&font, "space_glyph", glyphs::SPACE),
+        dot: config.glyph(&font, "dot_glyph", glyphs::DOT),
+    };
+    let mut dot_width = font.get_glyph_extents(ids.dot).width as f32 * 1.5 / scale_factor;
 
-    if let Some(dtsp) = font.get_var_coords().get(1) {
-        dot_width += *dtsp as f32 * 50.0 / scale_factor;
+    // Dot spacing runs from 0 to 100, adding up to 50 units.
+    if let Some(dtsp) = font.axis_value("DTSP") {
+        dot_width += dtsp * 0.5 / scale_factor;
     }
 
     for (ix, item) in old_buffer.iter().enumerate() {
@@ -163,6 +188,7 @@ pub fn shape(
                 cur_sequence,
                 &mut buffer.glyphs,
                 &font,
+                ids,
                 (dot_width * scale_factor).into(),
             );
             cur_sequence = vec![];
@@ -172,6 +198,7 @@ pub fn shape(
         cur_sequence,
         &mut buffer.glyphs,
         &font,
+        ids,
         (dot_width * scale_factor).into(),
     );
 
@@ -0,0 +1,239 @@
+//! The `BufferItem` derive for `harfbuzz-wasm`.
+//!
+//! Use it through the `derive` feature of `harfbuzz-wasm`, which
+//! re-exports it as `harfbuzz_wasm::BufferItem`.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident};
+
+// Fields which are copied to and from `CGlyphInfo`, as
+// (struct field, CGlyphInfo field).
+const INFO_FIELDS: &[(&str, &str)] = &[
+    ("codepoint", "codepoint"),
+    ("cluster", "cluster"),
+    ("mask", "mask"),
+    ("var1", "var1"),
+    ("var2", "var2"),
+];
+
+// Fields which are copied to and from `CGlyphPosition`.
+const POSITION_FIELDS: &[(&str, &str)] = &[
+    ("x_advance", "x_advance"),
+    ("y_advance", "y_advance"),
+    ("x_offset", "x_offset"),
+    ("y_offset", "y_offset"),
+    ("pos_var", "var"),
+];
+
+/// Derive `BufferItem` for a struct with named fields.
+///
+/// Fields called `codepoint`, `cluster`, `mask`, `var1`, `var2`,
+/// `x_advance`, `y_advance`, `x_offset`, `y_offset` and `pos_var`
+/// are copied to and from the matching Harfbuzz fields, just as for
+/// `Glyph`. Any other field is set to its `Default` when the buffer
+/// is read, and ignored when it is written back.
+///
+/// Mark a `CGlyphInfo` field with `#[buffer_item(info)]`, or a
+/// `CGlyphPosition` field with `#[buffer_item(position)]`, to keep
+/// the raw data Harfbuzz gave you. It is written back as it was,
+/// with the named fields above laid over it, so that private data
+/// you have no field for survives a round trip. Without one, the
+/// fields you don't have are written back as zero.
+///
+/// If the struct has a `cluster` field, `ClusterItem` is derived
+/// too, so the cluster-safe buffer editing methods can be used. If it
+/// has `codepoint` and all four advance and offset fields,
+/// `PositionedItem` is derived, so it can be laid out with the
+/// `geometry` module.
+#[proc_macro_derive(BufferItem, attributes(buffer_item))]
+pub fn derive_buffer_item(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+enum Raw {
+    Info,
+    Position,
+}
+
+fn raw_attribute(field: &syn::Field) -> syn::Result<Option<Raw>> {
+    let mut raw = None;
+    for attr in field.attrs.iter() {
+        if !attr.path().is_ident("buffer_item") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("info") {
+                raw = Some(Raw::Info);
+                Ok(())
+            } else if meta.path.is_ident("position") {
+                raw = Some(Raw::Position);
+                Ok(())
+            } else {
+                Err(meta.error("expected `info` or `position`"))
+            }
+        })?;
+    }
+    Ok(raw)
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "BufferItem can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "BufferItem can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut raw_info: Option<&Ident> = None;
+    let mut raw_position: Option<&Ident> = None;
+    let mut inits = vec![];
+    let mut info_writes = vec![];
+    let mut position_writes = vec![];
+    let mut has_cluster = false;
+    // codepoint and the four advance and offset fields
+    let mut positioned_fields = 0;
+    for field in fields.iter() {
+        let ident = field.ident.as_ref().unwrap();
+        let field_name = ident.to_string();
+        match raw_attribute(field)? {
+            Some(Raw::Info) => {
+                if raw_info.replace(ident).is_some() {
+                    return Err(syn::Error::new_spanned(
+                        ident,
+                        "only one field can keep the raw info",
+                    ));
+                }
+                inits.push(quote! { #ident: info.clone() });
+                continue;
+            }
+            Some(Raw::Position) => {
+                if raw_position.replace(ident).is_some() {
+                    return Err(syn::Error::new_spanned(
+                        ident,
+                        "only one field can keep the raw position",
+                    ));
+                }
+                inits.push(quote! { #ident: pos.clone() });
+                continue;
+            }
+            None => {}
+        }
+        if let Some((_, c_field)) = INFO_FIELDS.iter().find(|(f, _)| *f == field_name) {
+            let c_field = Ident::new(c_field, ident.span());
+            inits.push(quote! { #ident: info.#c_field });
+            info_writes.push(quote! { info.#c_field = self.#ident; });
+            has_cluster |= field_name == "cluster";
+            if field_name == "codepoint" {
+                positioned_fields += 1;
+            }
+        } else if let Some((_, c_field)) = POSITION_FIELDS.iter().find(|(f, _)| *f == field_name) {
+            let c_field = Ident::new(c_field, ident.span());
+            inits.push(quote! { #ident: pos.#c_field });
+            position_writes.push(quote! { pos.#c_field = self.#ident; });
+            if field_name != "pos_var" {
+                positioned_fields += 1;
+            }
+        } else {
+            inits.push(quote! { #ident: ::core::default::Default::default() });
+        }
+    }
+
+    let info_start = match raw_info {
+        Some(ident) => quote! { self.#ident },
+        None => quote! {
+            ::harfbuzz_wasm::CGlyphInfo {
+                codepoint: 0,
+                mask: 0,
+                cluster: 0,
+                var1: 0,
+                var2: 0,
+            }
+        },
+    };
+    let position_start = match raw_position {
+        Some(ident) => quote! { self.#ident },
+        None => quote! {
+            ::harfbuzz_wasm::CGlyphPosition {
+                x_advance: 0,
+                y_advance: 0,
+                x_offset: 0,
+                y_offset: 0,
+                var: 0,
+            }
+        },
+    };
+
+    let cluster_item = if has_cluster {
+        quote! {
+            impl #impl_generics ::harfbuzz_wasm::ClusterItem for #name #ty_generics #where_clause {
+                fn cluster(&self) -> u32 {
+                    self.cluster
+                }
+                fn set_cluster(&mut self, cluster: u32) {
+                    self.cluster = cluster
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let positioned_item = if positioned_fields == 5 {
+        quote! {
+            impl #impl_generics ::harfbuzz_wasm::PositionedItem for #name #ty_generics #where_clause {
+                fn glyph_id(&self) -> u32 {
+                    self.codepoint
+                }
+                fn advance(&self) -> (i32, i32) {
+                    (self.x_advance, self.y_advance)
+                }
+                fn offset(&self) -> (i32, i32) {
+                    (self.x_offset, self.y_offset)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::harfbuzz_wasm::BufferItem for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn from_c(
+                info: ::harfbuzz_wasm::CGlyphInfo,
+                pos: ::harfbuzz_wasm::CGlyphPosition,
+            ) -> Self {
+                Self {
+                    #(#inits,)*
+                }
+            }
+            #[allow(unused_mut)]
+            fn to_c(self) -> (::harfbuzz_wasm::CGlyphInfo, ::harfbuzz_wasm::CGlyphPosition) {
+                let mut info = #info_start;
+                let mut pos = #position_start;
+                #(#info_writes)*
+                #(#position_writes)*
+                (info, pos)
+            }
+        }
+        #cluster_item
+        #positioned_item
+    })
+}
@@ -0,0 +1,103 @@
+//! The functions Harfbuzz provides to WASM shapers, and the one it
+//! expects them to provide.
+//!
+//! Harfbuzz provides nothing else, so a shaper which imports any
+//! other function won't work. Tools which check a shaper before it
+//! is put in a font use this list to do so.
+//!
+//! The list is Harfbuzz's table of native symbols, from
+//! `src/hb-wasm-api-list.hh`, in the same order and with the same
+//! signature strings. When Harfbuzz adds a function, add it here
+//! before importing it in this crate; the crate's tests check that
+//! everything it imports is listed.
+//!
+//! The signatures use the WASM runtime's notation: `i` is a 32-bit
+//! integer, `$` a pointer to a string, `*` a pointer to a buffer and
+//! `~` that buffer's length. Every one of them is an `i32` in WASM.
+
+/// The WASM module Harfbuzz's functions are imported from.
+pub const HOST_MODULE: &str = "env";
+
+/// A function called across the boundary between Harfbuzz and a
+/// shaper.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AbiFunction {
+    /// Its name
+    pub name: &'static str,
+    /// Its signature, as Harfbuzz registers it, such as `"(iii)i"`
+    pub signature: &'static str,
+    /// How many `i32` parameters it takes
+    pub params: usize,
+    /// Whether it returns an `i32`
+    pub returns: bool,
+}
+
+const fn f(signature: &'static str, name: &'static str) -> AbiFunction {
+    let bytes = signature.as_bytes();
+    let mut close = 0;
+    while bytes[close] != b')' {
+        close += 1;
+    }
+    AbiFunction {
+        name,
+        signature,
+        params: close - 1,
+        returns: close + 1 < bytes.len(),
+    }
+}
+
+/// Every function Harfbuzz provides to shapers.
+///
+/// `debugprint` and its relatives are only provided by Harfbuzz
+/// builds with WASM debugging turned on; elsewhere, calling them
+/// fails.
+pub const HOST_FUNCTIONS: &[AbiFunction] = &[
+    // common
+    f("(i)i", "script_get_horizontal_direction"),
+    // blob
+    f("(i)", "blob_free"),
+    f("(ii)i", "blob_face_create"),
+    // buffer
+    f("(i)", "buffer_contents_free"),
+    f("(ii)i", "buffer_contents_realloc"),
+    f("(ii)i", "buffer_copy_contents"),
+    f("(ii)i", "buffer_set_contents"),
+    f("(i)i", "buffer_get_direction"),
+    f("(i)i", "buffer_get_script"),
+    f("(i)", "buffer_reverse"),
+    f("(i)", "buffer_reverse_clusters"),
+    // face
+    f("(iii)i", "face_copy_table"),
+    f("(i)i", "face_get_upem"),
+    f("(i)i", "face_create_font"),
+    // font
+    f("(i)i", "font_get_face"),
+    f("(iii)", "font_get_scale"),
+    f("(iii)i", "font_get_glyph"),
+    f("(ii)i", "font_get_glyph_h_advance"),
+    f("(ii)i", "font_get_glyph_v_advance"),
+    f("(iii)i", "font_get_glyph_extents"),
+    f("(ii*~)", "font_glyph_to_string"),
+    f("(iii)i", "font_copy_glyph_outline"),
+    f("(ii)i", "font_copy_coords"),
+    f("(ii)i", "font_set_coords"),
+    // outline
+    f("(i)", "glyph_outline_free"),
+    // shape
+    f("(iiii$)i", "shape_with"),
+    // debug
+    f("($)", "debugprint"),
+    f("($i)", "debugprint1"),
+    f("($ii)", "debugprint2"),
+    f("($iii)", "debugprint3"),
+    f("($iiii)", "debugprint4"),
+];
+
+/// Look up a function Harfbuzz provides by name.
+pub fn host_function(name: &str) -> Option<&'static AbiFunction> {
+    HOST_FUNCTIONS.iter().find(|f| f.name == name)
+}
+
+/// The function a shaper must export, which Harfbuzz calls to shape
+/// a buffer.
+pub const SHAPE_EXPORT: AbiFunction = f("(iiiii)i", "shape");
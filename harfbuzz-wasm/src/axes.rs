@@ -0,0 +1,188 @@
+use crate::cache::cached_table;
+use crate::tables::{avar, fvar};
+use crate::{tag_to_string, Face, Font, HbWasmError};
+
+/// A variation axis of a font, as described by its `fvar` table.
+///
+/// Values in user space (the units shown to the user, such as
+/// weights from 100 to 900) are converted to the normalized
+/// coordinates Harfbuzz works in using the axis range and the
+/// font's `avar` mapping.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Axis {
+    /// The axis tag, such as `wght`
+    pub tag: String,
+    /// The minimum user-space value
+    pub min: f32,
+    /// The default user-space value
+    pub default: f32,
+    /// The maximum user-space value
+    pub max: f32,
+    /// Should the axis be hidden from the user?
+    pub hidden: bool,
+    // The avar segment map, as (from, to) pairs; empty if none.
+    map: Vec<(f32, f32)>,
+}
+
+impl Axis {
+    /// Convert a user-space value to a normalized coordinate.
+    pub fn normalize(&self, value: f32) -> f32 {
+        let value = value.clamp(self.min, self.max);
+        let coord = if value < self.default {
+            (value - self.default) / (self.default - self.min)
+        } else if value > self.default {
+            (value - self.default) / (self.max - self.default)
+        } else {
+            0.0
+        };
+        piecewise_linear(coord, self.map.iter().copied())
+    }
+
+    /// Convert a normalized coordinate to a user-space value.
+    pub fn denormalize(&self, coord: f32) -> f32 {
+        let coord = piecewise_linear(coord, self.map.iter().map(|&(from, to)| (to, from)));
+        if coord < 0.0 {
+            self.default + coord * (self.default - self.min)
+        } else {
+            self.default + coord * (self.max - self.default)
+        }
+    }
+}
+
+// Map a value through a list of (from, to) points, interpolating
+// between them. Values outside the list are moved by the same
+// amount as the nearest point.
+fn piecewise_linear(value: f32, map: impl Iterator<Item = (f32, f32)>) -> f32 {
+    let mut previous: Option<(f32, f32)> = None;
+    for (from, to) in map {
+        if value <= from {
+            return match previous {
+                Some((prev_from, prev_to)) if from > prev_from => {
+                    prev_to + (value - prev_from) * (to - prev_to) / (from - prev_from)
+                }
+                _ => value + to - from,
+            };
+        }
+        previous = Some((from, to));
+    }
+    match previous {
+        Some((from, to)) => value + to - from,
+        None => value,
+    }
+}
+
+impl Face {
+    /// List the variation axes of the face, in `fvar` order.
+    ///
+    /// This is empty if the face is not a variable font.
+    pub fn axes(&self) -> Vec<Axis> {
+        let Some(fvar_blob) = cached_table(self, "fvar") else {
+            return vec![];
+        };
+        let Ok(fvar) = fvar_blob.parse::<fvar::Table>() else {
+            return vec![];
+        };
+        let mut maps: Vec<Vec<(f32, f32)>> = cached_table(self, "avar")
+            .as_ref()
+            .and_then(|blob| blob.parse::<avar::Table>().ok())
+            .map(|avar| {
+                avar.segment_maps
+                    .into_iter()
+                    .map(|segments| {
+                        segments
+                            .into_iter()
+                            .map(|m| {
+                                (
+                                    m.from_coordinate as f32 / 16384.0,
+                                    m.to_coordinate as f32 / 16384.0,
+                                )
+                            })
+                            .collect()
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        maps.resize(fvar.axes.len() as usize, vec![]);
+        fvar.axes
+            .into_iter()
+            .zip(maps)
+            .map(|(axis, map)| Axis {
+                tag: tag_to_string(axis.tag.0),
+                min: axis.min_value,
+                default: axis.def_value,
+                max: axis.max_value,
+                hidden: axis.hidden,
+                map,
+            })
+            .collect()
+    }
+}
+
+impl Font {
+    /// Get the current user-space value of a variation axis, or
+    /// `None` if the font has no such axis.
+    pub fn axis_value(&self, tag: &str) -> Option<f32> {
+        self.axis_values()
+            .into_iter()
+            .find(|(t, _)| t == tag)
+            .map(|(_, value)| value)
+    }
+
+    /// Get the current user-space value of every variation axis,
+    /// in `fvar` order.
+    pub fn axis_values(&self) -> Vec<(String, f32)> {
+        let coords = self.try_get_var_coords().unwrap_or_default();
+        self.get_face()
+            .axes()
+            .into_iter()
+            .enumerate()
+            .map(|(ix, axis)| {
+                let value = axis.denormalize(coords.get(ix).copied().unwrap_or(0.0));
+                (axis.tag, value)
+            })
+            .collect()
+    }
+
+    /// Set user-space values for some of the variation axes, by tag.
+    /// Axes which aren't mentioned keep their current values.
+    ///
+    /// Returns an error if the font doesn't have one of the axes.
+    pub fn set_axis_values(&self, values: &[(&str, f32)]) -> Result<(), HbWasmError> {
+        let axes = self.get_face().axes();
+        let mut coords = self.try_get_var_coords()?;
+        coords.resize(axes.len(), 0.0);
+        for (tag, value) in values {
+            let ix = axes
+                .iter()
+                .position(|axis| axis.tag == *tag)
+                .ok_or_else(|| HbWasmError::AxisNotFound(tag.to_string()))?;
+            coords[ix] = axes[ix].normalize(*value);
+        }
+        self.try_set_var_coords(&coords)
+    }
+
+    /// Set the user-space value of one variation axis.
+    pub fn set_axis_value(&self, tag: &str, value: f32) -> Result<(), HbWasmError> {
+        self.set_axis_values(&[(tag, value)])
+    }
+
+    /// Give this font the same user-space axis values as another,
+    /// matching axes by tag. Axes which only one of the fonts has
+    /// are left alone.
+    ///
+    /// This is useful for fonts embedded inside other fonts, whose
+    /// axes may be in a different order.
+    pub fn copy_axis_values_from(&self, other: &Font) -> Result<(), HbWasmError> {
+        let ours: Vec<String> = self.get_face().axes().into_iter().map(|a| a.tag).collect();
+        let values = other.axis_values();
+        let shared: Vec<(&str, f32)> = values
+            .iter()
+            .filter(|(tag, _)| ours.contains(tag))
+            .map(|(tag, value)| (tag.as_str(), *value))
+            .collect();
+        if shared.is_empty() {
+            return Ok(());
+        }
+        self.set_axis_values(&shared)
+    }
+}
@@ -0,0 +1,146 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use crate::{str_to_tag, Blob, Face, Font};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum CacheKey {
+    Face(u32),
+    FaceAtScale(u32),
+    ShapePlan(u32),
+}
+
+#[derive(Debug)]
+struct CacheEntry<T> {
+    coords: Vec<f32>,
+    // Only checked for values which depend on the font's size
+    scale: Option<(i32, i32)>,
+    value: Rc<T>,
+}
+
+/// Shaper state which persists from one call to `shape` to the next.
+///
+/// Harfbuzz keeps the WASM instance alive between calls, so anything
+/// expensive to work out (glyph name maps, outlines, and so on) can
+/// be worked out once and reused. Values are stored either per face
+/// or per shape plan, and are thrown away and rebuilt whenever the
+/// font's variation coordinates change.
+///
+/// WASM shapers are single-threaded, so the cache lives in a
+/// `thread_local!`:
+///
+/// ```rust,ignore
+/// thread_local! {
+///     static NAMES: ShaperCache<BTreeMap<String, u32>> = const { ShaperCache::new() };
+/// }
+///
+/// let names = NAMES.with(|cache| cache.get_or_insert_with(&font, || build_names(&font)));
+/// ```
+#[derive(Debug)]
+pub struct ShaperCache<T> {
+    entries: RefCell<BTreeMap<CacheKey, CacheEntry<T>>>,
+}
+
+impl<T> ShaperCache<T> {
+    /// Create an empty cache.
+    pub const fn new() -> Self {
+        Self {
+            entries: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    /// Return the value stored for the font's face, calling `f` to
+    /// create it if there isn't one yet or the variation coordinates
+    /// have changed since it was made.
+    pub fn get_or_insert_with(&self, font: &Font, f: impl FnOnce() -> T) -> Rc<T> {
+        self.get_or_insert(CacheKey::Face(font.get_face().0), font, None, f)
+    }
+
+    /// Like [`ShaperCache::get_or_insert_with`], but the value is
+    /// also rebuilt when the font's scale changes.
+    ///
+    /// Use this for anything measured in the font's scaled units,
+    /// such as outlines, since those change when the text is drawn
+    /// at a different size.
+    pub fn get_or_insert_with_scale(&self, font: &Font, f: impl FnOnce() -> T) -> Rc<T> {
+        let key = CacheKey::FaceAtScale(font.get_face().0);
+        self.get_or_insert(key, font, Some(font.get_scale()), f)
+    }
+
+    /// Return the value stored for a shape plan, calling `f` to create
+    /// it if there isn't one yet or the variation coordinates have
+    /// changed since it was made.
+    ///
+    /// Use this for state which depends on the features the user
+    /// asked for, since Harfbuzz makes a new shape plan for each set
+    /// of features.
+    pub fn get_or_insert_with_plan(
+        &self,
+        shape_plan: u32,
+        font: &Font,
+        f: impl FnOnce() -> T,
+    ) -> Rc<T> {
+        self.get_or_insert(CacheKey::ShapePlan(shape_plan), font, None, f)
+    }
+
+    /// Throw away everything in the cache.
+    pub fn clear(&self) {
+        self.entries.borrow_mut().clear()
+    }
+
+    fn get_or_insert(
+        &self,
+        key: CacheKey,
+        font: &Font,
+        scale: Option<(i32, i32)>,
+        f: impl FnOnce() -> T,
+    ) -> Rc<T> {
+        let coords = font.try_get_var_coords().unwrap_or_default();
+        if let Some(entry) = self.entries.borrow().get(&key) {
+            if entry.coords == coords && entry.scale == scale {
+                return entry.value.clone();
+            }
+        }
+        // Don't hold the borrow while `f` runs, in case it uses the
+        // cache too.
+        let value = Rc::new(f());
+        self.entries.borrow_mut().insert(
+            key,
+            CacheEntry {
+                coords,
+                scale,
+                value: value.clone(),
+            },
+        );
+        value
+    }
+}
+
+impl<T> Default for ShaperCache<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// A table, or `None` if the face doesn't have it.
+type CachedTable = Option<Rc<Blob>>;
+
+thread_local! {
+    // Tables read by this crate's own queries, keyed by face pointer
+    // and tag, so that Harfbuzz only copies each one to us once.
+    static TABLES: RefCell<BTreeMap<(u32, u32), CachedTable>> =
+        const { RefCell::new(BTreeMap::new()) };
+}
+
+/// Return a table of the face, fetching it from Harfbuzz only the
+/// first time it is asked for.
+pub(crate) fn cached_table(face: &Face, tag: &str) -> CachedTable {
+    let key = (face.0, str_to_tag(tag));
+    if let Some(table) = TABLES.with(|tables| tables.borrow().get(&key).cloned()) {
+        return table;
+    }
+    let table = face.try_reference_table(tag).ok().map(Rc::new);
+    TABLES.with(|tables| tables.borrow_mut().insert(key, table.clone()));
+    table
+}
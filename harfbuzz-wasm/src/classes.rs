@@ -0,0 +1,152 @@
+use ttf_parser::{GlyphId, NormalizedCoordinate};
+
+use crate::cache::cached_table;
+use crate::tables::gdef;
+use crate::{ot_round, Font};
+
+pub use gdef::GlyphClass;
+
+// Big-endian readers for the parts of GDEF which ttf-parser doesn't
+// expose. Offsets past the end of the data read as `None`.
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_i16(data: &[u8], offset: usize) -> Option<i16> {
+    read_u16(data, offset).map(|v| v as i16)
+}
+
+// Follow a 16-bit offset stored at `at` to the data it points to,
+// relative to the start of `data`. A zero offset means "nothing".
+fn follow(data: &[u8], at: usize) -> Option<&[u8]> {
+    match read_u16(data, at)? {
+        0 => None,
+        offset => data.get(offset as usize..),
+    }
+}
+
+// The index of a glyph in a coverage table, if it is covered.
+fn coverage_index(coverage: &[u8], glyph: u16) -> Option<u16> {
+    let count = read_u16(coverage, 2)? as usize;
+    match read_u16(coverage, 0)? {
+        1 => {
+            let (mut lo, mut hi) = (0, count);
+            while lo < hi {
+                let mid = (lo + hi) / 2;
+                let g = read_u16(coverage, 4 + mid * 2)?;
+                match g.cmp(&glyph) {
+                    std::cmp::Ordering::Less => lo = mid + 1,
+                    std::cmp::Ordering::Greater => hi = mid,
+                    std::cmp::Ordering::Equal => return Some(mid as u16),
+                }
+            }
+            None
+        }
+        2 => (0..count).find_map(|ix| {
+            let record = 4 + ix * 6;
+            let (start, end) = (read_u16(coverage, record)?, read_u16(coverage, record + 2)?);
+            (start..=end)
+                .contains(&glyph)
+                .then(|| read_u16(coverage, record + 4).map(|first| first + glyph - start))?
+        }),
+        _ => None,
+    }
+}
+
+impl Font {
+    /// Get the class of a glyph from the `GDEF` table: base,
+    /// ligature, mark or component.
+    ///
+    /// Returns `None` if the font has no glyph classes, or doesn't
+    /// give this glyph one.
+    pub fn glyph_class(&self, glyph: u32) -> Option<GlyphClass> {
+        let blob = cached_table(&self.get_face(), "GDEF")?;
+        let table: gdef::Table = blob.parse().ok()?;
+        table.glyph_class(GlyphId(glyph as u16))
+    }
+
+    /// Is the glyph a mark, according to the `GDEF` table?
+    pub fn is_mark(&self, glyph: u32) -> bool {
+        self.glyph_class(glyph) == Some(GlyphClass::Mark)
+    }
+
+    /// Get the mark attachment class of a glyph from the `GDEF`
+    /// table. Glyphs which aren't given a class are in class zero.
+    pub fn mark_attachment_class(&self, glyph: u32) -> u16 {
+        cached_table(&self.get_face(), "GDEF")
+            .and_then(|blob| {
+                let table: gdef::Table = blob.parse().ok()?;
+                Some(table.glyph_mark_attachment_class(GlyphId(glyph as u16)))
+            })
+            .unwrap_or(0)
+    }
+
+    /// Is the glyph in the `GDEF` mark glyph set with the given index?
+    pub fn is_in_mark_glyph_set(&self, glyph: u32, set: u16) -> bool {
+        cached_table(&self.get_face(), "GDEF")
+            .and_then(|blob| {
+                let table: gdef::Table = blob.parse().ok()?;
+                Some(table.is_mark_glyph(GlyphId(glyph as u16), Some(set)))
+            })
+            .unwrap_or(false)
+    }
+
+    /// Get the caret positions inside a ligature glyph from the
+    /// `GDEF` table, scaled to the font and adjusted for its
+    /// variation coordinates.
+    ///
+    /// There is one caret between each pair of components, so a
+    /// ligature of three characters has two. Carets which the font
+    /// places on outline points rather than at coordinates are left
+    /// out. Returns an empty list for glyphs which aren't ligatures.
+    pub fn ligature_carets(&self, glyph: u32) -> Vec<i32> {
+        self.try_ligature_carets(glyph).unwrap_or_default()
+    }
+
+    fn try_ligature_carets(&self, glyph: u32) -> Option<Vec<i32>> {
+        let blob = cached_table(&self.get_face(), "GDEF")?;
+        let data = blob.as_bytes();
+        let table: gdef::Table = blob.parse().ok()?;
+        let lig_caret_list = follow(data, 8)?;
+        let index = coverage_index(follow(lig_caret_list, 0)?, glyph as u16)?;
+        let lig_glyph = follow(lig_caret_list, 4 + index as usize * 2)?;
+        let caret_count = read_u16(lig_glyph, 0)? as usize;
+
+        let coords: Vec<NormalizedCoordinate> = self
+            .try_get_var_coords()
+            .unwrap_or_default()
+            .into_iter()
+            .map(NormalizedCoordinate::from)
+            .collect();
+        let (x_scale, _) = self.get_scale();
+        let upem = self.get_face().get_upem() as f32;
+
+        let mut carets = vec![];
+        for ix in 0..caret_count {
+            let Some(caret) = follow(lig_glyph, 2 + ix * 2) else {
+                continue;
+            };
+            let coordinate = match read_u16(caret, 0)? {
+                1 => read_i16(caret, 2)? as f32,
+                3 => {
+                    let mut coordinate = read_i16(caret, 2)? as f32;
+                    // Only variation deltas are applied; hinting
+                    // device tables depend on the pixel size.
+                    if let Some(device) = follow(caret, 4) {
+                        if read_u16(device, 4) == Some(0x8000) {
+                            let (outer, inner) = (read_u16(device, 0)?, read_u16(device, 2)?);
+                            coordinate += table
+                                .glyph_variation_delta(outer, inner, &coords)
+                                .unwrap_or(0.0);
+                        }
+                    }
+                    coordinate
+                }
+                _ => continue,
+            };
+            carets.push(ot_round(coordinate * x_scale as f32 / upem));
+        }
+        Some(carets)
+    }
+}
@@ -0,0 +1,290 @@
+//! Shaper settings stored in the font, in a `Wcfg` table.
+//!
+//! A shaper's tuning (how tightly to kern, how far to move a
+//! colliding mark, which glyph to use for a dot) usually lives in
+//! constants, so changing it means rebuilding the WASM binary. If
+//! the shaper reads them from the font instead, one binary can be
+//! shared between fonts and each font's designer can adjust it:
+//!
+//! ```rust,ignore
+//! let config = font.config();
+//! let kern_distance = config.float("kern_distance", 300.0);
+//! ```
+//!
+//! Every lookup takes a default, which is used if the font has no
+//! `Wcfg` table or doesn't set that key. `bin/wcfg` compiles a
+//! text file of settings into the table, ready for `otfsurgeon add`.
+//!
+//! # Table format
+//!
+//! All values are big-endian, and offsets are from the start of the
+//! table.
+//!
+//! | Type     | Name                   | Description                          |
+//! |----------|------------------------|--------------------------------------|
+//! | `uint16` | majorVersion           | 1                                    |
+//! | `uint16` | minorVersion           | 0                                    |
+//! | `uint16` | axisCount              | Axes in each region, in `fvar` order |
+//! | `uint16` | regionCount            |                                      |
+//! | `uint16` | entryCount             |                                      |
+//! | `uint16` | deltaCount             |                                      |
+//! | `Region` | `regions[regionCount]` |                                      |
+//! | `Entry`  | `entries[entryCount]`  |                                      |
+//! | `Delta`  | `deltas[deltaCount]`   |                                      |
+//! | `uint8`  | `strings[]`            | UTF-8 keys and string values         |
+//!
+//! A `Region` is `axisCount` triples of `F2DOT14` start, peak and end
+//! coordinates, exactly as in an OpenType `ItemVariationStore`.
+//!
+//! An `Entry` is 14 bytes:
+//!
+//! | Type       | Name          | Description                            |
+//! |------------|---------------|----------------------------------------|
+//! | `uint16`   | keyOffset     | Offset of the key in `strings`         |
+//! | `uint16`   | keyLength     | Length of the key in bytes             |
+//! | `uint16`   | type          | 1 = `int32`, 2 = `Fixed`, 3 = string   |
+//! | `uint32`   | value         | The default value; for strings, a `uint16` offset into `strings` followed by a `uint16` length |
+//! | `uint16`   | firstDelta    | Index of the entry's first delta       |
+//! | `uint16`   | deltaCount    | How many deltas the entry has          |
+//!
+//! A `Delta` is a `uint16` region index followed by a value of the
+//! same type as its entry's (`int32` or `Fixed`). At any variation
+//! coordinates, a number is its default plus each of its deltas
+//! scaled by how much of the delta's region applies, as OpenType
+//! does for the values in `GPOS` and `GDEF`. Strings can't vary.
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use crate::cache::cached_table;
+use crate::{ot_round, Font, HbWasmError, ShaperCache};
+
+/// A value from a font's [`Config`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigValue {
+    /// A whole number
+    Int(i32),
+    /// A number with a fractional part
+    Float(f32),
+    /// Some text, such as a glyph name
+    String(String),
+}
+
+/// Shaper settings from the font's `Wcfg` table, at the font's
+/// variation coordinates.
+///
+/// See the [module documentation](crate::config) for the table
+/// format.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Config {
+    values: BTreeMap<String, ConfigValue>,
+}
+
+// The numeric values stored in a table, before variations are
+// applied.
+#[derive(Clone, Copy)]
+enum Raw {
+    Int(i32),
+    Fixed(i32),
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+// How much of a region applies at the given coordinates, from zero
+// to one. Axes the font doesn't have are at their default.
+fn region_scalar(region: &[[f32; 3]], coords: &[f32]) -> f32 {
+    let mut scalar = 1.0;
+    for (ix, &[start, peak, end]) in region.iter().enumerate() {
+        let coord = coords.get(ix).copied().unwrap_or(0.0);
+        if start > peak || peak > end || (start < 0.0 && end > 0.0) || peak == 0.0 {
+            continue;
+        }
+        if coord == peak {
+            continue;
+        }
+        if coord <= start || coord >= end {
+            return 0.0;
+        }
+        scalar *= if coord < peak {
+            (coord - start) / (peak - start)
+        } else {
+            (end - coord) / (end - peak)
+        };
+    }
+    scalar
+}
+
+impl Config {
+    /// Read the contents of a `Wcfg` table, applying its variations
+    /// at the given normalized coordinates.
+    ///
+    /// Returns `None` if the data isn't a version 1 table, or if
+    /// anything in it points outside the data.
+    pub fn from_bytes(data: &[u8], coords: &[f32]) -> Option<Self> {
+        if read_u16(data, 0)? != 1 {
+            return None;
+        }
+        let axis_count = read_u16(data, 4)? as usize;
+        let region_count = read_u16(data, 6)? as usize;
+        let entry_count = read_u16(data, 8)? as usize;
+        let delta_count = read_u16(data, 10)? as usize;
+        let regions_start = 12;
+        let entries_start = regions_start + region_count * axis_count * 6;
+        let deltas_start = entries_start + entry_count * 14;
+        let strings = data.get(deltas_start + delta_count * 6..)?;
+        let string = |offset: u16, length: u16| {
+            let bytes = strings.get(offset as usize..offset as usize + length as usize)?;
+            String::from_utf8(bytes.to_vec()).ok()
+        };
+
+        let scalars = (0..region_count)
+            .map(|region| {
+                let axes = (0..axis_count)
+                    .map(|axis| {
+                        let at = regions_start + (region * axis_count + axis) * 6;
+                        let coord = |ix: usize| {
+                            read_u16(data, at + ix * 2).map(|v| v as i16 as f32 / 16384.0)
+                        };
+                        Some([coord(0)?, coord(1)?, coord(2)?])
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                Some(region_scalar(&axes, coords))
+            })
+            .collect::<Option<Vec<f32>>>()?;
+
+        let mut values = BTreeMap::new();
+        for ix in 0..entry_count {
+            let at = entries_start + ix * 14;
+            let key = string(read_u16(data, at)?, read_u16(data, at + 2)?)?;
+            let value = read_u32(data, at + 6)?;
+            let raw = match read_u16(data, at + 4)? {
+                1 => Raw::Int(value as i32),
+                2 => Raw::Fixed(value as i32),
+                3 => {
+                    let value = string((value >> 16) as u16, value as u16)?;
+                    values.insert(key, ConfigValue::String(value));
+                    continue;
+                }
+                _ => return None,
+            };
+            let first_delta = read_u16(data, at + 10)? as usize;
+            let mut total = match raw {
+                Raw::Int(v) => v as f32,
+                Raw::Fixed(v) => v as f32 / 65536.0,
+            };
+            for delta in first_delta..first_delta + read_u16(data, at + 12)? as usize {
+                if delta >= delta_count {
+                    return None;
+                }
+                let at = deltas_start + delta * 6;
+                let scalar = *scalars.get(read_u16(data, at)? as usize)?;
+                let delta = read_u32(data, at + 2)? as i32 as f32;
+                total += scalar
+                    * match raw {
+                        Raw::Int(_) => delta,
+                        Raw::Fixed(_) => delta / 65536.0,
+                    };
+            }
+            let value = match raw {
+                Raw::Int(_) => ConfigValue::Int(ot_round(total)),
+                Raw::Fixed(_) => ConfigValue::Float(total),
+            };
+            values.insert(key, value);
+        }
+        Some(Config { values })
+    }
+
+    /// Get the value for a key, if the font sets it.
+    pub fn get(&self, key: &str) -> Option<&ConfigValue> {
+        self.values.get(key)
+    }
+
+    /// Does the font set a value for this key?
+    pub fn contains(&self, key: &str) -> bool {
+        self.values.contains_key(key)
+    }
+
+    /// The keys the font sets values for, in sorted order.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.values.keys().map(|k| k.as_str())
+    }
+
+    /// Get a whole number, or `default` if the font doesn't set one.
+    /// Numbers with a fractional part are rounded.
+    pub fn int(&self, key: &str, default: i32) -> i32 {
+        match self.get(key) {
+            Some(ConfigValue::Int(v)) => *v,
+            Some(ConfigValue::Float(v)) => ot_round(*v),
+            _ => default,
+        }
+    }
+
+    /// Get a number, or `default` if the font doesn't set one.
+    pub fn float(&self, key: &str, default: f32) -> f32 {
+        match self.get(key) {
+            Some(ConfigValue::Int(v)) => *v as f32,
+            Some(ConfigValue::Float(v)) => *v,
+            _ => default,
+        }
+    }
+
+    /// Get a string, or `default` if the font doesn't set one.
+    pub fn string<'a>(&'a self, key: &str, default: &'a str) -> &'a str {
+        match self.get(key) {
+            Some(ConfigValue::String(v)) => v,
+            _ => default,
+        }
+    }
+
+    /// Get a glyph ID. The font can give either the ID itself or the
+    /// glyph's name; if it gives neither, gives a negative ID, or
+    /// names a glyph which isn't there, `default` is returned.
+    pub fn glyph(&self, font: &Font, key: &str, default: u32) -> u32 {
+        match self.get(key) {
+            Some(ConfigValue::Int(v)) => u32::try_from(*v).unwrap_or(default),
+            Some(ConfigValue::String(name)) => font.get_glyph_from_name(name).unwrap_or(default),
+            _ => default,
+        }
+    }
+}
+
+thread_local! {
+    static CONFIGS: ShaperCache<Result<Rc<Config>, HbWasmError>> = const { ShaperCache::new() };
+}
+
+impl Font {
+    /// Read the shaper settings in the font's `Wcfg` table, at the
+    /// font's current variation coordinates.
+    ///
+    /// If the font has no such table, or it can't be read, every
+    /// lookup returns its default; see [`Font::try_config`] to find
+    /// out why. The table is only copied from Harfbuzz once per
+    /// face, and only parsed again when the coordinates change, so
+    /// this is cheap to call on every shape.
+    pub fn config(&self) -> Rc<Config> {
+        self.try_config().unwrap_or_default()
+    }
+
+    /// Read the shaper settings in the font's `Wcfg` table, or return
+    /// an error if there is no such table or it can't be parsed.
+    pub fn try_config(&self) -> Result<Rc<Config>, HbWasmError> {
+        let config = CONFIGS.with(|cache| cache.get_or_insert_with(self, || self.read_config()));
+        (*config).clone()
+    }
+
+    fn read_config(&self) -> Result<Rc<Config>, HbWasmError> {
+        let blob = cached_table(&self.get_face(), "Wcfg")
+            .ok_or_else(|| HbWasmError::TableNotFound("Wcfg".to_string()))?;
+        let coords = self.try_get_var_coords()?;
+        Config::from_bytes(blob.as_bytes(), &coords)
+            .map(Rc::new)
+            .ok_or_else(|| HbWasmError::TableMalformed("Wcfg".to_string()))
+    }
+}
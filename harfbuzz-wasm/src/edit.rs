@@ -0,0 +1,185 @@
+use std::ops::Range;
+
+use crate::{Buffer, BufferItem, ClusterLevel, Direction, Glyph, HbWasmError};
+
+/// Buffer items which know which cluster they belong to.
+///
+/// Implement this for your own glyph structs to use the cluster-safe
+/// editing methods on [`Buffer`].
+pub trait ClusterItem {
+    /// The cluster this item belongs to
+    fn cluster(&self) -> u32;
+    /// Move this item into a different cluster
+    fn set_cluster(&mut self, cluster: u32);
+}
+
+impl ClusterItem for Glyph {
+    fn cluster(&self) -> u32 {
+        self.cluster
+    }
+    fn set_cluster(&mut self, cluster: u32) {
+        self.cluster = cluster
+    }
+}
+
+/// Buffer items which know their glyph and how it is positioned.
+///
+/// Implement this for your own glyph structs to lay them out with
+/// the `geometry` module.
+pub trait PositionedItem {
+    /// The glyph ID
+    fn glyph_id(&self) -> u32;
+    /// The glyph's (x, y) advance
+    fn advance(&self) -> (i32, i32);
+    /// The glyph's (x, y) offset from its pen position
+    fn offset(&self) -> (i32, i32);
+}
+
+impl PositionedItem for Glyph {
+    fn glyph_id(&self) -> u32 {
+        self.codepoint
+    }
+    fn advance(&self) -> (i32, i32) {
+        (self.x_advance, self.y_advance)
+    }
+    fn offset(&self) -> (i32, i32) {
+        (self.x_offset, self.y_offset)
+    }
+}
+
+/// Check that a run of cluster values is in order for the
+/// direction and cluster level of a buffer.
+pub(crate) fn check_clusters(
+    clusters: impl Iterator<Item = u32>,
+    direction: Direction,
+    level: ClusterLevel,
+) -> Result<(), HbWasmError> {
+    if !level.is_monotone() {
+        return Ok(());
+    }
+    let mut previous: Option<u32> = None;
+    for (ix, cluster) in clusters.enumerate() {
+        if let Some(previous) = previous {
+            let backwards = if direction.is_backward() {
+                cluster > previous
+            } else {
+                cluster < previous
+            };
+            if backwards {
+                return Err(HbWasmError::ClustersOutOfOrder(ix));
+            }
+        }
+        previous = Some(cluster);
+    }
+    Ok(())
+}
+
+impl<T: BufferItem> Buffer<T> {
+    /// The cluster level the buffer is being shaped with.
+    ///
+    /// Harfbuzz doesn't tell the shaper this, so it is Harfbuzz's
+    /// default unless you set it with [`Buffer::set_cluster_level`].
+    pub fn cluster_level(&self) -> ClusterLevel {
+        self.cluster_level
+    }
+
+    /// Set the cluster level used by the editing methods.
+    pub fn set_cluster_level(&mut self, level: ClusterLevel) {
+        self.cluster_level = level
+    }
+}
+
+impl<T: BufferItem + ClusterItem> Buffer<T> {
+    /// Merge the clusters of the glyphs in a range into one.
+    ///
+    /// As in Harfbuzz, the range is first widened so that it doesn't
+    /// split any cluster, and every glyph in it is then given the
+    /// lowest cluster value. Clusters aren't merged if the cluster
+    /// level isn't monotone.
+    pub fn merge_clusters(&mut self, range: Range<usize>) {
+        let Range { mut start, mut end } = range;
+        end = end.min(self.glyphs.len());
+        if end <= start + 1 || !self.cluster_level.is_monotone() {
+            return;
+        }
+        let cluster = self.glyphs[start..end]
+            .iter()
+            .map(|g| g.cluster())
+            .min()
+            .unwrap_or(0);
+        while end < self.glyphs.len()
+            && self.glyphs[end - 1].cluster() == self.glyphs[end].cluster()
+        {
+            end += 1;
+        }
+        while start > 0 && self.glyphs[start - 1].cluster() == self.glyphs[start].cluster() {
+            start -= 1;
+        }
+        for glyph in self.glyphs[start..end].iter_mut() {
+            glyph.set_cluster(cluster);
+        }
+    }
+
+    /// Replace a range of glyphs with some new ones.
+    ///
+    /// The clusters of the old glyphs are merged, and the new glyphs
+    /// all go into the merged cluster. If the range is empty, the new
+    /// glyphs join the cluster of the glyph before them (or after
+    /// them, at the start of the buffer).
+    pub fn replace_range(&mut self, range: Range<usize>, mut glyphs: Vec<T>) {
+        let Range { start, end } = range;
+        let end = end.min(self.glyphs.len());
+        let start = start.min(end);
+        let cluster = if start == end {
+            start
+                .checked_sub(1)
+                .and_then(|ix| self.glyphs.get(ix))
+                .or_else(|| self.glyphs.get(end))
+                .map(|g| g.cluster())
+        } else {
+            self.merge_clusters(start..end);
+            // Without merging, the result takes the cluster of the
+            // first glyph it replaces.
+            if self.cluster_level.is_monotone() {
+                self.glyphs[start..end].iter().map(|g| g.cluster()).min()
+            } else {
+                Some(self.glyphs[start].cluster())
+            }
+        };
+        if let Some(cluster) = cluster {
+            for glyph in glyphs.iter_mut() {
+                glyph.set_cluster(cluster);
+            }
+        }
+        self.glyphs.splice(start..end, glyphs);
+    }
+
+    /// Insert a glyph after the glyph at `ix`, in the same cluster.
+    pub fn insert_after(&mut self, ix: usize, glyph: T) {
+        let ix = (ix + 1).min(self.glyphs.len());
+        self.replace_range(ix..ix, vec![glyph]);
+    }
+
+    /// Replace a range of glyphs with a single ligature glyph.
+    pub fn ligate(&mut self, range: Range<usize>, glyph: T) {
+        self.replace_range(range, vec![glyph]);
+    }
+
+    /// Replace the glyph at `ix` with several glyphs, all in its cluster.
+    pub fn decompose(&mut self, ix: usize, glyphs: Vec<T>) {
+        self.replace_range(ix..ix + 1, glyphs);
+    }
+
+    /// Check that the cluster values of the glyphs are in order for
+    /// the buffer's direction and cluster level.
+    ///
+    /// In debug builds this is also checked when the buffer is sent
+    /// back to Harfbuzz, and any problem is written to the debug log.
+    pub fn validate_clusters(&self) -> Result<(), HbWasmError> {
+        check_clusters(
+            self.glyphs.iter().map(|g| g.cluster()),
+            self.direction(),
+            self.cluster_level,
+        )
+    }
+}
@@ -0,0 +1,60 @@
+use std::fmt;
+
+/// Things that can go wrong when talking to Harfbuzz.
+///
+/// The `try_` variants of this crate's functions return these
+/// instead of panicking. A panic inside a shaper traps the WASM
+/// instance and no text is shaped at all, so a shaper which can
+/// carry on without something (perhaps by falling back to
+/// `font.shape_with(buf_ref, "ot")`) should use them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HbWasmError {
+    /// The face has no table with the given tag
+    TableNotFound(String),
+    /// The table with the given tag couldn't be parsed
+    TableMalformed(String),
+    /// Harfbuzz couldn't copy the buffer contents to us
+    BufferCopyFailed,
+    /// Harfbuzz wouldn't accept new buffer contents
+    BufferSetFailed,
+    /// A blob couldn't be turned into a face
+    FaceCreationFailed,
+    /// Harfbuzz couldn't copy the variation coordinates to us
+    CoordsCopyFailed,
+    /// Harfbuzz wouldn't accept new variation coordinates
+    CoordsSetFailed,
+    /// The font has no variation axis with the given tag
+    AxisNotFound(String),
+    /// Cluster values go backwards at the glyph with this index
+    ClustersOutOfOrder(usize),
+    /// Harfbuzz couldn't copy the outline of the glyph with this ID
+    OutlineCopyFailed(u32),
+    /// The outline of the glyph with this ID doesn't make sense
+    OutlineMalformed(u32),
+}
+
+impl fmt::Display for HbWasmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HbWasmError::TableNotFound(tag) => write!(f, "Couldn't reference table '{}'", tag),
+            HbWasmError::TableMalformed(tag) => write!(f, "Couldn't parse table '{}'", tag),
+            HbWasmError::BufferCopyFailed => write!(f, "Couldn't copy buffer contents"),
+            HbWasmError::BufferSetFailed => write!(f, "Couldn't set buffer contents"),
+            HbWasmError::FaceCreationFailed => write!(f, "Couldn't create face from blob"),
+            HbWasmError::CoordsCopyFailed => write!(f, "Couldn't copy variation coordinates"),
+            HbWasmError::CoordsSetFailed => write!(f, "Couldn't set variation coordinates"),
+            HbWasmError::AxisNotFound(tag) => write!(f, "No variation axis '{}'", tag),
+            HbWasmError::ClustersOutOfOrder(ix) => {
+                write!(f, "Cluster values go backwards at glyph {}", ix)
+            }
+            HbWasmError::OutlineCopyFailed(glyph) => {
+                write!(f, "Couldn't copy the outline of glyph {}", glyph)
+            }
+            HbWasmError::OutlineMalformed(glyph) => {
+                write!(f, "The outline of glyph {} is malformed", glyph)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HbWasmError {}
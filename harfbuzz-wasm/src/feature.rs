@@ -0,0 +1,139 @@
+use std::ops::Range;
+
+use crate::str_to_tag;
+
+/// The cluster value used to mark the end of a global feature setting.
+pub const FEATURE_GLOBAL_END: u32 = u32::MAX;
+
+/// An OpenType feature setting requested by the user.
+///
+/// This is equivalent to Harfbuzz's `hb_feature_t`, and shares its
+/// memory layout, so it can be passed straight back to Harfbuzz.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Feature {
+    /// The feature tag (see [`str_to_tag`])
+    pub tag: u32,
+    /// Zero turns the feature off, one turns it on, and larger
+    /// values select alternates
+    pub value: u32,
+    /// The first cluster this setting applies to
+    pub start: u32,
+    /// The cluster after the last one this setting applies to
+    pub end: u32,
+}
+
+impl Feature {
+    /// Create a feature setting which applies to the whole buffer.
+    pub fn new(tag: &str, value: u32) -> Self {
+        Self {
+            tag: str_to_tag(tag),
+            value,
+            start: 0,
+            end: FEATURE_GLOBAL_END,
+        }
+    }
+
+    /// Create a feature setting which applies to a range of clusters.
+    pub fn with_range(tag: &str, value: u32, clusters: Range<u32>) -> Self {
+        Self {
+            tag: str_to_tag(tag),
+            value,
+            start: clusters.start,
+            end: clusters.end,
+        }
+    }
+
+    /// Does this setting apply to the whole buffer?
+    pub fn is_global(&self) -> bool {
+        self.start == 0 && self.end == FEATURE_GLOBAL_END
+    }
+
+    /// Does this setting apply to any of the clusters in the range?
+    pub fn overlaps(&self, clusters: &Range<u32>) -> bool {
+        // An empty range is treated as the single cluster at its start.
+        let end = clusters.end.max(clusters.start.saturating_add(1));
+        self.start < end && clusters.start < self.end
+    }
+}
+
+/// The list of feature settings passed to a shaper.
+///
+/// Settings are kept in the order Harfbuzz gave them to us; as
+/// in Harfbuzz, later settings override earlier ones.
+#[derive(Debug, Clone, Default)]
+pub struct FeatureList {
+    /// The feature settings
+    pub features: Vec<Feature>,
+}
+
+impl FeatureList {
+    /// Decode the feature array Harfbuzz passes to the `shape` function.
+    ///
+    /// ```rust,ignore
+    /// #[wasm_bindgen]
+    /// pub fn shape(_shape_plan: u32, font_ref: u32, buf_ref: u32, features: u32, num_features: u32) -> i32 {
+    ///     let features = FeatureList::from_ref(features, num_features);
+    ///     let font = Font::from_ref(font_ref);
+    ///     font.shape_with_features(buf_ref, "ot", &features.features);
+    ///     1
+    /// }
+    /// ```
+    pub fn from_ref(ptr: u32, count: u32) -> Self {
+        if ptr == 0 || count == 0 {
+            return Self::default();
+        }
+        #[cfg(not(feature = "native-host"))]
+        let features =
+            unsafe { std::slice::from_raw_parts(ptr as *const Feature, count as usize) }.to_vec();
+        #[cfg(feature = "native-host")]
+        let features = crate::native::features_from_ref(ptr, count);
+        Self { features }
+    }
+
+    /// Return the value of a feature at a given cluster, if the user
+    /// set it.
+    pub fn value_at(&self, tag: &str, cluster: u32) -> Option<u32> {
+        let tag = str_to_tag(tag);
+        self.features
+            .iter()
+            .rev()
+            .find(|f| f.tag == tag && f.start <= cluster && cluster < f.end)
+            .map(|f| f.value)
+    }
+
+    /// Is a feature turned on for a range of clusters?
+    ///
+    /// If the user did not mention the feature for any of these
+    /// clusters, `default` is returned. If several settings overlap
+    /// the range, the last one wins.
+    pub fn is_enabled(&self, tag: &str, clusters: Range<u32>, default: bool) -> bool {
+        let tag = str_to_tag(tag);
+        self.features
+            .iter()
+            .rev()
+            .find(|f| f.tag == tag && f.overlaps(&clusters))
+            .map(|f| f.value != 0)
+            .unwrap_or(default)
+    }
+
+    /// Has the user turned a feature off for a range of clusters?
+    ///
+    /// This is the question to ask for features which are on by
+    /// default, such as `kern`.
+    pub fn is_disabled(&self, tag: &str, clusters: Range<u32>) -> bool {
+        !self.is_enabled(tag, clusters, true)
+    }
+
+    /// Set a feature for the whole buffer, overriding any earlier
+    /// settings of the same feature.
+    pub fn set(&mut self, tag: &str, value: u32) {
+        self.features.push(Feature::new(tag, value))
+    }
+
+    /// Remove all settings of a feature.
+    pub fn remove(&mut self, tag: &str) {
+        let tag = str_to_tag(tag);
+        self.features.retain(|f| f.tag != tag)
+    }
+}
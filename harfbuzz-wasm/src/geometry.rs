@@ -0,0 +1,235 @@
+//! Where glyphs end up once they are positioned.
+//!
+//! Many shapers need to know where each glyph in a buffer is drawn:
+//! to kern by measuring the gap between outlines, or to move marks
+//! until they stop colliding with their neighbours. This module
+//! works out pen positions from the glyphs' advances, and moves
+//! bounding boxes and outlines into place.
+//!
+//! Positions are in the order of the buffer, starting from zero.
+//! Once Harfbuzz has shaped right-to-left text the buffer is in
+//! visual order, so the first glyph is the leftmost one (the end of
+//! the text), and positions still run from left to right.
+//!
+//! ```rust,ignore
+//! let outlines = OutlineCache::new();
+//! let placed = buffer.positioned_outlines(&font, &outlines);
+//! if placed[0].bounding_box().intersect(placed[1].bounding_box()).area() > 0.0 {
+//!     // ... look more closely ...
+//! }
+//! ```
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use kurbo::{Affine, BezPath, Point, Rect, Shape, Vec2};
+
+use crate::{Buffer, BufferItem, Font, PositionedItem};
+
+/// The pen position of each glyph: where the pen is before the
+/// glyph's offset is applied.
+///
+/// There is one more position than there are glyphs; the last is
+/// where the pen finishes, so its `x` is the total advance.
+pub fn pen_positions<T: PositionedItem>(glyphs: &[T]) -> Vec<Point> {
+    let mut pen = Point::ZERO;
+    let mut positions = Vec::with_capacity(glyphs.len() + 1);
+    positions.push(pen);
+    for glyph in glyphs {
+        let (x, y) = glyph.advance();
+        pen += Vec2::new(x as f64, y as f64);
+        positions.push(pen);
+    }
+    positions
+}
+
+/// Where a glyph's origin is drawn, given its pen position.
+pub fn glyph_origin<T: PositionedItem>(glyph: &T, pen: Point) -> Point {
+    let (x, y) = glyph.offset();
+    pen + Vec2::new(x as f64, y as f64)
+}
+
+/// The bounding box of a glyph drawn at a pen position, from the
+/// font's glyph extents.
+pub fn bounding_box<T: PositionedItem>(font: &Font, glyph: &T, pen: Point) -> Rect {
+    let extents = font.get_glyph_extents(glyph.glyph_id());
+    let origin = glyph_origin(glyph, pen);
+    let x0 = origin.x + extents.x_bearing as f64;
+    let y0 = origin.y + extents.y_bearing as f64;
+    Rect::from_points(
+        (x0, y0),
+        (x0 + extents.width as f64, y0 + extents.height as f64),
+    )
+}
+
+// The union of the bounding boxes of some paths.
+fn bounds_of(paths: &[BezPath]) -> Rect {
+    paths
+        .iter()
+        .map(|p| p.bounding_box())
+        .reduce(|a, b| a.union(b))
+        .unwrap_or_default()
+}
+
+/// The outline of a glyph, in font units relative to its origin.
+#[derive(Debug, Clone, Default)]
+pub struct GlyphOutline {
+    /// The closed paths making up the glyph
+    pub paths: Vec<BezPath>,
+    /// The bounding box of all the paths
+    pub bounds: Rect,
+}
+
+impl GlyphOutline {
+    /// Make an outline from a list of paths.
+    pub fn new(paths: Vec<BezPath>) -> Self {
+        let bounds = bounds_of(&paths);
+        Self { paths, bounds }
+    }
+
+    /// Is the glyph blank, like a space?
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// A copy of the paths moved so the glyph's origin is at `origin`.
+    pub fn positioned_paths(&self, origin: Point) -> Vec<BezPath> {
+        let affine = Affine::translate(origin.to_vec2());
+        self.paths.iter().map(|p| affine * p).collect()
+    }
+}
+
+impl From<Vec<BezPath>> for GlyphOutline {
+    fn from(paths: Vec<BezPath>) -> Self {
+        Self::new(paths)
+    }
+}
+
+/// Glyph outlines, read from the font once per glyph ID and then
+/// shared between every glyph in the buffer which uses them.
+///
+/// Outlines are stored as [`GlyphOutline`]s unless you ask for some
+/// other type which can be made from a list of paths, such as an
+/// outline prepared for collision tests.
+///
+/// Outlines depend on the font's variation coordinates, so don't
+/// keep a cache longer than a single shaping call unless you keep it
+/// in a [`ShaperCache`](crate::ShaperCache).
+#[derive(Debug)]
+pub struct OutlineCache<T = GlyphOutline> {
+    padding: Option<f64>,
+    outlines: RefCell<BTreeMap<u32, Rc<T>>>,
+}
+
+impl<T> Default for OutlineCache<T> {
+    fn default() -> Self {
+        Self {
+            padding: None,
+            outlines: RefCell::new(BTreeMap::new()),
+        }
+    }
+}
+
+impl<T: From<Vec<BezPath>>> OutlineCache<T> {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty cache whose outlines are scaled up by `scale`
+    /// (for example, `1.1` for ten percent) around their centres.
+    ///
+    /// This gives glyphs a little breathing space when they are
+    /// tested for collisions.
+    pub fn with_padding(scale: f64) -> Self {
+        Self {
+            padding: Some(scale),
+            ..Self::default()
+        }
+    }
+
+    /// Get the outline of a glyph, reading it from the font if it
+    /// isn't already cached.
+    pub fn get(&self, font: &Font, glyph: u32) -> Rc<T> {
+        self.outlines
+            .borrow_mut()
+            .entry(glyph)
+            .or_insert_with(|| {
+                let mut paths = font.get_outline(glyph);
+                if let Some(scale) = self.padding {
+                    let centre = bounds_of(&paths).center().to_vec2();
+                    let affine = Affine::translate(centre)
+                        * Affine::scale(scale)
+                        * Affine::translate(-centre);
+                    for path in paths.iter_mut() {
+                        path.apply_affine(affine);
+                    }
+                }
+                Rc::new(T::from(paths))
+            })
+            .clone()
+    }
+
+    /// Forget all the cached outlines.
+    pub fn clear(&self) {
+        self.outlines.borrow_mut().clear()
+    }
+}
+
+/// A glyph outline moved to where the glyph is drawn.
+///
+/// The outline itself is shared; only the origin belongs to this
+/// glyph.
+#[derive(Debug, Clone)]
+pub struct PositionedOutline {
+    /// The outline, relative to the glyph's origin
+    pub outline: Rc<GlyphOutline>,
+    /// Where the glyph's origin is drawn
+    pub origin: Point,
+}
+
+impl PositionedOutline {
+    /// The bounding box of the glyph where it is drawn.
+    pub fn bounding_box(&self) -> Rect {
+        self.outline.bounds + self.origin.to_vec2()
+    }
+
+    /// The glyph's paths where it is drawn.
+    pub fn paths(&self) -> Vec<BezPath> {
+        self.outline.positioned_paths(self.origin)
+    }
+}
+
+impl<T: BufferItem + PositionedItem> Buffer<T> {
+    /// The pen position of each glyph, plus where the pen finishes.
+    /// See [`pen_positions`].
+    pub fn pen_positions(&self) -> Vec<Point> {
+        pen_positions(&self.glyphs)
+    }
+
+    /// The bounding box of each glyph where it is drawn, from the
+    /// font's glyph extents.
+    pub fn bounding_boxes(&self, font: &Font) -> Vec<Rect> {
+        self.glyphs
+            .iter()
+            .zip(self.pen_positions())
+            .map(|(glyph, pen)| bounding_box(font, glyph, pen))
+            .collect()
+    }
+
+    /// The outline of each glyph where it is drawn.
+    pub fn positioned_outlines(
+        &self,
+        font: &Font,
+        outlines: &OutlineCache,
+    ) -> Vec<PositionedOutline> {
+        self.glyphs
+            .iter()
+            .zip(self.pen_positions())
+            .map(|(glyph, pen)| PositionedOutline {
+                outline: outlines.get(font, glyph.glyph_id()),
+                origin: glyph_origin(glyph, pen),
+            })
+            .collect()
+    }
+}
@@ -9,22 +9,67 @@
 //! structures. For example, here is a basic shaping engine:
 //!
 //!
-//! ```rust
+//! ```rust,ignore
 //! #[wasm_bindgen]
-//! pub fn shape(font_ref: u32, buf_ref: u32) -> i32 {
+//! pub fn shape(_shape_plan: u32, font_ref: u32, buf_ref: u32, _features: u32, _num_features: u32) -> i32 {
 //!     let font = Font::from_ref(font_ref);
 //!     let mut buffer = GlyphBuffer::from_ref(buf_ref);
 //!     for mut item in buffer.glyphs.iter_mut() {
 //!         // Map character to glyph
-//!         item.codepoint = font.get_glyph(codepoint, 0);
+//!         item.codepoint = font.get_glyph(item.codepoint, 0);
 //!         // Set advance width
-//!         item.h_advance = font.get_glyph_h_advance(item.codepoint);
+//!         item.x_advance = font.get_glyph_h_advance(item.codepoint);
 //!     }
 //!     1
 //! }
 //! ```
+use std::cell::RefCell;
 use std::ffi::{c_int, CStr, CString};
-
+use std::ops::Range;
+
+pub mod abi;
+#[cfg(feature = "tables")]
+mod axes;
+mod cache;
+#[cfg(feature = "tables")]
+mod classes;
+pub mod config;
+mod edit;
+mod error;
+mod feature;
+#[cfg(feature = "kurbo")]
+pub mod geometry;
+mod names;
+mod outline;
+#[cfg(feature = "tables")]
+mod positioning;
+mod segment;
+mod shaper;
+#[cfg(feature = "tables")]
+pub mod tables;
+mod text;
+#[cfg(feature = "unicode")]
+pub mod unicode;
+#[cfg(feature = "tables")]
+pub use axes::Axis;
+pub use cache::ShaperCache;
+#[cfg(feature = "tables")]
+pub use classes::GlyphClass;
+pub use config::{Config, ConfigValue};
+pub use edit::{ClusterItem, PositionedItem};
+pub use error::HbWasmError;
+pub use feature::{Feature, FeatureList, FEATURE_GLOBAL_END};
+#[cfg(feature = "derive")]
+pub use harfbuzz_wasm_derive::BufferItem;
+pub use outline::{OutlinePen, OutlinePoint, PointKind, RawOutline};
+#[cfg(feature = "tables")]
+pub use positioning::{Adjustment, Anchor, CursiveAnchors, MarkAttachment};
+pub use segment::{ClusterLevel, Direction};
+pub use shaper::{run_shaper, ShapeResult};
+pub use text::BufferText;
+
+/// Round a value to the nearest integer the way OpenType does
+/// (that is, halves are rounded towards positive infinity).
 pub fn ot_round<T>(value: T) -> i32
 where
     T: Into<f64>,
@@ -32,13 +77,34 @@ where
     (value.into() as f32 + 0.5_f32).floor() as i32
 }
 
+/// Convert an OpenType tag such as `"kern"` into its numeric form.
+///
+/// Tags shorter than four characters are padded with spaces.
+pub fn str_to_tag(tag: &str) -> u32 {
+    tag.bytes()
+        .chain(std::iter::repeat(b' '))
+        .take(4)
+        .fold(0, |acc, b| (acc << 8) | b as u32)
+}
+
+/// Convert a numeric OpenType tag back into a string.
+pub fn tag_to_string(tag: u32) -> String {
+    tag.to_be_bytes().iter().map(|&b| b as char).collect()
+}
+
 #[cfg(feature = "kurbo")]
 use kurbo::BezPath;
 
+#[cfg(feature = "native-host")]
+pub mod native;
+#[cfg(feature = "native-host")]
+use native::*;
+
 // We don't use #[wasm_bindgen] here because that makes
 // assumptions about Javascript calling conventions. We
 // really do just want to import some C symbols and run
 // them in unsafe-land!
+#[cfg(not(feature = "native-host"))]
 extern "C" {
     fn face_get_upem(face: u32) -> u32;
     fn font_get_face(font: u32) -> u32;
@@ -50,9 +116,12 @@ extern "C" {
     fn font_get_glyph_h_advance(font: u32, glyph: u32) -> i32;
     fn font_get_glyph_v_advance(font: u32, glyph: u32) -> i32;
     fn font_copy_glyph_outline(font: u32, glyph: u32, outline: *mut CGlyphOutline) -> bool;
+    fn glyph_outline_free(outline: *mut CGlyphOutline);
     fn face_copy_table(font: u32, tag: u32, blob: *mut Blob) -> bool;
     fn buffer_copy_contents(buffer: u32, cbuffer: *mut CBufferContents) -> bool;
     fn buffer_set_contents(buffer: u32, cbuffer: &CBufferContents) -> bool;
+    fn buffer_get_direction(buffer: u32) -> u32;
+    fn buffer_get_script(buffer: u32) -> u32;
     fn font_copy_coords(font: u32, coords: *mut CCoords) -> bool;
     fn font_set_coords(font: u32, coords: &CCoords) -> bool;
     fn debugprint(s: *const u8);
@@ -84,13 +153,60 @@ impl Font {
     /// run standard OpenType shaping, allowing you to modify
     /// the buffer contents after glyph mapping, substitution
     /// and positioning has taken place.
+    ///
+    /// No feature settings are passed on; if you want the user's
+    /// choices of features to be respected, use
+    /// [`Font::shape_with_features`].
     pub fn shape_with(&self, buffer_ref: u32, shaper: &str) {
+        self.shape_with_features(buffer_ref, shaper, &[])
+    }
+
+    /// Call the given Harfbuzz shaper on a buffer reference,
+    /// with a list of feature settings.
+    ///
+    /// Normally you will want to pass on the features given to your
+    /// `shape` function (see [`FeatureList::from_ref`]), perhaps after
+    /// adding or removing some of your own.
+    pub fn shape_with_features(&self, buffer_ref: u32, shaper: &str, features: &[Feature]) {
         let c_shaper = CString::new(shaper).unwrap();
+        #[cfg(not(feature = "native-host"))]
+        let features_ref = features.as_ptr() as u32;
+        #[cfg(feature = "native-host")]
+        let features_ref = native::features_to_ref(features);
         unsafe {
-            shape_with(self.0, buffer_ref, 0, 0, c_shaper.as_ptr() as *const u8);
+            shape_with(
+                self.0,
+                buffer_ref,
+                features_ref,
+                features.len() as u32,
+                c_shaper.as_ptr() as *const u8,
+            );
         }
     }
 
+    /// Run a Harfbuzz shaper on a buffer you are already working on.
+    ///
+    /// Your changes to the buffer are sent to Harfbuzz first, and
+    /// afterwards the buffer holds the shaper's output, so you can
+    /// process the text both before and after OpenType shaping:
+    ///
+    /// ```rust,ignore
+    /// let mut buffer = GlyphBuffer::from_ref(buf_ref);
+    /// buffer.glyphs.retain(|g| g.codepoint != 0x200B);
+    /// font.shape_buffer(&mut buffer, "ot", &features.features)?;
+    /// buffer.glyphs[0].x_offset += 100;
+    /// ```
+    pub fn shape_buffer<T: BufferItem>(
+        &self,
+        buffer: &mut Buffer<T>,
+        shaper: &str,
+        features: &[Feature],
+    ) -> Result<(), HbWasmError> {
+        buffer.commit()?;
+        self.shape_with_features(buffer._ptr, shaper, features);
+        buffer.reload()
+    }
+
     /// Return the font face object that this font belongs to.
     pub fn get_face(&self) -> Face {
         Face(unsafe { font_get_face(self.0) })
@@ -132,9 +248,9 @@ impl Font {
     /// If no names are provided by the font, names of the form
     /// `gidXXX` are constructed.
     pub fn get_glyph_name(&self, glyph: u32) -> String {
-        let mut s = [1u8; 32];
+        let mut s = [1u8; 64];
         unsafe {
-            font_glyph_to_string(self.0, glyph, s.as_mut_ptr(), 32);
+            font_glyph_to_string(self.0, glyph, s.as_mut_ptr(), 64);
         }
         unsafe { CStr::from_ptr(s.as_ptr() as *const _) }
             .to_str()
@@ -142,6 +258,16 @@ impl Font {
             .to_string()
     }
 
+    /// Find a glyph ID by name.
+    ///
+    /// The first call for a face builds an index of all the glyph
+    /// names in the font, which is kept for later calls. Names are
+    /// those returned by [`Font::get_glyph_name`], so `gidXXX` names
+    /// work for fonts without glyph names.
+    pub fn get_glyph_from_name(&self, name: &str) -> Option<u32> {
+        names::name_index(self).get(name).copied()
+    }
+
     /// Get the X and Y scale factor applied to this font.
     ///
     /// This should be divided by the units per em value to
@@ -160,87 +286,115 @@ impl Font {
         (x_scale, y_scale)
     }
 
+    /// Draw the outline of a glyph with a pen.
+    ///
+    /// See [`RawOutline`] for a pen which just collects the points,
+    /// or [`Font::get_outline`] (with the `kurbo` feature) for paths.
+    ///
+    /// Panics if Harfbuzz can't provide the outline or it doesn't
+    /// make sense; see [`Font::try_draw_glyph`].
+    pub fn draw_glyph(&self, glyph: u32, pen: &mut impl OutlinePen) {
+        self.try_draw_glyph(glyph, pen)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Draw the outline of a glyph with a pen, or return an error if
+    /// Harfbuzz can't provide it or it doesn't make sense.
+    ///
+    /// Nothing is drawn if there is an error.
+    pub fn try_draw_glyph(&self, glyph: u32, pen: &mut impl OutlinePen) -> Result<(), HbWasmError> {
+        let outline = outline::HostOutline::copy(self.0, glyph)
+            .ok_or(HbWasmError::OutlineCopyFailed(glyph))?;
+        outline
+            .draw(pen)
+            .ok_or(HbWasmError::OutlineMalformed(glyph))
+    }
+
+    /// Get the points and contours of a glyph's outline.
+    pub fn get_raw_outline(&self, glyph: u32) -> RawOutline {
+        let mut pen = RawOutline::default();
+        self.draw_glyph(glyph, &mut pen);
+        pen
+    }
+
     #[cfg(feature = "kurbo")]
     /// Get the outline of a glyph as a vector of bezier paths
+    ///
+    /// Panics if Harfbuzz can't provide the outline or it doesn't
+    /// make sense; see [`Font::try_get_outline`].
     pub fn get_outline(&self, glyph: u32) -> Vec<BezPath> {
-        let mut outline = CGlyphOutline {
-            n_points: 0,
-            points: std::ptr::null_mut(),
-            n_contours: 0,
-            contours: std::ptr::null_mut(),
-        };
-        let end_pts_of_contours: &[usize] = unsafe {
-            font_copy_glyph_outline(self.0, glyph, &mut outline);
-            std::slice::from_raw_parts(outline.contours, outline.n_contours as usize)
-        };
-        let points: &[CGlyphOutlinePoint] =
-            unsafe { std::slice::from_raw_parts(outline.points, outline.n_points as usize) };
-        let mut results: Vec<BezPath> = vec![];
-        let mut start_pt: usize = 0;
-        for end_pt in end_pts_of_contours {
-            let this_contour = &points[start_pt..*end_pt];
-            start_pt = *end_pt;
-            let mut path = BezPath::new();
-            let mut ix = 0;
-            while ix < this_contour.len() {
-                let point = &this_contour[ix];
-                match point.pointtype {
-                    PointType::MoveTo => path.move_to((point.x as f64, point.y as f64)),
-                    PointType::LineTo => path.line_to((point.x as f64, point.y as f64)),
-                    PointType::QuadraticTo => {
-                        ix += 1;
-                        let end_pt = &this_contour[ix];
-                        path.quad_to(
-                            (point.x as f64, point.y as f64),
-                            (end_pt.x as f64, end_pt.y as f64),
-                        );
-                    }
-                    PointType::CubicTo => {
-                        ix += 1;
-                        let mid_pt = &this_contour[ix];
-                        ix += 1;
-                        let end_pt = &this_contour[ix];
-                        path.curve_to(
-                            (point.x as f64, point.y as f64),
-                            (mid_pt.x as f64, mid_pt.y as f64),
-                            (end_pt.x as f64, end_pt.y as f64),
-                        );
-                    }
-                }
-                ix += 1;
-            }
-            path.close_path();
-            results.push(path);
-        }
-        results
+        self.try_get_outline(glyph)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    #[cfg(feature = "kurbo")]
+    /// Get the outline of a glyph as a vector of bezier paths, or
+    /// return an error if Harfbuzz can't provide it or it doesn't
+    /// make sense.
+    pub fn try_get_outline(&self, glyph: u32) -> Result<Vec<BezPath>, HbWasmError> {
+        let mut paths: Vec<BezPath> = vec![];
+        self.try_draw_glyph(glyph, &mut paths)?;
+        Ok(paths)
     }
 
     /// Get the normalized variation coordinates
+    ///
+    /// Panics if Harfbuzz can't provide them; see
+    /// [`Font::try_get_var_coords`].
     pub fn get_var_coords(&self) -> Vec<f32> {
-        let mut container = CCoords {
-            count: 0,
-            coords: std::ptr::null_mut(),
-        };
-        if !unsafe { font_copy_coords(self.0, &mut container) } {
-            panic!("Couldn't copy variation coordinates")
-        }
-        let coords =
-            unsafe { std::slice::from_raw_parts(container.coords, container.count as usize) }
-                .to_vec();
-        debug(&format!("Container coords: {:?}", coords));
-        return coords.iter().map(|i| *i as f32 / 16384.0).collect();
+        self.try_get_var_coords()
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Get the normalized variation coordinates, or an error if
+    /// Harfbuzz can't provide them.
+    pub fn try_get_var_coords(&self) -> Result<Vec<f32>, HbWasmError> {
+        COORDS.with(|scratch| {
+            let mut scratch = scratch.borrow_mut();
+            let mut container = CCoords {
+                count: scratch.len(),
+                coords: scratch.as_mut_ptr(),
+            };
+            if !unsafe { font_copy_coords(self.0, &mut container) } {
+                return Err(HbWasmError::CoordsCopyFailed);
+            }
+            if container.count == 0 || container.coords.is_null() {
+                return Ok(vec![]);
+            }
+            let coords = unsafe { std::slice::from_raw_parts(container.coords, container.count) }
+                .iter()
+                .map(|i| *i as f32 / 16384.0)
+                .collect();
+            if container.coords != scratch.as_mut_ptr() {
+                // There wasn't room, so Harfbuzz allocated its own copy.
+                // Make room so that it doesn't have to again.
+                scratch.resize(container.count, 0);
+            }
+            Ok(coords)
+        })
     }
 
     /// Set the normalized variation coordinates
+    ///
+    /// Panics if Harfbuzz won't accept them; see
+    /// [`Font::try_set_var_coords`].
     pub fn set_var_coords(&self, coords: &[f32]) {
+        self.try_set_var_coords(coords)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Set the normalized variation coordinates, or return an error
+    /// if Harfbuzz won't accept them.
+    pub fn try_set_var_coords(&self, coords: &[f32]) -> Result<(), HbWasmError> {
         let mut f2dot14: Vec<i32> = coords.iter().map(|i| ot_round(i * 16384.0)).collect();
         let container = CCoords {
             count: coords.len(),
             coords: f2dot14.as_mut_ptr(),
         };
         if !unsafe { font_set_coords(self.0, &container) } {
-            panic!("Couldn't copy variation coordinates")
+            return Err(HbWasmError::CoordsSetFailed);
         }
+        Ok(())
     }
 }
 
@@ -253,19 +407,25 @@ pub struct Face(u32);
 
 impl Face {
     /// Get a blob containing the contents of the given binary font table.
+    ///
+    /// Panics if the table isn't there; see [`Face::try_reference_table`].
     pub fn reference_table(&self, tag: &str) -> Blob {
-        let mut tag_u: u32 = 0;
-        let mut chars = tag.chars();
-        tag_u |= (chars.next().unwrap() as u32) << 24;
-        tag_u |= (chars.next().unwrap() as u32) << 16;
-        tag_u |= (chars.next().unwrap() as u32) << 8;
-        tag_u |= chars.next().unwrap() as u32;
+        self.try_reference_table(tag)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Get a blob containing the contents of the given binary font
+    /// table, or an error if the font has no such table.
+    pub fn try_reference_table(&self, tag: &str) -> Result<Blob, HbWasmError> {
+        let tag_u = str_to_tag(tag);
         let mut blob = Blob {
             data: std::ptr::null_mut(),
             length: 0,
         };
-        unsafe { face_copy_table(self.0, tag_u, &mut blob) || panic!("Couldn't reference table") };
-        blob
+        if !unsafe { face_copy_table(self.0, tag_u, &mut blob) } {
+            return Err(HbWasmError::TableNotFound(tag.to_string()));
+        }
+        Ok(blob)
     }
 
     /// Get the face's design units per em.
@@ -273,6 +433,19 @@ impl Face {
         unsafe { face_get_upem(self.0) }
     }
 
+    /// Get the number of glyphs in the face, from the `maxp` table.
+    ///
+    /// Returns zero if the face has no `maxp` table.
+    pub fn glyph_count(&self) -> u32 {
+        match self.try_reference_table("maxp") {
+            Ok(blob) if blob.length >= 6 => {
+                let data = blob.as_bytes();
+                u16::from_be_bytes([data[4], data[5]]) as u32
+            }
+            _ => 0,
+        }
+    }
+
     /// Create a font object from this face.
     pub fn create_font(&self) -> Font {
         Font(unsafe { face_create_font(self.0) })
@@ -280,6 +453,9 @@ impl Face {
 }
 
 /// Trait implemented by custom structs representing buffer items
+///
+/// With the `derive` feature, `#[derive(BufferItem)]` will write
+/// the implementation for you, matching fields to Harfbuzz's by name.
 pub trait BufferItem {
     /// Construct an item in your preferred representation out of the info and position data provided by Harfbuzz.
     fn from_c(info: CGlyphInfo, position: CGlyphPosition) -> Self;
@@ -300,6 +476,8 @@ pub struct Buffer<T: BufferItem> {
     _ptr: u32,
     /// Glyphs in the buffer
     pub glyphs: Vec<T>,
+    committed: bool,
+    cluster_level: ClusterLevel,
 }
 
 impl<T: BufferItem> Buffer<T> {
@@ -307,48 +485,122 @@ impl<T: BufferItem> Buffer<T> {
     ///
     /// The `Buffer` struct implements Drop, meaning that when the shaping
     /// function is finished, the buffer contents are sent back to Harfbuzz.
+    ///
+    /// Panics if Harfbuzz can't give us the buffer contents; see
+    /// [`Buffer::try_from_ref`].
     pub fn from_ref(ptr: u32) -> Self {
-        let mut c_contents = CBufferContents {
-            info: std::ptr::null_mut(),
-            position: std::ptr::null_mut(),
-            length: 0,
-        };
+        Self::try_from_ref(ptr).unwrap_or_else(|e| panic!("{}", e))
+    }
 
-        unsafe {
-            buffer_copy_contents(ptr, &mut c_contents) || panic!("Couldn't copy buffer contents")
-        };
-        let positions: Vec<CGlyphPosition> = unsafe {
-            std::slice::from_raw_parts(c_contents.position, c_contents.length as usize).to_vec()
-        };
-        let infos: Vec<CGlyphInfo> = unsafe {
-            std::slice::from_raw_parts(c_contents.info, c_contents.length as usize).to_vec()
-        };
-        Buffer {
-            glyphs: infos
-                .into_iter()
-                .zip(positions.into_iter())
-                .map(|(i, p)| T::from_c(i, p))
-                .collect(),
+    /// Construct a buffer from the pointer Harfbuzz provides to the
+    /// WASM, or return an error if the contents can't be copied.
+    pub fn try_from_ref(ptr: u32) -> Result<Self, HbWasmError> {
+        Ok(Buffer {
+            glyphs: copy_contents(ptr)?,
             _ptr: ptr,
-        }
+            committed: false,
+            cluster_level: ClusterLevel::default(),
+        })
     }
-}
 
-impl<T: BufferItem> Drop for Buffer<T> {
-    fn drop(&mut self) {
+    /// Send the glyphs back to Harfbuzz now, rather than when the
+    /// buffer is dropped, and report whether Harfbuzz accepted them.
+    ///
+    /// The glyphs are moved out of the buffer, leaving it empty,
+    /// and nothing more is sent when it is dropped unless you add
+    /// new glyphs to it. Call [`Buffer::reload`] to get the glyphs
+    /// back after running another shaper on the buffer.
+    pub fn commit(&mut self) -> Result<(), HbWasmError> {
         let mut positions: Vec<CGlyphPosition>;
         let mut infos: Vec<CGlyphInfo>;
         let glyphs = std::mem::take(&mut self.glyphs);
         (infos, positions) = glyphs.into_iter().map(|g| g.to_c()).unzip();
+        #[cfg(debug_assertions)]
+        if let Err(e) = edit::check_clusters(
+            infos.iter().map(|i| i.cluster),
+            self.direction(),
+            self.cluster_level,
+        ) {
+            debug(&e.to_string());
+        }
         let c_contents = CBufferContents {
             length: positions.len() as u32,
             info: infos[..].as_mut_ptr(),
             position: positions[..].as_mut_ptr(),
         };
-        unsafe {
-            if !buffer_set_contents(self._ptr, &c_contents) {
-                panic!("Couldn't set buffer contents");
-            }
+        self.committed = true;
+        if !unsafe { buffer_set_contents(self._ptr, &c_contents) } {
+            return Err(HbWasmError::BufferSetFailed);
+        }
+        Ok(())
+    }
+
+    /// Replace the glyphs with the current contents of the Harfbuzz
+    /// buffer, throwing away any changes which haven't been committed.
+    ///
+    /// The reloaded glyphs are sent back when the buffer is dropped,
+    /// as usual.
+    pub fn reload(&mut self) -> Result<(), HbWasmError> {
+        self.glyphs = copy_contents(self._ptr)?;
+        self.committed = false;
+        Ok(())
+    }
+
+    /// The direction of the text in this buffer.
+    ///
+    /// Note that once a buffer has been shaped, glyphs in a
+    /// backward (e.g. right-to-left) buffer are in visual order, so
+    /// if you're producing glyphs yourself you should reverse them.
+    pub fn direction(&self) -> Direction {
+        Direction::from_raw(unsafe { buffer_get_direction(self._ptr) })
+    }
+
+    /// The ISO 15924 script tag of the text in this buffer, such
+    /// as `Arab` or `Egyp`.
+    ///
+    /// Harfbuzz lets shapers read a buffer's direction and script,
+    /// but not change them. Its language, its flags and the text
+    /// on either side of the buffer aren't available to shapers.
+    pub fn script(&self) -> String {
+        tag_to_string(unsafe { buffer_get_script(self._ptr) })
+    }
+}
+
+/// Copy the contents of the buffer Harfbuzz gave us, without taking
+/// responsibility for writing them back.
+fn copy_contents<T: BufferItem>(ptr: u32) -> Result<Vec<T>, HbWasmError> {
+    let mut c_contents = CBufferContents {
+        info: std::ptr::null_mut(),
+        position: std::ptr::null_mut(),
+        length: 0,
+    };
+
+    if !unsafe { buffer_copy_contents(ptr, &mut c_contents) } {
+        return Err(HbWasmError::BufferCopyFailed);
+    }
+    if c_contents.length == 0 {
+        return Ok(vec![]);
+    }
+    let positions: Vec<CGlyphPosition> = unsafe {
+        std::slice::from_raw_parts(c_contents.position, c_contents.length as usize).to_vec()
+    };
+    let infos: Vec<CGlyphInfo> =
+        unsafe { std::slice::from_raw_parts(c_contents.info, c_contents.length as usize).to_vec() };
+    Ok(infos
+        .into_iter()
+        .zip(positions)
+        .map(|(i, p)| T::from_c(i, p))
+        .collect())
+}
+
+impl<T: BufferItem> Drop for Buffer<T> {
+    fn drop(&mut self) {
+        if self.committed && self.glyphs.is_empty() {
+            return;
+        }
+        // Panicking in a destructor would abort, so just report it.
+        if let Err(e) = self.commit() {
+            debug(&e.to_string());
         }
     }
 }
@@ -364,12 +616,32 @@ pub struct Blob {
 }
 
 impl Blob {
-    pub fn into_face(mut self, index: u32) -> Face {
+    /// The contents of the blob.
+    pub fn as_bytes(&self) -> &[u8] {
+        if self.data.is_null() || self.length == 0 {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.data, self.length as usize) }
+    }
+
+    /// Parse the blob as a font file and return the face at the given index.
+    ///
+    /// This is useful for fonts which carry other fonts inside a table.
+    ///
+    /// Panics if the blob isn't a font; see [`Blob::try_into_face`].
+    pub fn into_face(self, index: u32) -> Face {
+        self.try_into_face(index)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Parse the blob as a font file and return the face at the
+    /// given index, or an error if it can't be parsed.
+    pub fn try_into_face(mut self, index: u32) -> Result<Face, HbWasmError> {
         let faceptr = unsafe { blob_face_create(&mut self as *mut _, index) };
         if faceptr == 0 {
-            panic!("Couldn't create face from blob")
+            return Err(HbWasmError::FaceCreationFailed);
         }
-        Face(faceptr)
+        Ok(Face(faceptr))
     }
 }
 
@@ -380,10 +652,15 @@ impl Blob {
 #[repr(C)]
 #[derive(Debug, Clone)]
 pub struct CGlyphInfo {
+    /// The Unicode codepoint or glyph ID of the item
     pub codepoint: u32,
+    /// Harfbuzz's internal mask, which also carries the glyph flags
     pub mask: u32,
+    /// The index of the cluster in the input text where this came from
     pub cluster: u32,
+    /// Private data used by Harfbuzz
     pub var1: u32,
+    /// Private data used by Harfbuzz
     pub var2: u32,
 }
 
@@ -394,10 +671,15 @@ pub struct CGlyphInfo {
 #[derive(Debug, Clone)]
 #[repr(C)]
 pub struct CGlyphPosition {
+    /// The horizontal advance of the glyph
     pub x_advance: i32,
+    /// The vertical advance of the glyph
     pub y_advance: i32,
+    /// The horizontal offset of the glyph
     pub x_offset: i32,
+    /// The vertical offset of the glyph
     pub y_offset: i32,
+    /// Private data used by Harfbuzz
     pub var: u32,
 }
 
@@ -430,7 +712,12 @@ struct CBufferContents {
 /// representing glyph positioning. In Rust, this would
 /// require lots of zipping and unzipping, so we zip them
 /// together into a single structure for you.
-#[derive(Debug, Clone, Copy)]
+///
+/// Harfbuzz's private data is kept alongside the public fields
+/// and handed back unchanged, so that glyph flags computed by
+/// the shaper you called (see [`Glyph::glyph_flags`]) survive
+/// your changes to the buffer.
+#[derive(Debug, Clone, Copy, Default)]
 pub struct Glyph {
     /// The Unicode codepoint or glyph ID of the item
     pub codepoint: u32,
@@ -446,7 +733,39 @@ pub struct Glyph {
     pub y_offset: i32,
     /// You can use this for whatever you like
     pub flags: u32,
+    /// Harfbuzz's mask for this item, which carries its glyph flags
+    pub mask: u32,
+    /// Private data used by Harfbuzz
+    pub var1: u32,
+    /// Private data used by Harfbuzz
+    pub var2: u32,
+    /// Private positioning data used by Harfbuzz
+    pub pos_var: u32,
+}
+
+impl Glyph {
+    /// The glyph flags Harfbuzz has set on this item.
+    pub fn glyph_flags(&self) -> GlyphFlags {
+        GlyphFlags(self.mask & GlyphFlags::DEFINED.0)
+    }
+
+    /// Replace the glyph flags on this item.
+    pub fn set_glyph_flags(&mut self, flags: GlyphFlags) {
+        self.mask = (self.mask & !GlyphFlags::DEFINED.0) | (flags.0 & GlyphFlags::DEFINED.0);
+    }
+
+    /// Is it unsafe to break the text at the start of this glyph's cluster?
+    pub fn is_unsafe_to_break(&self) -> bool {
+        self.glyph_flags().contains(GlyphFlags::UNSAFE_TO_BREAK)
+    }
+
+    /// Is it unsafe to concatenate the results of shaping the text on
+    /// either side of this glyph's cluster?
+    pub fn is_unsafe_to_concat(&self) -> bool {
+        self.glyph_flags().contains(GlyphFlags::UNSAFE_TO_CONCAT)
+    }
 }
+
 impl BufferItem for Glyph {
     fn from_c(info: CGlyphInfo, pos: CGlyphPosition) -> Self {
         Self {
@@ -457,30 +776,87 @@ impl BufferItem for Glyph {
             x_offset: pos.x_offset,
             y_offset: pos.y_offset,
             flags: 0,
+            mask: info.mask,
+            var1: info.var1,
+            var2: info.var2,
+            pos_var: pos.var,
         }
     }
     fn to_c(self) -> (CGlyphInfo, CGlyphPosition) {
         let info = CGlyphInfo {
             codepoint: self.codepoint,
             cluster: self.cluster,
-            mask: 0,
-            var1: 0,
-            var2: 0,
+            mask: self.mask,
+            var1: self.var1,
+            var2: self.var2,
         };
         let pos = CGlyphPosition {
             x_advance: self.x_advance,
             y_advance: self.y_advance,
             x_offset: self.x_offset,
             y_offset: self.y_offset,
-            var: 0,
+            var: self.pos_var,
         };
         (info, pos)
     }
 }
 
+impl Buffer<Glyph> {
+    /// Mark the text between two clusters as unsafe to break.
+    ///
+    /// Call this when you have positioned or substituted glyphs
+    /// in one cluster based on the glyphs in another: it tells the
+    /// client that if the text is broken anywhere after the start
+    /// of the first cluster and before the end of the last one,
+    /// it will need to be shaped again. Every glyph whose cluster
+    /// falls strictly after `clusters.start` and before
+    /// `clusters.end` is marked unsafe to break and unsafe to
+    /// concatenate. The order of the glyphs in the buffer does not
+    /// matter, so this works for right-to-left text too.
+    pub fn unsafe_to_break(&mut self, clusters: Range<u32>) {
+        let flags = GlyphFlags::UNSAFE_TO_BREAK | GlyphFlags::UNSAFE_TO_CONCAT;
+        for glyph in self.glyphs.iter_mut() {
+            if glyph.cluster > clusters.start && glyph.cluster < clusters.end {
+                glyph.set_glyph_flags(glyph.glyph_flags() | flags);
+            }
+        }
+    }
+}
+
+/// Flags Harfbuzz sets on shaped glyphs, equivalent to
+/// `hb_glyph_flags_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GlyphFlags(pub u32);
+
+impl GlyphFlags {
+    /// Breaking the text at the start of this glyph's cluster and
+    /// shaping the two halves separately would give a different result
+    pub const UNSAFE_TO_BREAK: GlyphFlags = GlyphFlags(0x1);
+    /// Shaping the text on either side of this glyph's cluster
+    /// separately and concatenating the results would give a
+    /// different result
+    pub const UNSAFE_TO_CONCAT: GlyphFlags = GlyphFlags(0x2);
+    /// A tatweel can be inserted before this glyph's cluster
+    pub const SAFE_TO_INSERT_TATWEEL: GlyphFlags = GlyphFlags(0x4);
+    /// All of the flags Harfbuzz defines
+    pub const DEFINED: GlyphFlags = GlyphFlags(0x7);
+
+    /// Are all of the given flags set?
+    pub fn contains(&self, other: GlyphFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for GlyphFlags {
+    type Output = GlyphFlags;
+    fn bitor(self, rhs: Self) -> Self {
+        GlyphFlags(self.0 | rhs.0)
+    }
+}
+
 #[repr(C)]
 #[allow(clippy::enum_variant_names)]
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug)]
 enum PointType {
     MoveTo,
     LineTo,
@@ -488,12 +864,25 @@ enum PointType {
     CubicTo,
 }
 
+impl PointType {
+    fn from_raw(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(PointType::MoveTo),
+            1 => Some(PointType::LineTo),
+            2 => Some(PointType::QuadraticTo),
+            3 => Some(PointType::CubicTo),
+            _ => None,
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Clone, Debug)]
 struct CGlyphOutlinePoint {
     x: f32,
     y: f32,
-    pointtype: PointType,
+    // A `PointType`, kept as a number because it comes from the host
+    pointtype: u32,
 }
 
 #[repr(C)]
@@ -511,6 +900,13 @@ struct CCoords {
     coords: *mut i32,
 }
 
+thread_local! {
+    // Somewhere for Harfbuzz to copy a font's variation coordinates
+    // into. Given room for them, it fills that in rather than
+    // allocating memory which the shaper has no way to free.
+    static COORDS: RefCell<Vec<i32>> = RefCell::new(vec![0; 64]);
+}
+
 /// Our default buffer item struct. See also [`Glyph`].
 pub type GlyphBuffer = Buffer<Glyph>;
 
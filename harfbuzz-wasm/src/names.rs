@@ -0,0 +1,31 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use crate::Font;
+
+type NameIndex = Rc<BTreeMap<String, u32>>;
+
+thread_local! {
+    // Name-to-ID maps, keyed by face pointer. Building one means
+    // asking Harfbuzz for the name of every glyph, so it is only
+    // done once per face for the life of the WASM instance.
+    static NAME_INDEXES: RefCell<BTreeMap<u32, NameIndex>> =
+        const { RefCell::new(BTreeMap::new()) };
+}
+
+/// Return the name index for the font's face, building it if needed.
+pub(crate) fn name_index(font: &Font) -> NameIndex {
+    let face = font.get_face();
+    if let Some(index) = NAME_INDEXES.with(|indexes| indexes.borrow().get(&face.0).cloned()) {
+        return index;
+    }
+    let mut index = BTreeMap::new();
+    for glyph in 0..face.glyph_count() {
+        // Keep the first glyph if a name is used twice.
+        index.entry(font.get_glyph_name(glyph)).or_insert(glyph);
+    }
+    let index = Rc::new(index);
+    NAME_INDEXES.with(|indexes| indexes.borrow_mut().insert(face.0, index.clone()));
+    index
+}
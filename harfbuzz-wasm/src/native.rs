@@ -0,0 +1,672 @@
+//! A native stand-in for the Harfbuzz WASM runtime.
+//!
+//! Normally the functions this crate imports are provided by
+//! Harfbuzz when it loads your shaper, so a shaper can only be
+//! run inside Harfbuzz. With the `native-host` feature turned on,
+//! they are instead implemented here in plain Rust, backed by a
+//! font file read from disk, so that you can test your shaper
+//! with `cargo test`:
+//!
+//! ```rust,ignore
+//! use harfbuzz_wasm::native;
+//!
+//! #[test]
+//! fn shapes_something() {
+//!     let font = native::load_font("MyFont-Wasm.ttf").unwrap();
+//!     let buffer = native::create_buffer("Hello");
+//!     assert_eq!(shape(0, font, buffer, 0, 0), 1);
+//!     let glyphs = native::buffer_glyphs(buffer);
+//!     assert_eq!(glyphs.len(), 5);
+//! }
+//! ```
+//!
+//! This is a test harness, not a shaping engine. Calling
+//! [`Font::shape_with`](crate::Font::shape_with) with any shaper
+//! just maps characters to glyphs through the `cmap` table and
+//! sets their advances from `hmtx`; there is no substitution or
+//! positioning. Variation coordinates are supported, but `avar`
+//! mappings are only approximated.
+//!
+//! Each thread has its own host, so tests running in parallel do
+//! not see each other's fonts and buffers.
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::ffi::CStr;
+use std::path::Path;
+use std::rc::Rc;
+
+use ttf_parser::{GlyphId, OutlineBuilder, Tag};
+
+use crate::{
+    Blob, BufferItem, CBufferContents, CCoords, CGlyphExtents, CGlyphInfo, CGlyphOutline,
+    CGlyphOutlinePoint, CGlyphPosition, Direction, Feature, Glyph, PointType,
+};
+
+struct HostFace {
+    data: Rc<[u8]>,
+    index: u32,
+    // Tables added with `set_font_table`, newest last. Replaced
+    // tables are kept, since the shaper may still hold blobs of them.
+    added_tables: Vec<(u32, Rc<[u8]>)>,
+}
+
+struct HostFont {
+    face: u32,
+    x_scale: i32,
+    y_scale: i32,
+    coords: Vec<i32>,
+}
+
+#[derive(Default)]
+struct HostBuffer {
+    info: Vec<CGlyphInfo>,
+    position: Vec<CGlyphPosition>,
+    direction: u32,
+    script: u32,
+}
+
+#[derive(Default)]
+struct Host {
+    faces: Vec<HostFace>,
+    fonts: Vec<HostFont>,
+    buffers: Vec<HostBuffer>,
+    features: Vec<Vec<Feature>>,
+    log: Vec<String>,
+    // Memory handed out to the shaper, by address. Harfbuzz
+    // allocates this in the WASM instance's memory; we keep it alive
+    // until the shaper frees it, or for as long as the host lives.
+    allocations: BTreeMap<usize, Box<dyn Any>>,
+}
+
+thread_local! {
+    static HOST: RefCell<Host> = RefCell::new(Host::default());
+}
+
+fn with_host<R>(f: impl FnOnce(&mut Host) -> R) -> R {
+    HOST.with(|host| f(&mut host.borrow_mut()))
+}
+
+// References handed to the shaper are indices into the host's
+// tables, offset by one so that zero can mean "nothing".
+fn get<T>(items: &[T], reference: u32) -> Option<&T> {
+    items.get((reference as usize).checked_sub(1)?)
+}
+
+fn get_mut<T>(items: &mut [T], reference: u32) -> Option<&mut T> {
+    items.get_mut((reference as usize).checked_sub(1)?)
+}
+
+fn push<T>(items: &mut Vec<T>, item: T) -> u32 {
+    items.push(item);
+    items.len() as u32
+}
+
+impl Host {
+    fn add_face(&mut self, data: Rc<[u8]>, index: u32) -> Option<u32> {
+        ttf_parser::Face::parse(&data, index).ok()?;
+        let face = HostFace {
+            data,
+            index,
+            added_tables: vec![],
+        };
+        Some(push(&mut self.faces, face))
+    }
+
+    fn add_font(&mut self, face: u32) -> Option<u32> {
+        let (upem, axis_count) = {
+            let host_face = get(&self.faces, face)?;
+            let parsed = ttf_parser::Face::parse(&host_face.data, host_face.index).ok()?;
+            (
+                parsed.units_per_em() as i32,
+                parsed.variation_axes().len() as usize,
+            )
+        };
+        let font = HostFont {
+            face,
+            x_scale: upem,
+            y_scale: upem,
+            coords: vec![0; axis_count],
+        };
+        Some(push(&mut self.fonts, font))
+    }
+
+    // Parse the font's face with its variation coordinates applied.
+    fn parse_font(&self, font: u32) -> Option<(ttf_parser::Face<'_>, &HostFont)> {
+        let host_font = get(&self.fonts, font)?;
+        let host_face = get(&self.faces, host_font.face)?;
+        let mut face = ttf_parser::Face::parse(&host_face.data, host_face.index).ok()?;
+        let axes: Vec<_> = face.variation_axes().into_iter().collect();
+        for (axis, &coord) in axes.iter().zip(host_font.coords.iter()) {
+            // Undo the default normalization; this ignores avar.
+            let normalized = coord as f32 / 16384.0;
+            let value = if normalized < 0.0 {
+                axis.def_value + normalized * (axis.def_value - axis.min_value)
+            } else {
+                axis.def_value + normalized * (axis.max_value - axis.def_value)
+            };
+            face.set_variation(axis.tag, value);
+        }
+        Some((face, host_font))
+    }
+
+    fn keep<T: 'static>(&mut self, mut items: Vec<T>) -> *mut T {
+        let ptr = items.as_mut_ptr();
+        // Empty vectors don't own any memory to keep.
+        if !items.is_empty() {
+            self.allocations.insert(ptr as usize, Box::new(items));
+        }
+        ptr
+    }
+
+    fn release<T>(&mut self, ptr: *mut T) {
+        self.allocations.remove(&(ptr as usize));
+    }
+}
+
+fn scale(value: f32, scale: i32, upem: u16) -> i32 {
+    (value * scale as f32 / upem as f32).round() as i32
+}
+
+/// Load a font file from disk, returning a font reference which
+/// can be passed to your `shape` function.
+pub fn load_font(path: impl AsRef<Path>) -> std::io::Result<u32> {
+    let data = std::fs::read(path)?;
+    font_from_data(data, 0)
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidData, "Couldn't parse font"))
+}
+
+/// Create a font reference from the contents of a font file.
+///
+/// Returns `None` if the data can't be parsed as a font.
+pub fn font_from_data(data: Vec<u8>, index: u32) -> Option<u32> {
+    with_host(|host| {
+        let face = host.add_face(data.into(), index)?;
+        host.add_font(face)
+    })
+}
+
+/// Change the scale of a font. By default fonts are created at a
+/// scale equal to their units per em, as in Harfbuzz.
+pub fn set_font_scale(font: u32, x_scale: i32, y_scale: i32) {
+    with_host(|host| {
+        if let Some(host_font) = get_mut(&mut host.fonts, font) {
+            host_font.x_scale = x_scale;
+            host_font.y_scale = y_scale;
+        }
+    })
+}
+
+/// Add a table to a font's face, replacing any table it already has
+/// with that tag, as if the font file had been built with it.
+///
+/// This lets you test a shaper with different settings in its
+/// [`Wcfg`](crate::config) table without rebuilding the font. Set
+/// tables before shaping, since tables which have already been read
+/// may be cached.
+pub fn set_font_table(font: u32, tag: &str, data: &[u8]) {
+    with_host(|host| {
+        let Some(face) = get(&host.fonts, font).map(|f| f.face) else {
+            return;
+        };
+        if let Some(host_face) = get_mut(&mut host.faces, face) {
+            host_face
+                .added_tables
+                .push((crate::str_to_tag(tag), data.into()));
+        }
+    })
+}
+
+/// Create a buffer containing some text, returning a buffer
+/// reference which can be passed to your `shape` function.
+///
+/// As with `hb_buffer_add_utf8`, clusters are byte offsets into
+/// the text. The buffer is marked as being the whole of the text,
+/// and its direction is guessed from the first strong character.
+pub fn create_buffer(text: &str) -> u32 {
+    let info = text
+        .char_indices()
+        .map(|(ix, c)| CGlyphInfo {
+            codepoint: c as u32,
+            mask: 0,
+            cluster: ix as u32,
+            var1: 0,
+            var2: 0,
+        })
+        .collect::<Vec<_>>();
+    let position = info
+        .iter()
+        .map(|_| CGlyphPosition {
+            x_advance: 0,
+            y_advance: 0,
+            x_offset: 0,
+            y_offset: 0,
+            var: 0,
+        })
+        .collect();
+    let buffer = HostBuffer {
+        info,
+        position,
+        direction: guess_direction(text) as u32,
+        ..Default::default()
+    };
+    with_host(|host| push(&mut host.buffers, buffer))
+}
+
+fn guess_direction(text: &str) -> Direction {
+    for c in text.chars() {
+        match c as u32 {
+            0x0590..=0x08FF
+            | 0xFB1D..=0xFDFF
+            | 0xFE70..=0xFEFF
+            | 0x10800..=0x10FFF
+            | 0x1E800..=0x1EFFF => return Direction::RightToLeft,
+            _ if c.is_alphabetic() => return Direction::LeftToRight,
+            _ => {}
+        }
+    }
+    Direction::LeftToRight
+}
+
+/// Return the current contents of a buffer.
+pub fn buffer_glyphs(buffer: u32) -> Vec<Glyph> {
+    with_host(|host| {
+        get(&host.buffers, buffer)
+            .map(|b| {
+                b.info
+                    .iter()
+                    .zip(b.position.iter())
+                    .map(|(i, p)| Glyph::from_c(i.clone(), p.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    })
+}
+
+/// Store an array of feature settings, returning the pointer and
+/// count to pass to your `shape` function.
+pub fn features_ref(features: &[Feature]) -> (u32, u32) {
+    (features_to_ref(features), features.len() as u32)
+}
+
+/// How many arrays the host has handed to the shaper which haven't
+/// been freed yet.
+///
+/// Useful for checking that a shaper doesn't leak glyph outlines.
+pub fn outstanding_allocations() -> usize {
+    with_host(|host| host.allocations.len())
+}
+
+/// Return and clear everything written to the debug log.
+pub fn take_debug_log() -> Vec<String> {
+    with_host(|host| std::mem::take(&mut host.log))
+}
+
+// Pointers to feature arrays don't fit in a u32 on 64-bit
+// platforms, so the host stores them and hands out references.
+pub(crate) fn features_to_ref(features: &[Feature]) -> u32 {
+    if features.is_empty() {
+        return 0;
+    }
+    with_host(|host| push(&mut host.features, features.to_vec()))
+}
+
+pub(crate) fn features_from_ref(features: u32, count: u32) -> Vec<Feature> {
+    with_host(|host| {
+        get(&host.features, features)
+            .map(|f| f.iter().take(count as usize).copied().collect())
+            .unwrap_or_default()
+    })
+}
+
+// What follows are the functions Harfbuzz would provide. They
+// have the same signatures as the imports in lib.rs.
+
+pub(crate) unsafe fn face_get_upem(face: u32) -> u32 {
+    with_host(|host| {
+        get(&host.faces, face)
+            .and_then(|f| ttf_parser::Face::parse(&f.data, f.index).ok())
+            .map(|f| f.units_per_em() as u32)
+            .unwrap_or(1000)
+    })
+}
+
+pub(crate) unsafe fn font_get_face(font: u32) -> u32 {
+    with_host(|host| get(&host.fonts, font).map(|f| f.face).unwrap_or(0))
+}
+
+pub(crate) unsafe fn face_create_font(face: u32) -> u32 {
+    with_host(|host| host.add_font(face).unwrap_or(0))
+}
+
+pub(crate) unsafe fn font_get_glyph(font: u32, unicode: u32, uvs: u32) -> u32 {
+    with_host(|host| {
+        let (face, _) = host.parse_font(font)?;
+        let c = char::from_u32(unicode)?;
+        let glyph = match char::from_u32(uvs) {
+            Some(vs) if uvs != 0 => face.glyph_variation_index(c, vs),
+            _ => face.glyph_index(c),
+        };
+        glyph.map(|g| g.0 as u32)
+    })
+    .unwrap_or(0)
+}
+
+pub(crate) unsafe fn font_get_scale(font: u32, x_scale: *mut i32, y_scale: *mut i32) {
+    let (x, y) = with_host(|host| {
+        get(&host.fonts, font)
+            .map(|f| (f.x_scale, f.y_scale))
+            .unwrap_or((0, 0))
+    });
+    *x_scale = x;
+    *y_scale = y;
+}
+
+pub(crate) unsafe fn font_get_glyph_extents(
+    font: u32,
+    glyph: u32,
+    extents: *mut CGlyphExtents,
+) -> bool {
+    let result = with_host(|host| {
+        let (face, host_font) = host.parse_font(font)?;
+        let upem = face.units_per_em();
+        Some(match face.glyph_bounding_box(GlyphId(glyph as u16)) {
+            Some(bbox) => CGlyphExtents {
+                x_bearing: scale(bbox.x_min as f32, host_font.x_scale, upem),
+                y_bearing: scale(bbox.y_max as f32, host_font.y_scale, upem),
+                width: scale(bbox.x_max as f32, host_font.x_scale, upem)
+                    - scale(bbox.x_min as f32, host_font.x_scale, upem),
+                height: scale(bbox.y_min as f32, host_font.y_scale, upem)
+                    - scale(bbox.y_max as f32, host_font.y_scale, upem),
+            },
+            None => CGlyphExtents::default(),
+        })
+    });
+    match result {
+        Some(e) => {
+            *extents = e;
+            true
+        }
+        None => false,
+    }
+}
+
+pub(crate) unsafe fn font_glyph_to_string(font: u32, glyph: u32, str: *const u8, len: u32) {
+    if len == 0 {
+        return;
+    }
+    let name = with_host(|host| {
+        host.parse_font(font).and_then(|(face, _)| {
+            face.glyph_name(GlyphId(glyph as u16))
+                .map(|n| n.to_string())
+        })
+    })
+    .unwrap_or_else(|| format!("gid{}", glyph));
+    let bytes = name.as_bytes();
+    let count = bytes.len().min(len as usize - 1);
+    let out = str as *mut u8;
+    std::ptr::copy_nonoverlapping(bytes.as_ptr(), out, count);
+    *out.add(count) = 0;
+}
+
+pub(crate) unsafe fn font_get_glyph_h_advance(font: u32, glyph: u32) -> i32 {
+    with_host(|host| {
+        let (face, host_font) = host.parse_font(font)?;
+        let advance = face.glyph_hor_advance(GlyphId(glyph as u16))?;
+        Some(scale(
+            advance as f32,
+            host_font.x_scale,
+            face.units_per_em(),
+        ))
+    })
+    .unwrap_or(0)
+}
+
+pub(crate) unsafe fn font_get_glyph_v_advance(font: u32, glyph: u32) -> i32 {
+    with_host(|host| {
+        let (face, host_font) = host.parse_font(font)?;
+        let upem = face.units_per_em();
+        let advance = face
+            .glyph_ver_advance(GlyphId(glyph as u16))
+            .unwrap_or(upem);
+        // Harfbuzz's y axis points up, so vertical advances are negative.
+        Some(-scale(advance as f32, host_font.y_scale, upem))
+    })
+    .unwrap_or(0)
+}
+
+struct OutlineCollector {
+    x_scale: f32,
+    y_scale: f32,
+    points: Vec<CGlyphOutlinePoint>,
+    contours: Vec<usize>,
+}
+
+impl OutlineCollector {
+    fn point(&mut self, x: f32, y: f32, pointtype: PointType) {
+        self.points.push(CGlyphOutlinePoint {
+            x: x * self.x_scale,
+            y: y * self.y_scale,
+            pointtype: pointtype as u32,
+        })
+    }
+}
+
+impl OutlineBuilder for OutlineCollector {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.point(x, y, PointType::MoveTo)
+    }
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.point(x, y, PointType::LineTo)
+    }
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.point(x1, y1, PointType::QuadraticTo);
+        self.point(x, y, PointType::QuadraticTo)
+    }
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.point(x1, y1, PointType::CubicTo);
+        self.point(x2, y2, PointType::CubicTo);
+        self.point(x, y, PointType::CubicTo)
+    }
+    fn close(&mut self) {
+        self.contours.push(self.points.len())
+    }
+}
+
+pub(crate) unsafe fn font_copy_glyph_outline(
+    font: u32,
+    glyph: u32,
+    outline: *mut CGlyphOutline,
+) -> bool {
+    let collected = with_host(|host| {
+        let (face, host_font) = host.parse_font(font)?;
+        let upem = face.units_per_em() as f32;
+        let mut collector = OutlineCollector {
+            x_scale: host_font.x_scale as f32 / upem,
+            y_scale: host_font.y_scale as f32 / upem,
+            points: vec![],
+            contours: vec![],
+        };
+        face.outline_glyph(GlyphId(glyph as u16), &mut collector);
+        Some((collector.points, collector.contours))
+    });
+    let Some((points, contours)) = collected else {
+        return false;
+    };
+    let outline = &mut *outline;
+    outline.n_points = points.len();
+    outline.n_contours = contours.len();
+    with_host(|host| {
+        outline.points = host.keep(points);
+        outline.contours = host.keep(contours);
+    });
+    true
+}
+
+pub(crate) unsafe fn glyph_outline_free(outline: *mut CGlyphOutline) {
+    let outline = &mut *outline;
+    with_host(|host| {
+        host.release(outline.points);
+        host.release(outline.contours);
+    });
+    outline.n_points = 0;
+    outline.points = std::ptr::null_mut();
+    outline.n_contours = 0;
+    outline.contours = std::ptr::null_mut();
+}
+
+pub(crate) unsafe fn face_copy_table(face: u32, tag: u32, blob: *mut Blob) -> bool {
+    let table = with_host(|host| {
+        let host_face = get(&host.faces, face)?;
+        let added = host_face.added_tables.iter().rev().find(|(t, _)| *t == tag);
+        let table = match added {
+            Some((_, table)) => table.to_vec(),
+            None => {
+                let parsed = ttf_parser::Face::parse(&host_face.data, host_face.index).ok()?;
+                parsed.raw_face().table(Tag(tag))?.to_vec()
+            }
+        };
+        // Harfbuzz copies the table into the instance's memory.
+        let length = table.len() as u32;
+        Some((host.keep(table), length))
+    });
+    match table {
+        Some((data, length)) if length > 0 => {
+            (*blob).data = data;
+            (*blob).length = length;
+            true
+        }
+        _ => false,
+    }
+}
+
+pub(crate) unsafe fn buffer_copy_contents(buffer: u32, cbuffer: *mut CBufferContents) -> bool {
+    with_host(|host| {
+        let Some(b) = get(&host.buffers, buffer) else {
+            return false;
+        };
+        let (info, position) = (b.info.clone(), b.position.clone());
+        (*cbuffer).length = info.len() as u32;
+        (*cbuffer).info = host.keep(info);
+        (*cbuffer).position = host.keep(position);
+        true
+    })
+}
+
+pub(crate) unsafe fn buffer_set_contents(buffer: u32, cbuffer: &CBufferContents) -> bool {
+    let length = cbuffer.length as usize;
+    let (info, position) = if length == 0 {
+        (vec![], vec![])
+    } else {
+        (
+            std::slice::from_raw_parts(cbuffer.info, length).to_vec(),
+            std::slice::from_raw_parts(cbuffer.position, length).to_vec(),
+        )
+    };
+    with_host(|host| match get_mut(&mut host.buffers, buffer) {
+        Some(b) => {
+            b.info = info;
+            b.position = position;
+            true
+        }
+        None => false,
+    })
+}
+
+fn with_buffer<R: Default>(buffer: u32, f: impl FnOnce(&mut HostBuffer) -> R) -> R {
+    with_host(|host| {
+        get_mut(&mut host.buffers, buffer)
+            .map(f)
+            .unwrap_or_default()
+    })
+}
+
+pub(crate) unsafe fn buffer_get_direction(buffer: u32) -> u32 {
+    with_buffer(buffer, |b| b.direction)
+}
+
+pub(crate) unsafe fn buffer_get_script(buffer: u32) -> u32 {
+    with_buffer(buffer, |b| b.script)
+}
+
+pub(crate) unsafe fn font_copy_coords(font: u32, coords: *mut CCoords) -> bool {
+    with_host(|host| {
+        let Some(f) = get(&host.fonts, font) else {
+            return false;
+        };
+        let copy = f.coords.clone();
+        // As in Harfbuzz, fill in the shaper's array if it's big
+        // enough, and allocate a new one if not.
+        if copy.len() <= (*coords).count && !(*coords).coords.is_null() {
+            std::ptr::copy_nonoverlapping(copy.as_ptr(), (*coords).coords, copy.len());
+        } else {
+            (*coords).coords = host.keep(copy.clone());
+        }
+        (*coords).count = copy.len();
+        true
+    })
+}
+
+pub(crate) unsafe fn font_set_coords(font: u32, coords: &CCoords) -> bool {
+    let new_coords = if coords.count == 0 {
+        vec![]
+    } else {
+        std::slice::from_raw_parts(coords.coords, coords.count).to_vec()
+    };
+    with_host(|host| match get_mut(&mut host.fonts, font) {
+        Some(f) => {
+            for (coord, new) in f.coords.iter_mut().zip(new_coords) {
+                *coord = new;
+            }
+            true
+        }
+        None => false,
+    })
+}
+
+pub(crate) unsafe fn debugprint(s: *const u8) {
+    let message = CStr::from_ptr(s as *const _).to_string_lossy().into_owned();
+    eprintln!("{}", message);
+    with_host(|host| host.log.push(message))
+}
+
+pub(crate) unsafe fn blob_face_create(blob: *mut Blob, index: u32) -> u32 {
+    let blob = &*blob;
+    if blob.data.is_null() {
+        return 0;
+    }
+    let data: Rc<[u8]> = std::slice::from_raw_parts(blob.data, blob.length as usize).into();
+    with_host(|host| host.add_face(data, index).unwrap_or(0))
+}
+
+pub(crate) unsafe fn shape_with(
+    font: u32,
+    buffer: u32,
+    _features: u32,
+    _num_features: u32,
+    _shaper: *const u8,
+) -> i32 {
+    let mut glyphs = buffer_glyphs(buffer);
+    let direction = Direction::from_raw(buffer_get_direction(buffer));
+    for glyph in glyphs.iter_mut() {
+        glyph.codepoint = font_get_glyph(font, glyph.codepoint, 0);
+        glyph.x_advance = 0;
+        glyph.y_advance = 0;
+        if direction.is_vertical() {
+            glyph.y_advance = font_get_glyph_v_advance(font, glyph.codepoint);
+        } else {
+            glyph.x_advance = font_get_glyph_h_advance(font, glyph.codepoint);
+        }
+        glyph.x_offset = 0;
+        glyph.y_offset = 0;
+    }
+    if direction.is_backward() {
+        glyphs.reverse();
+    }
+    let (info, position): (Vec<_>, Vec<_>) = glyphs.into_iter().map(|g| g.to_c()).unzip();
+    with_buffer(buffer, |b| {
+        b.info = info;
+        b.position = position;
+    });
+    1
+}
@@ -0,0 +1,331 @@
+#[cfg(feature = "kurbo")]
+use kurbo::BezPath;
+
+use crate::{
+    font_copy_glyph_outline, glyph_outline_free, CGlyphOutline, CGlyphOutlinePoint, PointType,
+};
+
+/// Something which can draw a glyph outline, one contour at a time.
+///
+/// Each contour starts with `move_to` and ends with `close`.
+/// Coordinates are in the font's scale, with y pointing up. Pass a
+/// pen to [`Font::draw_glyph`](crate::Font::draw_glyph).
+pub trait OutlinePen {
+    /// Start a new contour at a point
+    fn move_to(&mut self, x: f32, y: f32);
+    /// Draw a straight line to a point
+    fn line_to(&mut self, x: f32, y: f32);
+    /// Draw a quadratic curve, with one control point
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32);
+    /// Draw a cubic curve, with two control points
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32);
+    /// Close the current contour
+    fn close(&mut self);
+}
+
+/// What a point in a [`RawOutline`] is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointKind {
+    /// A point the outline passes through
+    OnCurve,
+    /// The control point of a quadratic curve
+    QuadraticControl,
+    /// One of the two control points of a cubic curve
+    CubicControl,
+}
+
+/// A point in a [`RawOutline`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OutlinePoint {
+    /// Horizontal position
+    pub x: f32,
+    /// Vertical position
+    pub y: f32,
+    /// Whether the point is on the curve or a control point
+    pub kind: PointKind,
+}
+
+/// A pen which just collects the points of an outline.
+///
+/// This is all a shaper needs for things like counting contours or
+/// finding a glyph's extreme points, without pulling in a geometry
+/// library.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RawOutline {
+    /// Every point of every contour, in order
+    pub points: Vec<OutlinePoint>,
+    /// For each contour, the index in `points` just past its end
+    pub contours: Vec<usize>,
+}
+
+impl RawOutline {
+    /// The points of each contour.
+    pub fn contours(&self) -> impl Iterator<Item = &[OutlinePoint]> {
+        let starts = std::iter::once(0).chain(self.contours.iter().copied());
+        starts
+            .zip(self.contours.iter().copied())
+            .map(|(start, end)| &self.points[start..end])
+    }
+
+    /// The points the outline passes through, skipping control points.
+    pub fn on_curve_points(&self) -> impl Iterator<Item = &OutlinePoint> {
+        self.points.iter().filter(|p| p.kind == PointKind::OnCurve)
+    }
+
+    fn push(&mut self, x: f32, y: f32, kind: PointKind) {
+        self.points.push(OutlinePoint { x, y, kind })
+    }
+}
+
+impl OutlinePen for RawOutline {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.push(x, y, PointKind::OnCurve)
+    }
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.push(x, y, PointKind::OnCurve)
+    }
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.push(x1, y1, PointKind::QuadraticControl);
+        self.push(x, y, PointKind::OnCurve)
+    }
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.push(x1, y1, PointKind::CubicControl);
+        self.push(x2, y2, PointKind::CubicControl);
+        self.push(x, y, PointKind::OnCurve)
+    }
+    fn close(&mut self) {
+        self.contours.push(self.points.len())
+    }
+}
+
+#[cfg(feature = "kurbo")]
+impl OutlinePen for BezPath {
+    fn move_to(&mut self, x: f32, y: f32) {
+        BezPath::move_to(self, (x as f64, y as f64))
+    }
+    fn line_to(&mut self, x: f32, y: f32) {
+        BezPath::line_to(self, (x as f64, y as f64))
+    }
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        BezPath::quad_to(self, (x1 as f64, y1 as f64), (x as f64, y as f64))
+    }
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        BezPath::curve_to(
+            self,
+            (x1 as f64, y1 as f64),
+            (x2 as f64, y2 as f64),
+            (x as f64, y as f64),
+        )
+    }
+    fn close(&mut self) {
+        self.close_path()
+    }
+}
+
+/// Draws each contour as a separate path, as
+/// [`Font::get_outline`](crate::Font::get_outline) returns them.
+#[cfg(feature = "kurbo")]
+impl OutlinePen for Vec<BezPath> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let mut path = BezPath::new();
+        OutlinePen::move_to(&mut path, x, y);
+        self.push(path)
+    }
+    fn line_to(&mut self, x: f32, y: f32) {
+        if let Some(path) = self.last_mut() {
+            OutlinePen::line_to(path, x, y)
+        }
+    }
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        if let Some(path) = self.last_mut() {
+            OutlinePen::quad_to(path, x1, y1, x, y)
+        }
+    }
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        if let Some(path) = self.last_mut() {
+            OutlinePen::curve_to(path, x1, y1, x2, y2, x, y)
+        }
+    }
+    fn close(&mut self) {
+        if let Some(path) = self.last_mut() {
+            path.close_path()
+        }
+    }
+}
+
+/// An outline copied from Harfbuzz, which is handed back to be freed
+/// when dropped.
+pub(crate) struct HostOutline(CGlyphOutline);
+
+impl HostOutline {
+    pub(crate) fn copy(font: u32, glyph: u32) -> Option<Self> {
+        let mut outline = HostOutline(CGlyphOutline {
+            n_points: 0,
+            points: std::ptr::null_mut(),
+            n_contours: 0,
+            contours: std::ptr::null_mut(),
+        });
+        // If this fails, `outline` still frees anything the host
+        // managed to allocate.
+        unsafe { font_copy_glyph_outline(font, glyph, &mut outline.0) }.then_some(outline)
+    }
+
+    fn points(&self) -> &[CGlyphOutlinePoint] {
+        if self.0.points.is_null() {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.0.points, self.0.n_points) }
+    }
+
+    fn contours(&self) -> &[usize] {
+        if self.0.contours.is_null() {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.0.contours, self.0.n_contours) }
+    }
+
+    /// Check the outline and draw it with a pen, or return `None`
+    /// without drawing anything if it is malformed.
+    pub(crate) fn draw(&self, pen: &mut impl OutlinePen) -> Option<()> {
+        let ops = decode(self.points(), self.contours())?;
+        for op in ops {
+            match op {
+                Op::Move(p) => pen.move_to(p.x, p.y),
+                Op::Line(p) => pen.line_to(p.x, p.y),
+                Op::Quad(c, p) => pen.quad_to(c.x, c.y, p.x, p.y),
+                Op::Cubic(c1, c2, p) => pen.curve_to(c1.x, c1.y, c2.x, c2.y, p.x, p.y),
+                Op::Close => pen.close(),
+            }
+        }
+        Some(())
+    }
+}
+
+impl Drop for HostOutline {
+    fn drop(&mut self) {
+        unsafe { glyph_outline_free(&mut self.0) }
+    }
+}
+
+enum Op<'a> {
+    Move(&'a CGlyphOutlinePoint),
+    Line(&'a CGlyphOutlinePoint),
+    Quad(&'a CGlyphOutlinePoint, &'a CGlyphOutlinePoint),
+    Cubic(
+        &'a CGlyphOutlinePoint,
+        &'a CGlyphOutlinePoint,
+        &'a CGlyphOutlinePoint,
+    ),
+    Close,
+}
+
+// Turn the points and contour ends copied from Harfbuzz into drawing
+// operations. Each contour runs from the end of the last one up to
+// (but not including) its end index, starts with a move, and keeps
+// all the points of each curve together.
+fn decode<'a>(points: &'a [CGlyphOutlinePoint], contours: &[usize]) -> Option<Vec<Op<'a>>> {
+    let kind = |p: &CGlyphOutlinePoint| PointType::from_raw(p.pointtype);
+    let mut ops = vec![];
+    let mut start = 0;
+    for &end in contours {
+        let contour = points.get(start..end)?;
+        start = end;
+        let Some((first, mut rest)) = contour.split_first() else {
+            continue;
+        };
+        if !matches!(kind(first)?, PointType::MoveTo) {
+            return None;
+        }
+        ops.push(Op::Move(first));
+        while let Some(point) = rest.first() {
+            let (op, len) = match kind(point)? {
+                PointType::MoveTo => return None,
+                PointType::LineTo => (Op::Line(point), 1),
+                PointType::QuadraticTo => match rest {
+                    [c, p, ..] if matches!(kind(p)?, PointType::QuadraticTo) => (Op::Quad(c, p), 2),
+                    _ => return None,
+                },
+                PointType::CubicTo => match rest {
+                    [c1, c2, p, ..]
+                        if matches!(kind(c2)?, PointType::CubicTo)
+                            && matches!(kind(p)?, PointType::CubicTo) =>
+                    {
+                        (Op::Cubic(c1, c2, p), 3)
+                    }
+                    _ => return None,
+                },
+            };
+            ops.push(op);
+            rest = &rest[len..];
+        }
+        ops.push(Op::Close);
+    }
+    // Every point should belong to a contour.
+    (start == points.len()).then_some(ops)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Points of the given kinds (0 move, 1 line, 2 quadratic, 3
+    // cubic), numbered along the x axis.
+    fn points(kinds: &[u32]) -> Vec<CGlyphOutlinePoint> {
+        kinds
+            .iter()
+            .enumerate()
+            .map(|(ix, &pointtype)| CGlyphOutlinePoint {
+                x: ix as f32,
+                y: 0.0,
+                pointtype,
+            })
+            .collect()
+    }
+
+    fn ops(kinds: &[u32], contours: &[usize]) -> Option<usize> {
+        decode(&points(kinds), contours).map(|ops| ops.len())
+    }
+
+    #[test]
+    fn well_formed_contours() {
+        // A move, a line, a quad and a cubic, then a close
+        assert_eq!(ops(&[0, 1, 2, 2, 3, 3, 3], &[7]), Some(5));
+        assert_eq!(ops(&[0, 1, 0, 1], &[2, 4]), Some(6));
+        // An empty contour draws nothing
+        assert_eq!(ops(&[0, 1], &[0, 2]), Some(3));
+        assert_eq!(ops(&[], &[]), Some(0));
+    }
+
+    #[test]
+    fn truncated_curves() {
+        // A contour ending on a quadratic control point
+        assert_eq!(ops(&[0, 1, 2], &[3]), None);
+        // ...or with the quadratic's end point in the next contour
+        assert_eq!(ops(&[0, 2, 2, 1], &[2, 4]), None);
+        // A cubic missing its end point
+        assert_eq!(ops(&[0, 3, 3], &[3]), None);
+        assert_eq!(ops(&[0, 3, 3, 3, 0, 3], &[4, 6]), None);
+        // A cubic whose second point isn't a cubic control point
+        assert_eq!(ops(&[0, 3, 1, 3], &[4]), None);
+    }
+
+    #[test]
+    fn contours_start_with_a_move() {
+        assert_eq!(ops(&[1, 1], &[2]), None);
+        assert_eq!(ops(&[0, 1, 2, 2], &[1, 4]), None);
+        // ...and only there
+        assert_eq!(ops(&[0, 1, 0], &[3]), None);
+        // Unknown point types are an error too
+        assert_eq!(ops(&[0, 7], &[2]), None);
+    }
+
+    #[test]
+    fn contour_ends_in_range() {
+        // Past the last point
+        assert_eq!(ops(&[0, 1], &[3]), None);
+        // Going backwards
+        assert_eq!(ops(&[0, 1, 0, 1], &[2, 1]), None);
+        // Leaving points out of every contour
+        assert_eq!(ops(&[0, 1, 1], &[2]), None);
+    }
+}
@@ -0,0 +1,295 @@
+use ttf_parser::gpos::{
+    CursiveAdjustment, Device, PairAdjustment, PositioningSubtable, ValueRecord,
+};
+use ttf_parser::{GlyphId, NormalizedCoordinate};
+
+use crate::tables::{gdef, gpos, with_layout_face};
+use crate::{ot_round, Font};
+
+/// A point on a glyph where another glyph attaches, from the `GPOS`
+/// table, scaled to the font.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Anchor {
+    /// Horizontal position
+    pub x: i32,
+    /// Vertical position
+    pub y: i32,
+}
+
+/// Where a glyph joins the glyphs either side of it in cursive
+/// attachment.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CursiveAnchors {
+    /// Where the previous glyph's exit attaches
+    pub entry: Option<Anchor>,
+    /// Where the next glyph's entry attaches
+    pub exit: Option<Anchor>,
+}
+
+impl CursiveAnchors {
+    /// How far the exit is above the entry. A missing anchor counts
+    /// as being at the glyph's origin.
+    ///
+    /// Adding up the rise of each glyph in a cursively joined run
+    /// gives how far the origin of its last glyph (in logical order)
+    /// is above that of its first.
+    pub fn rise(&self) -> i32 {
+        self.exit.unwrap_or_default().y - self.entry.unwrap_or_default().y
+    }
+}
+
+/// How a mark attaches to a base glyph (or to another mark).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MarkAttachment {
+    /// The mark's class in the subtable which attaches it
+    pub class: u16,
+    /// The anchor on the base glyph for marks of this class
+    pub base: Anchor,
+    /// The anchor on the mark
+    pub mark: Anchor,
+}
+
+impl MarkAttachment {
+    /// How far to move the mark from the base glyph's origin so that
+    /// the anchors meet.
+    pub fn offset(&self) -> (i32, i32) {
+        (self.base.x - self.mark.x, self.base.y - self.mark.y)
+    }
+}
+
+/// A change to a glyph's position and advance, from the `GPOS`
+/// table, scaled to the font.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Adjustment {
+    /// Horizontal movement
+    pub x_placement: i32,
+    /// Vertical movement
+    pub y_placement: i32,
+    /// Change to the horizontal advance
+    pub x_advance: i32,
+    /// Change to the vertical advance
+    pub y_advance: i32,
+}
+
+impl std::ops::AddAssign for Adjustment {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x_placement += rhs.x_placement;
+        self.y_placement += rhs.y_placement;
+        self.x_advance += rhs.x_advance;
+        self.y_advance += rhs.y_advance;
+    }
+}
+
+// Turns design units from GPOS into the font's scale, adding any
+// variation deltas at the font's current coordinates.
+struct Scaler<'a> {
+    x_scale: f32,
+    y_scale: f32,
+    coords: Vec<NormalizedCoordinate>,
+    gdef: Option<gdef::Table<'a>>,
+}
+
+impl<'a> Scaler<'a> {
+    fn new(font: &Font, face: &ttf_parser::Face<'a>) -> Self {
+        let (x_scale, y_scale) = font.get_scale();
+        let upem = font.get_face().get_upem() as f32;
+        Scaler {
+            x_scale: x_scale as f32 / upem,
+            y_scale: y_scale as f32 / upem,
+            coords: font
+                .try_get_var_coords()
+                .unwrap_or_default()
+                .into_iter()
+                .map(NormalizedCoordinate::from)
+                .collect(),
+            gdef: face.tables().gdef,
+        }
+    }
+
+    // Hinting device tables depend on the pixel size, so only
+    // variation deltas are applied.
+    fn delta(&self, device: Option<Device>) -> f32 {
+        match (device, self.gdef) {
+            (Some(Device::Variation(v)), Some(gdef)) => gdef
+                .glyph_variation_delta(v.outer_index, v.inner_index, &self.coords)
+                .unwrap_or(0.0),
+            _ => 0.0,
+        }
+    }
+
+    fn x(&self, value: i16, device: Option<Device>) -> i32 {
+        ot_round((value as f32 + self.delta(device)) * self.x_scale)
+    }
+
+    fn y(&self, value: i16, device: Option<Device>) -> i32 {
+        ot_round((value as f32 + self.delta(device)) * self.y_scale)
+    }
+
+    fn anchor(&self, anchor: gpos::Anchor) -> Anchor {
+        Anchor {
+            x: self.x(anchor.x, anchor.x_device),
+            y: self.y(anchor.y, anchor.y_device),
+        }
+    }
+
+    fn adjustment(&self, value: &ValueRecord) -> Adjustment {
+        Adjustment {
+            x_placement: self.x(value.x_placement, value.x_placement_device),
+            y_placement: self.y(value.y_placement, value.y_placement_device),
+            x_advance: self.x(value.x_advance, value.x_advance_device),
+            y_advance: self.y(value.y_advance, value.y_advance_device),
+        }
+    }
+}
+
+// Every positioning subtable in the font, lookup by lookup.
+fn lookups<'a>(
+    face: &ttf_parser::Face<'a>,
+) -> impl Iterator<Item = impl Iterator<Item = PositioningSubtable<'a>>> {
+    face.tables()
+        .gpos
+        .into_iter()
+        .flat_map(|gpos| gpos.lookups)
+        .map(|lookup| lookup.subtables.into_iter::<PositioningSubtable>())
+}
+
+// The anchors a cursive attachment subtable gives a glyph, if it
+// covers it.
+fn cursive_in(cursive: &CursiveAdjustment, glyph: u32, scaler: &Scaler) -> Option<CursiveAnchors> {
+    let index = cursive.coverage.get(GlyphId(glyph as u16))?;
+    Some(CursiveAnchors {
+        entry: cursive.sets.entry(index).map(|a| scaler.anchor(a)),
+        exit: cursive.sets.exit(index).map(|a| scaler.anchor(a)),
+    })
+}
+
+// Apply `f` to every positioning subtable in lookup order, and
+// return its first answer.
+fn first_in_gpos<R>(
+    font: &Font,
+    f: impl Fn(&PositioningSubtable, &Scaler) -> Option<R>,
+) -> Option<R> {
+    with_layout_face(&font.get_face(), |face| {
+        let scaler = Scaler::new(font, face);
+        lookups(face)
+            .flatten()
+            .find_map(|subtable| f(&subtable, &scaler))
+    })?
+}
+
+impl Font {
+    /// Get the cursive entry and exit anchors of a glyph from the
+    /// `GPOS` table, at the font's current variation coordinates.
+    ///
+    /// The anchors come from the first cursive attachment subtable,
+    /// in lookup order, which covers the glyph. Returns `None` if
+    /// there isn't one.
+    ///
+    /// To look up the anchors of a whole buffer of glyphs, use
+    /// [`Font::cursive_anchors_for`], which reads the table once.
+    pub fn cursive_anchors(&self, glyph: u32) -> Option<CursiveAnchors> {
+        first_in_gpos(self, |subtable, scaler| match subtable {
+            PositioningSubtable::Cursive(cursive) => cursive_in(cursive, glyph, scaler),
+            _ => None,
+        })
+    }
+
+    /// Get the cursive entry and exit anchors of each of a list of
+    /// glyphs, as [`Font::cursive_anchors`] would.
+    pub fn cursive_anchors_for(&self, glyphs: &[u32]) -> Vec<Option<CursiveAnchors>> {
+        with_layout_face(&self.get_face(), |face| {
+            let scaler = Scaler::new(self, face);
+            let subtables: Vec<CursiveAdjustment> = lookups(face)
+                .flatten()
+                .filter_map(|subtable| match subtable {
+                    PositioningSubtable::Cursive(cursive) => Some(cursive),
+                    _ => None,
+                })
+                .collect();
+            glyphs
+                .iter()
+                .map(|&glyph| {
+                    subtables
+                        .iter()
+                        .find_map(|cursive| cursive_in(cursive, glyph, &scaler))
+                })
+                .collect()
+        })
+        .unwrap_or_else(|| vec![None; glyphs.len()])
+    }
+
+    /// Get the anchors which attach a mark to a base glyph from the
+    /// `GPOS` table, at the font's current variation coordinates.
+    ///
+    /// The base may itself be a mark, in which case mark-to-mark
+    /// attachment is used. The anchors come from the first subtable,
+    /// in lookup order, which covers both glyphs and has an anchor
+    /// on the base for the mark's class.
+    pub fn mark_attachment(&self, base: u32, mark: u32) -> Option<MarkAttachment> {
+        let (base, mark) = (GlyphId(base as u16), GlyphId(mark as u16));
+        first_in_gpos(self, |subtable, scaler| {
+            let (marks, base_anchors, base_index) = match subtable {
+                PositioningSubtable::MarkToBase(t) => {
+                    (t.marks, t.anchors, t.base_coverage.get(base)?)
+                }
+                PositioningSubtable::MarkToMark(t) => {
+                    (t.marks, t.mark2_matrix, t.mark2_coverage.get(base)?)
+                }
+                _ => return None,
+            };
+            let mark_index = subtable.coverage().get(mark)?;
+            let (class, mark_anchor) = marks.get(mark_index)?;
+            let base_anchor = base_anchors.get(base_index, class)?;
+            Some(MarkAttachment {
+                class,
+                base: scaler.anchor(base_anchor),
+                mark: scaler.anchor(mark_anchor),
+            })
+        })
+    }
+
+    /// Get the adjustments the `GPOS` table makes to a pair of glyphs
+    /// next to each other, at the font's current variation
+    /// coordinates.
+    ///
+    /// Each lookup adjusts the pair with the first of its subtables
+    /// which covers it, and the adjustments of every lookup are
+    /// added together. Returns `None` if no lookup covers the pair.
+    pub fn pair_adjustment(&self, first: u32, second: u32) -> Option<(Adjustment, Adjustment)> {
+        let (first, second) = (GlyphId(first as u16), GlyphId(second as u16));
+        with_layout_face(&self.get_face(), |face| {
+            let scaler = Scaler::new(self, face);
+            let mut total: Option<(Adjustment, Adjustment)> = None;
+            for mut lookup in lookups(face) {
+                let values = lookup.find_map(|subtable| match subtable {
+                    PositioningSubtable::Pair(PairAdjustment::Format1 { coverage, sets }) => {
+                        sets.get(coverage.get(first)?)?.get(second)
+                    }
+                    PositioningSubtable::Pair(PairAdjustment::Format2 {
+                        coverage,
+                        classes,
+                        matrix,
+                    }) => {
+                        coverage.get(first)?;
+                        matrix.get((classes.0.get(first), classes.1.get(second)))
+                    }
+                    _ => None,
+                });
+                if let Some((v1, v2)) = values {
+                    let sum = total.get_or_insert_with(Default::default);
+                    sum.0 += scaler.adjustment(&v1);
+                    sum.1 += scaler.adjustment(&v2);
+                }
+            }
+            total
+        })?
+    }
+
+    /// Get the kerning between two glyphs from the `GPOS` table: the
+    /// change to the first glyph's horizontal advance when the second
+    /// follows it. Returns zero if the pair isn't kerned.
+    pub fn pair_kerning(&self, first: u32, second: u32) -> i32 {
+        self.pair_adjustment(first, second)
+            .map_or(0, |(first, _)| first.x_advance)
+    }
+}
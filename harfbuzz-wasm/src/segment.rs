@@ -0,0 +1,108 @@
+//! Properties of the text in a buffer.
+//!
+//! Shapers can read a buffer's direction and script, but can't
+//! change them. A buffer's language and its flags (such as whether
+//! it holds the beginning or end of the text, or whether default
+//! ignorables should be kept) aren't available at all: Harfbuzz
+//! doesn't export functions for any of these to WASM shapers.
+
+/// The text flow direction of a buffer, equivalent to
+/// Harfbuzz's `hb_direction_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(u32)]
+pub enum Direction {
+    /// The direction has not been set
+    #[default]
+    Invalid = 0,
+    /// Text is set horizontally from left to right
+    LeftToRight = 4,
+    /// Text is set horizontally from right to left
+    RightToLeft = 5,
+    /// Text is set vertically from top to bottom
+    TopToBottom = 6,
+    /// Text is set vertically from bottom to top
+    BottomToTop = 7,
+}
+
+impl Direction {
+    /// Convert the value returned by Harfbuzz into a `Direction`.
+    pub fn from_raw(value: u32) -> Self {
+        match value {
+            4 => Direction::LeftToRight,
+            5 => Direction::RightToLeft,
+            6 => Direction::TopToBottom,
+            7 => Direction::BottomToTop,
+            _ => Direction::Invalid,
+        }
+    }
+
+    /// Is this a horizontal direction?
+    pub fn is_horizontal(&self) -> bool {
+        matches!(self, Direction::LeftToRight | Direction::RightToLeft)
+    }
+
+    /// Is this a vertical direction?
+    pub fn is_vertical(&self) -> bool {
+        matches!(self, Direction::TopToBottom | Direction::BottomToTop)
+    }
+
+    /// Does the text run backwards (right to left or bottom to top)?
+    ///
+    /// After shaping, glyphs in a backward buffer are stored in
+    /// visual order, i.e. the reverse of the input text.
+    pub fn is_backward(&self) -> bool {
+        matches!(self, Direction::RightToLeft | Direction::BottomToTop)
+    }
+
+    /// Does the text run forwards (left to right or top to bottom)?
+    pub fn is_forward(&self) -> bool {
+        matches!(self, Direction::LeftToRight | Direction::TopToBottom)
+    }
+
+    /// Return the opposite direction.
+    pub fn reverse(&self) -> Self {
+        match self {
+            Direction::LeftToRight => Direction::RightToLeft,
+            Direction::RightToLeft => Direction::LeftToRight,
+            Direction::TopToBottom => Direction::BottomToTop,
+            Direction::BottomToTop => Direction::TopToBottom,
+            Direction::Invalid => Direction::Invalid,
+        }
+    }
+}
+
+/// How Harfbuzz assigns clusters to glyphs, equivalent to
+/// Harfbuzz's `hb_buffer_cluster_level_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(u32)]
+pub enum ClusterLevel {
+    /// Marks are merged into their base's cluster, and cluster
+    /// values never go backwards (Harfbuzz's default)
+    #[default]
+    MonotoneGraphemes = 0,
+    /// Each character has its own cluster where possible, and
+    /// cluster values never go backwards
+    MonotoneCharacters = 1,
+    /// Each character has its own cluster, in any order
+    Characters = 2,
+    /// Marks are merged into their base's cluster, in any order
+    Graphemes = 3,
+}
+
+impl ClusterLevel {
+    /// Must cluster values follow the order of the text?
+    pub fn is_monotone(&self) -> bool {
+        matches!(
+            self,
+            ClusterLevel::MonotoneGraphemes | ClusterLevel::MonotoneCharacters
+        )
+    }
+
+    /// Are marks merged into the clusters of their bases?
+    pub fn is_graphemes(&self) -> bool {
+        matches!(
+            self,
+            ClusterLevel::MonotoneGraphemes | ClusterLevel::Graphemes
+        )
+    }
+}
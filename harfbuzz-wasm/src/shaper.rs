@@ -0,0 +1,126 @@
+use std::panic;
+use std::sync::Once;
+
+use crate::{copy_contents, debug, Buffer, ClusterLevel, FeatureList, Font, Glyph};
+
+/// What a shaping function returns. Returning an error makes
+/// [`run_shaper`] throw away whatever the shaper did to the buffer
+/// and shape it with the ordinary OpenType shaper instead.
+pub type ShapeResult = Result<(), Box<dyn std::error::Error>>;
+
+static PANIC_HOOK: Once = Once::new();
+
+/// Run a shaping function, falling back to OpenType shaping if it
+/// fails.
+///
+/// The contents of the buffer are saved before `shaper` is called.
+/// If it returns an error, the saved contents are put back, the
+/// buffer is shaped with `font.shape_with(buf_ref, "ot")` and the
+/// error is written to the debug log, so some text still gets shaped.
+///
+/// Panics can't be recovered from: WASM builds abort on panic, so
+/// the instance traps and Harfbuzz gives up on the text. The panic
+/// message is written to the debug log first, but anything which
+/// might go wrong while shaping (a missing glyph, text which can't
+/// be parsed) should be returned as an error instead.
+///
+/// The shaper is given the shape plan Harfbuzz passed in, so that it
+/// can keep state for it with
+/// [`ShaperCache::get_or_insert_with_plan`](crate::ShaperCache::get_or_insert_with_plan).
+///
+/// You will usually call this through [`register_shaper!`](crate::register_shaper).
+pub fn run_shaper<F>(
+    shape_plan: u32,
+    font_ref: u32,
+    buf_ref: u32,
+    features: u32,
+    num_features: u32,
+    shaper: F,
+) -> i32
+where
+    F: FnOnce(u32, &Font, u32, &FeatureList) -> ShapeResult,
+{
+    PANIC_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            debug(&format!("Shaper {}", info));
+            previous(info)
+        }))
+    });
+    let font = Font::from_ref(font_ref);
+    let features = FeatureList::from_ref(features, num_features);
+    let original: Vec<Glyph> = match copy_contents(buf_ref) {
+        Ok(glyphs) => glyphs,
+        Err(e) => {
+            debug(&e.to_string());
+            return 0;
+        }
+    };
+    let Err(error) = shaper(shape_plan, &font, buf_ref, &features) else {
+        return 1;
+    };
+    debug(&format!(
+        "Shaper failed, falling back to OpenType: {}",
+        error
+    ));
+    let mut buffer = Buffer {
+        _ptr: buf_ref,
+        glyphs: original,
+        committed: false,
+        cluster_level: ClusterLevel::default(),
+    };
+    if let Err(e) = buffer.commit() {
+        debug(&e.to_string());
+        return 0;
+    }
+    font.shape_with_features(buf_ref, "ot", &features.features);
+    1
+}
+
+/// Define the `shape` function Harfbuzz calls, wrapping a shaping
+/// function with [`run_shaper`] so that errors fall back to
+/// OpenType shaping rather than losing the text.
+///
+/// The shaping function takes the shape plan, the font, the buffer
+/// pointer and the user's feature settings, and returns a
+/// [`ShapeResult`]. Your crate needs to depend on `wasm-bindgen`.
+///
+/// ```rust,ignore
+/// use harfbuzz_wasm::{register_shaper, FeatureList, Font, GlyphBuffer, ShapeResult};
+///
+/// fn shape_text(
+///     _shape_plan: u32,
+///     font: &Font,
+///     buf_ref: u32,
+///     features: &FeatureList,
+/// ) -> ShapeResult {
+///     font.shape_with_features(buf_ref, "ot", &features.features);
+///     let mut buffer = GlyphBuffer::from_ref(buf_ref);
+///     // ... adjust the glyphs ...
+///     Ok(())
+/// }
+///
+/// register_shaper!(shape_text);
+/// ```
+#[macro_export]
+macro_rules! register_shaper {
+    ($shaper:expr) => {
+        #[::wasm_bindgen::prelude::wasm_bindgen]
+        pub fn shape(
+            shape_plan: u32,
+            font_ref: u32,
+            buf_ref: u32,
+            features: u32,
+            num_features: u32,
+        ) -> i32 {
+            $crate::run_shaper(
+                shape_plan,
+                font_ref,
+                buf_ref,
+                features,
+                num_features,
+                $shaper,
+            )
+        }
+    };
+}
@@ -0,0 +1,83 @@
+//! Typed readers for OpenType tables.
+//!
+//! The readers come from the [`ttf_parser`] crate, and are re-exported
+//! here by table so that shapers don't need to depend on it directly.
+//! Fetch the table with [`Face::reference_table`](crate::Face::reference_table)
+//! and parse it with [`Blob::parse`]:
+//!
+//! ```rust,ignore
+//! use harfbuzz_wasm::tables::os2;
+//!
+//! let blob = font.get_face().reference_table("OS/2");
+//! let os2: os2::Table = blob.parse()?;
+//! let x_height = os2.x_height();
+//! ```
+use crate::cache::cached_table;
+use crate::{Blob, Face, HbWasmError};
+
+pub use ttf_parser::{avar, cmap, fvar, gdef, gpos, head, hhea, maxp, name, os2, post, stat};
+
+/// An OpenType table which can be parsed from a [`Blob`].
+pub trait FontTable<'a>: Sized {
+    /// The table's tag
+    const TAG: &'static str;
+
+    /// Parse the table from its binary data.
+    fn parse(data: &'a [u8]) -> Option<Self>;
+}
+
+macro_rules! font_table {
+    ($tag:expr, $table:ty) => {
+        impl<'a> FontTable<'a> for $table {
+            const TAG: &'static str = $tag;
+            fn parse(data: &'a [u8]) -> Option<Self> {
+                <$table>::parse(data)
+            }
+        }
+    };
+}
+
+font_table!("head", head::Table);
+font_table!("hhea", hhea::Table);
+font_table!("maxp", maxp::Table);
+font_table!("OS/2", os2::Table<'a>);
+font_table!("name", name::Table<'a>);
+font_table!("post", post::Table<'a>);
+font_table!("fvar", fvar::Table<'a>);
+font_table!("avar", avar::Table<'a>);
+font_table!("STAT", stat::Table<'a>);
+font_table!("GDEF", gdef::Table<'a>);
+font_table!("cmap", cmap::Table<'a>);
+
+impl Blob {
+    /// Parse the blob as an OpenType table.
+    pub fn parse<'a, T: FontTable<'a>>(&'a self) -> Result<T, HbWasmError> {
+        T::parse(self.as_bytes()).ok_or_else(|| HbWasmError::TableMalformed(T::TAG.to_string()))
+    }
+}
+
+/// Run `f` on a ttf-parser face made from just the face's layout
+/// tables, or return `None` if it has no `GPOS` table.
+///
+/// ttf-parser will only read `GPOS` as part of a whole face, but it
+/// doesn't need the rest of the font to do so.
+pub(crate) fn with_layout_face<R>(
+    face: &Face,
+    f: impl FnOnce(&ttf_parser::Face) -> R,
+) -> Option<R> {
+    let head = cached_table(face, "head")?;
+    let hhea = cached_table(face, "hhea")?;
+    let maxp = cached_table(face, "maxp")?;
+    let gpos = cached_table(face, "GPOS")?;
+    let gdef = cached_table(face, "GDEF");
+    let raw = ttf_parser::RawFaceTables {
+        head: head.as_bytes(),
+        hhea: hhea.as_bytes(),
+        maxp: maxp.as_bytes(),
+        gpos: Some(gpos.as_bytes()),
+        gdef: gdef.as_ref().map(|blob| blob.as_bytes()),
+        ..Default::default()
+    };
+    let parsed = ttf_parser::Face::from_raw_tables(raw).ok()?;
+    Some(f(&parsed))
+}
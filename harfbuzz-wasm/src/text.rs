@@ -0,0 +1,82 @@
+use std::ops::Range;
+
+use crate::{Buffer, Glyph};
+
+/// The text held in a buffer before shaping, with a way to map
+/// positions in the text back to glyphs and clusters.
+///
+/// Positions are byte offsets into [`BufferText::text`], as returned
+/// by `str::find` or by regular expression matches.
+#[derive(Debug, Clone, Default)]
+pub struct BufferText {
+    /// The text
+    pub text: String,
+    // The byte offset of each glyph's character in the text
+    offsets: Vec<usize>,
+    // The cluster of each glyph
+    clusters: Vec<u32>,
+}
+
+impl BufferText {
+    /// The index of the glyph whose character contains the given byte.
+    pub fn glyph_index(&self, byte: usize) -> Option<usize> {
+        if byte >= self.text.len() {
+            return None;
+        }
+        Some(match self.offsets.binary_search(&byte) {
+            Ok(ix) => ix,
+            Err(ix) => ix - 1,
+        })
+    }
+
+    /// The cluster of the character containing the given byte.
+    pub fn cluster_at(&self, byte: usize) -> Option<u32> {
+        self.glyph_index(byte).map(|ix| self.clusters[ix])
+    }
+
+    /// The range of glyphs covering a range of bytes in the text.
+    pub fn glyph_range(&self, bytes: Range<usize>) -> Range<usize> {
+        let start = self.glyph_index(bytes.start).unwrap_or(self.offsets.len());
+        let end = match bytes.end.checked_sub(1) {
+            Some(last) if bytes.end > bytes.start => self
+                .glyph_index(last)
+                .map_or(self.offsets.len(), |ix| ix + 1),
+            _ => start,
+        };
+        start..end
+    }
+}
+
+impl Buffer<Glyph> {
+    /// The text in the buffer.
+    ///
+    /// This only makes sense before shaping, while the glyphs still
+    /// hold Unicode characters. Anything which isn't a valid character
+    /// becomes U+FFFD.
+    pub fn text(&self) -> String {
+        self.text_map().text
+    }
+
+    /// The text in the buffer, along with a mapping from positions in
+    /// the text back to glyphs and clusters. See [`Buffer::text`].
+    ///
+    /// ```rust,ignore
+    /// let text = buffer.text_map();
+    /// if let Some(m) = re.find(&text.text) {
+    ///     buffer.replace_range(text.glyph_range(m.range()), result);
+    /// }
+    /// ```
+    pub fn text_map(&self) -> BufferText {
+        let mut text = String::new();
+        let mut offsets = Vec::with_capacity(self.glyphs.len());
+        for glyph in self.glyphs.iter() {
+            offsets.push(text.len());
+            text.push(char::from_u32(glyph.codepoint).unwrap_or(char::REPLACEMENT_CHARACTER));
+        }
+        BufferText {
+            text,
+            offsets,
+            clusters: self.glyphs.iter().map(|g| g.cluster).collect(),
+        }
+    }
+}
@@ -0,0 +1,270 @@
+//! Unicode character properties.
+//!
+//! These let a shaper classify the characters in a buffer before
+//! shaping, rather than guessing from glyph names afterwards. The
+//! data comes from the Unicode Character Database (see
+//! `bin/gen-unicode-tables`), and is looked up with a binary search
+//! over compact range tables.
+//!
+//! ```rust,ignore
+//! use harfbuzz_wasm::unicode::{joining_type, JoiningType};
+//!
+//! let joins_left = matches!(
+//!     joining_type(c),
+//!     JoiningType::DualJoining | JoiningType::JoinCausing
+//! );
+//! ```
+mod tables;
+
+/// The general category of a character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GeneralCategory {
+    /// `Lu`
+    UppercaseLetter,
+    /// `Ll`
+    LowercaseLetter,
+    /// `Lt`
+    TitlecaseLetter,
+    /// `Lm`
+    ModifierLetter,
+    /// `Lo`
+    OtherLetter,
+    /// `Mn`
+    NonspacingMark,
+    /// `Mc`
+    SpacingMark,
+    /// `Me`
+    EnclosingMark,
+    /// `Nd`
+    DecimalNumber,
+    /// `Nl`
+    LetterNumber,
+    /// `No`
+    OtherNumber,
+    /// `Pc`
+    ConnectorPunctuation,
+    /// `Pd`
+    DashPunctuation,
+    /// `Ps`
+    OpenPunctuation,
+    /// `Pe`
+    ClosePunctuation,
+    /// `Pi`
+    InitialPunctuation,
+    /// `Pf`
+    FinalPunctuation,
+    /// `Po`
+    OtherPunctuation,
+    /// `Sm`
+    MathSymbol,
+    /// `Sc`
+    CurrencySymbol,
+    /// `Sk`
+    ModifierSymbol,
+    /// `So`
+    OtherSymbol,
+    /// `Zs`
+    SpaceSeparator,
+    /// `Zl`
+    LineSeparator,
+    /// `Zp`
+    ParagraphSeparator,
+    /// `Cc`
+    Control,
+    /// `Cf`
+    Format,
+    /// `Cs`
+    Surrogate,
+    /// `Co`
+    PrivateUse,
+    /// `Cn`
+    Unassigned,
+}
+
+impl GeneralCategory {
+    /// Is this a letter category?
+    pub fn is_letter(&self) -> bool {
+        use GeneralCategory::*;
+        matches!(
+            self,
+            UppercaseLetter | LowercaseLetter | TitlecaseLetter | ModifierLetter | OtherLetter
+        )
+    }
+
+    /// Is this a mark category?
+    pub fn is_mark(&self) -> bool {
+        use GeneralCategory::*;
+        matches!(self, NonspacingMark | SpacingMark | EnclosingMark)
+    }
+}
+
+/// How a character joins to its neighbours in cursive scripts such
+/// as Arabic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JoiningType {
+    /// `U`: doesn't join
+    NonJoining,
+    /// `R`: joins to the character before it
+    RightJoining,
+    /// `L`: joins to the character after it
+    LeftJoining,
+    /// `D`: joins on both sides
+    DualJoining,
+    /// `C`: makes the characters either side join to it, like tatweel
+    JoinCausing,
+    /// `T`: is skipped over when working out joining, like most marks
+    Transparent,
+}
+
+/// The bidirectional class of a character.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BidiClass {
+    /// Left-to-right
+    L,
+    /// Right-to-left
+    R,
+    /// Arabic letter
+    AL,
+    /// European number
+    EN,
+    /// European separator
+    ES,
+    /// European number terminator
+    ET,
+    /// Arabic number
+    AN,
+    /// Common number separator
+    CS,
+    /// Nonspacing mark
+    NSM,
+    /// Boundary neutral
+    BN,
+    /// Paragraph separator
+    B,
+    /// Segment separator
+    S,
+    /// Whitespace
+    WS,
+    /// Other neutral
+    ON,
+    /// Left-to-right embedding
+    LRE,
+    /// Left-to-right override
+    LRO,
+    /// Right-to-left embedding
+    RLE,
+    /// Right-to-left override
+    RLO,
+    /// Pop directional format
+    PDF,
+    /// Left-to-right isolate
+    LRI,
+    /// Right-to-left isolate
+    RLI,
+    /// First strong isolate
+    FSI,
+    /// Pop directional isolate
+    PDI,
+}
+
+/// The Egyptian hieroglyph format controls, which describe how signs
+/// are arranged into quadrats.
+///
+/// These are the controls of Unicode 14, which the character
+/// property tables come from. The ones Unicode 15 added, from U+13439
+/// to U+1343F, are unassigned characters here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EgyptianFormatControl {
+    /// U+13430, stacks signs vertically
+    VerticalJoiner,
+    /// U+13431, places signs side by side
+    HorizontalJoiner,
+    /// U+13432, inserts a sign at the top start of another
+    InsertAtTopStart,
+    /// U+13433, inserts a sign at the bottom start of another
+    InsertAtBottomStart,
+    /// U+13434, inserts a sign at the top end of another
+    InsertAtTopEnd,
+    /// U+13435, inserts a sign at the bottom end of another
+    InsertAtBottomEnd,
+    /// U+13436, overlays two signs
+    OverlayMiddle,
+    /// U+13437, begins a group
+    BeginSegment,
+    /// U+13438, ends a group
+    EndSegment,
+}
+
+// Find the value of the range containing a character.
+fn lookup<T: Copy>(table: &[(u32, T)], c: char) -> T {
+    let ix = table.partition_point(|&(start, _)| start <= c as u32);
+    table[ix - 1].1
+}
+
+/// The general category of a character.
+pub fn general_category(c: char) -> GeneralCategory {
+    lookup(tables::GENERAL_CATEGORY, c)
+}
+
+/// The script of a character, as an ISO 15924 tag such as `Arab`.
+///
+/// Characters used with many scripts are `Zyyy` (Common), marks
+/// which take the script of their base are `Zinh` (Inherited), and
+/// unassigned characters are `Zzzz`.
+pub fn script(c: char) -> &'static str {
+    lookup(tables::SCRIPT, c)
+}
+
+/// How a character joins to its neighbours.
+pub fn joining_type(c: char) -> JoiningType {
+    lookup(tables::JOINING_TYPE, c)
+}
+
+/// The joining group of a character, such as `Beh` or `Yeh_Barree`:
+/// letters in the same group share a basic shape, and differ only
+/// in their dots and other marks.
+pub fn joining_group(c: char) -> Option<&'static str> {
+    lookup(tables::JOINING_GROUP, c)
+}
+
+/// The bidirectional class of a character.
+pub fn bidi_class(c: char) -> BidiClass {
+    lookup(tables::BIDI_CLASS, c)
+}
+
+/// The canonical combining class of a character; zero for bases.
+pub fn combining_class(c: char) -> u8 {
+    lookup(tables::COMBINING_CLASS, c)
+}
+
+/// Should the character be mirrored in right-to-left text?
+pub fn is_mirrored(c: char) -> bool {
+    lookup(tables::MIRRORED, c)
+}
+
+/// The character whose glyph is the mirror image of this one's,
+/// such as `)` for `(`.
+pub fn mirror(c: char) -> Option<char> {
+    tables::MIRRORING_GLYPH
+        .binary_search_by_key(&(c as u32), |&(from, _)| from)
+        .ok()
+        .and_then(|ix| char::from_u32(tables::MIRRORING_GLYPH[ix].1))
+}
+
+/// The Egyptian hieroglyph format control a character represents, if any.
+pub fn egyptian_format_control(c: char) -> Option<EgyptianFormatControl> {
+    use EgyptianFormatControl::*;
+    Some(match c as u32 {
+        0x13430 => VerticalJoiner,
+        0x13431 => HorizontalJoiner,
+        0x13432 => InsertAtTopStart,
+        0x13433 => InsertAtBottomStart,
+        0x13434 => InsertAtTopEnd,
+        0x13435 => InsertAtBottomEnd,
+        0x13436 => OverlayMiddle,
+        0x13437 => BeginSegment,
+        0x13438 => EndSegment,
+        _ => return None,
+    })
+}
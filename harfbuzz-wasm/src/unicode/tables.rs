@@ -0,0 +1,9166 @@
+// Generated by bin/gen-unicode-tables from Unicode 14.0.0. Do not edit.
+use super::{BidiClass, BidiClass::*, GeneralCategory, GeneralCategory::*};
+use super::{JoiningType, JoiningType::*};
+
+#[rustfmt::skip]
+pub(super) static GENERAL_CATEGORY: &[(u32, GeneralCategory)] = &[
+    (0x0000, Control),
+    (0x0020, SpaceSeparator),
+    (0x0021, OtherPunctuation),
+    (0x0024, CurrencySymbol),
+    (0x0025, OtherPunctuation),
+    (0x0028, OpenPunctuation),
+    (0x0029, ClosePunctuation),
+    (0x002A, OtherPunctuation),
+    (0x002B, MathSymbol),
+    (0x002C, OtherPunctuation),
+    (0x002D, DashPunctuation),
+    (0x002E, OtherPunctuation),
+    (0x0030, DecimalNumber),
+    (0x003A, OtherPunctuation),
+    (0x003C, MathSymbol),
+    (0x003F, OtherPunctuation),
+    (0x0041, UppercaseLetter),
+    (0x005B, OpenPunctuation),
+    (0x005C, OtherPunctuation),
+    (0x005D, ClosePunctuation),
+    (0x005E, ModifierSymbol),
+    (0x005F, ConnectorPunctuation),
+    (0x0060, ModifierSymbol),
+    (0x0061, LowercaseLetter),
+    (0x007B, OpenPunctuation),
+    (0x007C, MathSymbol),
+    (0x007D, ClosePunctuation),
+    (0x007E, MathSymbol),
+    (0x007F, Control),
+    (0x00A0, SpaceSeparator),
+    (0x00A1, OtherPunctuation),
+    (0x00A2, CurrencySymbol),
+    (0x00A6, OtherSymbol),
+    (0x00A7, OtherPunctuation),
+    (0x00A8, ModifierSymbol),
+    (0x00A9, OtherSymbol),
+    (0x00AA, OtherLetter),
+    (0x00AB, InitialPunctuation),
+    (0x00AC, MathSymbol),
+    (0x00AD, Format),
+    (0x00AE, OtherSymbol),
+    (0x00AF, ModifierSymbol),
+    (0x00B0, OtherSymbol),
+    (0x00B1, MathSymbol),
+    (0x00B2, OtherNumber),
+    (0x00B4, ModifierSymbol),
+    (0x00B5, LowercaseLetter),
+    (0x00B6, OtherPunctuation),
+    (0x00B8, ModifierSymbol),
+    (0x00B9, OtherNumber),
+    (0x00BA, OtherLetter),
+    (0x00BB, FinalPunctuation),
+    (0x00BC, OtherNumber),
+    (0x00BF, OtherPunctuation),
+    (0x00C0, UppercaseLetter),
+    (0x00D7, MathSymbol),
+    (0x00D8, UppercaseLetter),
+    (0x00DF, LowercaseLetter),
+    (0x00F7, MathSymbol),
+    (0x00F8, LowercaseLetter),
+    (0x0100, UppercaseLetter),
+    (0x0101, LowercaseLetter),
+    (0x0102, UppercaseLetter),
+    (0x0103, LowercaseLetter),
+    (0x0104, UppercaseLetter),
+    (0x0105, LowercaseLetter),
+    (0x0106, UppercaseLetter),
+    (0x0107, LowercaseLetter),
+    (0x0108, UppercaseLetter),
+    (0x0109, LowercaseLetter),
+    (0x010A, UppercaseLetter),
+    (0x010B, LowercaseLetter),
+    (0x010C, UppercaseLetter),
+    (0x010D, LowercaseLetter),
+    (0x010E, UppercaseLetter),
+    (0x010F, LowercaseLetter),
+    (0x0110, UppercaseLetter),
+    (0x0111, LowercaseLetter),
+    (0x0112, UppercaseLetter),
+    (0x0113, LowercaseLetter),
+    (0x0114, UppercaseLetter),
+    (0x0115, LowercaseLetter),
+    (0x0116, UppercaseLetter),
+    (0x0117, LowercaseLetter),
+    (0x0118, UppercaseLetter),
+    (0x0119, LowercaseLetter),
+    (0x011A, UppercaseLetter),
+    (0x011B, LowercaseLetter),
+    (0x011C, UppercaseLetter),
+    (0x011D, LowercaseLetter),
+    (0x011E, UppercaseLetter),
+    (0x011F, LowercaseLetter),
+    (0x0120, UppercaseLetter),
+    (0x0121, LowercaseLetter),
+    (0x0122, UppercaseLetter),
+    (0x0123, LowercaseLetter),
+    (0x0124, UppercaseLetter),
+    (0x0125, LowercaseLetter),
+    (0x0126, UppercaseLetter),
+    (0x0127, LowercaseLetter),
+    (0x0128, UppercaseLetter),
+    (0x0129, LowercaseLetter),
+    (0x012A, UppercaseLetter),
+    (0x012B, LowercaseLetter),
+    (0x012C, UppercaseLetter),
+    (0x012D, LowercaseLetter),
+    (0x012E, UppercaseLetter),
+    (0x012F, LowercaseLetter),
+    (0x0130, UppercaseLetter),
+    (0x0131, LowercaseLetter),
+    (0x0132, UppercaseLetter),
+    (0x0133, LowercaseLetter),
+    (0x0134, UppercaseLetter),
+    (0x0135, LowercaseLetter),
+    (0x0136, UppercaseLetter),
+    (0x0137, LowercaseLetter),
+    (0x0139, UppercaseLetter),
+    (0x013A, LowercaseLetter),
+    (0x013B, UppercaseLetter),
+    (0x013C, LowercaseLetter),
+    (0x013D, UppercaseLetter),
+    (0x013E, LowercaseLetter),
+    (0x013F, UppercaseLetter),
+    (0x0140, LowercaseLetter),
+    (0x0141, UppercaseLetter),
+    (0x0142, LowercaseLetter),
+    (0x0143, UppercaseLetter),
+    (0x0144, LowercaseLetter),
+    (0x0145, UppercaseLetter),
+    (0x0146, LowercaseLetter),
+    (0x0147, UppercaseLetter),
+    (0x0148, LowercaseLetter),
+    (0x014A, UppercaseLetter),
+    (0x014B, LowercaseLetter),
+    (0x014C, UppercaseLetter),
+    (0x014D, LowercaseLetter),
+    (0x014E, UppercaseLetter),
+    (0x014F, LowercaseLetter),
+    (0x0150, UppercaseLetter),
+    (0x0151, LowercaseLetter),
+    (0x0152, UppercaseLetter),
+    (0x0153, LowercaseLetter),
+    (0x0154, UppercaseLetter),
+    (0x0155, LowercaseLetter),
+    (0x0156, UppercaseLetter),
+    (0x0157, LowercaseLetter),
+    (0x0158, UppercaseLetter),
+    (0x0159, LowercaseLetter),
+    (0x015A, UppercaseLetter),
+    (0x015B, LowercaseLetter),
+    (0x015C, UppercaseLetter),
+    (0x015D, LowercaseLetter),
+    (0x015E, UppercaseLetter),
+    (0x015F, LowercaseLetter),
+    (0x0160, UppercaseLetter),
+    (0x0161, LowercaseLetter),
+    (0x0162, UppercaseLetter),
+    (0x0163, LowercaseLetter),
+    (0x0164, UppercaseLetter),
+    (0x0165, LowercaseLetter),
+    (0x0166, UppercaseLetter),
+    (0x0167, LowercaseLetter),
+    (0x0168, UppercaseLetter),
+    (0x0169, LowercaseLetter),
+    (0x016A, UppercaseLetter),
+    (0x016B, LowercaseLetter),
+    (0x016C, UppercaseLetter),
+    (0x016D, LowercaseLetter),
+    (0x016E, UppercaseLetter),
+    (0x016F, LowercaseLetter),
+    (0x0170, UppercaseLetter),
+    (0x0171, LowercaseLetter),
+    (0x0172, UppercaseLetter),
+    (0x0173, LowercaseLetter),
+    (0x0174, UppercaseLetter),
+    (0x0175, LowercaseLetter),
+    (0x0176, UppercaseLetter),
+    (0x0177, LowercaseLetter),
+    (0x0178, UppercaseLetter),
+    (0x017A, LowercaseLetter),
+    (0x017B, UppercaseLetter),
+    (0x017C, LowercaseLetter),
+    (0x017D, UppercaseLetter),
+    (0x017E, LowercaseLetter),
+    (0x0181, UppercaseLetter),
+    (0x0183, LowercaseLetter),
+    (0x0184, UppercaseLetter),
+    (0x0185, LowercaseLetter),
+    (0x0186, UppercaseLetter),
+    (0x0188, LowercaseLetter),
+    (0x0189, UppercaseLetter),
+    (0x018C, LowercaseLetter),
+    (0x018E, UppercaseLetter),
+    (0x0192, LowercaseLetter),
+    (0x0193, UppercaseLetter),
+    (0x0195, LowercaseLetter),
+    (0x0196, UppercaseLetter),
+    (0x0199, LowercaseLetter),
+    (0x019C, UppercaseLetter),
+    (0x019E, LowercaseLetter),
+    (0x019F, UppercaseLetter),
+    (0x01A1, LowercaseLetter),
+    (0x01A2, UppercaseLetter),
+    (0x01A3, LowercaseLetter),
+    (0x01A4, UppercaseLetter),
+    (0x01A5, LowercaseLetter),
+    (0x01A6, UppercaseLetter),
+    (0x01A8, LowercaseLetter),
+    (0x01A9, UppercaseLetter),
+    (0x01AA, LowercaseLetter),
+    (0x01AC, UppercaseLetter),
+    (0x01AD, LowercaseLetter),
+    (0x01AE, UppercaseLetter),
+    (0x01B0, LowercaseLetter),
+    (0x01B1, UppercaseLetter),
+    (0x01B4, LowercaseLetter),
+    (0x01B5, UppercaseLetter),
+    (0x01B6, LowercaseLetter),
+    (0x01B7, UppercaseLetter),
+    (0x01B9, LowercaseLetter),
+    (0x01BB, OtherLetter),
+    (0x01BC, UppercaseLetter),
+    (0x01BD, LowercaseLetter),
+    (0x01C0, OtherLetter),
+    (0x01C4, UppercaseLetter),
+    (0x01C5, TitlecaseLetter),
+    (0x01C6, LowercaseLetter),
+    (0x01C7, UppercaseLetter),
+    (0x01C8, TitlecaseLetter),
+    (0x01C9, LowercaseLetter),
+    (0x01CA, UppercaseLetter),
+    (0x01CB, TitlecaseLetter),
+    (0x01CC, LowercaseLetter),
+    (0x01CD, UppercaseLetter),
+    (0x01CE, LowercaseLetter),
+    (0x01CF, UppercaseLetter),
+    (0x01D0, LowercaseLetter),
+    (0x01D1, UppercaseLetter),
+    (0x01D2, LowercaseLetter),
+    (0x01D3, UppercaseLetter),
+    (0x01D4, LowercaseLetter),
+    (0x01D5, UppercaseLetter),
+    (0x01D6, LowercaseLetter),
+    (0x01D7, UppercaseLetter),
+    (0x01D8, LowercaseLetter),
+    (0x01D9, UppercaseLetter),
+    (0x01DA, LowercaseLetter),
+    (0x01DB, UppercaseLetter),
+    (0x01DC, LowercaseLetter),
+    (0x01DE, UppercaseLetter),
+    (0x01DF, LowercaseLetter),
+    (0x01E0, UppercaseLetter),
+    (0x01E1, LowercaseLetter),
+    (0x01E2, UppercaseLetter),
+    (0x01E3, LowercaseLetter),
+    (0x01E4, UppercaseLetter),
+    (0x01E5, LowercaseLetter),
+    (0x01E6, UppercaseLetter),
+    (0x01E7, LowercaseLetter),
+    (0x01E8, UppercaseLetter),
+    (0x01E9, LowercaseLetter),
+    (0x01EA, UppercaseLetter),
+    (0x01EB, LowercaseLetter),
+    (0x01EC, UppercaseLetter),
+    (0x01ED, LowercaseLetter),
+    (0x01EE, UppercaseLetter),
+    (0x01EF, LowercaseLetter),
+    (0x01F1, UppercaseLetter),
+    (0x01F2, TitlecaseLetter),
+    (0x01F3, LowercaseLetter),
+    (0x01F4, UppercaseLetter),
+    (0x01F5, LowercaseLetter),
+    (0x01F6, UppercaseLetter),
+    (0x01F9, LowercaseLetter),
+    (0x01FA, UppercaseLetter),
+    (0x01FB, LowercaseLetter),
+    (0x01FC, UppercaseLetter),
+    (0x01FD, LowercaseLetter),
+    (0x01FE, UppercaseLetter),
+    (0x01FF, LowercaseLetter),
+    (0x0200, UppercaseLetter),
+    (0x0201, LowercaseLetter),
+    (0x0202, UppercaseLetter),
+    (0x0203, LowercaseLetter),
+    (0x0204, UppercaseLetter),
+    (0x0205, LowercaseLetter),
+    (0x0206, UppercaseLetter),
+    (0x0207, LowercaseLetter),
+    (0x0208, UppercaseLetter),
+    (0x0209, LowercaseLetter),
+    (0x020A, UppercaseLetter),
+    (0x020B, LowercaseLetter),
+    (0x020C, UppercaseLetter),
+    (0x020D, LowercaseLetter),
+    (0x020E, UppercaseLetter),
+    (0x020F, LowercaseLetter),
+    (0x0210, UppercaseLetter),
+    (0x0211, LowercaseLetter),
+    (0x0212, UppercaseLetter),
+    (0x0213, LowercaseLetter),
+    (0x0214, UppercaseLetter),
+    (0x0215, LowercaseLetter),
+    (0x0216, UppercaseLetter),
+    (0x0217, LowercaseLetter),
+    (0x0218, UppercaseLetter),
+    (0x0219, LowercaseLetter),
+    (0x021A, UppercaseLetter),
+    (0x021B, LowercaseLetter),
+    (0x021C, UppercaseLetter),
+    (0x021D, LowercaseLetter),
+    (0x021E, UppercaseLetter),
+    (0x021F, LowercaseLetter),
+    (0x0220, UppercaseLetter),
+    (0x0221, LowercaseLetter),
+    (0x0222, UppercaseLetter),
+    (0x0223, LowercaseLetter),
+    (0x0224, UppercaseLetter),
+    (0x0225, LowercaseLetter),
+    (0x0226, UppercaseLetter),
+    (0x0227, LowercaseLetter),
+    (0x0228, UppercaseLetter),
+    (0x0229, LowercaseLetter),
+    (0x022A, UppercaseLetter),
+    (0x022B, LowercaseLetter),
+    (0x022C, UppercaseLetter),
+    (0x022D, LowercaseLetter),
+    (0x022E, UppercaseLetter),
+    (0x022F, LowercaseLetter),
+    (0x0230, UppercaseLetter),
+    (0x0231, LowercaseLetter),
+    (0x0232, UppercaseLetter),
+    (0x0233, LowercaseLetter),
+    (0x023A, UppercaseLetter),
+    (0x023C, LowercaseLetter),
+    (0x023D, UppercaseLetter),
+    (0x023F, LowercaseLetter),
+    (0x0241, UppercaseLetter),
+    (0x0242, LowercaseLetter),
+    (0x0243, UppercaseLetter),
+    (0x0247, LowercaseLetter),
+    (0x0248, UppercaseLetter),
+    (0x0249, LowercaseLetter),
+    (0x024A, UppercaseLetter),
+    (0x024B, LowercaseLetter),
+    (0x024C, UppercaseLetter),
+    (0x024D, LowercaseLetter),
+    (0x024E, UppercaseLetter),
+    (0x024F, LowercaseLetter),
+    (0x0294, OtherLetter),
+    (0x0295, LowercaseLetter),
+    (0x02B0, ModifierLetter),
+    (0x02C2, ModifierSymbol),
+    (0x02C6, ModifierLetter),
+    (0x02D2, ModifierSymbol),
+    (0x02E0, ModifierLetter),
+    (0x02E5, ModifierSymbol),
+    (0x02EC, ModifierLetter),
+    (0x02ED, ModifierSymbol),
+    (0x02EE, ModifierLetter),
+    (0x02EF, ModifierSymbol),
+    (0x0300, NonspacingMark),
+    (0x0370, UppercaseLetter),
+    (0x0371, LowercaseLetter),
+    (0x0372, UppercaseLetter),
+    (0x0373, LowercaseLetter),
+    (0x0374, ModifierLetter),
+    (0x0375, ModifierSymbol),
+    (0x0376, UppercaseLetter),
+    (0x0377, LowercaseLetter),
+    (0x0378, Unassigned),
+    (0x037A, ModifierLetter),
+    (0x037B, LowercaseLetter),
+    (0x037E, OtherPunctuation),
+    (0x037F, UppercaseLetter),
+    (0x0380, Unassigned),
+    (0x0384, ModifierSymbol),
+    (0x0386, UppercaseLetter),
+    (0x0387, OtherPunctuation),
+    (0x0388, UppercaseLetter),
+    (0x038B, Unassigned),
+    (0x038C, UppercaseLetter),
+    (0x038D, Unassigned),
+    (0x038E, UppercaseLetter),
+    (0x0390, LowercaseLetter),
+    (0x0391, UppercaseLetter),
+    (0x03A2, Unassigned),
+    (0x03A3, UppercaseLetter),
+    (0x03AC, LowercaseLetter),
+    (0x03CF, UppercaseLetter),
+    (0x03D0, LowercaseLetter),
+    (0x03D2, UppercaseLetter),
+    (0x03D5, LowercaseLetter),
+    (0x03D8, UppercaseLetter),
+    (0x03D9, LowercaseLetter),
+    (0x03DA, UppercaseLetter),
+    (0x03DB, LowercaseLetter),
+    (0x03DC, UppercaseLetter),
+    (0x03DD, LowercaseLetter),
+    (0x03DE, UppercaseLetter),
+    (0x03DF, LowercaseLetter),
+    (0x03E0, UppercaseLetter),
+    (0x03E1, LowercaseLetter),
+    (0x03E2, UppercaseLetter),
+    (0x03E3, LowercaseLetter),
+    (0x03E4, UppercaseLetter),
+    (0x03E5, LowercaseLetter),
+    (0x03E6, UppercaseLetter),
+    (0x03E7, LowercaseLetter),
+    (0x03E8, UppercaseLetter),
+    (0x03E9, LowercaseLetter),
+    (0x03EA, UppercaseLetter),
+    (0x03EB, LowercaseLetter),
+    (0x03EC, UppercaseLetter),
+    (0x03ED, LowercaseLetter),
+    (0x03EE, UppercaseLetter),
+    (0x03EF, LowercaseLetter),
+    (0x03F4, UppercaseLetter),
+    (0x03F5, LowercaseLetter),
+    (0x03F6, MathSymbol),
+    (0x03F7, UppercaseLetter),
+    (0x03F8, LowercaseLetter),
+    (0x03F9, UppercaseLetter),
+    (0x03FB, LowercaseLetter),
+    (0x03FD, UppercaseLetter),
+    (0x0430, LowercaseLetter),
+    (0x0460, UppercaseLetter),
+    (0x0461, LowercaseLetter),
+    (0x0462, UppercaseLetter),
+    (0x0463, LowercaseLetter),
+    (0x0464, UppercaseLetter),
+    (0x0465, LowercaseLetter),
+    (0x0466, UppercaseLetter),
+    (0x0467, LowercaseLetter),
+    (0x0468, UppercaseLetter),
+    (0x0469, LowercaseLetter),
+    (0x046A, UppercaseLetter),
+    (0x046B, LowercaseLetter),
+    (0x046C, UppercaseLetter),
+    (0x046D, LowercaseLetter),
+    (0x046E, UppercaseLetter),
+    (0x046F, LowercaseLetter),
+    (0x0470, UppercaseLetter),
+    (0x0471, LowercaseLetter),
+    (0x0472, UppercaseLetter),
+    (0x0473, LowercaseLetter),
+    (0x0474, UppercaseLetter),
+    (0x0475, LowercaseLetter),
+    (0x0476, UppercaseLetter),
+    (0x0477, LowercaseLetter),
+    (0x0478, UppercaseLetter),
+    (0x0479, LowercaseLetter),
+    (0x047A, UppercaseLetter),
+    (0x047B, LowercaseLetter),
+    (0x047C, UppercaseLetter),
+    (0x047D, LowercaseLetter),
+    (0x047E, UppercaseLetter),
+    (0x047F, LowercaseLetter),
+    (0x0480, UppercaseLetter),
+    (0x0481, LowercaseLetter),
+    (0x0482, OtherSymbol),
+    (0x0483, NonspacingMark),
+    (0x0488, EnclosingMark),
+    (0x048A, UppercaseLetter),
+    (0x048B, LowercaseLetter),
+    (0x048C, UppercaseLetter),
+    (0x048D, LowercaseLetter),
+    (0x048E, UppercaseLetter),
+    (0x048F, LowercaseLetter),
+    (0x0490, UppercaseLetter),
+    (0x0491, LowercaseLetter),
+    (0x0492, UppercaseLetter),
+    (0x0493, LowercaseLetter),
+    (0x0494, UppercaseLetter),
+    (0x0495, LowercaseLetter),
+    (0x0496, UppercaseLetter),
+    (0x0497, LowercaseLetter),
+    (0x0498, UppercaseLetter),
+    (0x0499, LowercaseLetter),
+    (0x049A, UppercaseLetter),
+    (0x049B, LowercaseLetter),
+    (0x049C, UppercaseLetter),
+    (0x049D, LowercaseLetter),
+    (0x049E, UppercaseLetter),
+    (0x049F, LowercaseLetter),
+    (0x04A0, UppercaseLetter),
+    (0x04A1, LowercaseLetter),
+    (0x04A2, UppercaseLetter),
+    (0x04A3, LowercaseLetter),
+    (0x04A4, UppercaseLetter),
+    (0x04A5, LowercaseLetter),
+    (0x04A6, UppercaseLetter),
+    (0x04A7, LowercaseLetter),
+    (0x04A8, UppercaseLetter),
+    (0x04A9, LowercaseLetter),
+    (0x04AA, UppercaseLetter),
+    (0x04AB, LowercaseLetter),
+    (0x04AC, UppercaseLetter),
+    (0x04AD, LowercaseLetter),
+    (0x04AE, UppercaseLetter),
+    (0x04AF, LowercaseLetter),
+    (0x04B0, UppercaseLetter),
+    (0x04B1, LowercaseLetter),
+    (0x04B2, UppercaseLetter),
+    (0x04B3, LowercaseLetter),
+    (0x04B4, UppercaseLetter),
+    (0x04B5, LowercaseLetter),
+    (0x04B6, UppercaseLetter),
+    (0x04B7, LowercaseLetter),
+    (0x04B8, UppercaseLetter),
+    (0x04B9, LowercaseLetter),
+    (0x04BA, UppercaseLetter),
+    (0x04BB, LowercaseLetter),
+    (0x04BC, UppercaseLetter),
+    (0x04BD, LowercaseLetter),
+    (0x04BE, UppercaseLetter),
+    (0x04BF, LowercaseLetter),
+    (0x04C0, UppercaseLetter),
+    (0x04C2, LowercaseLetter),
+    (0x04C3, UppercaseLetter),
+    (0x04C4, LowercaseLetter),
+    (0x04C5, UppercaseLetter),
+    (0x04C6, LowercaseLetter),
+    (0x04C7, UppercaseLetter),
+    (0x04C8, LowercaseLetter),
+    (0x04C9, UppercaseLetter),
+    (0x04CA, LowercaseLetter),
+    (0x04CB, UppercaseLetter),
+    (0x04CC, LowercaseLetter),
+    (0x04CD, UppercaseLetter),
+    (0x04CE, LowercaseLetter),
+    (0x04D0, UppercaseLetter),
+    (0x04D1, LowercaseLetter),
+    (0x04D2, UppercaseLetter),
+    (0x04D3, LowercaseLetter),
+    (0x04D4, UppercaseLetter),
+    (0x04D5, LowercaseLetter),
+    (0x04D6, UppercaseLetter),
+    (0x04D7, LowercaseLetter),
+    (0x04D8, UppercaseLetter),
+    (0x04D9, LowercaseLetter),
+    (0x04DA, UppercaseLetter),
+    (0x04DB, LowercaseLetter),
+    (0x04DC, UppercaseLetter),
+    (0x04DD, LowercaseLetter),
+    (0x04DE, UppercaseLetter),
+    (0x04DF, LowercaseLetter),
+    (0x04E0, UppercaseLetter),
+    (0x04E1, LowercaseLetter),
+    (0x04E2, UppercaseLetter),
+    (0x04E3, LowercaseLetter),
+    (0x04E4, UppercaseLetter),
+    (0x04E5, LowercaseLetter),
+    (0x04E6, UppercaseLetter),
+    (0x04E7, LowercaseLetter),
+    (0x04E8, UppercaseLetter),
+    (0x04E9, LowercaseLetter),
+    (0x04EA, UppercaseLetter),
+    (0x04EB, LowercaseLetter),
+    (0x04EC, UppercaseLetter),
+    (0x04ED, LowercaseLetter),
+    (0x04EE, UppercaseLetter),
+    (0x04EF, LowercaseLetter),
+    (0x04F0, UppercaseLetter),
+    (0x04F1, LowercaseLetter),
+    (0x04F2, UppercaseLetter),
+    (0x04F3, LowercaseLetter),
+    (0x04F4, UppercaseLetter),
+    (0x04F5, LowercaseLetter),
+    (0x04F6, UppercaseLetter),
+    (0x04F7, LowercaseLetter),
+    (0x04F8, UppercaseLetter),
+    (0x04F9, LowercaseLetter),
+    (0x04FA, UppercaseLetter),
+    (0x04FB, LowercaseLetter),
+    (0x04FC, UppercaseLetter),
+    (0x04FD, LowercaseLetter),
+    (0x04FE, UppercaseLetter),
+    (0x04FF, LowercaseLetter),
+    (0x0500, UppercaseLetter),
+    (0x0501, LowercaseLetter),
+    (0x0502, UppercaseLetter),
+    (0x0503, LowercaseLetter),
+    (0x0504, UppercaseLetter),
+    (0x0505, LowercaseLetter),
+    (0x0506, UppercaseLetter),
+    (0x0507, LowercaseLetter),
+    (0x0508, UppercaseLetter),
+    (0x0509, LowercaseLetter),
+    (0x050A, UppercaseLetter),
+    (0x050B, LowercaseLetter),
+    (0x050C, UppercaseLetter),
+    (0x050D, LowercaseLetter),
+    (0x050E, UppercaseLetter),
+    (0x050F, LowercaseLetter),
+    (0x0510, UppercaseLetter),
+    (0x0511, LowercaseLetter),
+    (0x0512, UppercaseLetter),
+    (0x0513, LowercaseLetter),
+    (0x0514, UppercaseLetter),
+    (0x0515, LowercaseLetter),
+    (0x0516, UppercaseLetter),
+    (0x0517, LowercaseLetter),
+    (0x0518, UppercaseLetter),
+    (0x0519, LowercaseLetter),
+    (0x051A, UppercaseLetter),
+    (0x051B, LowercaseLetter),
+    (0x051C, UppercaseLetter),
+    (0x051D, LowercaseLetter),
+    (0x051E, UppercaseLetter),
+    (0x051F, LowercaseLetter),
+    (0x0520, UppercaseLetter),
+    (0x0521, LowercaseLetter),
+    (0x0522, UppercaseLetter),
+    (0x0523, LowercaseLetter),
+    (0x0524, UppercaseLetter),
+    (0x0525, LowercaseLetter),
+    (0x0526, UppercaseLetter),
+    (0x0527, LowercaseLetter),
+    (0x0528, UppercaseLetter),
+    (0x0529, LowercaseLetter),
+    (0x052A, UppercaseLetter),
+    (0x052B, LowercaseLetter),
+    (0x052C, UppercaseLetter),
+    (0x052D, LowercaseLetter),
+    (0x052E, UppercaseLetter),
+    (0x052F, LowercaseLetter),
+    (0x0530, Unassigned),
+    (0x0531, UppercaseLetter),
+    (0x0557, Unassigned),
+    (0x0559, ModifierLetter),
+    (0x055A, OtherPunctuation),
+    (0x0560, LowercaseLetter),
+    (0x0589, OtherPunctuation),
+    (0x058A, DashPunctuation),
+    (0x058B, Unassigned),
+    (0x058D, OtherSymbol),
+    (0x058F, CurrencySymbol),
+    (0x0590, Unassigned),
+    (0x0591, NonspacingMark),
+    (0x05BE, DashPunctuation),
+    (0x05BF, NonspacingMark),
+    (0x05C0, OtherPunctuation),
+    (0x05C1, NonspacingMark),
+    (0x05C3, OtherPunctuation),
+    (0x05C4, NonspacingMark),
+    (0x05C6, OtherPunctuation),
+    (0x05C7, NonspacingMark),
+    (0x05C8, Unassigned),
+    (0x05D0, OtherLetter),
+    (0x05EB, Unassigned),
+    (0x05EF, OtherLetter),
+    (0x05F3, OtherPunctuation),
+    (0x05F5, Unassigned),
+    (0x0600, Format),
+    (0x0606, MathSymbol),
+    (0x0609, OtherPunctuation),
+    (0x060B, CurrencySymbol),
+    (0x060C, OtherPunctuation),
+    (0x060E, OtherSymbol),
+    (0x0610, NonspacingMark),
+    (0x061B, OtherPunctuation),
+    (0x061C, Format),
+    (0x061D, OtherPunctuation),
+    (0x0620, OtherLetter),
+    (0x0640, ModifierLetter),
+    (0x0641, OtherLetter),
+    (0x064B, NonspacingMark),
+    (0x0660, DecimalNumber),
+    (0x066A, OtherPunctuation),
+    (0x066E, OtherLetter),
+    (0x0670, NonspacingMark),
+    (0x0671, OtherLetter),
+    (0x06D4, OtherPunctuation),
+    (0x06D5, OtherLetter),
+    (0x06D6, NonspacingMark),
+    (0x06DD, Format),
+    (0x06DE, OtherSymbol),
+    (0x06DF, NonspacingMark),
+    (0x06E5, ModifierLetter),
+    (0x06E7, NonspacingMark),
+    (0x06E9, OtherSymbol),
+    (0x06EA, NonspacingMark),
+    (0x06EE, OtherLetter),
+    (0x06F0, DecimalNumber),
+    (0x06FA, OtherLetter),
+    (0x06FD, OtherSymbol),
+    (0x06FF, OtherLetter),
+    (0x0700, OtherPunctuation),
+    (0x070E, Unassigned),
+    (0x070F, Format),
+    (0x0710, OtherLetter),
+    (0x0711, NonspacingMark),
+    (0x0712, OtherLetter),
+    (0x0730, NonspacingMark),
+    (0x074B, Unassigned),
+    (0x074D, OtherLetter),
+    (0x07A6, NonspacingMark),
+    (0x07B1, OtherLetter),
+    (0x07B2, Unassigned),
+    (0x07C0, DecimalNumber),
+    (0x07CA, OtherLetter),
+    (0x07EB, NonspacingMark),
+    (0x07F4, ModifierLetter),
+    (0x07F6, OtherSymbol),
+    (0x07F7, OtherPunctuation),
+    (0x07FA, ModifierLetter),
+    (0x07FB, Unassigned),
+    (0x07FD, NonspacingMark),
+    (0x07FE, CurrencySymbol),
+    (0x0800, OtherLetter),
+    (0x0816, NonspacingMark),
+    (0x081A, ModifierLetter),
+    (0x081B, NonspacingMark),
+    (0x0824, ModifierLetter),
+    (0x0825, NonspacingMark),
+    (0x0828, ModifierLetter),
+    (0x0829, NonspacingMark),
+    (0x082E, Unassigned),
+    (0x0830, OtherPunctuation),
+    (0x083F, Unassigned),
+    (0x0840, OtherLetter),
+    (0x0859, NonspacingMark),
+    (0x085C, Unassigned),
+    (0x085E, OtherPunctuation),
+    (0x085F, Unassigned),
+    (0x0860, OtherLetter),
+    (0x086B, Unassigned),
+    (0x0870, OtherLetter),
+    (0x0888, ModifierSymbol),
+    (0x0889, OtherLetter),
+    (0x088F, Unassigned),
+    (0x0890, Format),
+    (0x0892, Unassigned),
+    (0x0898, NonspacingMark),
+    (0x08A0, OtherLetter),
+    (0x08C9, ModifierLetter),
+    (0x08CA, NonspacingMark),
+    (0x08E2, Format),
+    (0x08E3, NonspacingMark),
+    (0x0903, SpacingMark),
+    (0x0904, OtherLetter),
+    (0x093A, NonspacingMark),
+    (0x093B, SpacingMark),
+    (0x093C, NonspacingMark),
+    (0x093D, OtherLetter),
+    (0x093E, SpacingMark),
+    (0x0941, NonspacingMark),
+    (0x0949, SpacingMark),
+    (0x094D, NonspacingMark),
+    (0x094E, SpacingMark),
+    (0x0950, OtherLetter),
+    (0x0951, NonspacingMark),
+    (0x0958, OtherLetter),
+    (0x0962, NonspacingMark),
+    (0x0964, OtherPunctuation),
+    (0x0966, DecimalNumber),
+    (0x0970, OtherPunctuation),
+    (0x0971, ModifierLetter),
+    (0x0972, OtherLetter),
+    (0x0981, NonspacingMark),
+    (0x0982, SpacingMark),
+    (0x0984, Unassigned),
+    (0x0985, OtherLetter),
+    (0x098D, Unassigned),
+    (0x098F, OtherLetter),
+    (0x0991, Unassigned),
+    (0x0993, OtherLetter),
+    (0x09A9, Unassigned),
+    (0x09AA, OtherLetter),
+    (0x09B1, Unassigned),
+    (0x09B2, OtherLetter),
+    (0x09B3, Unassigned),
+    (0x09B6, OtherLetter),
+    (0x09BA, Unassigned),
+    (0x09BC, NonspacingMark),
+    (0x09BD, OtherLetter),
+    (0x09BE, SpacingMark),
+    (0x09C1, NonspacingMark),
+    (0x09C5, Unassigned),
+    (0x09C7, SpacingMark),
+    (0x09C9, Unassigned),
+    (0x09CB, SpacingMark),
+    (0x09CD, NonspacingMark),
+    (0x09CE, OtherLetter),
+    (0x09CF, Unassigned),
+    (0x09D7, SpacingMark),
+    (0x09D8, Unassigned),
+    (0x09DC, OtherLetter),
+    (0x09DE, Unassigned),
+    (0x09DF, OtherLetter),
+    (0x09E2, NonspacingMark),
+    (0x09E4, Unassigned),
+    (0x09E6, DecimalNumber),
+    (0x09F0, OtherLetter),
+    (0x09F2, CurrencySymbol),
+    (0x09F4, OtherNumber),
+    (0x09FA, OtherSymbol),
+    (0x09FB, CurrencySymbol),
+    (0x09FC, OtherLetter),
+    (0x09FD, OtherPunctuation),
+    (0x09FE, NonspacingMark),
+    (0x09FF, Unassigned),
+    (0x0A01, NonspacingMark),
+    (0x0A03, SpacingMark),
+    (0x0A04, Unassigned),
+    (0x0A05, OtherLetter),
+    (0x0A0B, Unassigned),
+    (0x0A0F, OtherLetter),
+    (0x0A11, Unassigned),
+    (0x0A13, OtherLetter),
+    (0x0A29, Unassigned),
+    (0x0A2A, OtherLetter),
+    (0x0A31, Unassigned),
+    (0x0A32, OtherLetter),
+    (0x0A34, Unassigned),
+    (0x0A35, OtherLetter),
+    (0x0A37, Unassigned),
+    (0x0A38, OtherLetter),
+    (0x0A3A, Unassigned),
+    (0x0A3C, NonspacingMark),
+    (0x0A3D, Unassigned),
+    (0x0A3E, SpacingMark),
+    (0x0A41, NonspacingMark),
+    (0x0A43, Unassigned),
+    (0x0A47, NonspacingMark),
+    (0x0A49, Unassigned),
+    (0x0A4B, NonspacingMark),
+    (0x0A4E, Unassigned),
+    (0x0A51, NonspacingMark),
+    (0x0A52, Unassigned),
+    (0x0A59, OtherLetter),
+    (0x0A5D, Unassigned),
+    (0x0A5E, OtherLetter),
+    (0x0A5F, Unassigned),
+    (0x0A66, DecimalNumber),
+    (0x0A70, NonspacingMark),
+    (0x0A72, OtherLetter),
+    (0x0A75, NonspacingMark),
+    (0x0A76, OtherPunctuation),
+    (0x0A77, Unassigned),
+    (0x0A81, NonspacingMark),
+    (0x0A83, SpacingMark),
+    (0x0A84, Unassigned),
+    (0x0A85, OtherLetter),
+    (0x0A8E, Unassigned),
+    (0x0A8F, OtherLetter),
+    (0x0A92, Unassigned),
+    (0x0A93, OtherLetter),
+    (0x0AA9, Unassigned),
+    (0x0AAA, OtherLetter),
+    (0x0AB1, Unassigned),
+    (0x0AB2, OtherLetter),
+    (0x0AB4, Unassigned),
+    (0x0AB5, OtherLetter),
+    (0x0ABA, Unassigned),
+    (0x0ABC, NonspacingMark),
+    (0x0ABD, OtherLetter),
+    (0x0ABE, SpacingMark),
+    (0x0AC1, NonspacingMark),
+    (0x0AC6, Unassigned),
+    (0x0AC7, NonspacingMark),
+    (0x0AC9, SpacingMark),
+    (0x0ACA, Unassigned),
+    (0x0ACB, SpacingMark),
+    (0x0ACD, NonspacingMark),
+    (0x0ACE, Unassigned),
+    (0x0AD0, OtherLetter),
+    (0x0AD1, Unassigned),
+    (0x0AE0, OtherLetter),
+    (0x0AE2, NonspacingMark),
+    (0x0AE4, Unassigned),
+    (0x0AE6, DecimalNumber),
+    (0x0AF0, OtherPunctuation),
+    (0x0AF1, CurrencySymbol),
+    (0x0AF2, Unassigned),
+    (0x0AF9, OtherLetter),
+    (0x0AFA, NonspacingMark),
+    (0x0B00, Unassigned),
+    (0x0B01, NonspacingMark),
+    (0x0B02, SpacingMark),
+    (0x0B04, Unassigned),
+    (0x0B05, OtherLetter),
+    (0x0B0D, Unassigned),
+    (0x0B0F, OtherLetter),
+    (0x0B11, Unassigned),
+    (0x0B13, OtherLetter),
+    (0x0B29, Unassigned),
+    (0x0B2A, OtherLetter),
+    (0x0B31, Unassigned),
+    (0x0B32, OtherLetter),
+    (0x0B34, Unassigned),
+    (0x0B35, OtherLetter),
+    (0x0B3A, Unassigned),
+    (0x0B3C, NonspacingMark),
+    (0x0B3D, OtherLetter),
+    (0x0B3E, SpacingMark),
+    (0x0B3F, NonspacingMark),
+    (0x0B40, SpacingMark),
+    (0x0B41, NonspacingMark),
+    (0x0B45, Unassigned),
+    (0x0B47, SpacingMark),
+    (0x0B49, Unassigned),
+    (0x0B4B, SpacingMark),
+    (0x0B4D, NonspacingMark),
+    (0x0B4E, Unassigned),
+    (0x0B55, NonspacingMark),
+    (0x0B57, SpacingMark),
+    (0x0B58, Unassigned),
+    (0x0B5C, OtherLetter),
+    (0x0B5E, Unassigned),
+    (0x0B5F, OtherLetter),
+    (0x0B62, NonspacingMark),
+    (0x0B64, Unassigned),
+    (0x0B66, DecimalNumber),
+    (0x0B70, OtherSymbol),
+    (0x0B71, OtherLetter),
+    (0x0B72, OtherNumber),
+    (0x0B78, Unassigned),
+    (0x0B82, NonspacingMark),
+    (0x0B83, OtherLetter),
+    (0x0B84, Unassigned),
+    (0x0B85, OtherLetter),
+    (0x0B8B, Unassigned),
+    (0x0B8E, OtherLetter),
+    (0x0B91, Unassigned),
+    (0x0B92, OtherLetter),
+    (0x0B96, Unassigned),
+    (0x0B99, OtherLetter),
+    (0x0B9B, Unassigned),
+    (0x0B9C, OtherLetter),
+    (0x0B9D, Unassigned),
+    (0x0B9E, OtherLetter),
+    (0x0BA0, Unassigned),
+    (0x0BA3, OtherLetter),
+    (0x0BA5, Unassigned),
+    (0x0BA8, OtherLetter),
+    (0x0BAB, Unassigned),
+    (0x0BAE, OtherLetter),
+    (0x0BBA, Unassigned),
+    (0x0BBE, SpacingMark),
+    (0x0BC0, NonspacingMark),
+    (0x0BC1, SpacingMark),
+    (0x0BC3, Unassigned),
+    (0x0BC6, SpacingMark),
+    (0x0BC9, Unassigned),
+    (0x0BCA, SpacingMark),
+    (0x0BCD, NonspacingMark),
+    (0x0BCE, Unassigned),
+    (0x0BD0, OtherLetter),
+    (0x0BD1, Unassigned),
+    (0x0BD7, SpacingMark),
+    (0x0BD8, Unassigned),
+    (0x0BE6, DecimalNumber),
+    (0x0BF0, OtherNumber),
+    (0x0BF3, OtherSymbol),
+    (0x0BF9, CurrencySymbol),
+    (0x0BFA, OtherSymbol),
+    (0x0BFB, Unassigned),
+    (0x0C00, NonspacingMark),
+    (0x0C01, SpacingMark),
+    (0x0C04, NonspacingMark),
+    (0x0C05, OtherLetter),
+    (0x0C0D, Unassigned),
+    (0x0C0E, OtherLetter),
+    (0x0C11, Unassigned),
+    (0x0C12, OtherLetter),
+    (0x0C29, Unassigned),
+    (0x0C2A, OtherLetter),
+    (0x0C3A, Unassigned),
+    (0x0C3C, NonspacingMark),
+    (0x0C3D, OtherLetter),
+    (0x0C3E, NonspacingMark),
+    (0x0C41, SpacingMark),
+    (0x0C45, Unassigned),
+    (0x0C46, NonspacingMark),
+    (0x0C49, Unassigned),
+    (0x0C4A, NonspacingMark),
+    (0x0C4E, Unassigned),
+    (0x0C55, NonspacingMark),
+    (0x0C57, Unassigned),
+    (0x0C58, OtherLetter),
+    (0x0C5B, Unassigned),
+    (0x0C5D, OtherLetter),
+    (0x0C5E, Unassigned),
+    (0x0C60, OtherLetter),
+    (0x0C62, NonspacingMark),
+    (0x0C64, Unassigned),
+    (0x0C66, DecimalNumber),
+    (0x0C70, Unassigned),
+    (0x0C77, OtherPunctuation),
+    (0x0C78, OtherNumber),
+    (0x0C7F, OtherSymbol),
+    (0x0C80, OtherLetter),
+    (0x0C81, NonspacingMark),
+    (0x0C82, SpacingMark),
+    (0x0C84, OtherPunctuation),
+    (0x0C85, OtherLetter),
+    (0x0C8D, Unassigned),
+    (0x0C8E, OtherLetter),
+    (0x0C91, Unassigned),
+    (0x0C92, OtherLetter),
+    (0x0CA9, Unassigned),
+    (0x0CAA, OtherLetter),
+    (0x0CB4, Unassigned),
+    (0x0CB5, OtherLetter),
+    (0x0CBA, Unassigned),
+    (0x0CBC, NonspacingMark),
+    (0x0CBD, OtherLetter),
+    (0x0CBE, SpacingMark),
+    (0x0CBF, NonspacingMark),
+    (0x0CC0, SpacingMark),
+    (0x0CC5, Unassigned),
+    (0x0CC6, NonspacingMark),
+    (0x0CC7, SpacingMark),
+    (0x0CC9, Unassigned),
+    (0x0CCA, SpacingMark),
+    (0x0CCC, NonspacingMark),
+    (0x0CCE, Unassigned),
+    (0x0CD5, SpacingMark),
+    (0x0CD7, Unassigned),
+    (0x0CDD, OtherLetter),
+    (0x0CDF, Unassigned),
+    (0x0CE0, OtherLetter),
+    (0x0CE2, NonspacingMark),
+    (0x0CE4, Unassigned),
+    (0x0CE6, DecimalNumber),
+    (0x0CF0, Unassigned),
+    (0x0CF1, OtherLetter),
+    (0x0CF3, Unassigned),
+    (0x0D00, NonspacingMark),
+    (0x0D02, SpacingMark),
+    (0x0D04, OtherLetter),
+    (0x0D0D, Unassigned),
+    (0x0D0E, OtherLetter),
+    (0x0D11, Unassigned),
+    (0x0D12, OtherLetter),
+    (0x0D3B, NonspacingMark),
+    (0x0D3D, OtherLetter),
+    (0x0D3E, SpacingMark),
+    (0x0D41, NonspacingMark),
+    (0x0D45, Unassigned),
+    (0x0D46, SpacingMark),
+    (0x0D49, Unassigned),
+    (0x0D4A, SpacingMark),
+    (0x0D4D, NonspacingMark),
+    (0x0D4E, OtherLetter),
+    (0x0D4F, OtherSymbol),
+    (0x0D50, Unassigned),
+    (0x0D54, OtherLetter),
+    (0x0D57, SpacingMark),
+    (0x0D58, OtherNumber),
+    (0x0D5F, OtherLetter),
+    (0x0D62, NonspacingMark),
+    (0x0D64, Unassigned),
+    (0x0D66, DecimalNumber),
+    (0x0D70, OtherNumber),
+    (0x0D79, OtherSymbol),
+    (0x0D7A, OtherLetter),
+    (0x0D80, Unassigned),
+    (0x0D81, NonspacingMark),
+    (0x0D82, SpacingMark),
+    (0x0D84, Unassigned),
+    (0x0D85, OtherLetter),
+    (0x0D97, Unassigned),
+    (0x0D9A, OtherLetter),
+    (0x0DB2, Unassigned),
+    (0x0DB3, OtherLetter),
+    (0x0DBC, Unassigned),
+    (0x0DBD, OtherLetter),
+    (0x0DBE, Unassigned),
+    (0x0DC0, OtherLetter),
+    (0x0DC7, Unassigned),
+    (0x0DCA, NonspacingMark),
+    (0x0DCB, Unassigned),
+    (0x0DCF, SpacingMark),
+    (0x0DD2, NonspacingMark),
+    (0x0DD5, Unassigned),
+    (0x0DD6, NonspacingMark),
+    (0x0DD7, Unassigned),
+    (0x0DD8, SpacingMark),
+    (0x0DE0, Unassigned),
+    (0x0DE6, DecimalNumber),
+    (0x0DF0, Unassigned),
+    (0x0DF2, SpacingMark),
+    (0x0DF4, OtherPunctuation),
+    (0x0DF5, Unassigned),
+    (0x0E01, OtherLetter),
+    (0x0E31, NonspacingMark),
+    (0x0E32, OtherLetter),
+    (0x0E34, NonspacingMark),
+    (0x0E3B, Unassigned),
+    (0x0E3F, CurrencySymbol),
+    (0x0E40, OtherLetter),
+    (0x0E46, ModifierLetter),
+    (0x0E47, NonspacingMark),
+    (0x0E4F, OtherPunctuation),
+    (0x0E50, DecimalNumber),
+    (0x0E5A, OtherPunctuation),
+    (0x0E5C, Unassigned),
+    (0x0E81, OtherLetter),
+    (0x0E83, Unassigned),
+    (0x0E84, OtherLetter),
+    (0x0E85, Unassigned),
+    (0x0E86, OtherLetter),
+    (0x0E8B, Unassigned),
+    (0x0E8C, OtherLetter),
+    (0x0EA4, Unassigned),
+    (0x0EA5, OtherLetter),
+    (0x0EA6, Unassigned),
+    (0x0EA7, OtherLetter),
+    (0x0EB1, NonspacingMark),
+    (0x0EB2, OtherLetter),
+    (0x0EB4, NonspacingMark),
+    (0x0EBD, OtherLetter),
+    (0x0EBE, Unassigned),
+    (0x0EC0, OtherLetter),
+    (0x0EC5, Unassigned),
+    (0x0EC6, ModifierLetter),
+    (0x0EC7, Unassigned),
+    (0x0EC8, NonspacingMark),
+    (0x0ECE, Unassigned),
+    (0x0ED0, DecimalNumber),
+    (0x0EDA, Unassigned),
+    (0x0EDC, OtherLetter),
+    (0x0EE0, Unassigned),
+    (0x0F00, OtherLetter),
+    (0x0F01, OtherSymbol),
+    (0x0F04, OtherPunctuation),
+    (0x0F13, OtherSymbol),
+    (0x0F14, OtherPunctuation),
+    (0x0F15, OtherSymbol),
+    (0x0F18, NonspacingMark),
+    (0x0F1A, OtherSymbol),
+    (0x0F20, DecimalNumber),
+    (0x0F2A, OtherNumber),
+    (0x0F34, OtherSymbol),
+    (0x0F35, NonspacingMark),
+    (0x0F36, OtherSymbol),
+    (0x0F37, NonspacingMark),
+    (0x0F38, OtherSymbol),
+    (0x0F39, NonspacingMark),
+    (0x0F3A, OpenPunctuation),
+    (0x0F3B, ClosePunctuation),
+    (0x0F3C, OpenPunctuation),
+    (0x0F3D, ClosePunctuation),
+    (0x0F3E, SpacingMark),
+    (0x0F40, OtherLetter),
+    (0x0F48, Unassigned),
+    (0x0F49, OtherLetter),
+    (0x0F6D, Unassigned),
+    (0x0F71, NonspacingMark),
+    (0x0F7F, SpacingMark),
+    (0x0F80, NonspacingMark),
+    (0x0F85, OtherPunctuation),
+    (0x0F86, NonspacingMark),
+    (0x0F88, OtherLetter),
+    (0x0F8D, NonspacingMark),
+    (0x0F98, Unassigned),
+    (0x0F99, NonspacingMark),
+    (0x0FBD, Unassigned),
+    (0x0FBE, OtherSymbol),
+    (0x0FC6, NonspacingMark),
+    (0x0FC7, OtherSymbol),
+    (0x0FCD, Unassigned),
+    (0x0FCE, OtherSymbol),
+    (0x0FD0, OtherPunctuation),
+    (0x0FD5, OtherSymbol),
+    (0x0FD9, OtherPunctuation),
+    (0x0FDB, Unassigned),
+    (0x1000, OtherLetter),
+    (0x102B, SpacingMark),
+    (0x102D, NonspacingMark),
+    (0x1031, SpacingMark),
+    (0x1032, NonspacingMark),
+    (0x1038, SpacingMark),
+    (0x1039, NonspacingMark),
+    (0x103B, SpacingMark),
+    (0x103D, NonspacingMark),
+    (0x103F, OtherLetter),
+    (0x1040, DecimalNumber),
+    (0x104A, OtherPunctuation),
+    (0x1050, OtherLetter),
+    (0x1056, SpacingMark),
+    (0x1058, NonspacingMark),
+    (0x105A, OtherLetter),
+    (0x105E, NonspacingMark),
+    (0x1061, OtherLetter),
+    (0x1062, SpacingMark),
+    (0x1065, OtherLetter),
+    (0x1067, SpacingMark),
+    (0x106E, OtherLetter),
+    (0x1071, NonspacingMark),
+    (0x1075, OtherLetter),
+    (0x1082, NonspacingMark),
+    (0x1083, SpacingMark),
+    (0x1085, NonspacingMark),
+    (0x1087, SpacingMark),
+    (0x108D, NonspacingMark),
+    (0x108E, OtherLetter),
+    (0x108F, SpacingMark),
+    (0x1090, DecimalNumber),
+    (0x109A, SpacingMark),
+    (0x109D, NonspacingMark),
+    (0x109E, OtherSymbol),
+    (0x10A0, UppercaseLetter),
+    (0x10C6, Unassigned),
+    (0x10C7, UppercaseLetter),
+    (0x10C8, Unassigned),
+    (0x10CD, UppercaseLetter),
+    (0x10CE, Unassigned),
+    (0x10D0, LowercaseLetter),
+    (0x10FB, OtherPunctuation),
+    (0x10FC, ModifierLetter),
+    (0x10FD, LowercaseLetter),
+    (0x1100, OtherLetter),
+    (0x1249, Unassigned),
+    (0x124A, OtherLetter),
+    (0x124E, Unassigned),
+    (0x1250, OtherLetter),
+    (0x1257, Unassigned),
+    (0x1258, OtherLetter),
+    (0x1259, Unassigned),
+    (0x125A, OtherLetter),
+    (0x125E, Unassigned),
+    (0x1260, OtherLetter),
+    (0x1289, Unassigned),
+    (0x128A, OtherLetter),
+    (0x128E, Unassigned),
+    (0x1290, OtherLetter),
+    (0x12B1, Unassigned),
+    (0x12B2, OtherLetter),
+    (0x12B6, Unassigned),
+    (0x12B8, OtherLetter),
+    (0x12BF, Unassigned),
+    (0x12C0, OtherLetter),
+    (0x12C1, Unassigned),
+    (0x12C2, OtherLetter),
+    (0x12C6, Unassigned),
+    (0x12C8, OtherLetter),
+    (0x12D7, Unassigned),
+    (0x12D8, OtherLetter),
+    (0x1311, Unassigned),
+    (0x1312, OtherLetter),
+    (0x1316, Unassigned),
+    (0x1318, OtherLetter),
+    (0x135B, Unassigned),
+    (0x135D, NonspacingMark),
+    (0x1360, OtherPunctuation),
+    (0x1369, OtherNumber),
+    (0x137D, Unassigned),
+    (0x1380, OtherLetter),
+    (0x1390, OtherSymbol),
+    (0x139A, Unassigned),
+    (0x13A0, UppercaseLetter),
+    (0x13F6, Unassigned),
+    (0x13F8, LowercaseLetter),
+    (0x13FE, Unassigned),
+    (0x1400, DashPunctuation),
+    (0x1401, OtherLetter),
+    (0x166D, OtherSymbol),
+    (0x166E, OtherPunctuation),
+    (0x166F, OtherLetter),
+    (0x1680, SpaceSeparator),
+    (0x1681, OtherLetter),
+    (0x169B, OpenPunctuation),
+    (0x169C, ClosePunctuation),
+    (0x169D, Unassigned),
+    (0x16A0, OtherLetter),
+    (0x16EB, OtherPunctuation),
+    (0x16EE, LetterNumber),
+    (0x16F1, OtherLetter),
+    (0x16F9, Unassigned),
+    (0x1700, OtherLetter),
+    (0x1712, NonspacingMark),
+    (0x1715, SpacingMark),
+    (0x1716, Unassigned),
+    (0x171F, OtherLetter),
+    (0x1732, NonspacingMark),
+    (0x1734, SpacingMark),
+    (0x1735, OtherPunctuation),
+    (0x1737, Unassigned),
+    (0x1740, OtherLetter),
+    (0x1752, NonspacingMark),
+    (0x1754, Unassigned),
+    (0x1760, OtherLetter),
+    (0x176D, Unassigned),
+    (0x176E, OtherLetter),
+    (0x1771, Unassigned),
+    (0x1772, NonspacingMark),
+    (0x1774, Unassigned),
+    (0x1780, OtherLetter),
+    (0x17B4, NonspacingMark),
+    (0x17B6, SpacingMark),
+    (0x17B7, NonspacingMark),
+    (0x17BE, SpacingMark),
+    (0x17C6, NonspacingMark),
+    (0x17C7, SpacingMark),
+    (0x17C9, NonspacingMark),
+    (0x17D4, OtherPunctuation),
+    (0x17D7, ModifierLetter),
+    (0x17D8, OtherPunctuation),
+    (0x17DB, CurrencySymbol),
+    (0x17DC, OtherLetter),
+    (0x17DD, NonspacingMark),
+    (0x17DE, Unassigned),
+    (0x17E0, DecimalNumber),
+    (0x17EA, Unassigned),
+    (0x17F0, OtherNumber),
+    (0x17FA, Unassigned),
+    (0x1800, OtherPunctuation),
+    (0x1806, DashPunctuation),
+    (0x1807, OtherPunctuation),
+    (0x180B, NonspacingMark),
+    (0x180E, Format),
+    (0x180F, NonspacingMark),
+    (0x1810, DecimalNumber),
+    (0x181A, Unassigned),
+    (0x1820, OtherLetter),
+    (0x1843, ModifierLetter),
+    (0x1844, OtherLetter),
+    (0x1879, Unassigned),
+    (0x1880, OtherLetter),
+    (0x1885, NonspacingMark),
+    (0x1887, OtherLetter),
+    (0x18A9, NonspacingMark),
+    (0x18AA, OtherLetter),
+    (0x18AB, Unassigned),
+    (0x18B0, OtherLetter),
+    (0x18F6, Unassigned),
+    (0x1900, OtherLetter),
+    (0x191F, Unassigned),
+    (0x1920, NonspacingMark),
+    (0x1923, SpacingMark),
+    (0x1927, NonspacingMark),
+    (0x1929, SpacingMark),
+    (0x192C, Unassigned),
+    (0x1930, SpacingMark),
+    (0x1932, NonspacingMark),
+    (0x1933, SpacingMark),
+    (0x1939, NonspacingMark),
+    (0x193C, Unassigned),
+    (0x1940, OtherSymbol),
+    (0x1941, Unassigned),
+    (0x1944, OtherPunctuation),
+    (0x1946, DecimalNumber),
+    (0x1950, OtherLetter),
+    (0x196E, Unassigned),
+    (0x1970, OtherLetter),
+    (0x1975, Unassigned),
+    (0x1980, OtherLetter),
+    (0x19AC, Unassigned),
+    (0x19B0, OtherLetter),
+    (0x19CA, Unassigned),
+    (0x19D0, DecimalNumber),
+    (0x19DA, OtherNumber),
+    (0x19DB, Unassigned),
+    (0x19DE, OtherSymbol),
+    (0x1A00, OtherLetter),
+    (0x1A17, NonspacingMark),
+    (0x1A19, SpacingMark),
+    (0x1A1B, NonspacingMark),
+    (0x1A1C, Unassigned),
+    (0x1A1E, OtherPunctuation),
+    (0x1A20, OtherLetter),
+    (0x1A55, SpacingMark),
+    (0x1A56, NonspacingMark),
+    (0x1A57, SpacingMark),
+    (0x1A58, NonspacingMark),
+    (0x1A5F, Unassigned),
+    (0x1A60, NonspacingMark),
+    (0x1A61, SpacingMark),
+    (0x1A62, NonspacingMark),
+    (0x1A63, SpacingMark),
+    (0x1A65, NonspacingMark),
+    (0x1A6D, SpacingMark),
+    (0x1A73, NonspacingMark),
+    (0x1A7D, Unassigned),
+    (0x1A7F, NonspacingMark),
+    (0x1A80, DecimalNumber),
+    (0x1A8A, Unassigned),
+    (0x1A90, DecimalNumber),
+    (0x1A9A, Unassigned),
+    (0x1AA0, OtherPunctuation),
+    (0x1AA7, ModifierLetter),
+    (0x1AA8, OtherPunctuation),
+    (0x1AAE, Unassigned),
+    (0x1AB0, NonspacingMark),
+    (0x1ABE, EnclosingMark),
+    (0x1ABF, NonspacingMark),
+    (0x1ACF, Unassigned),
+    (0x1B00, NonspacingMark),
+    (0x1B04, SpacingMark),
+    (0x1B05, OtherLetter),
+    (0x1B34, NonspacingMark),
+    (0x1B35, SpacingMark),
+    (0x1B36, NonspacingMark),
+    (0x1B3B, SpacingMark),
+    (0x1B3C, NonspacingMark),
+    (0x1B3D, SpacingMark),
+    (0x1B42, NonspacingMark),
+    (0x1B43, SpacingMark),
+    (0x1B45, OtherLetter),
+    (0x1B4D, Unassigned),
+    (0x1B50, DecimalNumber),
+    (0x1B5A, OtherPunctuation),
+    (0x1B61, OtherSymbol),
+    (0x1B6B, NonspacingMark),
+    (0x1B74, OtherSymbol),
+    (0x1B7D, OtherPunctuation),
+    (0x1B7F, Unassigned),
+    (0x1B80, NonspacingMark),
+    (0x1B82, SpacingMark),
+    (0x1B83, OtherLetter),
+    (0x1BA1, SpacingMark),
+    (0x1BA2, NonspacingMark),
+    (0x1BA6, SpacingMark),
+    (0x1BA8, NonspacingMark),
+    (0x1BAA, SpacingMark),
+    (0x1BAB, NonspacingMark),
+    (0x1BAE, OtherLetter),
+    (0x1BB0, DecimalNumber),
+    (0x1BBA, OtherLetter),
+    (0x1BE6, NonspacingMark),
+    (0x1BE7, SpacingMark),
+    (0x1BE8, NonspacingMark),
+    (0x1BEA, SpacingMark),
+    (0x1BED, NonspacingMark),
+    (0x1BEE, SpacingMark),
+    (0x1BEF, NonspacingMark),
+    (0x1BF2, SpacingMark),
+    (0x1BF4, Unassigned),
+    (0x1BFC, OtherPunctuation),
+    (0x1C00, OtherLetter),
+    (0x1C24, SpacingMark),
+    (0x1C2C, NonspacingMark),
+    (0x1C34, SpacingMark),
+    (0x1C36, NonspacingMark),
+    (0x1C38, Unassigned),
+    (0x1C3B, OtherPunctuation),
+    (0x1C40, DecimalNumber),
+    (0x1C4A, Unassigned),
+    (0x1C4D, OtherLetter),
+    (0x1C50, DecimalNumber),
+    (0x1C5A, OtherLetter),
+    (0x1C78, ModifierLetter),
+    (0x1C7E, OtherPunctuation),
+    (0x1C80, LowercaseLetter),
+    (0x1C89, Unassigned),
+    (0x1C90, UppercaseLetter),
+    (0x1CBB, Unassigned),
+    (0x1CBD, UppercaseLetter),
+    (0x1CC0, OtherPunctuation),
+    (0x1CC8, Unassigned),
+    (0x1CD0, NonspacingMark),
+    (0x1CD3, OtherPunctuation),
+    (0x1CD4, NonspacingMark),
+    (0x1CE1, SpacingMark),
+    (0x1CE2, NonspacingMark),
+    (0x1CE9, OtherLetter),
+    (0x1CED, NonspacingMark),
+    (0x1CEE, OtherLetter),
+    (0x1CF4, NonspacingMark),
+    (0x1CF5, OtherLetter),
+    (0x1CF7, SpacingMark),
+    (0x1CF8, NonspacingMark),
+    (0x1CFA, OtherLetter),
+    (0x1CFB, Unassigned),
+    (0x1D00, LowercaseLetter),
+    (0x1D2C, ModifierLetter),
+    (0x1D6B, LowercaseLetter),
+    (0x1D78, ModifierLetter),
+    (0x1D79, LowercaseLetter),
+    (0x1D9B, ModifierLetter),
+    (0x1DC0, NonspacingMark),
+    (0x1E00, UppercaseLetter),
+    (0x1E01, LowercaseLetter),
+    (0x1E02, UppercaseLetter),
+    (0x1E03, LowercaseLetter),
+    (0x1E04, UppercaseLetter),
+    (0x1E05, LowercaseLetter),
+    (0x1E06, UppercaseLetter),
+    (0x1E07, LowercaseLetter),
+    (0x1E08, UppercaseLetter),
+    (0x1E09, LowercaseLetter),
+    (0x1E0A, UppercaseLetter),
+    (0x1E0B, LowercaseLetter),
+    (0x1E0C, UppercaseLetter),
+    (0x1E0D, LowercaseLetter),
+    (0x1E0E, UppercaseLetter),
+    (0x1E0F, LowercaseLetter),
+    (0x1E10, UppercaseLetter),
+    (0x1E11, LowercaseLetter),
+    (0x1E12, UppercaseLetter),
+    (0x1E13, LowercaseLetter),
+    (0x1E14, UppercaseLetter),
+    (0x1E15, LowercaseLetter),
+    (0x1E16, UppercaseLetter),
+    (0x1E17, LowercaseLetter),
+    (0x1E18, UppercaseLetter),
+    (0x1E19, LowercaseLetter),
+    (0x1E1A, UppercaseLetter),
+    (0x1E1B, LowercaseLetter),
+    (0x1E1C, UppercaseLetter),
+    (0x1E1D, LowercaseLetter),
+    (0x1E1E, UppercaseLetter),
+    (0x1E1F, LowercaseLetter),
+    (0x1E20, UppercaseLetter),
+    (0x1E21, LowercaseLetter),
+    (0x1E22, UppercaseLetter),
+    (0x1E23, LowercaseLetter),
+    (0x1E24, UppercaseLetter),
+    (0x1E25, LowercaseLetter),
+    (0x1E26, UppercaseLetter),
+    (0x1E27, LowercaseLetter),
+    (0x1E28, UppercaseLetter),
+    (0x1E29, LowercaseLetter),
+    (0x1E2A, UppercaseLetter),
+    (0x1E2B, LowercaseLetter),
+    (0x1E2C, UppercaseLetter),
+    (0x1E2D, LowercaseLetter),
+    (0x1E2E, UppercaseLetter),
+    (0x1E2F, LowercaseLetter),
+    (0x1E30, UppercaseLetter),
+    (0x1E31, LowercaseLetter),
+    (0x1E32, UppercaseLetter),
+    (0x1E33, LowercaseLetter),
+    (0x1E34, UppercaseLetter),
+    (0x1E35, LowercaseLetter),
+    (0x1E36, UppercaseLetter),
+    (0x1E37, LowercaseLetter),
+    (0x1E38, UppercaseLetter),
+    (0x1E39, LowercaseLetter),
+    (0x1E3A, UppercaseLetter),
+    (0x1E3B, LowercaseLetter),
+    (0x1E3C, UppercaseLetter),
+    (0x1E3D, LowercaseLetter),
+    (0x1E3E, UppercaseLetter),
+    (0x1E3F, LowercaseLetter),
+    (0x1E40, UppercaseLetter),
+    (0x1E41, LowercaseLetter),
+    (0x1E42, UppercaseLetter),
+    (0x1E43, LowercaseLetter),
+    (0x1E44, UppercaseLetter),
+    (0x1E45, LowercaseLetter),
+    (0x1E46, UppercaseLetter),
+    (0x1E47, LowercaseLetter),
+    (0x1E48, UppercaseLetter),
+    (0x1E49, LowercaseLetter),
+    (0x1E4A, UppercaseLetter),
+    (0x1E4B, LowercaseLetter),
+    (0x1E4C, UppercaseLetter),
+    (0x1E4D, LowercaseLetter),
+    (0x1E4E, UppercaseLetter),
+    (0x1E4F, LowercaseLetter),
+    (0x1E50, UppercaseLetter),
+    (0x1E51, LowercaseLetter),
+    (0x1E52, UppercaseLetter),
+    (0x1E53, LowercaseLetter),
+    (0x1E54, UppercaseLetter),
+    (0x1E55, LowercaseLetter),
+    (0x1E56, UppercaseLetter),
+    (0x1E57, LowercaseLetter),
+    (0x1E58, UppercaseLetter),
+    (0x1E59, LowercaseLetter),
+    (0x1E5A, UppercaseLetter),
+    (0x1E5B, LowercaseLetter),
+    (0x1E5C, UppercaseLetter),
+    (0x1E5D, LowercaseLetter),
+    (0x1E5E, UppercaseLetter),
+    (0x1E5F, LowercaseLetter),
+    (0x1E60, UppercaseLetter),
+    (0x1E61, LowercaseLetter),
+    (0x1E62, UppercaseLetter),
+    (0x1E63, LowercaseLetter),
+    (0x1E64, UppercaseLetter),
+    (0x1E65, LowercaseLetter),
+    (0x1E66, UppercaseLetter),
+    (0x1E67, LowercaseLetter),
+    (0x1E68, UppercaseLetter),
+    (0x1E69, LowercaseLetter),
+    (0x1E6A, UppercaseLetter),
+    (0x1E6B, LowercaseLetter),
+    (0x1E6C, UppercaseLetter),
+    (0x1E6D, LowercaseLetter),
+    (0x1E6E, UppercaseLetter),
+    (0x1E6F, LowercaseLetter),
+    (0x1E70, UppercaseLetter),
+    (0x1E71, LowercaseLetter),
+    (0x1E72, UppercaseLetter),
+    (0x1E73, LowercaseLetter),
+    (0x1E74, UppercaseLetter),
+    (0x1E75, LowercaseLetter),
+    (0x1E76, UppercaseLetter),
+    (0x1E77, LowercaseLetter),
+    (0x1E78, UppercaseLetter),
+    (0x1E79, LowercaseLetter),
+    (0x1E7A, UppercaseLetter),
+    (0x1E7B, LowercaseLetter),
+    (0x1E7C, UppercaseLetter),
+    (0x1E7D, LowercaseLetter),
+    (0x1E7E, UppercaseLetter),
+    (0x1E7F, LowercaseLetter),
+    (0x1E80, UppercaseLetter),
+    (0x1E81, LowercaseLetter),
+    (0x1E82, UppercaseLetter),
+    (0x1E83, LowercaseLetter),
+    (0x1E84, UppercaseLetter),
+    (0x1E85, LowercaseLetter),
+    (0x1E86, UppercaseLetter),
+    (0x1E87, LowercaseLetter),
+    (0x1E88, UppercaseLetter),
+    (0x1E89, LowercaseLetter),
+    (0x1E8A, UppercaseLetter),
+    (0x1E8B, LowercaseLetter),
+    (0x1E8C, UppercaseLetter),
+    (0x1E8D, LowercaseLetter),
+    (0x1E8E, UppercaseLetter),
+    (0x1E8F, LowercaseLetter),
+    (0x1E90, UppercaseLetter),
+    (0x1E91, LowercaseLetter),
+    (0x1E92, UppercaseLetter),
+    (0x1E93, LowercaseLetter),
+    (0x1E94, UppercaseLetter),
+    (0x1E95, LowercaseLetter),
+    (0x1E9E, UppercaseLetter),
+    (0x1E9F, LowercaseLetter),
+    (0x1EA0, UppercaseLetter),
+    (0x1EA1, LowercaseLetter),
+    (0x1EA2, UppercaseLetter),
+    (0x1EA3, LowercaseLetter),
+    (0x1EA4, UppercaseLetter),
+    (0x1EA5, LowercaseLetter),
+    (0x1EA6, UppercaseLetter),
+    (0x1EA7, LowercaseLetter),
+    (0x1EA8, UppercaseLetter),
+    (0x1EA9, LowercaseLetter),
+    (0x1EAA, UppercaseLetter),
+    (0x1EAB, LowercaseLetter),
+    (0x1EAC, UppercaseLetter),
+    (0x1EAD, LowercaseLetter),
+    (0x1EAE, UppercaseLetter),
+    (0x1EAF, LowercaseLetter),
+    (0x1EB0, UppercaseLetter),
+    (0x1EB1, LowercaseLetter),
+    (0x1EB2, UppercaseLetter),
+    (0x1EB3, LowercaseLetter),
+    (0x1EB4, UppercaseLetter),
+    (0x1EB5, LowercaseLetter),
+    (0x1EB6, UppercaseLetter),
+    (0x1EB7, LowercaseLetter),
+    (0x1EB8, UppercaseLetter),
+    (0x1EB9, LowercaseLetter),
+    (0x1EBA, UppercaseLetter),
+    (0x1EBB, LowercaseLetter),
+    (0x1EBC, UppercaseLetter),
+    (0x1EBD, LowercaseLetter),
+    (0x1EBE, UppercaseLetter),
+    (0x1EBF, LowercaseLetter),
+    (0x1EC0, UppercaseLetter),
+    (0x1EC1, LowercaseLetter),
+    (0x1EC2, UppercaseLetter),
+    (0x1EC3, LowercaseLetter),
+    (0x1EC4, UppercaseLetter),
+    (0x1EC5, LowercaseLetter),
+    (0x1EC6, UppercaseLetter),
+    (0x1EC7, LowercaseLetter),
+    (0x1EC8, UppercaseLetter),
+    (0x1EC9, LowercaseLetter),
+    (0x1ECA, UppercaseLetter),
+    (0x1ECB, LowercaseLetter),
+    (0x1ECC, UppercaseLetter),
+    (0x1ECD, LowercaseLetter),
+    (0x1ECE, UppercaseLetter),
+    (0x1ECF, LowercaseLetter),
+    (0x1ED0, UppercaseLetter),
+    (0x1ED1, LowercaseLetter),
+    (0x1ED2, UppercaseLetter),
+    (0x1ED3, LowercaseLetter),
+    (0x1ED4, UppercaseLetter),
+    (0x1ED5, LowercaseLetter),
+    (0x1ED6, UppercaseLetter),
+    (0x1ED7, LowercaseLetter),
+    (0x1ED8, UppercaseLetter),
+    (0x1ED9, LowercaseLetter),
+    (0x1EDA, UppercaseLetter),
+    (0x1EDB, LowercaseLetter),
+    (0x1EDC, UppercaseLetter),
+    (0x1EDD, LowercaseLetter),
+    (0x1EDE, UppercaseLetter),
+    (0x1EDF, LowercaseLetter),
+    (0x1EE0, UppercaseLetter),
+    (0x1EE1, LowercaseLetter),
+    (0x1EE2, UppercaseLetter),
+    (0x1EE3, LowercaseLetter),
+    (0x1EE4, UppercaseLetter),
+    (0x1EE5, LowercaseLetter),
+    (0x1EE6, UppercaseLetter),
+    (0x1EE7, LowercaseLetter),
+    (0x1EE8, UppercaseLetter),
+    (0x1EE9, LowercaseLetter),
+    (0x1EEA, UppercaseLetter),
+    (0x1EEB, LowercaseLetter),
+    (0x1EEC, UppercaseLetter),
+    (0x1EED, LowercaseLetter),
+    (0x1EEE, UppercaseLetter),
+    (0x1EEF, LowercaseLetter),
+    (0x1EF0, UppercaseLetter),
+    (0x1EF1, LowercaseLetter),
+    (0x1EF2, UppercaseLetter),
+    (0x1EF3, LowercaseLetter),
+    (0x1EF4, UppercaseLetter),
+    (0x1EF5, LowercaseLetter),
+    (0x1EF6, UppercaseLetter),
+    (0x1EF7, LowercaseLetter),
+    (0x1EF8, UppercaseLetter),
+    (0x1EF9, LowercaseLetter),
+    (0x1EFA, UppercaseLetter),
+    (0x1EFB, LowercaseLetter),
+    (0x1EFC, UppercaseLetter),
+    (0x1EFD, LowercaseLetter),
+    (0x1EFE, UppercaseLetter),
+    (0x1EFF, LowercaseLetter),
+    (0x1F08, UppercaseLetter),
+    (0x1F10, LowercaseLetter),
+    (0x1F16, Unassigned),
+    (0x1F18, UppercaseLetter),
+    (0x1F1E, Unassigned),
+    (0x1F20, LowercaseLetter),
+    (0x1F28, UppercaseLetter),
+    (0x1F30, LowercaseLetter),
+    (0x1F38, UppercaseLetter),
+    (0x1F40, LowercaseLetter),
+    (0x1F46, Unassigned),
+    (0x1F48, UppercaseLetter),
+    (0x1F4E, Unassigned),
+    (0x1F50, LowercaseLetter),
+    (0x1F58, Unassigned),
+    (0x1F59, UppercaseLetter),
+    (0x1F5A, Unassigned),
+    (0x1F5B, UppercaseLetter),
+    (0x1F5C, Unassigned),
+    (0x1F5D, UppercaseLetter),
+    (0x1F5E, Unassigned),
+    (0x1F5F, UppercaseLetter),
+    (0x1F60, LowercaseLetter),
+    (0x1F68, UppercaseLetter),
+    (0x1F70, LowercaseLetter),
+    (0x1F7E, Unassigned),
+    (0x1F80, LowercaseLetter),
+    (0x1F88, TitlecaseLetter),
+    (0x1F90, LowercaseLetter),
+    (0x1F98, TitlecaseLetter),
+    (0x1FA0, LowercaseLetter),
+    (0x1FA8, TitlecaseLetter),
+    (0x1FB0, LowercaseLetter),
+    (0x1FB5, Unassigned),
+    (0x1FB6, LowercaseLetter),
+    (0x1FB8, UppercaseLetter),
+    (0x1FBC, TitlecaseLetter),
+    (0x1FBD, ModifierSymbol),
+    (0x1FBE, LowercaseLetter),
+    (0x1FBF, ModifierSymbol),
+    (0x1FC2, LowercaseLetter),
+    (0x1FC5, Unassigned),
+    (0x1FC6, LowercaseLetter),
+    (0x1FC8, UppercaseLetter),
+    (0x1FCC, TitlecaseLetter),
+    (0x1FCD, ModifierSymbol),
+    (0x1FD0, LowercaseLetter),
+    (0x1FD4, Unassigned),
+    (0x1FD6, LowercaseLetter),
+    (0x1FD8, UppercaseLetter),
+    (0x1FDC, Unassigned),
+    (0x1FDD, ModifierSymbol),
+    (0x1FE0, LowercaseLetter),
+    (0x1FE8, UppercaseLetter),
+    (0x1FED, ModifierSymbol),
+    (0x1FF0, Unassigned),
+    (0x1FF2, LowercaseLetter),
+    (0x1FF5, Unassigned),
+    (0x1FF6, LowercaseLetter),
+    (0x1FF8, UppercaseLetter),
+    (0x1FFC, TitlecaseLetter),
+    (0x1FFD, ModifierSymbol),
+    (0x1FFF, Unassigned),
+    (0x2000, SpaceSeparator),
+    (0x200B, Format),
+    (0x2010, DashPunctuation),
+    (0x2016, OtherPunctuation),
+    (0x2018, InitialPunctuation),
+    (0x2019, FinalPunctuation),
+    (0x201A, OpenPunctuation),
+    (0x201B, InitialPunctuation),
+    (0x201D, FinalPunctuation),
+    (0x201E, OpenPunctuation),
+    (0x201F, InitialPunctuation),
+    (0x2020, OtherPunctuation),
+    (0x2028, LineSeparator),
+    (0x2029, ParagraphSeparator),
+    (0x202A, Format),
+    (0x202F, SpaceSeparator),
+    (0x2030, OtherPunctuation),
+    (0x2039, InitialPunctuation),
+    (0x203A, FinalPunctuation),
+    (0x203B, OtherPunctuation),
+    (0x203F, ConnectorPunctuation),
+    (0x2041, OtherPunctuation),
+    (0x2044, MathSymbol),
+    (0x2045, OpenPunctuation),
+    (0x2046, ClosePunctuation),
+    (0x2047, OtherPunctuation),
+    (0x2052, MathSymbol),
+    (0x2053, OtherPunctuation),
+    (0x2054, ConnectorPunctuation),
+    (0x2055, OtherPunctuation),
+    (0x205F, SpaceSeparator),
+    (0x2060, Format),
+    (0x2065, Unassigned),
+    (0x2066, Format),
+    (0x2070, OtherNumber),
+    (0x2071, ModifierLetter),
+    (0x2072, Unassigned),
+    (0x2074, OtherNumber),
+    (0x207A, MathSymbol),
+    (0x207D, OpenPunctuation),
+    (0x207E, ClosePunctuation),
+    (0x207F, ModifierLetter),
+    (0x2080, OtherNumber),
+    (0x208A, MathSymbol),
+    (0x208D, OpenPunctuation),
+    (0x208E, ClosePunctuation),
+    (0x208F, Unassigned),
+    (0x2090, ModifierLetter),
+    (0x209D, Unassigned),
+    (0x20A0, CurrencySymbol),
+    (0x20C1, Unassigned),
+    (0x20D0, NonspacingMark),
+    (0x20DD, EnclosingMark),
+    (0x20E1, NonspacingMark),
+    (0x20E2, EnclosingMark),
+    (0x20E5, NonspacingMark),
+    (0x20F1, Unassigned),
+    (0x2100, OtherSymbol),
+    (0x2102, UppercaseLetter),
+    (0x2103, OtherSymbol),
+    (0x2107, UppercaseLetter),
+    (0x2108, OtherSymbol),
+    (0x210A, LowercaseLetter),
+    (0x210B, UppercaseLetter),
+    (0x210E, LowercaseLetter),
+    (0x2110, UppercaseLetter),
+    (0x2113, LowercaseLetter),
+    (0x2114, OtherSymbol),
+    (0x2115, UppercaseLetter),
+    (0x2116, OtherSymbol),
+    (0x2118, MathSymbol),
+    (0x2119, UppercaseLetter),
+    (0x211E, OtherSymbol),
+    (0x2124, UppercaseLetter),
+    (0x2125, OtherSymbol),
+    (0x2126, UppercaseLetter),
+    (0x2127, OtherSymbol),
+    (0x2128, UppercaseLetter),
+    (0x2129, OtherSymbol),
+    (0x212A, UppercaseLetter),
+    (0x212E, OtherSymbol),
+    (0x212F, LowercaseLetter),
+    (0x2130, UppercaseLetter),
+    (0x2134, LowercaseLetter),
+    (0x2135, OtherLetter),
+    (0x2139, LowercaseLetter),
+    (0x213A, OtherSymbol),
+    (0x213C, LowercaseLetter),
+    (0x213E, UppercaseLetter),
+    (0x2140, MathSymbol),
+    (0x2145, UppercaseLetter),
+    (0x2146, LowercaseLetter),
+    (0x214A, OtherSymbol),
+    (0x214B, MathSymbol),
+    (0x214C, OtherSymbol),
+    (0x214E, LowercaseLetter),
+    (0x214F, OtherSymbol),
+    (0x2150, OtherNumber),
+    (0x2160, LetterNumber),
+    (0x2183, UppercaseLetter),
+    (0x2184, LowercaseLetter),
+    (0x2185, LetterNumber),
+    (0x2189, OtherNumber),
+    (0x218A, OtherSymbol),
+    (0x218C, Unassigned),
+    (0x2190, MathSymbol),
+    (0x2195, OtherSymbol),
+    (0x219A, MathSymbol),
+    (0x219C, OtherSymbol),
+    (0x21A0, MathSymbol),
+    (0x21A1, OtherSymbol),
+    (0x21A3, MathSymbol),
+    (0x21A4, OtherSymbol),
+    (0x21A6, MathSymbol),
+    (0x21A7, OtherSymbol),
+    (0x21AE, MathSymbol),
+    (0x21AF, OtherSymbol),
+    (0x21CE, MathSymbol),
+    (0x21D0, OtherSymbol),
+    (0x21D2, MathSymbol),
+    (0x21D3, OtherSymbol),
+    (0x21D4, MathSymbol),
+    (0x21D5, OtherSymbol),
+    (0x21F4, MathSymbol),
+    (0x2300, OtherSymbol),
+    (0x2308, OpenPunctuation),
+    (0x2309, ClosePunctuation),
+    (0x230A, OpenPunctuation),
+    (0x230B, ClosePunctuation),
+    (0x230C, OtherSymbol),
+    (0x2320, MathSymbol),
+    (0x2322, OtherSymbol),
+    (0x2329, OpenPunctuation),
+    (0x232A, ClosePunctuation),
+    (0x232B, OtherSymbol),
+    (0x237C, MathSymbol),
+    (0x237D, OtherSymbol),
+    (0x239B, MathSymbol),
+    (0x23B4, OtherSymbol),
+    (0x23DC, MathSymbol),
+    (0x23E2, OtherSymbol),
+    (0x2427, Unassigned),
+    (0x2440, OtherSymbol),
+    (0x244B, Unassigned),
+    (0x2460, OtherNumber),
+    (0x249C, OtherSymbol),
+    (0x24EA, OtherNumber),
+    (0x2500, OtherSymbol),
+    (0x25B7, MathSymbol),
+    (0x25B8, OtherSymbol),
+    (0x25C1, MathSymbol),
+    (0x25C2, OtherSymbol),
+    (0x25F8, MathSymbol),
+    (0x2600, OtherSymbol),
+    (0x266F, MathSymbol),
+    (0x2670, OtherSymbol),
+    (0x2768, OpenPunctuation),
+    (0x2769, ClosePunctuation),
+    (0x276A, OpenPunctuation),
+    (0x276B, ClosePunctuation),
+    (0x276C, OpenPunctuation),
+    (0x276D, ClosePunctuation),
+    (0x276E, OpenPunctuation),
+    (0x276F, ClosePunctuation),
+    (0x2770, OpenPunctuation),
+    (0x2771, ClosePunctuation),
+    (0x2772, OpenPunctuation),
+    (0x2773, ClosePunctuation),
+    (0x2774, OpenPunctuation),
+    (0x2775, ClosePunctuation),
+    (0x2776, OtherNumber),
+    (0x2794, OtherSymbol),
+    (0x27C0, MathSymbol),
+    (0x27C5, OpenPunctuation),
+    (0x27C6, ClosePunctuation),
+    (0x27C7, MathSymbol),
+    (0x27E6, OpenPunctuation),
+    (0x27E7, ClosePunctuation),
+    (0x27E8, OpenPunctuation),
+    (0x27E9, ClosePunctuation),
+    (0x27EA, OpenPunctuation),
+    (0x27EB, ClosePunctuation),
+    (0x27EC, OpenPunctuation),
+    (0x27ED, ClosePunctuation),
+    (0x27EE, OpenPunctuation),
+    (0x27EF, ClosePunctuation),
+    (0x27F0, MathSymbol),
+    (0x2800, OtherSymbol),
+    (0x2900, MathSymbol),
+    (0x2983, OpenPunctuation),
+    (0x2984, ClosePunctuation),
+    (0x2985, OpenPunctuation),
+    (0x2986, ClosePunctuation),
+    (0x2987, OpenPunctuation),
+    (0x2988, ClosePunctuation),
+    (0x2989, OpenPunctuation),
+    (0x298A, ClosePunctuation),
+    (0x298B, OpenPunctuation),
+    (0x298C, ClosePunctuation),
+    (0x298D, OpenPunctuation),
+    (0x298E, ClosePunctuation),
+    (0x298F, OpenPunctuation),
+    (0x2990, ClosePunctuation),
+    (0x2991, OpenPunctuation),
+    (0x2992, ClosePunctuation),
+    (0x2993, OpenPunctuation),
+    (0x2994, ClosePunctuation),
+    (0x2995, OpenPunctuation),
+    (0x2996, ClosePunctuation),
+    (0x2997, OpenPunctuation),
+    (0x2998, ClosePunctuation),
+    (0x2999, MathSymbol),
+    (0x29D8, OpenPunctuation),
+    (0x29D9, ClosePunctuation),
+    (0x29DA, OpenPunctuation),
+    (0x29DB, ClosePunctuation),
+    (0x29DC, MathSymbol),
+    (0x29FC, OpenPunctuation),
+    (0x29FD, ClosePunctuation),
+    (0x29FE, MathSymbol),
+    (0x2B00, OtherSymbol),
+    (0x2B30, MathSymbol),
+    (0x2B45, OtherSymbol),
+    (0x2B47, MathSymbol),
+    (0x2B4D, OtherSymbol),
+    (0x2B74, Unassigned),
+    (0x2B76, OtherSymbol),
+    (0x2B96, Unassigned),
+    (0x2B97, OtherSymbol),
+    (0x2C00, UppercaseLetter),
+    (0x2C30, LowercaseLetter),
+    (0x2C60, UppercaseLetter),
+    (0x2C61, LowercaseLetter),
+    (0x2C62, UppercaseLetter),
+    (0x2C65, LowercaseLetter),
+    (0x2C67, UppercaseLetter),
+    (0x2C68, LowercaseLetter),
+    (0x2C69, UppercaseLetter),
+    (0x2C6A, LowercaseLetter),
+    (0x2C6B, UppercaseLetter),
+    (0x2C6C, LowercaseLetter),
+    (0x2C6D, UppercaseLetter),
+    (0x2C71, LowercaseLetter),
+    (0x2C72, UppercaseLetter),
+    (0x2C73, LowercaseLetter),
+    (0x2C75, UppercaseLetter),
+    (0x2C76, LowercaseLetter),
+    (0x2C7C, ModifierLetter),
+    (0x2C7E, UppercaseLetter),
+    (0x2C81, LowercaseLetter),
+    (0x2C82, UppercaseLetter),
+    (0x2C83, LowercaseLetter),
+    (0x2C84, UppercaseLetter),
+    (0x2C85, LowercaseLetter),
+    (0x2C86, UppercaseLetter),
+    (0x2C87, LowercaseLetter),
+    (0x2C88, UppercaseLetter),
+    (0x2C89, LowercaseLetter),
+    (0x2C8A, UppercaseLetter),
+    (0x2C8B, LowercaseLetter),
+    (0x2C8C, UppercaseLetter),
+    (0x2C8D, LowercaseLetter),
+    (0x2C8E, UppercaseLetter),
+    (0x2C8F, LowercaseLetter),
+    (0x2C90, UppercaseLetter),
+    (0x2C91, LowercaseLetter),
+    (0x2C92, UppercaseLetter),
+    (0x2C93, LowercaseLetter),
+    (0x2C94, UppercaseLetter),
+    (0x2C95, LowercaseLetter),
+    (0x2C96, UppercaseLetter),
+    (0x2C97, LowercaseLetter),
+    (0x2C98, UppercaseLetter),
+    (0x2C99, LowercaseLetter),
+    (0x2C9A, UppercaseLetter),
+    (0x2C9B, LowercaseLetter),
+    (0x2C9C, UppercaseLetter),
+    (0x2C9D, LowercaseLetter),
+    (0x2C9E, UppercaseLetter),
+    (0x2C9F, LowercaseLetter),
+    (0x2CA0, UppercaseLetter),
+    (0x2CA1, LowercaseLetter),
+    (0x2CA2, UppercaseLetter),
+    (0x2CA3, LowercaseLetter),
+    (0x2CA4, UppercaseLetter),
+    (0x2CA5, LowercaseLetter),
+    (0x2CA6, UppercaseLetter),
+    (0x2CA7, LowercaseLetter),
+    (0x2CA8, UppercaseLetter),
+    (0x2CA9, LowercaseLetter),
+    (0x2CAA, UppercaseLetter),
+    (0x2CAB, LowercaseLetter),
+    (0x2CAC, UppercaseLetter),
+    (0x2CAD, LowercaseLetter),
+    (0x2CAE, UppercaseLetter),
+    (0x2CAF, LowercaseLetter),
+    (0x2CB0, UppercaseLetter),
+    (0x2CB1, LowercaseLetter),
+    (0x2CB2, UppercaseLetter),
+    (0x2CB3, LowercaseLetter),
+    (0x2CB4, UppercaseLetter),
+    (0x2CB5, LowercaseLetter),
+    (0x2CB6, UppercaseLetter),
+    (0x2CB7, LowercaseLetter),
+    (0x2CB8, UppercaseLetter),
+    (0x2CB9, LowercaseLetter),
+    (0x2CBA, UppercaseLetter),
+    (0x2CBB, LowercaseLetter),
+    (0x2CBC, UppercaseLetter),
+    (0x2CBD, LowercaseLetter),
+    (0x2CBE, UppercaseLetter),
+    (0x2CBF, LowercaseLetter),
+    (0x2CC0, UppercaseLetter),
+    (0x2CC1, LowercaseLetter),
+    (0x2CC2, UppercaseLetter),
+    (0x2CC3, LowercaseLetter),
+    (0x2CC4, UppercaseLetter),
+    (0x2CC5, LowercaseLetter),
+    (0x2CC6, UppercaseLetter),
+    (0x2CC7, LowercaseLetter),
+    (0x2CC8, UppercaseLetter),
+    (0x2CC9, LowercaseLetter),
+    (0x2CCA, UppercaseLetter),
+    (0x2CCB, LowercaseLetter),
+    (0x2CCC, UppercaseLetter),
+    (0x2CCD, LowercaseLetter),
+    (0x2CCE, UppercaseLetter),
+    (0x2CCF, LowercaseLetter),
+    (0x2CD0, UppercaseLetter),
+    (0x2CD1, LowercaseLetter),
+    (0x2CD2, UppercaseLetter),
+    (0x2CD3, LowercaseLetter),
+    (0x2CD4, UppercaseLetter),
+    (0x2CD5, LowercaseLetter),
+    (0x2CD6, UppercaseLetter),
+    (0x2CD7, LowercaseLetter),
+    (0x2CD8, UppercaseLetter),
+    (0x2CD9, LowercaseLetter),
+    (0x2CDA, UppercaseLetter),
+    (0x2CDB, LowercaseLetter),
+    (0x2CDC, UppercaseLetter),
+    (0x2CDD, LowercaseLetter),
+    (0x2CDE, UppercaseLetter),
+    (0x2CDF, LowercaseLetter),
+    (0x2CE0, UppercaseLetter),
+    (0x2CE1, LowercaseLetter),
+    (0x2CE2, UppercaseLetter),
+    (0x2CE3, LowercaseLetter),
+    (0x2CE5, OtherSymbol),
+    (0x2CEB, UppercaseLetter),
+    (0x2CEC, LowercaseLetter),
+    (0x2CED, UppercaseLetter),
+    (0x2CEE, LowercaseLetter),
+    (0x2CEF, NonspacingMark),
+    (0x2CF2, UppercaseLetter),
+    (0x2CF3, LowercaseLetter),
+    (0x2CF4, Unassigned),
+    (0x2CF9, OtherPunctuation),
+    (0x2CFD, OtherNumber),
+    (0x2CFE, OtherPunctuation),
+    (0x2D00, LowercaseLetter),
+    (0x2D26, Unassigned),
+    (0x2D27, LowercaseLetter),
+    (0x2D28, Unassigned),
+    (0x2D2D, LowercaseLetter),
+    (0x2D2E, Unassigned),
+    (0x2D30, OtherLetter),
+    (0x2D68, Unassigned),
+    (0x2D6F, ModifierLetter),
+    (0x2D70, OtherPunctuation),
+    (0x2D71, Unassigned),
+    (0x2D7F, NonspacingMark),
+    (0x2D80, OtherLetter),
+    (0x2D97, Unassigned),
+    (0x2DA0, OtherLetter),
+    (0x2DA7, Unassigned),
+    (0x2DA8, OtherLetter),
+    (0x2DAF, Unassigned),
+    (0x2DB0, OtherLetter),
+    (0x2DB7, Unassigned),
+    (0x2DB8, OtherLetter),
+    (0x2DBF, Unassigned),
+    (0x2DC0, OtherLetter),
+    (0x2DC7, Unassigned),
+    (0x2DC8, OtherLetter),
+    (0x2DCF, Unassigned),
+    (0x2DD0, OtherLetter),
+    (0x2DD7, Unassigned),
+    (0x2DD8, OtherLetter),
+    (0x2DDF, Unassigned),
+    (0x2DE0, NonspacingMark),
+    (0x2E00, OtherPunctuation),
+    (0x2E02, InitialPunctuation),
+    (0x2E03, FinalPunctuation),
+    (0x2E04, InitialPunctuation),
+    (0x2E05, FinalPunctuation),
+    (0x2E06, OtherPunctuation),
+    (0x2E09, InitialPunctuation),
+    (0x2E0A, FinalPunctuation),
+    (0x2E0B, OtherPunctuation),
+    (0x2E0C, InitialPunctuation),
+    (0x2E0D, FinalPunctuation),
+    (0x2E0E, OtherPunctuation),
+    (0x2E17, DashPunctuation),
+    (0x2E18, OtherPunctuation),
+    (0x2E1A, DashPunctuation),
+    (0x2E1B, OtherPunctuation),
+    (0x2E1C, InitialPunctuation),
+    (0x2E1D, FinalPunctuation),
+    (0x2E1E, OtherPunctuation),
+    (0x2E20, InitialPunctuation),
+    (0x2E21, FinalPunctuation),
+    (0x2E22, OpenPunctuation),
+    (0x2E23, ClosePunctuation),
+    (0x2E24, OpenPunctuation),
+    (0x2E25, ClosePunctuation),
+    (0x2E26, OpenPunctuation),
+    (0x2E27, ClosePunctuation),
+    (0x2E28, OpenPunctuation),
+    (0x2E29, ClosePunctuation),
+    (0x2E2A, OtherPunctuation),
+    (0x2E2F, ModifierLetter),
+    (0x2E30, OtherPunctuation),
+    (0x2E3A, DashPunctuation),
+    (0x2E3C, OtherPunctuation),
+    (0x2E40, DashPunctuation),
+    (0x2E41, OtherPunctuation),
+    (0x2E42, OpenPunctuation),
+    (0x2E43, OtherPunctuation),
+    (0x2E50, OtherSymbol),
+    (0x2E52, OtherPunctuation),
+    (0x2E55, OpenPunctuation),
+    (0x2E56, ClosePunctuation),
+    (0x2E57, OpenPunctuation),
+    (0x2E58, ClosePunctuation),
+    (0x2E59, OpenPunctuation),
+    (0x2E5A, ClosePunctuation),
+    (0x2E5B, OpenPunctuation),
+    (0x2E5C, ClosePunctuation),
+    (0x2E5D, DashPunctuation),
+    (0x2E5E, Unassigned),
+    (0x2E80, OtherSymbol),
+    (0x2E9A, Unassigned),
+    (0x2E9B, OtherSymbol),
+    (0x2EF4, Unassigned),
+    (0x2F00, OtherSymbol),
+    (0x2FD6, Unassigned),
+    (0x2FF0, OtherSymbol),
+    (0x2FFC, Unassigned),
+    (0x3000, SpaceSeparator),
+    (0x3001, OtherPunctuation),
+    (0x3004, OtherSymbol),
+    (0x3005, ModifierLetter),
+    (0x3006, OtherLetter),
+    (0x3007, LetterNumber),
+    (0x3008, OpenPunctuation),
+    (0x3009, ClosePunctuation),
+    (0x300A, OpenPunctuation),
+    (0x300B, ClosePunctuation),
+    (0x300C, OpenPunctuation),
+    (0x300D, ClosePunctuation),
+    (0x300E, OpenPunctuation),
+    (0x300F, ClosePunctuation),
+    (0x3010, OpenPunctuation),
+    (0x3011, ClosePunctuation),
+    (0x3012, OtherSymbol),
+    (0x3014, OpenPunctuation),
+    (0x3015, ClosePunctuation),
+    (0x3016, OpenPunctuation),
+    (0x3017, ClosePunctuation),
+    (0x3018, OpenPunctuation),
+    (0x3019, ClosePunctuation),
+    (0x301A, OpenPunctuation),
+    (0x301B, ClosePunctuation),
+    (0x301C, DashPunctuation),
+    (0x301D, OpenPunctuation),
+    (0x301E, ClosePunctuation),
+    (0x3020, OtherSymbol),
+    (0x3021, LetterNumber),
+    (0x302A, NonspacingMark),
+    (0x302E, SpacingMark),
+    (0x3030, DashPunctuation),
+    (0x3031, ModifierLetter),
+    (0x3036, OtherSymbol),
+    (0x3038, LetterNumber),
+    (0x303B, ModifierLetter),
+    (0x303C, OtherLetter),
+    (0x303D, OtherPunctuation),
+    (0x303E, OtherSymbol),
+    (0x3040, Unassigned),
+    (0x3041, OtherLetter),
+    (0x3097, Unassigned),
+    (0x3099, NonspacingMark),
+    (0x309B, ModifierSymbol),
+    (0x309D, ModifierLetter),
+    (0x309F, OtherLetter),
+    (0x30A0, DashPunctuation),
+    (0x30A1, OtherLetter),
+    (0x30FB, OtherPunctuation),
+    (0x30FC, ModifierLetter),
+    (0x30FF, OtherLetter),
+    (0x3100, Unassigned),
+    (0x3105, OtherLetter),
+    (0x3130, Unassigned),
+    (0x3131, OtherLetter),
+    (0x318F, Unassigned),
+    (0x3190, OtherSymbol),
+    (0x3192, OtherNumber),
+    (0x3196, OtherSymbol),
+    (0x31A0, OtherLetter),
+    (0x31C0, OtherSymbol),
+    (0x31E4, Unassigned),
+    (0x31F0, OtherLetter),
+    (0x3200, OtherSymbol),
+    (0x321F, Unassigned),
+    (0x3220, OtherNumber),
+    (0x322A, OtherSymbol),
+    (0x3248, OtherNumber),
+    (0x3250, OtherSymbol),
+    (0x3251, OtherNumber),
+    (0x3260, OtherSymbol),
+    (0x3280, OtherNumber),
+    (0x328A, OtherSymbol),
+    (0x32B1, OtherNumber),
+    (0x32C0, OtherSymbol),
+    (0x3400, OtherLetter),
+    (0x4DC0, OtherSymbol),
+    (0x4E00, OtherLetter),
+    (0xA015, ModifierLetter),
+    (0xA016, OtherLetter),
+    (0xA48D, Unassigned),
+    (0xA490, OtherSymbol),
+    (0xA4C7, Unassigned),
+    (0xA4D0, OtherLetter),
+    (0xA4F8, ModifierLetter),
+    (0xA4FE, OtherPunctuation),
+    (0xA500, OtherLetter),
+    (0xA60C, ModifierLetter),
+    (0xA60D, OtherPunctuation),
+    (0xA610, OtherLetter),
+    (0xA620, DecimalNumber),
+    (0xA62A, OtherLetter),
+    (0xA62C, Unassigned),
+    (0xA640, UppercaseLetter),
+    (0xA641, LowercaseLetter),
+    (0xA642, UppercaseLetter),
+    (0xA643, LowercaseLetter),
+    (0xA644, UppercaseLetter),
+    (0xA645, LowercaseLetter),
+    (0xA646, UppercaseLetter),
+    (0xA647, LowercaseLetter),
+    (0xA648, UppercaseLetter),
+    (0xA649, LowercaseLetter),
+    (0xA64A, UppercaseLetter),
+    (0xA64B, LowercaseLetter),
+    (0xA64C, UppercaseLetter),
+    (0xA64D, LowercaseLetter),
+    (0xA64E, UppercaseLetter),
+    (0xA64F, LowercaseLetter),
+    (0xA650, UppercaseLetter),
+    (0xA651, LowercaseLetter),
+    (0xA652, UppercaseLetter),
+    (0xA653, LowercaseLetter),
+    (0xA654, UppercaseLetter),
+    (0xA655, LowercaseLetter),
+    (0xA656, UppercaseLetter),
+    (0xA657, LowercaseLetter),
+    (0xA658, UppercaseLetter),
+    (0xA659, LowercaseLetter),
+    (0xA65A, UppercaseLetter),
+    (0xA65B, LowercaseLetter),
+    (0xA65C, UppercaseLetter),
+    (0xA65D, LowercaseLetter),
+    (0xA65E, UppercaseLetter),
+    (0xA65F, LowercaseLetter),
+    (0xA660, UppercaseLetter),
+    (0xA661, LowercaseLetter),
+    (0xA662, UppercaseLetter),
+    (0xA663, LowercaseLetter),
+    (0xA664, UppercaseLetter),
+    (0xA665, LowercaseLetter),
+    (0xA666, UppercaseLetter),
+    (0xA667, LowercaseLetter),
+    (0xA668, UppercaseLetter),
+    (0xA669, LowercaseLetter),
+    (0xA66A, UppercaseLetter),
+    (0xA66B, LowercaseLetter),
+    (0xA66C, UppercaseLetter),
+    (0xA66D, LowercaseLetter),
+    (0xA66E, OtherLetter),
+    (0xA66F, NonspacingMark),
+    (0xA670, EnclosingMark),
+    (0xA673, OtherPunctuation),
+    (0xA674, NonspacingMark),
+    (0xA67E, OtherPunctuation),
+    (0xA67F, ModifierLetter),
+    (0xA680, UppercaseLetter),
+    (0xA681, LowercaseLetter),
+    (0xA682, UppercaseLetter),
+    (0xA683, LowercaseLetter),
+    (0xA684, UppercaseLetter),
+    (0xA685, LowercaseLetter),
+    (0xA686, UppercaseLetter),
+    (0xA687, LowercaseLetter),
+    (0xA688, UppercaseLetter),
+    (0xA689, LowercaseLetter),
+    (0xA68A, UppercaseLetter),
+    (0xA68B, LowercaseLetter),
+    (0xA68C, UppercaseLetter),
+    (0xA68D, LowercaseLetter),
+    (0xA68E, UppercaseLetter),
+    (0xA68F, LowercaseLetter),
+    (0xA690, UppercaseLetter),
+    (0xA691, LowercaseLetter),
+    (0xA692, UppercaseLetter),
+    (0xA693, LowercaseLetter),
+    (0xA694, UppercaseLetter),
+    (0xA695, LowercaseLetter),
+    (0xA696, UppercaseLetter),
+    (0xA697, LowercaseLetter),
+    (0xA698, UppercaseLetter),
+    (0xA699, LowercaseLetter),
+    (0xA69A, UppercaseLetter),
+    (0xA69B, LowercaseLetter),
+    (0xA69C, ModifierLetter),
+    (0xA69E, NonspacingMark),
+    (0xA6A0, OtherLetter),
+    (0xA6E6, LetterNumber),
+    (0xA6F0, NonspacingMark),
+    (0xA6F2, OtherPunctuation),
+    (0xA6F8, Unassigned),
+    (0xA700, ModifierSymbol),
+    (0xA717, ModifierLetter),
+    (0xA720, ModifierSymbol),
+    (0xA722, UppercaseLetter),
+    (0xA723, LowercaseLetter),
+    (0xA724, UppercaseLetter),
+    (0xA725, LowercaseLetter),
+    (0xA726, UppercaseLetter),
+    (0xA727, LowercaseLetter),
+    (0xA728, UppercaseLetter),
+    (0xA729, LowercaseLetter),
+    (0xA72A, UppercaseLetter),
+    (0xA72B, LowercaseLetter),
+    (0xA72C, UppercaseLetter),
+    (0xA72D, LowercaseLetter),
+    (0xA72E, UppercaseLetter),
+    (0xA72F, LowercaseLetter),
+    (0xA732, UppercaseLetter),
+    (0xA733, LowercaseLetter),
+    (0xA734, UppercaseLetter),
+    (0xA735, LowercaseLetter),
+    (0xA736, UppercaseLetter),
+    (0xA737, LowercaseLetter),
+    (0xA738, UppercaseLetter),
+    (0xA739, LowercaseLetter),
+    (0xA73A, UppercaseLetter),
+    (0xA73B, LowercaseLetter),
+    (0xA73C, UppercaseLetter),
+    (0xA73D, LowercaseLetter),
+    (0xA73E, UppercaseLetter),
+    (0xA73F, LowercaseLetter),
+    (0xA740, UppercaseLetter),
+    (0xA741, LowercaseLetter),
+    (0xA742, UppercaseLetter),
+    (0xA743, LowercaseLetter),
+    (0xA744, UppercaseLetter),
+    (0xA745, LowercaseLetter),
+    (0xA746, UppercaseLetter),
+    (0xA747, LowercaseLetter),
+    (0xA748, UppercaseLetter),
+    (0xA749, LowercaseLetter),
+    (0xA74A, UppercaseLetter),
+    (0xA74B, LowercaseLetter),
+    (0xA74C, UppercaseLetter),
+    (0xA74D, LowercaseLetter),
+    (0xA74E, UppercaseLetter),
+    (0xA74F, LowercaseLetter),
+    (0xA750, UppercaseLetter),
+    (0xA751, LowercaseLetter),
+    (0xA752, UppercaseLetter),
+    (0xA753, LowercaseLetter),
+    (0xA754, UppercaseLetter),
+    (0xA755, LowercaseLetter),
+    (0xA756, UppercaseLetter),
+    (0xA757, LowercaseLetter),
+    (0xA758, UppercaseLetter),
+    (0xA759, LowercaseLetter),
+    (0xA75A, UppercaseLetter),
+    (0xA75B, LowercaseLetter),
+    (0xA75C, UppercaseLetter),
+    (0xA75D, LowercaseLetter),
+    (0xA75E, UppercaseLetter),
+    (0xA75F, LowercaseLetter),
+    (0xA760, UppercaseLetter),
+    (0xA761, LowercaseLetter),
+    (0xA762, UppercaseLetter),
+    (0xA763, LowercaseLetter),
+    (0xA764, UppercaseLetter),
+    (0xA765, LowercaseLetter),
+    (0xA766, UppercaseLetter),
+    (0xA767, LowercaseLetter),
+    (0xA768, UppercaseLetter),
+    (0xA769, LowercaseLetter),
+    (0xA76A, UppercaseLetter),
+    (0xA76B, LowercaseLetter),
+    (0xA76C, UppercaseLetter),
+    (0xA76D, LowercaseLetter),
+    (0xA76E, UppercaseLetter),
+    (0xA76F, LowercaseLetter),
+    (0xA770, ModifierLetter),
+    (0xA771, LowercaseLetter),
+    (0xA779, UppercaseLetter),
+    (0xA77A, LowercaseLetter),
+    (0xA77B, UppercaseLetter),
+    (0xA77C, LowercaseLetter),
+    (0xA77D, UppercaseLetter),
+    (0xA77F, LowercaseLetter),
+    (0xA780, UppercaseLetter),
+    (0xA781, LowercaseLetter),
+    (0xA782, UppercaseLetter),
+    (0xA783, LowercaseLetter),
+    (0xA784, UppercaseLetter),
+    (0xA785, LowercaseLetter),
+    (0xA786, UppercaseLetter),
+    (0xA787, LowercaseLetter),
+    (0xA788, ModifierLetter),
+    (0xA789, ModifierSymbol),
+    (0xA78B, UppercaseLetter),
+    (0xA78C, LowercaseLetter),
+    (0xA78D, UppercaseLetter),
+    (0xA78E, LowercaseLetter),
+    (0xA78F, OtherLetter),
+    (0xA790, UppercaseLetter),
+    (0xA791, LowercaseLetter),
+    (0xA792, UppercaseLetter),
+    (0xA793, LowercaseLetter),
+    (0xA796, UppercaseLetter),
+    (0xA797, LowercaseLetter),
+    (0xA798, UppercaseLetter),
+    (0xA799, LowercaseLetter),
+    (0xA79A, UppercaseLetter),
+    (0xA79B, LowercaseLetter),
+    (0xA79C, UppercaseLetter),
+    (0xA79D, LowercaseLetter),
+    (0xA79E, UppercaseLetter),
+    (0xA79F, LowercaseLetter),
+    (0xA7A0, UppercaseLetter),
+    (0xA7A1, LowercaseLetter),
+    (0xA7A2, UppercaseLetter),
+    (0xA7A3, LowercaseLetter),
+    (0xA7A4, UppercaseLetter),
+    (0xA7A5, LowercaseLetter),
+    (0xA7A6, UppercaseLetter),
+    (0xA7A7, LowercaseLetter),
+    (0xA7A8, UppercaseLetter),
+    (0xA7A9, LowercaseLetter),
+    (0xA7AA, UppercaseLetter),
+    (0xA7AF, LowercaseLetter),
+    (0xA7B0, UppercaseLetter),
+    (0xA7B5, LowercaseLetter),
+    (0xA7B6, UppercaseLetter),
+    (0xA7B7, LowercaseLetter),
+    (0xA7B8, UppercaseLetter),
+    (0xA7B9, LowercaseLetter),
+    (0xA7BA, UppercaseLetter),
+    (0xA7BB, LowercaseLetter),
+    (0xA7BC, UppercaseLetter),
+    (0xA7BD, LowercaseLetter),
+    (0xA7BE, UppercaseLetter),
+    (0xA7BF, LowercaseLetter),
+    (0xA7C0, UppercaseLetter),
+    (0xA7C1, LowercaseLetter),
+    (0xA7C2, UppercaseLetter),
+    (0xA7C3, LowercaseLetter),
+    (0xA7C4, UppercaseLetter),
+    (0xA7C8, LowercaseLetter),
+    (0xA7C9, UppercaseLetter),
+    (0xA7CA, LowercaseLetter),
+    (0xA7CB, Unassigned),
+    (0xA7D0, UppercaseLetter),
+    (0xA7D1, LowercaseLetter),
+    (0xA7D2, Unassigned),
+    (0xA7D3, LowercaseLetter),
+    (0xA7D4, Unassigned),
+    (0xA7D5, LowercaseLetter),
+    (0xA7D6, UppercaseLetter),
+    (0xA7D7, LowercaseLetter),
+    (0xA7D8, UppercaseLetter),
+    (0xA7D9, LowercaseLetter),
+    (0xA7DA, Unassigned),
+    (0xA7F2, ModifierLetter),
+    (0xA7F5, UppercaseLetter),
+    (0xA7F6, LowercaseLetter),
+    (0xA7F7, OtherLetter),
+    (0xA7F8, ModifierLetter),
+    (0xA7FA, LowercaseLetter),
+    (0xA7FB, OtherLetter),
+    (0xA802, NonspacingMark),
+    (0xA803, OtherLetter),
+    (0xA806, NonspacingMark),
+    (0xA807, OtherLetter),
+    (0xA80B, NonspacingMark),
+    (0xA80C, OtherLetter),
+    (0xA823, SpacingMark),
+    (0xA825, NonspacingMark),
+    (0xA827, SpacingMark),
+    (0xA828, OtherSymbol),
+    (0xA82C, NonspacingMark),
+    (0xA82D, Unassigned),
+    (0xA830, OtherNumber),
+    (0xA836, OtherSymbol),
+    (0xA838, CurrencySymbol),
+    (0xA839, OtherSymbol),
+    (0xA83A, Unassigned),
+    (0xA840, OtherLetter),
+    (0xA874, OtherPunctuation),
+    (0xA878, Unassigned),
+    (0xA880, SpacingMark),
+    (0xA882, OtherLetter),
+    (0xA8B4, SpacingMark),
+    (0xA8C4, NonspacingMark),
+    (0xA8C6, Unassigned),
+    (0xA8CE, OtherPunctuation),
+    (0xA8D0, DecimalNumber),
+    (0xA8DA, Unassigned),
+    (0xA8E0, NonspacingMark),
+    (0xA8F2, OtherLetter),
+    (0xA8F8, OtherPunctuation),
+    (0xA8FB, OtherLetter),
+    (0xA8FC, OtherPunctuation),
+    (0xA8FD, OtherLetter),
+    (0xA8FF, NonspacingMark),
+    (0xA900, DecimalNumber),
+    (0xA90A, OtherLetter),
+    (0xA926, NonspacingMark),
+    (0xA92E, OtherPunctuation),
+    (0xA930, OtherLetter),
+    (0xA947, NonspacingMark),
+    (0xA952, SpacingMark),
+    (0xA954, Unassigned),
+    (0xA95F, OtherPunctuation),
+    (0xA960, OtherLetter),
+    (0xA97D, Unassigned),
+    (0xA980, NonspacingMark),
+    (0xA983, SpacingMark),
+    (0xA984, OtherLetter),
+    (0xA9B3, NonspacingMark),
+    (0xA9B4, SpacingMark),
+    (0xA9B6, NonspacingMark),
+    (0xA9BA, SpacingMark),
+    (0xA9BC, NonspacingMark),
+    (0xA9BE, SpacingMark),
+    (0xA9C1, OtherPunctuation),
+    (0xA9CE, Unassigned),
+    (0xA9CF, ModifierLetter),
+    (0xA9D0, DecimalNumber),
+    (0xA9DA, Unassigned),
+    (0xA9DE, OtherPunctuation),
+    (0xA9E0, OtherLetter),
+    (0xA9E5, NonspacingMark),
+    (0xA9E6, ModifierLetter),
+    (0xA9E7, OtherLetter),
+    (0xA9F0, DecimalNumber),
+    (0xA9FA, OtherLetter),
+    (0xA9FF, Unassigned),
+    (0xAA00, OtherLetter),
+    (0xAA29, NonspacingMark),
+    (0xAA2F, SpacingMark),
+    (0xAA31, NonspacingMark),
+    (0xAA33, SpacingMark),
+    (0xAA35, NonspacingMark),
+    (0xAA37, Unassigned),
+    (0xAA40, OtherLetter),
+    (0xAA43, NonspacingMark),
+    (0xAA44, OtherLetter),
+    (0xAA4C, NonspacingMark),
+    (0xAA4D, SpacingMark),
+    (0xAA4E, Unassigned),
+    (0xAA50, DecimalNumber),
+    (0xAA5A, Unassigned),
+    (0xAA5C, OtherPunctuation),
+    (0xAA60, OtherLetter),
+    (0xAA70, ModifierLetter),
+    (0xAA71, OtherLetter),
+    (0xAA77, OtherSymbol),
+    (0xAA7A, OtherLetter),
+    (0xAA7B, SpacingMark),
+    (0xAA7C, NonspacingMark),
+    (0xAA7D, SpacingMark),
+    (0xAA7E, OtherLetter),
+    (0xAAB0, NonspacingMark),
+    (0xAAB1, OtherLetter),
+    (0xAAB2, NonspacingMark),
+    (0xAAB5, OtherLetter),
+    (0xAAB7, NonspacingMark),
+    (0xAAB9, OtherLetter),
+    (0xAABE, NonspacingMark),
+    (0xAAC0, OtherLetter),
+    (0xAAC1, NonspacingMark),
+    (0xAAC2, OtherLetter),
+    (0xAAC3, Unassigned),
+    (0xAADB, OtherLetter),
+    (0xAADD, ModifierLetter),
+    (0xAADE, OtherPunctuation),
+    (0xAAE0, OtherLetter),
+    (0xAAEB, SpacingMark),
+    (0xAAEC, NonspacingMark),
+    (0xAAEE, SpacingMark),
+    (0xAAF0, OtherPunctuation),
+    (0xAAF2, OtherLetter),
+    (0xAAF3, ModifierLetter),
+    (0xAAF5, SpacingMark),
+    (0xAAF6, NonspacingMark),
+    (0xAAF7, Unassigned),
+    (0xAB01, OtherLetter),
+    (0xAB07, Unassigned),
+    (0xAB09, OtherLetter),
+    (0xAB0F, Unassigned),
+    (0xAB11, OtherLetter),
+    (0xAB17, Unassigned),
+    (0xAB20, OtherLetter),
+    (0xAB27, Unassigned),
+    (0xAB28, OtherLetter),
+    (0xAB2F, Unassigned),
+    (0xAB30, LowercaseLetter),
+    (0xAB5B, ModifierSymbol),
+    (0xAB5C, ModifierLetter),
+    (0xAB60, LowercaseLetter),
+    (0xAB69, ModifierLetter),
+    (0xAB6A, ModifierSymbol),
+    (0xAB6C, Unassigned),
+    (0xAB70, LowercaseLetter),
+    (0xABC0, OtherLetter),
+    (0xABE3, SpacingMark),
+    (0xABE5, NonspacingMark),
+    (0xABE6, SpacingMark),
+    (0xABE8, NonspacingMark),
+    (0xABE9, SpacingMark),
+    (0xABEB, OtherPunctuation),
+    (0xABEC, SpacingMark),
+    (0xABED, NonspacingMark),
+    (0xABEE, Unassigned),
+    (0xABF0, DecimalNumber),
+    (0xABFA, Unassigned),
+    (0xAC00, OtherLetter),
+    (0xD7A4, Unassigned),
+    (0xD7B0, OtherLetter),
+    (0xD7C7, Unassigned),
+    (0xD7CB, OtherLetter),
+    (0xD7FC, Unassigned),
+    (0xD800, Surrogate),
+    (0xE000, PrivateUse),
+    (0xF900, OtherLetter),
+    (0xFA6E, Unassigned),
+    (0xFA70, OtherLetter),
+    (0xFADA, Unassigned),
+    (0xFB00, LowercaseLetter),
+    (0xFB07, Unassigned),
+    (0xFB13, LowercaseLetter),
+    (0xFB18, Unassigned),
+    (0xFB1D, OtherLetter),
+    (0xFB1E, NonspacingMark),
+    (0xFB1F, OtherLetter),
+    (0xFB29, MathSymbol),
+    (0xFB2A, OtherLetter),
+    (0xFB37, Unassigned),
+    (0xFB38, OtherLetter),
+    (0xFB3D, Unassigned),
+    (0xFB3E, OtherLetter),
+    (0xFB3F, Unassigned),
+    (0xFB40, OtherLetter),
+    (0xFB42, Unassigned),
+    (0xFB43, OtherLetter),
+    (0xFB45, Unassigned),
+    (0xFB46, OtherLetter),
+    (0xFBB2, ModifierSymbol),
+    (0xFBC3, Unassigned),
+    (0xFBD3, OtherLetter),
+    (0xFD3E, ClosePunctuation),
+    (0xFD3F, OpenPunctuation),
+    (0xFD40, OtherSymbol),
+    (0xFD50, OtherLetter),
+    (0xFD90, Unassigned),
+    (0xFD92, OtherLetter),
+    (0xFDC8, Unassigned),
+    (0xFDCF, OtherSymbol),
+    (0xFDD0, Unassigned),
+    (0xFDF0, OtherLetter),
+    (0xFDFC, CurrencySymbol),
+    (0xFDFD, OtherSymbol),
+    (0xFE00, NonspacingMark),
+    (0xFE10, OtherPunctuation),
+    (0xFE17, OpenPunctuation),
+    (0xFE18, ClosePunctuation),
+    (0xFE19, OtherPunctuation),
+    (0xFE1A, Unassigned),
+    (0xFE20, NonspacingMark),
+    (0xFE30, OtherPunctuation),
+    (0xFE31, DashPunctuation),
+    (0xFE33, ConnectorPunctuation),
+    (0xFE35, OpenPunctuation),
+    (0xFE36, ClosePunctuation),
+    (0xFE37, OpenPunctuation),
+    (0xFE38, ClosePunctuation),
+    (0xFE39, OpenPunctuation),
+    (0xFE3A, ClosePunctuation),
+    (0xFE3B, OpenPunctuation),
+    (0xFE3C, ClosePunctuation),
+    (0xFE3D, OpenPunctuation),
+    (0xFE3E, ClosePunctuation),
+    (0xFE3F, OpenPunctuation),
+    (0xFE40, ClosePunctuation),
+    (0xFE41, OpenPunctuation),
+    (0xFE42, ClosePunctuation),
+    (0xFE43, OpenPunctuation),
+    (0xFE44, ClosePunctuation),
+    (0xFE45, OtherPunctuation),
+    (0xFE47, OpenPunctuation),
+    (0xFE48, ClosePunctuation),
+    (0xFE49, OtherPunctuation),
+    (0xFE4D, ConnectorPunctuation),
+    (0xFE50, OtherPunctuation),
+    (0xFE53, Unassigned),
+    (0xFE54, OtherPunctuation),
+    (0xFE58, DashPunctuation),
+    (0xFE59, OpenPunctuation),
+    (0xFE5A, ClosePunctuation),
+    (0xFE5B, OpenPunctuation),
+    (0xFE5C, ClosePunctuation),
+    (0xFE5D, OpenPunctuation),
+    (0xFE5E, ClosePunctuation),
+    (0xFE5F, OtherPunctuation),
+    (0xFE62, MathSymbol),
+    (0xFE63, DashPunctuation),
+    (0xFE64, MathSymbol),
+    (0xFE67, Unassigned),
+    (0xFE68, OtherPunctuation),
+    (0xFE69, CurrencySymbol),
+    (0xFE6A, OtherPunctuation),
+    (0xFE6C, Unassigned),
+    (0xFE70, OtherLetter),
+    (0xFE75, Unassigned),
+    (0xFE76, OtherLetter),
+    (0xFEFD, Unassigned),
+    (0xFEFF, Format),
+    (0xFF00, Unassigned),
+    (0xFF01, OtherPunctuation),
+    (0xFF04, CurrencySymbol),
+    (0xFF05, OtherPunctuation),
+    (0xFF08, OpenPunctuation),
+    (0xFF09, ClosePunctuation),
+    (0xFF0A, OtherPunctuation),
+    (0xFF0B, MathSymbol),
+    (0xFF0C, OtherPunctuation),
+    (0xFF0D, DashPunctuation),
+    (0xFF0E, OtherPunctuation),
+    (0xFF10, DecimalNumber),
+    (0xFF1A, OtherPunctuation),
+    (0xFF1C, MathSymbol),
+    (0xFF1F, OtherPunctuation),
+    (0xFF21, UppercaseLetter),
+    (0xFF3B, OpenPunctuation),
+    (0xFF3C, OtherPunctuation),
+    (0xFF3D, ClosePunctuation),
+    (0xFF3E, ModifierSymbol),
+    (0xFF3F, ConnectorPunctuation),
+    (0xFF40, ModifierSymbol),
+    (0xFF41, LowercaseLetter),
+    (0xFF5B, OpenPunctuation),
+    (0xFF5C, MathSymbol),
+    (0xFF5D, ClosePunctuation),
+    (0xFF5E, MathSymbol),
+    (0xFF5F, OpenPunctuation),
+    (0xFF60, ClosePunctuation),
+    (0xFF61, OtherPunctuation),
+    (0xFF62, OpenPunctuation),
+    (0xFF63, ClosePunctuation),
+    (0xFF64, OtherPunctuation),
+    (0xFF66, OtherLetter),
+    (0xFF70, ModifierLetter),
+    (0xFF71, OtherLetter),
+    (0xFF9E, ModifierLetter),
+    (0xFFA0, OtherLetter),
+    (0xFFBF, Unassigned),
+    (0xFFC2, OtherLetter),
+    (0xFFC8, Unassigned),
+    (0xFFCA, OtherLetter),
+    (0xFFD0, Unassigned),
+    (0xFFD2, OtherLetter),
+    (0xFFD8, Unassigned),
+    (0xFFDA, OtherLetter),
+    (0xFFDD, Unassigned),
+    (0xFFE0, CurrencySymbol),
+    (0xFFE2, MathSymbol),
+    (0xFFE3, ModifierSymbol),
+    (0xFFE4, OtherSymbol),
+    (0xFFE5, CurrencySymbol),
+    (0xFFE7, Unassigned),
+    (0xFFE8, OtherSymbol),
+    (0xFFE9, MathSymbol),
+    (0xFFED, OtherSymbol),
+    (0xFFEF, Unassigned),
+    (0xFFF9, Format),
+    (0xFFFC, OtherSymbol),
+    (0xFFFE, Unassigned),
+    (0x10000, OtherLetter),
+    (0x1000C, Unassigned),
+    (0x1000D, OtherLetter),
+    (0x10027, Unassigned),
+    (0x10028, OtherLetter),
+    (0x1003B, Unassigned),
+    (0x1003C, OtherLetter),
+    (0x1003E, Unassigned),
+    (0x1003F, OtherLetter),
+    (0x1004E, Unassigned),
+    (0x10050, OtherLetter),
+    (0x1005E, Unassigned),
+    (0x10080, OtherLetter),
+    (0x100FB, Unassigned),
+    (0x10100, OtherPunctuation),
+    (0x10103, Unassigned),
+    (0x10107, OtherNumber),
+    (0x10134, Unassigned),
+    (0x10137, OtherSymbol),
+    (0x10140, LetterNumber),
+    (0x10175, OtherNumber),
+    (0x10179, OtherSymbol),
+    (0x1018A, OtherNumber),
+    (0x1018C, OtherSymbol),
+    (0x1018F, Unassigned),
+    (0x10190, OtherSymbol),
+    (0x1019D, Unassigned),
+    (0x101A0, OtherSymbol),
+    (0x101A1, Unassigned),
+    (0x101D0, OtherSymbol),
+    (0x101FD, NonspacingMark),
+    (0x101FE, Unassigned),
+    (0x10280, OtherLetter),
+    (0x1029D, Unassigned),
+    (0x102A0, OtherLetter),
+    (0x102D1, Unassigned),
+    (0x102E0, NonspacingMark),
+    (0x102E1, OtherNumber),
+    (0x102FC, Unassigned),
+    (0x10300, OtherLetter),
+    (0x10320, OtherNumber),
+    (0x10324, Unassigned),
+    (0x1032D, OtherLetter),
+    (0x10341, LetterNumber),
+    (0x10342, OtherLetter),
+    (0x1034A, LetterNumber),
+    (0x1034B, Unassigned),
+    (0x10350, OtherLetter),
+    (0x10376, NonspacingMark),
+    (0x1037B, Unassigned),
+    (0x10380, OtherLetter),
+    (0x1039E, Unassigned),
+    (0x1039F, OtherPunctuation),
+    (0x103A0, OtherLetter),
+    (0x103C4, Unassigned),
+    (0x103C8, OtherLetter),
+    (0x103D0, OtherPunctuation),
+    (0x103D1, LetterNumber),
+    (0x103D6, Unassigned),
+    (0x10400, UppercaseLetter),
+    (0x10428, LowercaseLetter),
+    (0x10450, OtherLetter),
+    (0x1049E, Unassigned),
+    (0x104A0, DecimalNumber),
+    (0x104AA, Unassigned),
+    (0x104B0, UppercaseLetter),
+    (0x104D4, Unassigned),
+    (0x104D8, LowercaseLetter),
+    (0x104FC, Unassigned),
+    (0x10500, OtherLetter),
+    (0x10528, Unassigned),
+    (0x10530, OtherLetter),
+    (0x10564, Unassigned),
+    (0x1056F, OtherPunctuation),
+    (0x10570, UppercaseLetter),
+    (0x1057B, Unassigned),
+    (0x1057C, UppercaseLetter),
+    (0x1058B, Unassigned),
+    (0x1058C, UppercaseLetter),
+    (0x10593, Unassigned),
+    (0x10594, UppercaseLetter),
+    (0x10596, Unassigned),
+    (0x10597, LowercaseLetter),
+    (0x105A2, Unassigned),
+    (0x105A3, LowercaseLetter),
+    (0x105B2, Unassigned),
+    (0x105B3, LowercaseLetter),
+    (0x105BA, Unassigned),
+    (0x105BB, LowercaseLetter),
+    (0x105BD, Unassigned),
+    (0x10600, OtherLetter),
+    (0x10737, Unassigned),
+    (0x10740, OtherLetter),
+    (0x10756, Unassigned),
+    (0x10760, OtherLetter),
+    (0x10768, Unassigned),
+    (0x10780, ModifierLetter),
+    (0x10786, Unassigned),
+    (0x10787, ModifierLetter),
+    (0x107B1, Unassigned),
+    (0x107B2, ModifierLetter),
+    (0x107BB, Unassigned),
+    (0x10800, OtherLetter),
+    (0x10806, Unassigned),
+    (0x10808, OtherLetter),
+    (0x10809, Unassigned),
+    (0x1080A, OtherLetter),
+    (0x10836, Unassigned),
+    (0x10837, OtherLetter),
+    (0x10839, Unassigned),
+    (0x1083C, OtherLetter),
+    (0x1083D, Unassigned),
+    (0x1083F, OtherLetter),
+    (0x10856, Unassigned),
+    (0x10857, OtherPunctuation),
+    (0x10858, OtherNumber),
+    (0x10860, OtherLetter),
+    (0x10877, OtherSymbol),
+    (0x10879, OtherNumber),
+    (0x10880, OtherLetter),
+    (0x1089F, Unassigned),
+    (0x108A7, OtherNumber),
+    (0x108B0, Unassigned),
+    (0x108E0, OtherLetter),
+    (0x108F3, Unassigned),
+    (0x108F4, OtherLetter),
+    (0x108F6, Unassigned),
+    (0x108FB, OtherNumber),
+    (0x10900, OtherLetter),
+    (0x10916, OtherNumber),
+    (0x1091C, Unassigned),
+    (0x1091F, OtherPunctuation),
+    (0x10920, OtherLetter),
+    (0x1093A, Unassigned),
+    (0x1093F, OtherPunctuation),
+    (0x10940, Unassigned),
+    (0x10980, OtherLetter),
+    (0x109B8, Unassigned),
+    (0x109BC, OtherNumber),
+    (0x109BE, OtherLetter),
+    (0x109C0, OtherNumber),
+    (0x109D0, Unassigned),
+    (0x109D2, OtherNumber),
+    (0x10A00, OtherLetter),
+    (0x10A01, NonspacingMark),
+    (0x10A04, Unassigned),
+    (0x10A05, NonspacingMark),
+    (0x10A07, Unassigned),
+    (0x10A0C, NonspacingMark),
+    (0x10A10, OtherLetter),
+    (0x10A14, Unassigned),
+    (0x10A15, OtherLetter),
+    (0x10A18, Unassigned),
+    (0x10A19, OtherLetter),
+    (0x10A36, Unassigned),
+    (0x10A38, NonspacingMark),
+    (0x10A3B, Unassigned),
+    (0x10A3F, NonspacingMark),
+    (0x10A40, OtherNumber),
+    (0x10A49, Unassigned),
+    (0x10A50, OtherPunctuation),
+    (0x10A59, Unassigned),
+    (0x10A60, OtherLetter),
+    (0x10A7D, OtherNumber),
+    (0x10A7F, OtherPunctuation),
+    (0x10A80, OtherLetter),
+    (0x10A9D, OtherNumber),
+    (0x10AA0, Unassigned),
+    (0x10AC0, OtherLetter),
+    (0x10AC8, OtherSymbol),
+    (0x10AC9, OtherLetter),
+    (0x10AE5, NonspacingMark),
+    (0x10AE7, Unassigned),
+    (0x10AEB, OtherNumber),
+    (0x10AF0, OtherPunctuation),
+    (0x10AF7, Unassigned),
+    (0x10B00, OtherLetter),
+    (0x10B36, Unassigned),
+    (0x10B39, OtherPunctuation),
+    (0x10B40, OtherLetter),
+    (0x10B56, Unassigned),
+    (0x10B58, OtherNumber),
+    (0x10B60, OtherLetter),
+    (0x10B73, Unassigned),
+    (0x10B78, OtherNumber),
+    (0x10B80, OtherLetter),
+    (0x10B92, Unassigned),
+    (0x10B99, OtherPunctuation),
+    (0x10B9D, Unassigned),
+    (0x10BA9, OtherNumber),
+    (0x10BB0, Unassigned),
+    (0x10C00, OtherLetter),
+    (0x10C49, Unassigned),
+    (0x10C80, UppercaseLetter),
+    (0x10CB3, Unassigned),
+    (0x10CC0, LowercaseLetter),
+    (0x10CF3, Unassigned),
+    (0x10CFA, OtherNumber),
+    (0x10D00, OtherLetter),
+    (0x10D24, NonspacingMark),
+    (0x10D28, Unassigned),
+    (0x10D30, DecimalNumber),
+    (0x10D3A, Unassigned),
+    (0x10E60, OtherNumber),
+    (0x10E7F, Unassigned),
+    (0x10E80, OtherLetter),
+    (0x10EAA, Unassigned),
+    (0x10EAB, NonspacingMark),
+    (0x10EAD, DashPunctuation),
+    (0x10EAE, Unassigned),
+    (0x10EB0, OtherLetter),
+    (0x10EB2, Unassigned),
+    (0x10F00, OtherLetter),
+    (0x10F1D, OtherNumber),
+    (0x10F27, OtherLetter),
+    (0x10F28, Unassigned),
+    (0x10F30, OtherLetter),
+    (0x10F46, NonspacingMark),
+    (0x10F51, OtherNumber),
+    (0x10F55, OtherPunctuation),
+    (0x10F5A, Unassigned),
+    (0x10F70, OtherLetter),
+    (0x10F82, NonspacingMark),
+    (0x10F86, OtherPunctuation),
+    (0x10F8A, Unassigned),
+    (0x10FB0, OtherLetter),
+    (0x10FC5, OtherNumber),
+    (0x10FCC, Unassigned),
+    (0x10FE0, OtherLetter),
+    (0x10FF7, Unassigned),
+    (0x11000, SpacingMark),
+    (0x11001, NonspacingMark),
+    (0x11002, SpacingMark),
+    (0x11003, OtherLetter),
+    (0x11038, NonspacingMark),
+    (0x11047, OtherPunctuation),
+    (0x1104E, Unassigned),
+    (0x11052, OtherNumber),
+    (0x11066, DecimalNumber),
+    (0x11070, NonspacingMark),
+    (0x11071, OtherLetter),
+    (0x11073, NonspacingMark),
+    (0x11075, OtherLetter),
+    (0x11076, Unassigned),
+    (0x1107F, NonspacingMark),
+    (0x11082, SpacingMark),
+    (0x11083, OtherLetter),
+    (0x110B0, SpacingMark),
+    (0x110B3, NonspacingMark),
+    (0x110B7, SpacingMark),
+    (0x110B9, NonspacingMark),
+    (0x110BB, OtherPunctuation),
+    (0x110BD, Format),
+    (0x110BE, OtherPunctuation),
+    (0x110C2, NonspacingMark),
+    (0x110C3, Unassigned),
+    (0x110CD, Format),
+    (0x110CE, Unassigned),
+    (0x110D0, OtherLetter),
+    (0x110E9, Unassigned),
+    (0x110F0, DecimalNumber),
+    (0x110FA, Unassigned),
+    (0x11100, NonspacingMark),
+    (0x11103, OtherLetter),
+    (0x11127, NonspacingMark),
+    (0x1112C, SpacingMark),
+    (0x1112D, NonspacingMark),
+    (0x11135, Unassigned),
+    (0x11136, DecimalNumber),
+    (0x11140, OtherPunctuation),
+    (0x11144, OtherLetter),
+    (0x11145, SpacingMark),
+    (0x11147, OtherLetter),
+    (0x11148, Unassigned),
+    (0x11150, OtherLetter),
+    (0x11173, NonspacingMark),
+    (0x11174, OtherPunctuation),
+    (0x11176, OtherLetter),
+    (0x11177, Unassigned),
+    (0x11180, NonspacingMark),
+    (0x11182, SpacingMark),
+    (0x11183, OtherLetter),
+    (0x111B3, SpacingMark),
+    (0x111B6, NonspacingMark),
+    (0x111BF, SpacingMark),
+    (0x111C1, OtherLetter),
+    (0x111C5, OtherPunctuation),
+    (0x111C9, NonspacingMark),
+    (0x111CD, OtherPunctuation),
+    (0x111CE, SpacingMark),
+    (0x111CF, NonspacingMark),
+    (0x111D0, DecimalNumber),
+    (0x111DA, OtherLetter),
+    (0x111DB, OtherPunctuation),
+    (0x111DC, OtherLetter),
+    (0x111DD, OtherPunctuation),
+    (0x111E0, Unassigned),
+    (0x111E1, OtherNumber),
+    (0x111F5, Unassigned),
+    (0x11200, OtherLetter),
+    (0x11212, Unassigned),
+    (0x11213, OtherLetter),
+    (0x1122C, SpacingMark),
+    (0x1122F, NonspacingMark),
+    (0x11232, SpacingMark),
+    (0x11234, NonspacingMark),
+    (0x11235, SpacingMark),
+    (0x11236, NonspacingMark),
+    (0x11238, OtherPunctuation),
+    (0x1123E, NonspacingMark),
+    (0x1123F, Unassigned),
+    (0x11280, OtherLetter),
+    (0x11287, Unassigned),
+    (0x11288, OtherLetter),
+    (0x11289, Unassigned),
+    (0x1128A, OtherLetter),
+    (0x1128E, Unassigned),
+    (0x1128F, OtherLetter),
+    (0x1129E, Unassigned),
+    (0x1129F, OtherLetter),
+    (0x112A9, OtherPunctuation),
+    (0x112AA, Unassigned),
+    (0x112B0, OtherLetter),
+    (0x112DF, NonspacingMark),
+    (0x112E0, SpacingMark),
+    (0x112E3, NonspacingMark),
+    (0x112EB, Unassigned),
+    (0x112F0, DecimalNumber),
+    (0x112FA, Unassigned),
+    (0x11300, NonspacingMark),
+    (0x11302, SpacingMark),
+    (0x11304, Unassigned),
+    (0x11305, OtherLetter),
+    (0x1130D, Unassigned),
+    (0x1130F, OtherLetter),
+    (0x11311, Unassigned),
+    (0x11313, OtherLetter),
+    (0x11329, Unassigned),
+    (0x1132A, OtherLetter),
+    (0x11331, Unassigned),
+    (0x11332, OtherLetter),
+    (0x11334, Unassigned),
+    (0x11335, OtherLetter),
+    (0x1133A, Unassigned),
+    (0x1133B, NonspacingMark),
+    (0x1133D, OtherLetter),
+    (0x1133E, SpacingMark),
+    (0x11340, NonspacingMark),
+    (0x11341, SpacingMark),
+    (0x11345, Unassigned),
+    (0x11347, SpacingMark),
+    (0x11349, Unassigned),
+    (0x1134B, SpacingMark),
+    (0x1134E, Unassigned),
+    (0x11350, OtherLetter),
+    (0x11351, Unassigned),
+    (0x11357, SpacingMark),
+    (0x11358, Unassigned),
+    (0x1135D, OtherLetter),
+    (0x11362, SpacingMark),
+    (0x11364, Unassigned),
+    (0x11366, NonspacingMark),
+    (0x1136D, Unassigned),
+    (0x11370, NonspacingMark),
+    (0x11375, Unassigned),
+    (0x11400, OtherLetter),
+    (0x11435, SpacingMark),
+    (0x11438, NonspacingMark),
+    (0x11440, SpacingMark),
+    (0x11442, NonspacingMark),
+    (0x11445, SpacingMark),
+    (0x11446, NonspacingMark),
+    (0x11447, OtherLetter),
+    (0x1144B, OtherPunctuation),
+    (0x11450, DecimalNumber),
+    (0x1145A, OtherPunctuation),
+    (0x1145C, Unassigned),
+    (0x1145D, OtherPunctuation),
+    (0x1145E, NonspacingMark),
+    (0x1145F, OtherLetter),
+    (0x11462, Unassigned),
+    (0x11480, OtherLetter),
+    (0x114B0, SpacingMark),
+    (0x114B3, NonspacingMark),
+    (0x114B9, SpacingMark),
+    (0x114BA, NonspacingMark),
+    (0x114BB, SpacingMark),
+    (0x114BF, NonspacingMark),
+    (0x114C1, SpacingMark),
+    (0x114C2, NonspacingMark),
+    (0x114C4, OtherLetter),
+    (0x114C6, OtherPunctuation),
+    (0x114C7, OtherLetter),
+    (0x114C8, Unassigned),
+    (0x114D0, DecimalNumber),
+    (0x114DA, Unassigned),
+    (0x11580, OtherLetter),
+    (0x115AF, SpacingMark),
+    (0x115B2, NonspacingMark),
+    (0x115B6, Unassigned),
+    (0x115B8, SpacingMark),
+    (0x115BC, NonspacingMark),
+    (0x115BE, SpacingMark),
+    (0x115BF, NonspacingMark),
+    (0x115C1, OtherPunctuation),
+    (0x115D8, OtherLetter),
+    (0x115DC, NonspacingMark),
+    (0x115DE, Unassigned),
+    (0x11600, OtherLetter),
+    (0x11630, SpacingMark),
+    (0x11633, NonspacingMark),
+    (0x1163B, SpacingMark),
+    (0x1163D, NonspacingMark),
+    (0x1163E, SpacingMark),
+    (0x1163F, NonspacingMark),
+    (0x11641, OtherPunctuation),
+    (0x11644, OtherLetter),
+    (0x11645, Unassigned),
+    (0x11650, DecimalNumber),
+    (0x1165A, Unassigned),
+    (0x11660, OtherPunctuation),
+    (0x1166D, Unassigned),
+    (0x11680, OtherLetter),
+    (0x116AB, NonspacingMark),
+    (0x116AC, SpacingMark),
+    (0x116AD, NonspacingMark),
+    (0x116AE, SpacingMark),
+    (0x116B0, NonspacingMark),
+    (0x116B6, SpacingMark),
+    (0x116B7, NonspacingMark),
+    (0x116B8, OtherLetter),
+    (0x116B9, OtherPunctuation),
+    (0x116BA, Unassigned),
+    (0x116C0, DecimalNumber),
+    (0x116CA, Unassigned),
+    (0x11700, OtherLetter),
+    (0x1171B, Unassigned),
+    (0x1171D, NonspacingMark),
+    (0x11720, SpacingMark),
+    (0x11722, NonspacingMark),
+    (0x11726, SpacingMark),
+    (0x11727, NonspacingMark),
+    (0x1172C, Unassigned),
+    (0x11730, DecimalNumber),
+    (0x1173A, OtherNumber),
+    (0x1173C, OtherPunctuation),
+    (0x1173F, OtherSymbol),
+    (0x11740, OtherLetter),
+    (0x11747, Unassigned),
+    (0x11800, OtherLetter),
+    (0x1182C, SpacingMark),
+    (0x1182F, NonspacingMark),
+    (0x11838, SpacingMark),
+    (0x11839, NonspacingMark),
+    (0x1183B, OtherPunctuation),
+    (0x1183C, Unassigned),
+    (0x118A0, UppercaseLetter),
+    (0x118C0, LowercaseLetter),
+    (0x118E0, DecimalNumber),
+    (0x118EA, OtherNumber),
+    (0x118F3, Unassigned),
+    (0x118FF, OtherLetter),
+    (0x11907, Unassigned),
+    (0x11909, OtherLetter),
+    (0x1190A, Unassigned),
+    (0x1190C, OtherLetter),
+    (0x11914, Unassigned),
+    (0x11915, OtherLetter),
+    (0x11917, Unassigned),
+    (0x11918, OtherLetter),
+    (0x11930, SpacingMark),
+    (0x11936, Unassigned),
+    (0x11937, SpacingMark),
+    (0x11939, Unassigned),
+    (0x1193B, NonspacingMark),
+    (0x1193D, SpacingMark),
+    (0x1193E, NonspacingMark),
+    (0x1193F, OtherLetter),
+    (0x11940, SpacingMark),
+    (0x11941, OtherLetter),
+    (0x11942, SpacingMark),
+    (0x11943, NonspacingMark),
+    (0x11944, OtherPunctuation),
+    (0x11947, Unassigned),
+    (0x11950, DecimalNumber),
+    (0x1195A, Unassigned),
+    (0x119A0, OtherLetter),
+    (0x119A8, Unassigned),
+    (0x119AA, OtherLetter),
+    (0x119D1, SpacingMark),
+    (0x119D4, NonspacingMark),
+    (0x119D8, Unassigned),
+    (0x119DA, NonspacingMark),
+    (0x119DC, SpacingMark),
+    (0x119E0, NonspacingMark),
+    (0x119E1, OtherLetter),
+    (0x119E2, OtherPunctuation),
+    (0x119E3, OtherLetter),
+    (0x119E4, SpacingMark),
+    (0x119E5, Unassigned),
+    (0x11A00, OtherLetter),
+    (0x11A01, NonspacingMark),
+    (0x11A0B, OtherLetter),
+    (0x11A33, NonspacingMark),
+    (0x11A39, SpacingMark),
+    (0x11A3A, OtherLetter),
+    (0x11A3B, NonspacingMark),
+    (0x11A3F, OtherPunctuation),
+    (0x11A47, NonspacingMark),
+    (0x11A48, Unassigned),
+    (0x11A50, OtherLetter),
+    (0x11A51, NonspacingMark),
+    (0x11A57, SpacingMark),
+    (0x11A59, NonspacingMark),
+    (0x11A5C, OtherLetter),
+    (0x11A8A, NonspacingMark),
+    (0x11A97, SpacingMark),
+    (0x11A98, NonspacingMark),
+    (0x11A9A, OtherPunctuation),
+    (0x11A9D, OtherLetter),
+    (0x11A9E, OtherPunctuation),
+    (0x11AA3, Unassigned),
+    (0x11AB0, OtherLetter),
+    (0x11AF9, Unassigned),
+    (0x11C00, OtherLetter),
+    (0x11C09, Unassigned),
+    (0x11C0A, OtherLetter),
+    (0x11C2F, SpacingMark),
+    (0x11C30, NonspacingMark),
+    (0x11C37, Unassigned),
+    (0x11C38, NonspacingMark),
+    (0x11C3E, SpacingMark),
+    (0x11C3F, NonspacingMark),
+    (0x11C40, OtherLetter),
+    (0x11C41, OtherPunctuation),
+    (0x11C46, Unassigned),
+    (0x11C50, DecimalNumber),
+    (0x11C5A, OtherNumber),
+    (0x11C6D, Unassigned),
+    (0x11C70, OtherPunctuation),
+    (0x11C72, OtherLetter),
+    (0x11C90, Unassigned),
+    (0x11C92, NonspacingMark),
+    (0x11CA8, Unassigned),
+    (0x11CA9, SpacingMark),
+    (0x11CAA, NonspacingMark),
+    (0x11CB1, SpacingMark),
+    (0x11CB2, NonspacingMark),
+    (0x11CB4, SpacingMark),
+    (0x11CB5, NonspacingMark),
+    (0x11CB7, Unassigned),
+    (0x11D00, OtherLetter),
+    (0x11D07, Unassigned),
+    (0x11D08, OtherLetter),
+    (0x11D0A, Unassigned),
+    (0x11D0B, OtherLetter),
+    (0x11D31, NonspacingMark),
+    (0x11D37, Unassigned),
+    (0x11D3A, NonspacingMark),
+    (0x11D3B, Unassigned),
+    (0x11D3C, NonspacingMark),
+    (0x11D3E, Unassigned),
+    (0x11D3F, NonspacingMark),
+    (0x11D46, OtherLetter),
+    (0x11D47, NonspacingMark),
+    (0x11D48, Unassigned),
+    (0x11D50, DecimalNumber),
+    (0x11D5A, Unassigned),
+    (0x11D60, OtherLetter),
+    (0x11D66, Unassigned),
+    (0x11D67, OtherLetter),
+    (0x11D69, Unassigned),
+    (0x11D6A, OtherLetter),
+    (0x11D8A, SpacingMark),
+    (0x11D8F, Unassigned),
+    (0x11D90, NonspacingMark),
+    (0x11D92, Unassigned),
+    (0x11D93, SpacingMark),
+    (0x11D95, NonspacingMark),
+    (0x11D96, SpacingMark),
+    (0x11D97, NonspacingMark),
+    (0x11D98, OtherLetter),
+    (0x11D99, Unassigned),
+    (0x11DA0, DecimalNumber),
+    (0x11DAA, Unassigned),
+    (0x11EE0, OtherLetter),
+    (0x11EF3, NonspacingMark),
+    (0x11EF5, SpacingMark),
+    (0x11EF7, OtherPunctuation),
+    (0x11EF9, Unassigned),
+    (0x11FB0, OtherLetter),
+    (0x11FB1, Unassigned),
+    (0x11FC0, OtherNumber),
+    (0x11FD5, OtherSymbol),
+    (0x11FDD, CurrencySymbol),
+    (0x11FE1, OtherSymbol),
+    (0x11FF2, Unassigned),
+    (0x11FFF, OtherPunctuation),
+    (0x12000, OtherLetter),
+    (0x1239A, Unassigned),
+    (0x12400, LetterNumber),
+    (0x1246F, Unassigned),
+    (0x12470, OtherPunctuation),
+    (0x12475, Unassigned),
+    (0x12480, OtherLetter),
+    (0x12544, Unassigned),
+    (0x12F90, OtherLetter),
+    (0x12FF1, OtherPunctuation),
+    (0x12FF3, Unassigned),
+    (0x13000, OtherLetter),
+    (0x1342F, Unassigned),
+    (0x13430, Format),
+    (0x13439, Unassigned),
+    (0x14400, OtherLetter),
+    (0x14647, Unassigned),
+    (0x16800, OtherLetter),
+    (0x16A39, Unassigned),
+    (0x16A40, OtherLetter),
+    (0x16A5F, Unassigned),
+    (0x16A60, DecimalNumber),
+    (0x16A6A, Unassigned),
+    (0x16A6E, OtherPunctuation),
+    (0x16A70, OtherLetter),
+    (0x16ABF, Unassigned),
+    (0x16AC0, DecimalNumber),
+    (0x16ACA, Unassigned),
+    (0x16AD0, OtherLetter),
+    (0x16AEE, Unassigned),
+    (0x16AF0, NonspacingMark),
+    (0x16AF5, OtherPunctuation),
+    (0x16AF6, Unassigned),
+    (0x16B00, OtherLetter),
+    (0x16B30, NonspacingMark),
+    (0x16B37, OtherPunctuation),
+    (0x16B3C, OtherSymbol),
+    (0x16B40, ModifierLetter),
+    (0x16B44, OtherPunctuation),
+    (0x16B45, OtherSymbol),
+    (0x16B46, Unassigned),
+    (0x16B50, DecimalNumber),
+    (0x16B5A, Unassigned),
+    (0x16B5B, OtherNumber),
+    (0x16B62, Unassigned),
+    (0x16B63, OtherLetter),
+    (0x16B78, Unassigned),
+    (0x16B7D, OtherLetter),
+    (0x16B90, Unassigned),
+    (0x16E40, UppercaseLetter),
+    (0x16E60, LowercaseLetter),
+    (0x16E80, OtherNumber),
+    (0x16E97, OtherPunctuation),
+    (0x16E9B, Unassigned),
+    (0x16F00, OtherLetter),
+    (0x16F4B, Unassigned),
+    (0x16F4F, NonspacingMark),
+    (0x16F50, OtherLetter),
+    (0x16F51, SpacingMark),
+    (0x16F88, Unassigned),
+    (0x16F8F, NonspacingMark),
+    (0x16F93, ModifierLetter),
+    (0x16FA0, Unassigned),
+    (0x16FE0, ModifierLetter),
+    (0x16FE2, OtherPunctuation),
+    (0x16FE3, ModifierLetter),
+    (0x16FE4, NonspacingMark),
+    (0x16FE5, Unassigned),
+    (0x16FF0, SpacingMark),
+    (0x16FF2, Unassigned),
+    (0x17000, OtherLetter),
+    (0x187F8, Unassigned),
+    (0x18800, OtherLetter),
+    (0x18CD6, Unassigned),
+    (0x18D00, OtherLetter),
+    (0x18D09, Unassigned),
+    (0x1AFF0, ModifierLetter),
+    (0x1AFF4, Unassigned),
+    (0x1AFF5, ModifierLetter),
+    (0x1AFFC, Unassigned),
+    (0x1AFFD, ModifierLetter),
+    (0x1AFFF, Unassigned),
+    (0x1B000, OtherLetter),
+    (0x1B123, Unassigned),
+    (0x1B150, OtherLetter),
+    (0x1B153, Unassigned),
+    (0x1B164, OtherLetter),
+    (0x1B168, Unassigned),
+    (0x1B170, OtherLetter),
+    (0x1B2FC, Unassigned),
+    (0x1BC00, OtherLetter),
+    (0x1BC6B, Unassigned),
+    (0x1BC70, OtherLetter),
+    (0x1BC7D, Unassigned),
+    (0x1BC80, OtherLetter),
+    (0x1BC89, Unassigned),
+    (0x1BC90, OtherLetter),
+    (0x1BC9A, Unassigned),
+    (0x1BC9C, OtherSymbol),
+    (0x1BC9D, NonspacingMark),
+    (0x1BC9F, OtherPunctuation),
+    (0x1BCA0, Format),
+    (0x1BCA4, Unassigned),
+    (0x1CF00, NonspacingMark),
+    (0x1CF2E, Unassigned),
+    (0x1CF30, NonspacingMark),
+    (0x1CF47, Unassigned),
+    (0x1CF50, OtherSymbol),
+    (0x1CFC4, Unassigned),
+    (0x1D000, OtherSymbol),
+    (0x1D0F6, Unassigned),
+    (0x1D100, OtherSymbol),
+    (0x1D127, Unassigned),
+    (0x1D129, OtherSymbol),
+    (0x1D165, SpacingMark),
+    (0x1D167, NonspacingMark),
+    (0x1D16A, OtherSymbol),
+    (0x1D16D, SpacingMark),
+    (0x1D173, Format),
+    (0x1D17B, NonspacingMark),
+    (0x1D183, OtherSymbol),
+    (0x1D185, NonspacingMark),
+    (0x1D18C, OtherSymbol),
+    (0x1D1AA, NonspacingMark),
+    (0x1D1AE, OtherSymbol),
+    (0x1D1EB, Unassigned),
+    (0x1D200, OtherSymbol),
+    (0x1D242, NonspacingMark),
+    (0x1D245, OtherSymbol),
+    (0x1D246, Unassigned),
+    (0x1D2E0, OtherNumber),
+    (0x1D2F4, Unassigned),
+    (0x1D300, OtherSymbol),
+    (0x1D357, Unassigned),
+    (0x1D360, OtherNumber),
+    (0x1D379, Unassigned),
+    (0x1D400, UppercaseLetter),
+    (0x1D41A, LowercaseLetter),
+    (0x1D434, UppercaseLetter),
+    (0x1D44E, LowercaseLetter),
+    (0x1D455, Unassigned),
+    (0x1D456, LowercaseLetter),
+    (0x1D468, UppercaseLetter),
+    (0x1D482, LowercaseLetter),
+    (0x1D49C, UppercaseLetter),
+    (0x1D49D, Unassigned),
+    (0x1D49E, UppercaseLetter),
+    (0x1D4A0, Unassigned),
+    (0x1D4A2, UppercaseLetter),
+    (0x1D4A3, Unassigned),
+    (0x1D4A5, UppercaseLetter),
+    (0x1D4A7, Unassigned),
+    (0x1D4A9, UppercaseLetter),
+    (0x1D4AD, Unassigned),
+    (0x1D4AE, UppercaseLetter),
+    (0x1D4B6, LowercaseLetter),
+    (0x1D4BA, Unassigned),
+    (0x1D4BB, LowercaseLetter),
+    (0x1D4BC, Unassigned),
+    (0x1D4BD, LowercaseLetter),
+    (0x1D4C4, Unassigned),
+    (0x1D4C5, LowercaseLetter),
+    (0x1D4D0, UppercaseLetter),
+    (0x1D4EA, LowercaseLetter),
+    (0x1D504, UppercaseLetter),
+    (0x1D506, Unassigned),
+    (0x1D507, UppercaseLetter),
+    (0x1D50B, Unassigned),
+    (0x1D50D, UppercaseLetter),
+    (0x1D515, Unassigned),
+    (0x1D516, UppercaseLetter),
+    (0x1D51D, Unassigned),
+    (0x1D51E, LowercaseLetter),
+    (0x1D538, UppercaseLetter),
+    (0x1D53A, Unassigned),
+    (0x1D53B, UppercaseLetter),
+    (0x1D53F, Unassigned),
+    (0x1D540, UppercaseLetter),
+    (0x1D545, Unassigned),
+    (0x1D546, UppercaseLetter),
+    (0x1D547, Unassigned),
+    (0x1D54A, UppercaseLetter),
+    (0x1D551, Unassigned),
+    (0x1D552, LowercaseLetter),
+    (0x1D56C, UppercaseLetter),
+    (0x1D586, LowercaseLetter),
+    (0x1D5A0, UppercaseLetter),
+    (0x1D5BA, LowercaseLetter),
+    (0x1D5D4, UppercaseLetter),
+    (0x1D5EE, LowercaseLetter),
+    (0x1D608, UppercaseLetter),
+    (0x1D622, LowercaseLetter),
+    (0x1D63C, UppercaseLetter),
+    (0x1D656, LowercaseLetter),
+    (0x1D670, UppercaseLetter),
+    (0x1D68A, LowercaseLetter),
+    (0x1D6A6, Unassigned),
+    (0x1D6A8, UppercaseLetter),
+    (0x1D6C1, MathSymbol),
+    (0x1D6C2, LowercaseLetter),
+    (0x1D6DB, MathSymbol),
+    (0x1D6DC, LowercaseLetter),
+    (0x1D6E2, UppercaseLetter),
+    (0x1D6FB, MathSymbol),
+    (0x1D6FC, LowercaseLetter),
+    (0x1D715, MathSymbol),
+    (0x1D716, LowercaseLetter),
+    (0x1D71C, UppercaseLetter),
+    (0x1D735, MathSymbol),
+    (0x1D736, LowercaseLetter),
+    (0x1D74F, MathSymbol),
+    (0x1D750, LowercaseLetter),
+    (0x1D756, UppercaseLetter),
+    (0x1D76F, MathSymbol),
+    (0x1D770, LowercaseLetter),
+    (0x1D789, MathSymbol),
+    (0x1D78A, LowercaseLetter),
+    (0x1D790, UppercaseLetter),
+    (0x1D7A9, MathSymbol),
+    (0x1D7AA, LowercaseLetter),
+    (0x1D7C3, MathSymbol),
+    (0x1D7C4, LowercaseLetter),
+    (0x1D7CA, UppercaseLetter),
+    (0x1D7CB, LowercaseLetter),
+    (0x1D7CC, Unassigned),
+    (0x1D7CE, DecimalNumber),
+    (0x1D800, OtherSymbol),
+    (0x1DA00, NonspacingMark),
+    (0x1DA37, OtherSymbol),
+    (0x1DA3B, NonspacingMark),
+    (0x1DA6D, OtherSymbol),
+    (0x1DA75, NonspacingMark),
+    (0x1DA76, OtherSymbol),
+    (0x1DA84, NonspacingMark),
+    (0x1DA85, OtherSymbol),
+    (0x1DA87, OtherPunctuation),
+    (0x1DA8C, Unassigned),
+    (0x1DA9B, NonspacingMark),
+    (0x1DAA0, Unassigned),
+    (0x1DAA1, NonspacingMark),
+    (0x1DAB0, Unassigned),
+    (0x1DF00, LowercaseLetter),
+    (0x1DF0A, OtherLetter),
+    (0x1DF0B, LowercaseLetter),
+    (0x1DF1F, Unassigned),
+    (0x1E000, NonspacingMark),
+    (0x1E007, Unassigned),
+    (0x1E008, NonspacingMark),
+    (0x1E019, Unassigned),
+    (0x1E01B, NonspacingMark),
+    (0x1E022, Unassigned),
+    (0x1E023, NonspacingMark),
+    (0x1E025, Unassigned),
+    (0x1E026, NonspacingMark),
+    (0x1E02B, Unassigned),
+    (0x1E100, OtherLetter),
+    (0x1E12D, Unassigned),
+    (0x1E130, NonspacingMark),
+    (0x1E137, ModifierLetter),
+    (0x1E13E, Unassigned),
+    (0x1E140, DecimalNumber),
+    (0x1E14A, Unassigned),
+    (0x1E14E, OtherLetter),
+    (0x1E14F, OtherSymbol),
+    (0x1E150, Unassigned),
+    (0x1E290, OtherLetter),
+    (0x1E2AE, NonspacingMark),
+    (0x1E2AF, Unassigned),
+    (0x1E2C0, OtherLetter),
+    (0x1E2EC, NonspacingMark),
+    (0x1E2F0, DecimalNumber),
+    (0x1E2FA, Unassigned),
+    (0x1E2FF, CurrencySymbol),
+    (0x1E300, Unassigned),
+    (0x1E7E0, OtherLetter),
+    (0x1E7E7, Unassigned),
+    (0x1E7E8, OtherLetter),
+    (0x1E7EC, Unassigned),
+    (0x1E7ED, OtherLetter),
+    (0x1E7EF, Unassigned),
+    (0x1E7F0, OtherLetter),
+    (0x1E7FF, Unassigned),
+    (0x1E800, OtherLetter),
+    (0x1E8C5, Unassigned),
+    (0x1E8C7, OtherNumber),
+    (0x1E8D0, NonspacingMark),
+    (0x1E8D7, Unassigned),
+    (0x1E900, UppercaseLetter),
+    (0x1E922, LowercaseLetter),
+    (0x1E944, NonspacingMark),
+    (0x1E94B, ModifierLetter),
+    (0x1E94C, Unassigned),
+    (0x1E950, DecimalNumber),
+    (0x1E95A, Unassigned),
+    (0x1E95E, OtherPunctuation),
+    (0x1E960, Unassigned),
+    (0x1EC71, OtherNumber),
+    (0x1ECAC, OtherSymbol),
+    (0x1ECAD, OtherNumber),
+    (0x1ECB0, CurrencySymbol),
+    (0x1ECB1, OtherNumber),
+    (0x1ECB5, Unassigned),
+    (0x1ED01, OtherNumber),
+    (0x1ED2E, OtherSymbol),
+    (0x1ED2F, OtherNumber),
+    (0x1ED3E, Unassigned),
+    (0x1EE00, OtherLetter),
+    (0x1EE04, Unassigned),
+    (0x1EE05, OtherLetter),
+    (0x1EE20, Unassigned),
+    (0x1EE21, OtherLetter),
+    (0x1EE23, Unassigned),
+    (0x1EE24, OtherLetter),
+    (0x1EE25, Unassigned),
+    (0x1EE27, OtherLetter),
+    (0x1EE28, Unassigned),
+    (0x1EE29, OtherLetter),
+    (0x1EE33, Unassigned),
+    (0x1EE34, OtherLetter),
+    (0x1EE38, Unassigned),
+    (0x1EE39, OtherLetter),
+    (0x1EE3A, Unassigned),
+    (0x1EE3B, OtherLetter),
+    (0x1EE3C, Unassigned),
+    (0x1EE42, OtherLetter),
+    (0x1EE43, Unassigned),
+    (0x1EE47, OtherLetter),
+    (0x1EE48, Unassigned),
+    (0x1EE49, OtherLetter),
+    (0x1EE4A, Unassigned),
+    (0x1EE4B, OtherLetter),
+    (0x1EE4C, Unassigned),
+    (0x1EE4D, OtherLetter),
+    (0x1EE50, Unassigned),
+    (0x1EE51, OtherLetter),
+    (0x1EE53, Unassigned),
+    (0x1EE54, OtherLetter),
+    (0x1EE55, Unassigned),
+    (0x1EE57, OtherLetter),
+    (0x1EE58, Unassigned),
+    (0x1EE59, OtherLetter),
+    (0x1EE5A, Unassigned),
+    (0x1EE5B, OtherLetter),
+    (0x1EE5C, Unassigned),
+    (0x1EE5D, OtherLetter),
+    (0x1EE5E, Unassigned),
+    (0x1EE5F, OtherLetter),
+    (0x1EE60, Unassigned),
+    (0x1EE61, OtherLetter),
+    (0x1EE63, Unassigned),
+    (0x1EE64, OtherLetter),
+    (0x1EE65, Unassigned),
+    (0x1EE67, OtherLetter),
+    (0x1EE6B, Unassigned),
+    (0x1EE6C, OtherLetter),
+    (0x1EE73, Unassigned),
+    (0x1EE74, OtherLetter),
+    (0x1EE78, Unassigned),
+    (0x1EE79, OtherLetter),
+    (0x1EE7D, Unassigned),
+    (0x1EE7E, OtherLetter),
+    (0x1EE7F, Unassigned),
+    (0x1EE80, OtherLetter),
+    (0x1EE8A, Unassigned),
+    (0x1EE8B, OtherLetter),
+    (0x1EE9C, Unassigned),
+    (0x1EEA1, OtherLetter),
+    (0x1EEA4, Unassigned),
+    (0x1EEA5, OtherLetter),
+    (0x1EEAA, Unassigned),
+    (0x1EEAB, OtherLetter),
+    (0x1EEBC, Unassigned),
+    (0x1EEF0, MathSymbol),
+    (0x1EEF2, Unassigned),
+    (0x1F000, OtherSymbol),
+    (0x1F02C, Unassigned),
+    (0x1F030, OtherSymbol),
+    (0x1F094, Unassigned),
+    (0x1F0A0, OtherSymbol),
+    (0x1F0AF, Unassigned),
+    (0x1F0B1, OtherSymbol),
+    (0x1F0C0, Unassigned),
+    (0x1F0C1, OtherSymbol),
+    (0x1F0D0, Unassigned),
+    (0x1F0D1, OtherSymbol),
+    (0x1F0F6, Unassigned),
+    (0x1F100, OtherNumber),
+    (0x1F10D, OtherSymbol),
+    (0x1F1AE, Unassigned),
+    (0x1F1E6, OtherSymbol),
+    (0x1F203, Unassigned),
+    (0x1F210, OtherSymbol),
+    (0x1F23C, Unassigned),
+    (0x1F240, OtherSymbol),
+    (0x1F249, Unassigned),
+    (0x1F250, OtherSymbol),
+    (0x1F252, Unassigned),
+    (0x1F260, OtherSymbol),
+    (0x1F266, Unassigned),
+    (0x1F300, OtherSymbol),
+    (0x1F3FB, ModifierSymbol),
+    (0x1F400, OtherSymbol),
+    (0x1F6D8, Unassigned),
+    (0x1F6DD, OtherSymbol),
+    (0x1F6ED, Unassigned),
+    (0x1F6F0, OtherSymbol),
+    (0x1F6FD, Unassigned),
+    (0x1F700, OtherSymbol),
+    (0x1F774, Unassigned),
+    (0x1F780, OtherSymbol),
+    (0x1F7D9, Unassigned),
+    (0x1F7E0, OtherSymbol),
+    (0x1F7EC, Unassigned),
+    (0x1F7F0, OtherSymbol),
+    (0x1F7F1, Unassigned),
+    (0x1F800, OtherSymbol),
+    (0x1F80C, Unassigned),
+    (0x1F810, OtherSymbol),
+    (0x1F848, Unassigned),
+    (0x1F850, OtherSymbol),
+    (0x1F85A, Unassigned),
+    (0x1F860, OtherSymbol),
+    (0x1F888, Unassigned),
+    (0x1F890, OtherSymbol),
+    (0x1F8AE, Unassigned),
+    (0x1F8B0, OtherSymbol),
+    (0x1F8B2, Unassigned),
+    (0x1F900, OtherSymbol),
+    (0x1FA54, Unassigned),
+    (0x1FA60, OtherSymbol),
+    (0x1FA6E, Unassigned),
+    (0x1FA70, OtherSymbol),
+    (0x1FA75, Unassigned),
+    (0x1FA78, OtherSymbol),
+    (0x1FA7D, Unassigned),
+    (0x1FA80, OtherSymbol),
+    (0x1FA87, Unassigned),
+    (0x1FA90, OtherSymbol),
+    (0x1FAAD, Unassigned),
+    (0x1FAB0, OtherSymbol),
+    (0x1FABB, Unassigned),
+    (0x1FAC0, OtherSymbol),
+    (0x1FAC6, Unassigned),
+    (0x1FAD0, OtherSymbol),
+    (0x1FADA, Unassigned),
+    (0x1FAE0, OtherSymbol),
+    (0x1FAE8, Unassigned),
+    (0x1FAF0, OtherSymbol),
+    (0x1FAF7, Unassigned),
+    (0x1FB00, OtherSymbol),
+    (0x1FB93, Unassigned),
+    (0x1FB94, OtherSymbol),
+    (0x1FBCB, Unassigned),
+    (0x1FBF0, DecimalNumber),
+    (0x1FBFA, Unassigned),
+    (0x20000, OtherLetter),
+    (0x2A6E0, Unassigned),
+    (0x2A700, OtherLetter),
+    (0x2B739, Unassigned),
+    (0x2B740, OtherLetter),
+    (0x2B81E, Unassigned),
+    (0x2B820, OtherLetter),
+    (0x2CEA2, Unassigned),
+    (0x2CEB0, OtherLetter),
+    (0x2EBE1, Unassigned),
+    (0x2F800, OtherLetter),
+    (0x2FA1E, Unassigned),
+    (0x30000, OtherLetter),
+    (0x3134B, Unassigned),
+    (0xE0001, Format),
+    (0xE0002, Unassigned),
+    (0xE0020, Format),
+    (0xE0080, Unassigned),
+    (0xE0100, NonspacingMark),
+    (0xE01F0, Unassigned),
+    (0xF0000, PrivateUse),
+    (0xFFFFE, Unassigned),
+    (0x100000, PrivateUse),
+    (0x10FFFE, Unassigned),
+];
+
+#[rustfmt::skip]
+pub(super) static SCRIPT: &[(u32, &str)] = &[
+    (0x0000, "Zyyy"),
+    (0x0041, "Latn"),
+    (0x005B, "Zyyy"),
+    (0x0061, "Latn"),
+    (0x007B, "Zyyy"),
+    (0x00AA, "Latn"),
+    (0x00AB, "Zyyy"),
+    (0x00BA, "Latn"),
+    (0x00BB, "Zyyy"),
+    (0x00C0, "Latn"),
+    (0x00D7, "Zyyy"),
+    (0x00D8, "Latn"),
+    (0x00F7, "Zyyy"),
+    (0x00F8, "Latn"),
+    (0x02B9, "Zyyy"),
+    (0x02E0, "Latn"),
+    (0x02E5, "Zyyy"),
+    (0x02EA, "Bopo"),
+    (0x02EC, "Zyyy"),
+    (0x0300, "Zinh"),
+    (0x0370, "Grek"),
+    (0x0374, "Zyyy"),
+    (0x0375, "Grek"),
+    (0x0378, "Zzzz"),
+    (0x037A, "Grek"),
+    (0x037E, "Zyyy"),
+    (0x037F, "Grek"),
+    (0x0380, "Zzzz"),
+    (0x0384, "Grek"),
+    (0x0385, "Zyyy"),
+    (0x0386, "Grek"),
+    (0x0387, "Zyyy"),
+    (0x0388, "Grek"),
+    (0x038B, "Zzzz"),
+    (0x038C, "Grek"),
+    (0x038D, "Zzzz"),
+    (0x038E, "Grek"),
+    (0x03A2, "Zzzz"),
+    (0x03A3, "Grek"),
+    (0x03E2, "Copt"),
+    (0x03F0, "Grek"),
+    (0x0400, "Cyrl"),
+    (0x0485, "Zinh"),
+    (0x0487, "Cyrl"),
+    (0x0530, "Zzzz"),
+    (0x0531, "Armn"),
+    (0x0557, "Zzzz"),
+    (0x0559, "Armn"),
+    (0x058B, "Zzzz"),
+    (0x058D, "Armn"),
+    (0x0590, "Zzzz"),
+    (0x0591, "Hebr"),
+    (0x05C8, "Zzzz"),
+    (0x05D0, "Hebr"),
+    (0x05EB, "Zzzz"),
+    (0x05EF, "Hebr"),
+    (0x05F5, "Zzzz"),
+    (0x0600, "Arab"),
+    (0x0605, "Zyyy"),
+    (0x0606, "Arab"),
+    (0x060C, "Zyyy"),
+    (0x060D, "Arab"),
+    (0x061B, "Zyyy"),
+    (0x061C, "Arab"),
+    (0x061F, "Zyyy"),
+    (0x0620, "Arab"),
+    (0x0640, "Zyyy"),
+    (0x0641, "Arab"),
+    (0x064B, "Zinh"),
+    (0x0656, "Arab"),
+    (0x0670, "Zinh"),
+    (0x0671, "Arab"),
+    (0x06DD, "Zyyy"),
+    (0x06DE, "Arab"),
+    (0x0700, "Syrc"),
+    (0x070E, "Zzzz"),
+    (0x070F, "Syrc"),
+    (0x074B, "Zzzz"),
+    (0x074D, "Syrc"),
+    (0x0750, "Arab"),
+    (0x0780, "Thaa"),
+    (0x07B2, "Zzzz"),
+    (0x07C0, "Nkoo"),
+    (0x07FB, "Zzzz"),
+    (0x07FD, "Nkoo"),
+    (0x0800, "Samr"),
+    (0x082E, "Zzzz"),
+    (0x0830, "Samr"),
+    (0x083F, "Zzzz"),
+    (0x0840, "Mand"),
+    (0x085C, "Zzzz"),
+    (0x085E, "Mand"),
+    (0x085F, "Zzzz"),
+    (0x0860, "Syrc"),
+    (0x086B, "Zzzz"),
+    (0x0870, "Arab"),
+    (0x088F, "Zzzz"),
+    (0x0890, "Arab"),
+    (0x0892, "Zzzz"),
+    (0x0898, "Arab"),
+    (0x08E2, "Zyyy"),
+    (0x08E3, "Arab"),
+    (0x0900, "Deva"),
+    (0x0951, "Zinh"),
+    (0x0955, "Deva"),
+    (0x0964, "Zyyy"),
+    (0x0966, "Deva"),
+    (0x0980, "Beng"),
+    (0x0984, "Zzzz"),
+    (0x0985, "Beng"),
+    (0x098D, "Zzzz"),
+    (0x098F, "Beng"),
+    (0x0991, "Zzzz"),
+    (0x0993, "Beng"),
+    (0x09A9, "Zzzz"),
+    (0x09AA, "Beng"),
+    (0x09B1, "Zzzz"),
+    (0x09B2, "Beng"),
+    (0x09B3, "Zzzz"),
+    (0x09B6, "Beng"),
+    (0x09BA, "Zzzz"),
+    (0x09BC, "Beng"),
+    (0x09C5, "Zzzz"),
+    (0x09C7, "Beng"),
+    (0x09C9, "Zzzz"),
+    (0x09CB, "Beng"),
+    (0x09CF, "Zzzz"),
+    (0x09D7, "Beng"),
+    (0x09D8, "Zzzz"),
+    (0x09DC, "Beng"),
+    (0x09DE, "Zzzz"),
+    (0x09DF, "Beng"),
+    (0x09E4, "Zzzz"),
+    (0x09E6, "Beng"),
+    (0x09FF, "Zzzz"),
+    (0x0A01, "Guru"),
+    (0x0A04, "Zzzz"),
+    (0x0A05, "Guru"),
+    (0x0A0B, "Zzzz"),
+    (0x0A0F, "Guru"),
+    (0x0A11, "Zzzz"),
+    (0x0A13, "Guru"),
+    (0x0A29, "Zzzz"),
+    (0x0A2A, "Guru"),
+    (0x0A31, "Zzzz"),
+    (0x0A32, "Guru"),
+    (0x0A34, "Zzzz"),
+    (0x0A35, "Guru"),
+    (0x0A37, "Zzzz"),
+    (0x0A38, "Guru"),
+    (0x0A3A, "Zzzz"),
+    (0x0A3C, "Guru"),
+    (0x0A3D, "Zzzz"),
+    (0x0A3E, "Guru"),
+    (0x0A43, "Zzzz"),
+    (0x0A47, "Guru"),
+    (0x0A49, "Zzzz"),
+    (0x0A4B, "Guru"),
+    (0x0A4E, "Zzzz"),
+    (0x0A51, "Guru"),
+    (0x0A52, "Zzzz"),
+    (0x0A59, "Guru"),
+    (0x0A5D, "Zzzz"),
+    (0x0A5E, "Guru"),
+    (0x0A5F, "Zzzz"),
+    (0x0A66, "Guru"),
+    (0x0A77, "Zzzz"),
+    (0x0A81, "Gujr"),
+    (0x0A84, "Zzzz"),
+    (0x0A85, "Gujr"),
+    (0x0A8E, "Zzzz"),
+    (0x0A8F, "Gujr"),
+    (0x0A92, "Zzzz"),
+    (0x0A93, "Gujr"),
+    (0x0AA9, "Zzzz"),
+    (0x0AAA, "Gujr"),
+    (0x0AB1, "Zzzz"),
+    (0x0AB2, "Gujr"),
+    (0x0AB4, "Zzzz"),
+    (0x0AB5, "Gujr"),
+    (0x0ABA, "Zzzz"),
+    (0x0ABC, "Gujr"),
+    (0x0AC6, "Zzzz"),
+    (0x0AC7, "Gujr"),
+    (0x0ACA, "Zzzz"),
+    (0x0ACB, "Gujr"),
+    (0x0ACE, "Zzzz"),
+    (0x0AD0, "Gujr"),
+    (0x0AD1, "Zzzz"),
+    (0x0AE0, "Gujr"),
+    (0x0AE4, "Zzzz"),
+    (0x0AE6, "Gujr"),
+    (0x0AF2, "Zzzz"),
+    (0x0AF9, "Gujr"),
+    (0x0B00, "Zzzz"),
+    (0x0B01, "Orya"),
+    (0x0B04, "Zzzz"),
+    (0x0B05, "Orya"),
+    (0x0B0D, "Zzzz"),
+    (0x0B0F, "Orya"),
+    (0x0B11, "Zzzz"),
+    (0x0B13, "Orya"),
+    (0x0B29, "Zzzz"),
+    (0x0B2A, "Orya"),
+    (0x0B31, "Zzzz"),
+    (0x0B32, "Orya"),
+    (0x0B34, "Zzzz"),
+    (0x0B35, "Orya"),
+    (0x0B3A, "Zzzz"),
+    (0x0B3C, "Orya"),
+    (0x0B45, "Zzzz"),
+    (0x0B47, "Orya"),
+    (0x0B49, "Zzzz"),
+    (0x0B4B, "Orya"),
+    (0x0B4E, "Zzzz"),
+    (0x0B55, "Orya"),
+    (0x0B58, "Zzzz"),
+    (0x0B5C, "Orya"),
+    (0x0B5E, "Zzzz"),
+    (0x0B5F, "Orya"),
+    (0x0B64, "Zzzz"),
+    (0x0B66, "Orya"),
+    (0x0B78, "Zzzz"),
+    (0x0B82, "Taml"),
+    (0x0B84, "Zzzz"),
+    (0x0B85, "Taml"),
+    (0x0B8B, "Zzzz"),
+    (0x0B8E, "Taml"),
+    (0x0B91, "Zzzz"),
+    (0x0B92, "Taml"),
+    (0x0B96, "Zzzz"),
+    (0x0B99, "Taml"),
+    (0x0B9B, "Zzzz"),
+    (0x0B9C, "Taml"),
+    (0x0B9D, "Zzzz"),
+    (0x0B9E, "Taml"),
+    (0x0BA0, "Zzzz"),
+    (0x0BA3, "Taml"),
+    (0x0BA5, "Zzzz"),
+    (0x0BA8, "Taml"),
+    (0x0BAB, "Zzzz"),
+    (0x0BAE, "Taml"),
+    (0x0BBA, "Zzzz"),
+    (0x0BBE, "Taml"),
+    (0x0BC3, "Zzzz"),
+    (0x0BC6, "Taml"),
+    (0x0BC9, "Zzzz"),
+    (0x0BCA, "Taml"),
+    (0x0BCE, "Zzzz"),
+    (0x0BD0, "Taml"),
+    (0x0BD1, "Zzzz"),
+    (0x0BD7, "Taml"),
+    (0x0BD8, "Zzzz"),
+    (0x0BE6, "Taml"),
+    (0x0BFB, "Zzzz"),
+    (0x0C00, "Telu"),
+    (0x0C0D, "Zzzz"),
+    (0x0C0E, "Telu"),
+    (0x0C11, "Zzzz"),
+    (0x0C12, "Telu"),
+    (0x0C29, "Zzzz"),
+    (0x0C2A, "Telu"),
+    (0x0C3A, "Zzzz"),
+    (0x0C3C, "Telu"),
+    (0x0C45, "Zzzz"),
+    (0x0C46, "Telu"),
+    (0x0C49, "Zzzz"),
+    (0x0C4A, "Telu"),
+    (0x0C4E, "Zzzz"),
+    (0x0C55, "Telu"),
+    (0x0C57, "Zzzz"),
+    (0x0C58, "Telu"),
+    (0x0C5B, "Zzzz"),
+    (0x0C5D, "Telu"),
+    (0x0C5E, "Zzzz"),
+    (0x0C60, "Telu"),
+    (0x0C64, "Zzzz"),
+    (0x0C66, "Telu"),
+    (0x0C70, "Zzzz"),
+    (0x0C77, "Telu"),
+    (0x0C80, "Knda"),
+    (0x0C8D, "Zzzz"),
+    (0x0C8E, "Knda"),
+    (0x0C91, "Zzzz"),
+    (0x0C92, "Knda"),
+    (0x0CA9, "Zzzz"),
+    (0x0CAA, "Knda"),
+    (0x0CB4, "Zzzz"),
+    (0x0CB5, "Knda"),
+    (0x0CBA, "Zzzz"),
+    (0x0CBC, "Knda"),
+    (0x0CC5, "Zzzz"),
+    (0x0CC6, "Knda"),
+    (0x0CC9, "Zzzz"),
+    (0x0CCA, "Knda"),
+    (0x0CCE, "Zzzz"),
+    (0x0CD5, "Knda"),
+    (0x0CD7, "Zzzz"),
+    (0x0CDD, "Knda"),
+    (0x0CDF, "Zzzz"),
+    (0x0CE0, "Knda"),
+    (0x0CE4, "Zzzz"),
+    (0x0CE6, "Knda"),
+    (0x0CF0, "Zzzz"),
+    (0x0CF1, "Knda"),
+    (0x0CF3, "Zzzz"),
+    (0x0D00, "Mlym"),
+    (0x0D0D, "Zzzz"),
+    (0x0D0E, "Mlym"),
+    (0x0D11, "Zzzz"),
+    (0x0D12, "Mlym"),
+    (0x0D45, "Zzzz"),
+    (0x0D46, "Mlym"),
+    (0x0D49, "Zzzz"),
+    (0x0D4A, "Mlym"),
+    (0x0D50, "Zzzz"),
+    (0x0D54, "Mlym"),
+    (0x0D64, "Zzzz"),
+    (0x0D66, "Mlym"),
+    (0x0D80, "Zzzz"),
+    (0x0D81, "Sinh"),
+    (0x0D84, "Zzzz"),
+    (0x0D85, "Sinh"),
+    (0x0D97, "Zzzz"),
+    (0x0D9A, "Sinh"),
+    (0x0DB2, "Zzzz"),
+    (0x0DB3, "Sinh"),
+    (0x0DBC, "Zzzz"),
+    (0x0DBD, "Sinh"),
+    (0x0DBE, "Zzzz"),
+    (0x0DC0, "Sinh"),
+    (0x0DC7, "Zzzz"),
+    (0x0DCA, "Sinh"),
+    (0x0DCB, "Zzzz"),
+    (0x0DCF, "Sinh"),
+    (0x0DD5, "Zzzz"),
+    (0x0DD6, "Sinh"),
+    (0x0DD7, "Zzzz"),
+    (0x0DD8, "Sinh"),
+    (0x0DE0, "Zzzz"),
+    (0x0DE6, "Sinh"),
+    (0x0DF0, "Zzzz"),
+    (0x0DF2, "Sinh"),
+    (0x0DF5, "Zzzz"),
+    (0x0E01, "Thai"),
+    (0x0E3B, "Zzzz"),
+    (0x0E3F, "Zyyy"),
+    (0x0E40, "Thai"),
+    (0x0E5C, "Zzzz"),
+    (0x0E81, "Laoo"),
+    (0x0E83, "Zzzz"),
+    (0x0E84, "Laoo"),
+    (0x0E85, "Zzzz"),
+    (0x0E86, "Laoo"),
+    (0x0E8B, "Zzzz"),
+    (0x0E8C, "Laoo"),
+    (0x0EA4, "Zzzz"),
+    (0x0EA5, "Laoo"),
+    (0x0EA6, "Zzzz"),
+    (0x0EA7, "Laoo"),
+    (0x0EBE, "Zzzz"),
+    (0x0EC0, "Laoo"),
+    (0x0EC5, "Zzzz"),
+    (0x0EC6, "Laoo"),
+    (0x0EC7, "Zzzz"),
+    (0x0EC8, "Laoo"),
+    (0x0ECE, "Zzzz"),
+    (0x0ED0, "Laoo"),
+    (0x0EDA, "Zzzz"),
+    (0x0EDC, "Laoo"),
+    (0x0EE0, "Zzzz"),
+    (0x0F00, "Tibt"),
+    (0x0F48, "Zzzz"),
+    (0x0F49, "Tibt"),
+    (0x0F6D, "Zzzz"),
+    (0x0F71, "Tibt"),
+    (0x0F98, "Zzzz"),
+    (0x0F99, "Tibt"),
+    (0x0FBD, "Zzzz"),
+    (0x0FBE, "Tibt"),
+    (0x0FCD, "Zzzz"),
+    (0x0FCE, "Tibt"),
+    (0x0FD5, "Zyyy"),
+    (0x0FD9, "Tibt"),
+    (0x0FDB, "Zzzz"),
+    (0x1000, "Mymr"),
+    (0x10A0, "Geor"),
+    (0x10C6, "Zzzz"),
+    (0x10C7, "Geor"),
+    (0x10C8, "Zzzz"),
+    (0x10CD, "Geor"),
+    (0x10CE, "Zzzz"),
+    (0x10D0, "Geor"),
+    (0x10FB, "Zyyy"),
+    (0x10FC, "Geor"),
+    (0x1100, "Hang"),
+    (0x1200, "Ethi"),
+    (0x1249, "Zzzz"),
+    (0x124A, "Ethi"),
+    (0x124E, "Zzzz"),
+    (0x1250, "Ethi"),
+    (0x1257, "Zzzz"),
+    (0x1258, "Ethi"),
+    (0x1259, "Zzzz"),
+    (0x125A, "Ethi"),
+    (0x125E, "Zzzz"),
+    (0x1260, "Ethi"),
+    (0x1289, "Zzzz"),
+    (0x128A, "Ethi"),
+    (0x128E, "Zzzz"),
+    (0x1290, "Ethi"),
+    (0x12B1, "Zzzz"),
+    (0x12B2, "Ethi"),
+    (0x12B6, "Zzzz"),
+    (0x12B8, "Ethi"),
+    (0x12BF, "Zzzz"),
+    (0x12C0, "Ethi"),
+    (0x12C1, "Zzzz"),
+    (0x12C2, "Ethi"),
+    (0x12C6, "Zzzz"),
+    (0x12C8, "Ethi"),
+    (0x12D7, "Zzzz"),
+    (0x12D8, "Ethi"),
+    (0x1311, "Zzzz"),
+    (0x1312, "Ethi"),
+    (0x1316, "Zzzz"),
+    (0x1318, "Ethi"),
+    (0x135B, "Zzzz"),
+    (0x135D, "Ethi"),
+    (0x137D, "Zzzz"),
+    (0x1380, "Ethi"),
+    (0x139A, "Zzzz"),
+    (0x13A0, "Cher"),
+    (0x13F6, "Zzzz"),
+    (0x13F8, "Cher"),
+    (0x13FE, "Zzzz"),
+    (0x1400, "Cans"),
+    (0x1680, "Ogam"),
+    (0x169D, "Zzzz"),
+    (0x16A0, "Runr"),
+    (0x16EB, "Zyyy"),
+    (0x16EE, "Runr"),
+    (0x16F9, "Zzzz"),
+    (0x1700, "Tglg"),
+    (0x1716, "Zzzz"),
+    (0x171F, "Tglg"),
+    (0x1720, "Hano"),
+    (0x1735, "Zyyy"),
+    (0x1737, "Zzzz"),
+    (0x1740, "Buhd"),
+    (0x1754, "Zzzz"),
+    (0x1760, "Tagb"),
+    (0x176D, "Zzzz"),
+    (0x176E, "Tagb"),
+    (0x1771, "Zzzz"),
+    (0x1772, "Tagb"),
+    (0x1774, "Zzzz"),
+    (0x1780, "Khmr"),
+    (0x17DE, "Zzzz"),
+    (0x17E0, "Khmr"),
+    (0x17EA, "Zzzz"),
+    (0x17F0, "Khmr"),
+    (0x17FA, "Zzzz"),
+    (0x1800, "Mong"),
+    (0x1802, "Zyyy"),
+    (0x1804, "Mong"),
+    (0x1805, "Zyyy"),
+    (0x1806, "Mong"),
+    (0x181A, "Zzzz"),
+    (0x1820, "Mong"),
+    (0x1879, "Zzzz"),
+    (0x1880, "Mong"),
+    (0x18AB, "Zzzz"),
+    (0x18B0, "Cans"),
+    (0x18F6, "Zzzz"),
+    (0x1900, "Limb"),
+    (0x191F, "Zzzz"),
+    (0x1920, "Limb"),
+    (0x192C, "Zzzz"),
+    (0x1930, "Limb"),
+    (0x193C, "Zzzz"),
+    (0x1940, "Limb"),
+    (0x1941, "Zzzz"),
+    (0x1944, "Limb"),
+    (0x1950, "Tale"),
+    (0x196E, "Zzzz"),
+    (0x1970, "Tale"),
+    (0x1975, "Zzzz"),
+    (0x1980, "Talu"),
+    (0x19AC, "Zzzz"),
+    (0x19B0, "Talu"),
+    (0x19CA, "Zzzz"),
+    (0x19D0, "Talu"),
+    (0x19DB, "Zzzz"),
+    (0x19DE, "Talu"),
+    (0x19E0, "Khmr"),
+    (0x1A00, "Bugi"),
+    (0x1A1C, "Zzzz"),
+    (0x1A1E, "Bugi"),
+    (0x1A20, "Lana"),
+    (0x1A5F, "Zzzz"),
+    (0x1A60, "Lana"),
+    (0x1A7D, "Zzzz"),
+    (0x1A7F, "Lana"),
+    (0x1A8A, "Zzzz"),
+    (0x1A90, "Lana"),
+    (0x1A9A, "Zzzz"),
+    (0x1AA0, "Lana"),
+    (0x1AAE, "Zzzz"),
+    (0x1AB0, "Zinh"),
+    (0x1ACF, "Zzzz"),
+    (0x1B00, "Bali"),
+    (0x1B4D, "Zzzz"),
+    (0x1B50, "Bali"),
+    (0x1B7F, "Zzzz"),
+    (0x1B80, "Sund"),
+    (0x1BC0, "Batk"),
+    (0x1BF4, "Zzzz"),
+    (0x1BFC, "Batk"),
+    (0x1C00, "Lepc"),
+    (0x1C38, "Zzzz"),
+    (0x1C3B, "Lepc"),
+    (0x1C4A, "Zzzz"),
+    (0x1C4D, "Lepc"),
+    (0x1C50, "Olck"),
+    (0x1C80, "Cyrl"),
+    (0x1C89, "Zzzz"),
+    (0x1C90, "Geor"),
+    (0x1CBB, "Zzzz"),
+    (0x1CBD, "Geor"),
+    (0x1CC0, "Sund"),
+    (0x1CC8, "Zzzz"),
+    (0x1CD0, "Zinh"),
+    (0x1CD3, "Zyyy"),
+    (0x1CD4, "Zinh"),
+    (0x1CE1, "Zyyy"),
+    (0x1CE2, "Zinh"),
+    (0x1CE9, "Zyyy"),
+    (0x1CED, "Zinh"),
+    (0x1CEE, "Zyyy"),
+    (0x1CF4, "Zinh"),
+    (0x1CF5, "Zyyy"),
+    (0x1CF8, "Zinh"),
+    (0x1CFA, "Zyyy"),
+    (0x1CFB, "Zzzz"),
+    (0x1D00, "Latn"),
+    (0x1D26, "Grek"),
+    (0x1D2B, "Cyrl"),
+    (0x1D2C, "Latn"),
+    (0x1D5D, "Grek"),
+    (0x1D62, "Latn"),
+    (0x1D66, "Grek"),
+    (0x1D6B, "Latn"),
+    (0x1D78, "Cyrl"),
+    (0x1D79, "Latn"),
+    (0x1DBF, "Grek"),
+    (0x1DC0, "Zinh"),
+    (0x1E00, "Latn"),
+    (0x1F00, "Grek"),
+    (0x1F16, "Zzzz"),
+    (0x1F18, "Grek"),
+    (0x1F1E, "Zzzz"),
+    (0x1F20, "Grek"),
+    (0x1F46, "Zzzz"),
+    (0x1F48, "Grek"),
+    (0x1F4E, "Zzzz"),
+    (0x1F50, "Grek"),
+    (0x1F58, "Zzzz"),
+    (0x1F59, "Grek"),
+    (0x1F5A, "Zzzz"),
+    (0x1F5B, "Grek"),
+    (0x1F5C, "Zzzz"),
+    (0x1F5D, "Grek"),
+    (0x1F5E, "Zzzz"),
+    (0x1F5F, "Grek"),
+    (0x1F7E, "Zzzz"),
+    (0x1F80, "Grek"),
+    (0x1FB5, "Zzzz"),
+    (0x1FB6, "Grek"),
+    (0x1FC5, "Zzzz"),
+    (0x1FC6, "Grek"),
+    (0x1FD4, "Zzzz"),
+    (0x1FD6, "Grek"),
+    (0x1FDC, "Zzzz"),
+    (0x1FDD, "Grek"),
+    (0x1FF0, "Zzzz"),
+    (0x1FF2, "Grek"),
+    (0x1FF5, "Zzzz"),
+    (0x1FF6, "Grek"),
+    (0x1FFF, "Zzzz"),
+    (0x2000, "Zyyy"),
+    (0x200C, "Zinh"),
+    (0x200E, "Zyyy"),
+    (0x2065, "Zzzz"),
+    (0x2066, "Zyyy"),
+    (0x2071, "Latn"),
+    (0x2072, "Zzzz"),
+    (0x2074, "Zyyy"),
+    (0x207F, "Latn"),
+    (0x2080, "Zyyy"),
+    (0x208F, "Zzzz"),
+    (0x2090, "Latn"),
+    (0x209D, "Zzzz"),
+    (0x20A0, "Zyyy"),
+    (0x20C1, "Zzzz"),
+    (0x20D0, "Zinh"),
+    (0x20F1, "Zzzz"),
+    (0x2100, "Zyyy"),
+    (0x2126, "Grek"),
+    (0x2127, "Zyyy"),
+    (0x212A, "Latn"),
+    (0x212C, "Zyyy"),
+    (0x2132, "Latn"),
+    (0x2133, "Zyyy"),
+    (0x214E, "Latn"),
+    (0x214F, "Zyyy"),
+    (0x2160, "Latn"),
+    (0x2189, "Zyyy"),
+    (0x218C, "Zzzz"),
+    (0x2190, "Zyyy"),
+    (0x2427, "Zzzz"),
+    (0x2440, "Zyyy"),
+    (0x244B, "Zzzz"),
+    (0x2460, "Zyyy"),
+    (0x2800, "Brai"),
+    (0x2900, "Zyyy"),
+    (0x2B74, "Zzzz"),
+    (0x2B76, "Zyyy"),
+    (0x2B96, "Zzzz"),
+    (0x2B97, "Zyyy"),
+    (0x2C00, "Glag"),
+    (0x2C60, "Latn"),
+    (0x2C80, "Copt"),
+    (0x2CF4, "Zzzz"),
+    (0x2CF9, "Copt"),
+    (0x2D00, "Geor"),
+    (0x2D26, "Zzzz"),
+    (0x2D27, "Geor"),
+    (0x2D28, "Zzzz"),
+    (0x2D2D, "Geor"),
+    (0x2D2E, "Zzzz"),
+    (0x2D30, "Tfng"),
+    (0x2D68, "Zzzz"),
+    (0x2D6F, "Tfng"),
+    (0x2D71, "Zzzz"),
+    (0x2D7F, "Tfng"),
+    (0x2D80, "Ethi"),
+    (0x2D97, "Zzzz"),
+    (0x2DA0, "Ethi"),
+    (0x2DA7, "Zzzz"),
+    (0x2DA8, "Ethi"),
+    (0x2DAF, "Zzzz"),
+    (0x2DB0, "Ethi"),
+    (0x2DB7, "Zzzz"),
+    (0x2DB8, "Ethi"),
+    (0x2DBF, "Zzzz"),
+    (0x2DC0, "Ethi"),
+    (0x2DC7, "Zzzz"),
+    (0x2DC8, "Ethi"),
+    (0x2DCF, "Zzzz"),
+    (0x2DD0, "Ethi"),
+    (0x2DD7, "Zzzz"),
+    (0x2DD8, "Ethi"),
+    (0x2DDF, "Zzzz"),
+    (0x2DE0, "Cyrl"),
+    (0x2E00, "Zyyy"),
+    (0x2E5E, "Zzzz"),
+    (0x2E80, "Hani"),
+    (0x2E9A, "Zzzz"),
+    (0x2E9B, "Hani"),
+    (0x2EF4, "Zzzz"),
+    (0x2F00, "Hani"),
+    (0x2FD6, "Zzzz"),
+    (0x2FF0, "Zyyy"),
+    (0x2FFC, "Zzzz"),
+    (0x3000, "Zyyy"),
+    (0x3005, "Hani"),
+    (0x3006, "Zyyy"),
+    (0x3007, "Hani"),
+    (0x3008, "Zyyy"),
+    (0x3021, "Hani"),
+    (0x302A, "Zinh"),
+    (0x302E, "Hang"),
+    (0x3030, "Zyyy"),
+    (0x3038, "Hani"),
+    (0x303C, "Zyyy"),
+    (0x3040, "Zzzz"),
+    (0x3041, "Hira"),
+    (0x3097, "Zzzz"),
+    (0x3099, "Zinh"),
+    (0x309B, "Zyyy"),
+    (0x309D, "Hira"),
+    (0x30A0, "Zyyy"),
+    (0x30A1, "Kana"),
+    (0x30FB, "Zyyy"),
+    (0x30FD, "Kana"),
+    (0x3100, "Zzzz"),
+    (0x3105, "Bopo"),
+    (0x3130, "Zzzz"),
+    (0x3131, "Hang"),
+    (0x318F, "Zzzz"),
+    (0x3190, "Zyyy"),
+    (0x31A0, "Bopo"),
+    (0x31C0, "Zyyy"),
+    (0x31E4, "Zzzz"),
+    (0x31F0, "Kana"),
+    (0x3200, "Hang"),
+    (0x321F, "Zzzz"),
+    (0x3220, "Zyyy"),
+    (0x3260, "Hang"),
+    (0x327F, "Zyyy"),
+    (0x32D0, "Kana"),
+    (0x32FF, "Zyyy"),
+    (0x3300, "Kana"),
+    (0x3358, "Zyyy"),
+    (0x3400, "Hani"),
+    (0x4DC0, "Zyyy"),
+    (0x4E00, "Hani"),
+    (0xA000, "Yiii"),
+    (0xA48D, "Zzzz"),
+    (0xA490, "Yiii"),
+    (0xA4C7, "Zzzz"),
+    (0xA4D0, "Lisu"),
+    (0xA500, "Vaii"),
+    (0xA62C, "Zzzz"),
+    (0xA640, "Cyrl"),
+    (0xA6A0, "Bamu"),
+    (0xA6F8, "Zzzz"),
+    (0xA700, "Zyyy"),
+    (0xA722, "Latn"),
+    (0xA788, "Zyyy"),
+    (0xA78B, "Latn"),
+    (0xA7CB, "Zzzz"),
+    (0xA7D0, "Latn"),
+    (0xA7D2, "Zzzz"),
+    (0xA7D3, "Latn"),
+    (0xA7D4, "Zzzz"),
+    (0xA7D5, "Latn"),
+    (0xA7DA, "Zzzz"),
+    (0xA7F2, "Latn"),
+    (0xA800, "Sylo"),
+    (0xA82D, "Zzzz"),
+    (0xA830, "Zyyy"),
+    (0xA83A, "Zzzz"),
+    (0xA840, "Phag"),
+    (0xA878, "Zzzz"),
+    (0xA880, "Saur"),
+    (0xA8C6, "Zzzz"),
+    (0xA8CE, "Saur"),
+    (0xA8DA, "Zzzz"),
+    (0xA8E0, "Deva"),
+    (0xA900, "Kali"),
+    (0xA92E, "Zyyy"),
+    (0xA92F, "Kali"),
+    (0xA930, "Rjng"),
+    (0xA954, "Zzzz"),
+    (0xA95F, "Rjng"),
+    (0xA960, "Hang"),
+    (0xA97D, "Zzzz"),
+    (0xA980, "Java"),
+    (0xA9CE, "Zzzz"),
+    (0xA9CF, "Zyyy"),
+    (0xA9D0, "Java"),
+    (0xA9DA, "Zzzz"),
+    (0xA9DE, "Java"),
+    (0xA9E0, "Mymr"),
+    (0xA9FF, "Zzzz"),
+    (0xAA00, "Cham"),
+    (0xAA37, "Zzzz"),
+    (0xAA40, "Cham"),
+    (0xAA4E, "Zzzz"),
+    (0xAA50, "Cham"),
+    (0xAA5A, "Zzzz"),
+    (0xAA5C, "Cham"),
+    (0xAA60, "Mymr"),
+    (0xAA80, "Tavt"),
+    (0xAAC3, "Zzzz"),
+    (0xAADB, "Tavt"),
+    (0xAAE0, "Mtei"),
+    (0xAAF7, "Zzzz"),
+    (0xAB01, "Ethi"),
+    (0xAB07, "Zzzz"),
+    (0xAB09, "Ethi"),
+    (0xAB0F, "Zzzz"),
+    (0xAB11, "Ethi"),
+    (0xAB17, "Zzzz"),
+    (0xAB20, "Ethi"),
+    (0xAB27, "Zzzz"),
+    (0xAB28, "Ethi"),
+    (0xAB2F, "Zzzz"),
+    (0xAB30, "Latn"),
+    (0xAB5B, "Zyyy"),
+    (0xAB5C, "Latn"),
+    (0xAB65, "Grek"),
+    (0xAB66, "Latn"),
+    (0xAB6A, "Zyyy"),
+    (0xAB6C, "Zzzz"),
+    (0xAB70, "Cher"),
+    (0xABC0, "Mtei"),
+    (0xABEE, "Zzzz"),
+    (0xABF0, "Mtei"),
+    (0xABFA, "Zzzz"),
+    (0xAC00, "Hang"),
+    (0xD7A4, "Zzzz"),
+    (0xD7B0, "Hang"),
+    (0xD7C7, "Zzzz"),
+    (0xD7CB, "Hang"),
+    (0xD7FC, "Zzzz"),
+    (0xF900, "Hani"),
+    (0xFA6E, "Zzzz"),
+    (0xFA70, "Hani"),
+    (0xFADA, "Zzzz"),
+    (0xFB00, "Latn"),
+    (0xFB07, "Zzzz"),
+    (0xFB13, "Armn"),
+    (0xFB18, "Zzzz"),
+    (0xFB1D, "Hebr"),
+    (0xFB37, "Zzzz"),
+    (0xFB38, "Hebr"),
+    (0xFB3D, "Zzzz"),
+    (0xFB3E, "Hebr"),
+    (0xFB3F, "Zzzz"),
+    (0xFB40, "Hebr"),
+    (0xFB42, "Zzzz"),
+    (0xFB43, "Hebr"),
+    (0xFB45, "Zzzz"),
+    (0xFB46, "Hebr"),
+    (0xFB50, "Arab"),
+    (0xFBC3, "Zzzz"),
+    (0xFBD3, "Arab"),
+    (0xFD3E, "Zyyy"),
+    (0xFD40, "Arab"),
+    (0xFD90, "Zzzz"),
+    (0xFD92, "Arab"),
+    (0xFDC8, "Zzzz"),
+    (0xFDCF, "Arab"),
+    (0xFDD0, "Zzzz"),
+    (0xFDF0, "Arab"),
+    (0xFE00, "Zinh"),
+    (0xFE10, "Zyyy"),
+    (0xFE1A, "Zzzz"),
+    (0xFE20, "Zinh"),
+    (0xFE2E, "Cyrl"),
+    (0xFE30, "Zyyy"),
+    (0xFE53, "Zzzz"),
+    (0xFE54, "Zyyy"),
+    (0xFE67, "Zzzz"),
+    (0xFE68, "Zyyy"),
+    (0xFE6C, "Zzzz"),
+    (0xFE70, "Arab"),
+    (0xFE75, "Zzzz"),
+    (0xFE76, "Arab"),
+    (0xFEFD, "Zzzz"),
+    (0xFEFF, "Zyyy"),
+    (0xFF00, "Zzzz"),
+    (0xFF01, "Zyyy"),
+    (0xFF21, "Latn"),
+    (0xFF3B, "Zyyy"),
+    (0xFF41, "Latn"),
+    (0xFF5B, "Zyyy"),
+    (0xFF66, "Kana"),
+    (0xFF70, "Zyyy"),
+    (0xFF71, "Kana"),
+    (0xFF9E, "Zyyy"),
+    (0xFFA0, "Hang"),
+    (0xFFBF, "Zzzz"),
+    (0xFFC2, "Hang"),
+    (0xFFC8, "Zzzz"),
+    (0xFFCA, "Hang"),
+    (0xFFD0, "Zzzz"),
+    (0xFFD2, "Hang"),
+    (0xFFD8, "Zzzz"),
+    (0xFFDA, "Hang"),
+    (0xFFDD, "Zzzz"),
+    (0xFFE0, "Zyyy"),
+    (0xFFE7, "Zzzz"),
+    (0xFFE8, "Zyyy"),
+    (0xFFEF, "Zzzz"),
+    (0xFFF9, "Zyyy"),
+    (0xFFFE, "Zzzz"),
+    (0x10000, "Linb"),
+    (0x1000C, "Zzzz"),
+    (0x1000D, "Linb"),
+    (0x10027, "Zzzz"),
+    (0x10028, "Linb"),
+    (0x1003B, "Zzzz"),
+    (0x1003C, "Linb"),
+    (0x1003E, "Zzzz"),
+    (0x1003F, "Linb"),
+    (0x1004E, "Zzzz"),
+    (0x10050, "Linb"),
+    (0x1005E, "Zzzz"),
+    (0x10080, "Linb"),
+    (0x100FB, "Zzzz"),
+    (0x10100, "Zyyy"),
+    (0x10103, "Zzzz"),
+    (0x10107, "Zyyy"),
+    (0x10134, "Zzzz"),
+    (0x10137, "Zyyy"),
+    (0x10140, "Grek"),
+    (0x1018F, "Zzzz"),
+    (0x10190, "Zyyy"),
+    (0x1019D, "Zzzz"),
+    (0x101A0, "Grek"),
+    (0x101A1, "Zzzz"),
+    (0x101D0, "Zyyy"),
+    (0x101FD, "Zinh"),
+    (0x101FE, "Zzzz"),
+    (0x10280, "Lyci"),
+    (0x1029D, "Zzzz"),
+    (0x102A0, "Cari"),
+    (0x102D1, "Zzzz"),
+    (0x102E0, "Zinh"),
+    (0x102E1, "Zyyy"),
+    (0x102FC, "Zzzz"),
+    (0x10300, "Ital"),
+    (0x10324, "Zzzz"),
+    (0x1032D, "Ital"),
+    (0x10330, "Goth"),
+    (0x1034B, "Zzzz"),
+    (0x10350, "Perm"),
+    (0x1037B, "Zzzz"),
+    (0x10380, "Ugar"),
+    (0x1039E, "Zzzz"),
+    (0x1039F, "Ugar"),
+    (0x103A0, "Xpeo"),
+    (0x103C4, "Zzzz"),
+    (0x103C8, "Xpeo"),
+    (0x103D6, "Zzzz"),
+    (0x10400, "Dsrt"),
+    (0x10450, "Shaw"),
+    (0x10480, "Osma"),
+    (0x1049E, "Zzzz"),
+    (0x104A0, "Osma"),
+    (0x104AA, "Zzzz"),
+    (0x104B0, "Osge"),
+    (0x104D4, "Zzzz"),
+    (0x104D8, "Osge"),
+    (0x104FC, "Zzzz"),
+    (0x10500, "Elba"),
+    (0x10528, "Zzzz"),
+    (0x10530, "Aghb"),
+    (0x10564, "Zzzz"),
+    (0x1056F, "Aghb"),
+    (0x10570, "Vith"),
+    (0x1057B, "Zzzz"),
+    (0x1057C, "Vith"),
+    (0x1058B, "Zzzz"),
+    (0x1058C, "Vith"),
+    (0x10593, "Zzzz"),
+    (0x10594, "Vith"),
+    (0x10596, "Zzzz"),
+    (0x10597, "Vith"),
+    (0x105A2, "Zzzz"),
+    (0x105A3, "Vith"),
+    (0x105B2, "Zzzz"),
+    (0x105B3, "Vith"),
+    (0x105BA, "Zzzz"),
+    (0x105BB, "Vith"),
+    (0x105BD, "Zzzz"),
+    (0x10600, "Lina"),
+    (0x10737, "Zzzz"),
+    (0x10740, "Lina"),
+    (0x10756, "Zzzz"),
+    (0x10760, "Lina"),
+    (0x10768, "Zzzz"),
+    (0x10780, "Latn"),
+    (0x10786, "Zzzz"),
+    (0x10787, "Latn"),
+    (0x107B1, "Zzzz"),
+    (0x107B2, "Latn"),
+    (0x107BB, "Zzzz"),
+    (0x10800, "Cprt"),
+    (0x10806, "Zzzz"),
+    (0x10808, "Cprt"),
+    (0x10809, "Zzzz"),
+    (0x1080A, "Cprt"),
+    (0x10836, "Zzzz"),
+    (0x10837, "Cprt"),
+    (0x10839, "Zzzz"),
+    (0x1083C, "Cprt"),
+    (0x1083D, "Zzzz"),
+    (0x1083F, "Cprt"),
+    (0x10840, "Armi"),
+    (0x10856, "Zzzz"),
+    (0x10857, "Armi"),
+    (0x10860, "Palm"),
+    (0x10880, "Nbat"),
+    (0x1089F, "Zzzz"),
+    (0x108A7, "Nbat"),
+    (0x108B0, "Zzzz"),
+    (0x108E0, "Hatr"),
+    (0x108F3, "Zzzz"),
+    (0x108F4, "Hatr"),
+    (0x108F6, "Zzzz"),
+    (0x108FB, "Hatr"),
+    (0x10900, "Phnx"),
+    (0x1091C, "Zzzz"),
+    (0x1091F, "Phnx"),
+    (0x10920, "Lydi"),
+    (0x1093A, "Zzzz"),
+    (0x1093F, "Lydi"),
+    (0x10940, "Zzzz"),
+    (0x10980, "Mero"),
+    (0x109A0, "Merc"),
+    (0x109B8, "Zzzz"),
+    (0x109BC, "Merc"),
+    (0x109D0, "Zzzz"),
+    (0x109D2, "Merc"),
+    (0x10A00, "Khar"),
+    (0x10A04, "Zzzz"),
+    (0x10A05, "Khar"),
+    (0x10A07, "Zzzz"),
+    (0x10A0C, "Khar"),
+    (0x10A14, "Zzzz"),
+    (0x10A15, "Khar"),
+    (0x10A18, "Zzzz"),
+    (0x10A19, "Khar"),
+    (0x10A36, "Zzzz"),
+    (0x10A38, "Khar"),
+    (0x10A3B, "Zzzz"),
+    (0x10A3F, "Khar"),
+    (0x10A49, "Zzzz"),
+    (0x10A50, "Khar"),
+    (0x10A59, "Zzzz"),
+    (0x10A60, "Sarb"),
+    (0x10A80, "Narb"),
+    (0x10AA0, "Zzzz"),
+    (0x10AC0, "Mani"),
+    (0x10AE7, "Zzzz"),
+    (0x10AEB, "Mani"),
+    (0x10AF7, "Zzzz"),
+    (0x10B00, "Avst"),
+    (0x10B36, "Zzzz"),
+    (0x10B39, "Avst"),
+    (0x10B40, "Prti"),
+    (0x10B56, "Zzzz"),
+    (0x10B58, "Prti"),
+    (0x10B60, "Phli"),
+    (0x10B73, "Zzzz"),
+    (0x10B78, "Phli"),
+    (0x10B80, "Phlp"),
+    (0x10B92, "Zzzz"),
+    (0x10B99, "Phlp"),
+    (0x10B9D, "Zzzz"),
+    (0x10BA9, "Phlp"),
+    (0x10BB0, "Zzzz"),
+    (0x10C00, "Orkh"),
+    (0x10C49, "Zzzz"),
+    (0x10C80, "Hung"),
+    (0x10CB3, "Zzzz"),
+    (0x10CC0, "Hung"),
+    (0x10CF3, "Zzzz"),
+    (0x10CFA, "Hung"),
+    (0x10D00, "Rohg"),
+    (0x10D28, "Zzzz"),
+    (0x10D30, "Rohg"),
+    (0x10D3A, "Zzzz"),
+    (0x10E60, "Arab"),
+    (0x10E7F, "Zzzz"),
+    (0x10E80, "Yezi"),
+    (0x10EAA, "Zzzz"),
+    (0x10EAB, "Yezi"),
+    (0x10EAE, "Zzzz"),
+    (0x10EB0, "Yezi"),
+    (0x10EB2, "Zzzz"),
+    (0x10F00, "Sogo"),
+    (0x10F28, "Zzzz"),
+    (0x10F30, "Sogd"),
+    (0x10F5A, "Zzzz"),
+    (0x10F70, "Ougr"),
+    (0x10F8A, "Zzzz"),
+    (0x10FB0, "Chrs"),
+    (0x10FCC, "Zzzz"),
+    (0x10FE0, "Elym"),
+    (0x10FF7, "Zzzz"),
+    (0x11000, "Brah"),
+    (0x1104E, "Zzzz"),
+    (0x11052, "Brah"),
+    (0x11076, "Zzzz"),
+    (0x1107F, "Brah"),
+    (0x11080, "Kthi"),
+    (0x110C3, "Zzzz"),
+    (0x110CD, "Kthi"),
+    (0x110CE, "Zzzz"),
+    (0x110D0, "Sora"),
+    (0x110E9, "Zzzz"),
+    (0x110F0, "Sora"),
+    (0x110FA, "Zzzz"),
+    (0x11100, "Cakm"),
+    (0x11135, "Zzzz"),
+    (0x11136, "Cakm"),
+    (0x11148, "Zzzz"),
+    (0x11150, "Mahj"),
+    (0x11177, "Zzzz"),
+    (0x11180, "Shrd"),
+    (0x111E0, "Zzzz"),
+    (0x111E1, "Sinh"),
+    (0x111F5, "Zzzz"),
+    (0x11200, "Khoj"),
+    (0x11212, "Zzzz"),
+    (0x11213, "Khoj"),
+    (0x1123F, "Zzzz"),
+    (0x11280, "Mult"),
+    (0x11287, "Zzzz"),
+    (0x11288, "Mult"),
+    (0x11289, "Zzzz"),
+    (0x1128A, "Mult"),
+    (0x1128E, "Zzzz"),
+    (0x1128F, "Mult"),
+    (0x1129E, "Zzzz"),
+    (0x1129F, "Mult"),
+    (0x112AA, "Zzzz"),
+    (0x112B0, "Sind"),
+    (0x112EB, "Zzzz"),
+    (0x112F0, "Sind"),
+    (0x112FA, "Zzzz"),
+    (0x11300, "Gran"),
+    (0x11304, "Zzzz"),
+    (0x11305, "Gran"),
+    (0x1130D, "Zzzz"),
+    (0x1130F, "Gran"),
+    (0x11311, "Zzzz"),
+    (0x11313, "Gran"),
+    (0x11329, "Zzzz"),
+    (0x1132A, "Gran"),
+    (0x11331, "Zzzz"),
+    (0x11332, "Gran"),
+    (0x11334, "Zzzz"),
+    (0x11335, "Gran"),
+    (0x1133A, "Zzzz"),
+    (0x1133B, "Zinh"),
+    (0x1133C, "Gran"),
+    (0x11345, "Zzzz"),
+    (0x11347, "Gran"),
+    (0x11349, "Zzzz"),
+    (0x1134B, "Gran"),
+    (0x1134E, "Zzzz"),
+    (0x11350, "Gran"),
+    (0x11351, "Zzzz"),
+    (0x11357, "Gran"),
+    (0x11358, "Zzzz"),
+    (0x1135D, "Gran"),
+    (0x11364, "Zzzz"),
+    (0x11366, "Gran"),
+    (0x1136D, "Zzzz"),
+    (0x11370, "Gran"),
+    (0x11375, "Zzzz"),
+    (0x11400, "Newa"),
+    (0x1145C, "Zzzz"),
+    (0x1145D, "Newa"),
+    (0x11462, "Zzzz"),
+    (0x11480, "Tirh"),
+    (0x114C8, "Zzzz"),
+    (0x114D0, "Tirh"),
+    (0x114DA, "Zzzz"),
+    (0x11580, "Sidd"),
+    (0x115B6, "Zzzz"),
+    (0x115B8, "Sidd"),
+    (0x115DE, "Zzzz"),
+    (0x11600, "Modi"),
+    (0x11645, "Zzzz"),
+    (0x11650, "Modi"),
+    (0x1165A, "Zzzz"),
+    (0x11660, "Mong"),
+    (0x1166D, "Zzzz"),
+    (0x11680, "Takr"),
+    (0x116BA, "Zzzz"),
+    (0x116C0, "Takr"),
+    (0x116CA, "Zzzz"),
+    (0x11700, "Ahom"),
+    (0x1171B, "Zzzz"),
+    (0x1171D, "Ahom"),
+    (0x1172C, "Zzzz"),
+    (0x11730, "Ahom"),
+    (0x11747, "Zzzz"),
+    (0x11800, "Dogr"),
+    (0x1183C, "Zzzz"),
+    (0x118A0, "Wara"),
+    (0x118F3, "Zzzz"),
+    (0x118FF, "Wara"),
+    (0x11900, "Diak"),
+    (0x11907, "Zzzz"),
+    (0x11909, "Diak"),
+    (0x1190A, "Zzzz"),
+    (0x1190C, "Diak"),
+    (0x11914, "Zzzz"),
+    (0x11915, "Diak"),
+    (0x11917, "Zzzz"),
+    (0x11918, "Diak"),
+    (0x11936, "Zzzz"),
+    (0x11937, "Diak"),
+    (0x11939, "Zzzz"),
+    (0x1193B, "Diak"),
+    (0x11947, "Zzzz"),
+    (0x11950, "Diak"),
+    (0x1195A, "Zzzz"),
+    (0x119A0, "Nand"),
+    (0x119A8, "Zzzz"),
+    (0x119AA, "Nand"),
+    (0x119D8, "Zzzz"),
+    (0x119DA, "Nand"),
+    (0x119E5, "Zzzz"),
+    (0x11A00, "Zanb"),
+    (0x11A48, "Zzzz"),
+    (0x11A50, "Soyo"),
+    (0x11AA3, "Zzzz"),
+    (0x11AB0, "Cans"),
+    (0x11AC0, "Pauc"),
+    (0x11AF9, "Zzzz"),
+    (0x11C00, "Bhks"),
+    (0x11C09, "Zzzz"),
+    (0x11C0A, "Bhks"),
+    (0x11C37, "Zzzz"),
+    (0x11C38, "Bhks"),
+    (0x11C46, "Zzzz"),
+    (0x11C50, "Bhks"),
+    (0x11C6D, "Zzzz"),
+    (0x11C70, "Marc"),
+    (0x11C90, "Zzzz"),
+    (0x11C92, "Marc"),
+    (0x11CA8, "Zzzz"),
+    (0x11CA9, "Marc"),
+    (0x11CB7, "Zzzz"),
+    (0x11D00, "Gonm"),
+    (0x11D07, "Zzzz"),
+    (0x11D08, "Gonm"),
+    (0x11D0A, "Zzzz"),
+    (0x11D0B, "Gonm"),
+    (0x11D37, "Zzzz"),
+    (0x11D3A, "Gonm"),
+    (0x11D3B, "Zzzz"),
+    (0x11D3C, "Gonm"),
+    (0x11D3E, "Zzzz"),
+    (0x11D3F, "Gonm"),
+    (0x11D48, "Zzzz"),
+    (0x11D50, "Gonm"),
+    (0x11D5A, "Zzzz"),
+    (0x11D60, "Gong"),
+    (0x11D66, "Zzzz"),
+    (0x11D67, "Gong"),
+    (0x11D69, "Zzzz"),
+    (0x11D6A, "Gong"),
+    (0x11D8F, "Zzzz"),
+    (0x11D90, "Gong"),
+    (0x11D92, "Zzzz"),
+    (0x11D93, "Gong"),
+    (0x11D99, "Zzzz"),
+    (0x11DA0, "Gong"),
+    (0x11DAA, "Zzzz"),
+    (0x11EE0, "Maka"),
+    (0x11EF9, "Zzzz"),
+    (0x11FB0, "Lisu"),
+    (0x11FB1, "Zzzz"),
+    (0x11FC0, "Taml"),
+    (0x11FF2, "Zzzz"),
+    (0x11FFF, "Taml"),
+    (0x12000, "Xsux"),
+    (0x1239A, "Zzzz"),
+    (0x12400, "Xsux"),
+    (0x1246F, "Zzzz"),
+    (0x12470, "Xsux"),
+    (0x12475, "Zzzz"),
+    (0x12480, "Xsux"),
+    (0x12544, "Zzzz"),
+    (0x12F90, "Cpmn"),
+    (0x12FF3, "Zzzz"),
+    (0x13000, "Egyp"),
+    (0x1342F, "Zzzz"),
+    (0x13430, "Egyp"),
+    (0x13439, "Zzzz"),
+    (0x14400, "Hluw"),
+    (0x14647, "Zzzz"),
+    (0x16800, "Bamu"),
+    (0x16A39, "Zzzz"),
+    (0x16A40, "Mroo"),
+    (0x16A5F, "Zzzz"),
+    (0x16A60, "Mroo"),
+    (0x16A6A, "Zzzz"),
+    (0x16A6E, "Mroo"),
+    (0x16A70, "Tnsa"),
+    (0x16ABF, "Zzzz"),
+    (0x16AC0, "Tnsa"),
+    (0x16ACA, "Zzzz"),
+    (0x16AD0, "Bass"),
+    (0x16AEE, "Zzzz"),
+    (0x16AF0, "Bass"),
+    (0x16AF6, "Zzzz"),
+    (0x16B00, "Hmng"),
+    (0x16B46, "Zzzz"),
+    (0x16B50, "Hmng"),
+    (0x16B5A, "Zzzz"),
+    (0x16B5B, "Hmng"),
+    (0x16B62, "Zzzz"),
+    (0x16B63, "Hmng"),
+    (0x16B78, "Zzzz"),
+    (0x16B7D, "Hmng"),
+    (0x16B90, "Zzzz"),
+    (0x16E40, "Medf"),
+    (0x16E9B, "Zzzz"),
+    (0x16F00, "Plrd"),
+    (0x16F4B, "Zzzz"),
+    (0x16F4F, "Plrd"),
+    (0x16F88, "Zzzz"),
+    (0x16F8F, "Plrd"),
+    (0x16FA0, "Zzzz"),
+    (0x16FE0, "Tang"),
+    (0x16FE1, "Nshu"),
+    (0x16FE2, "Hani"),
+    (0x16FE4, "Kits"),
+    (0x16FE5, "Zzzz"),
+    (0x16FF0, "Hani"),
+    (0x16FF2, "Zzzz"),
+    (0x17000, "Tang"),
+    (0x187F8, "Zzzz"),
+    (0x18800, "Tang"),
+    (0x18B00, "Kits"),
+    (0x18CD6, "Zzzz"),
+    (0x18D00, "Tang"),
+    (0x18D09, "Zzzz"),
+    (0x1AFF0, "Kana"),
+    (0x1AFF4, "Zzzz"),
+    (0x1AFF5, "Kana"),
+    (0x1AFFC, "Zzzz"),
+    (0x1AFFD, "Kana"),
+    (0x1AFFF, "Zzzz"),
+    (0x1B000, "Kana"),
+    (0x1B001, "Hira"),
+    (0x1B120, "Kana"),
+    (0x1B123, "Zzzz"),
+    (0x1B150, "Hira"),
+    (0x1B153, "Zzzz"),
+    (0x1B164, "Kana"),
+    (0x1B168, "Zzzz"),
+    (0x1B170, "Nshu"),
+    (0x1B2FC, "Zzzz"),
+    (0x1BC00, "Dupl"),
+    (0x1BC6B, "Zzzz"),
+    (0x1BC70, "Dupl"),
+    (0x1BC7D, "Zzzz"),
+    (0x1BC80, "Dupl"),
+    (0x1BC89, "Zzzz"),
+    (0x1BC90, "Dupl"),
+    (0x1BC9A, "Zzzz"),
+    (0x1BC9C, "Dupl"),
+    (0x1BCA0, "Zyyy"),
+    (0x1BCA4, "Zzzz"),
+    (0x1CF00, "Zinh"),
+    (0x1CF2E, "Zzzz"),
+    (0x1CF30, "Zinh"),
+    (0x1CF47, "Zzzz"),
+    (0x1CF50, "Zyyy"),
+    (0x1CFC4, "Zzzz"),
+    (0x1D000, "Zyyy"),
+    (0x1D0F6, "Zzzz"),
+    (0x1D100, "Zyyy"),
+    (0x1D127, "Zzzz"),
+    (0x1D129, "Zyyy"),
+    (0x1D167, "Zinh"),
+    (0x1D16A, "Zyyy"),
+    (0x1D17B, "Zinh"),
+    (0x1D183, "Zyyy"),
+    (0x1D185, "Zinh"),
+    (0x1D18C, "Zyyy"),
+    (0x1D1AA, "Zinh"),
+    (0x1D1AE, "Zyyy"),
+    (0x1D1EB, "Zzzz"),
+    (0x1D200, "Grek"),
+    (0x1D246, "Zzzz"),
+    (0x1D2E0, "Zyyy"),
+    (0x1D2F4, "Zzzz"),
+    (0x1D300, "Zyyy"),
+    (0x1D357, "Zzzz"),
+    (0x1D360, "Zyyy"),
+    (0x1D379, "Zzzz"),
+    (0x1D400, "Zyyy"),
+    (0x1D455, "Zzzz"),
+    (0x1D456, "Zyyy"),
+    (0x1D49D, "Zzzz"),
+    (0x1D49E, "Zyyy"),
+    (0x1D4A0, "Zzzz"),
+    (0x1D4A2, "Zyyy"),
+    (0x1D4A3, "Zzzz"),
+    (0x1D4A5, "Zyyy"),
+    (0x1D4A7, "Zzzz"),
+    (0x1D4A9, "Zyyy"),
+    (0x1D4AD, "Zzzz"),
+    (0x1D4AE, "Zyyy"),
+    (0x1D4BA, "Zzzz"),
+    (0x1D4BB, "Zyyy"),
+    (0x1D4BC, "Zzzz"),
+    (0x1D4BD, "Zyyy"),
+    (0x1D4C4, "Zzzz"),
+    (0x1D4C5, "Zyyy"),
+    (0x1D506, "Zzzz"),
+    (0x1D507, "Zyyy"),
+    (0x1D50B, "Zzzz"),
+    (0x1D50D, "Zyyy"),
+    (0x1D515, "Zzzz"),
+    (0x1D516, "Zyyy"),
+    (0x1D51D, "Zzzz"),
+    (0x1D51E, "Zyyy"),
+    (0x1D53A, "Zzzz"),
+    (0x1D53B, "Zyyy"),
+    (0x1D53F, "Zzzz"),
+    (0x1D540, "Zyyy"),
+    (0x1D545, "Zzzz"),
+    (0x1D546, "Zyyy"),
+    (0x1D547, "Zzzz"),
+    (0x1D54A, "Zyyy"),
+    (0x1D551, "Zzzz"),
+    (0x1D552, "Zyyy"),
+    (0x1D6A6, "Zzzz"),
+    (0x1D6A8, "Zyyy"),
+    (0x1D7CC, "Zzzz"),
+    (0x1D7CE, "Zyyy"),
+    (0x1D800, "Sgnw"),
+    (0x1DA8C, "Zzzz"),
+    (0x1DA9B, "Sgnw"),
+    (0x1DAA0, "Zzzz"),
+    (0x1DAA1, "Sgnw"),
+    (0x1DAB0, "Zzzz"),
+    (0x1DF00, "Latn"),
+    (0x1DF1F, "Zzzz"),
+    (0x1E000, "Glag"),
+    (0x1E007, "Zzzz"),
+    (0x1E008, "Glag"),
+    (0x1E019, "Zzzz"),
+    (0x1E01B, "Glag"),
+    (0x1E022, "Zzzz"),
+    (0x1E023, "Glag"),
+    (0x1E025, "Zzzz"),
+    (0x1E026, "Glag"),
+    (0x1E02B, "Zzzz"),
+    (0x1E100, "Hmnp"),
+    (0x1E12D, "Zzzz"),
+    (0x1E130, "Hmnp"),
+    (0x1E13E, "Zzzz"),
+    (0x1E140, "Hmnp"),
+    (0x1E14A, "Zzzz"),
+    (0x1E14E, "Hmnp"),
+    (0x1E150, "Zzzz"),
+    (0x1E290, "Toto"),
+    (0x1E2AF, "Zzzz"),
+    (0x1E2C0, "Wcho"),
+    (0x1E2FA, "Zzzz"),
+    (0x1E2FF, "Wcho"),
+    (0x1E300, "Zzzz"),
+    (0x1E7E0, "Ethi"),
+    (0x1E7E7, "Zzzz"),
+    (0x1E7E8, "Ethi"),
+    (0x1E7EC, "Zzzz"),
+    (0x1E7ED, "Ethi"),
+    (0x1E7EF, "Zzzz"),
+    (0x1E7F0, "Ethi"),
+    (0x1E7FF, "Zzzz"),
+    (0x1E800, "Mend"),
+    (0x1E8C5, "Zzzz"),
+    (0x1E8C7, "Mend"),
+    (0x1E8D7, "Zzzz"),
+    (0x1E900, "Adlm"),
+    (0x1E94C, "Zzzz"),
+    (0x1E950, "Adlm"),
+    (0x1E95A, "Zzzz"),
+    (0x1E95E, "Adlm"),
+    (0x1E960, "Zzzz"),
+    (0x1EC71, "Zyyy"),
+    (0x1ECB5, "Zzzz"),
+    (0x1ED01, "Zyyy"),
+    (0x1ED3E, "Zzzz"),
+    (0x1EE00, "Arab"),
+    (0x1EE04, "Zzzz"),
+    (0x1EE05, "Arab"),
+    (0x1EE20, "Zzzz"),
+    (0x1EE21, "Arab"),
+    (0x1EE23, "Zzzz"),
+    (0x1EE24, "Arab"),
+    (0x1EE25, "Zzzz"),
+    (0x1EE27, "Arab"),
+    (0x1EE28, "Zzzz"),
+    (0x1EE29, "Arab"),
+    (0x1EE33, "Zzzz"),
+    (0x1EE34, "Arab"),
+    (0x1EE38, "Zzzz"),
+    (0x1EE39, "Arab"),
+    (0x1EE3A, "Zzzz"),
+    (0x1EE3B, "Arab"),
+    (0x1EE3C, "Zzzz"),
+    (0x1EE42, "Arab"),
+    (0x1EE43, "Zzzz"),
+    (0x1EE47, "Arab"),
+    (0x1EE48, "Zzzz"),
+    (0x1EE49, "Arab"),
+    (0x1EE4A, "Zzzz"),
+    (0x1EE4B, "Arab"),
+    (0x1EE4C, "Zzzz"),
+    (0x1EE4D, "Arab"),
+    (0x1EE50, "Zzzz"),
+    (0x1EE51, "Arab"),
+    (0x1EE53, "Zzzz"),
+    (0x1EE54, "Arab"),
+    (0x1EE55, "Zzzz"),
+    (0x1EE57, "Arab"),
+    (0x1EE58, "Zzzz"),
+    (0x1EE59, "Arab"),
+    (0x1EE5A, "Zzzz"),
+    (0x1EE5B, "Arab"),
+    (0x1EE5C, "Zzzz"),
+    (0x1EE5D, "Arab"),
+    (0x1EE5E, "Zzzz"),
+    (0x1EE5F, "Arab"),
+    (0x1EE60, "Zzzz"),
+    (0x1EE61, "Arab"),
+    (0x1EE63, "Zzzz"),
+    (0x1EE64, "Arab"),
+    (0x1EE65, "Zzzz"),
+    (0x1EE67, "Arab"),
+    (0x1EE6B, "Zzzz"),
+    (0x1EE6C, "Arab"),
+    (0x1EE73, "Zzzz"),
+    (0x1EE74, "Arab"),
+    (0x1EE78, "Zzzz"),
+    (0x1EE79, "Arab"),
+    (0x1EE7D, "Zzzz"),
+    (0x1EE7E, "Arab"),
+    (0x1EE7F, "Zzzz"),
+    (0x1EE80, "Arab"),
+    (0x1EE8A, "Zzzz"),
+    (0x1EE8B, "Arab"),
+    (0x1EE9C, "Zzzz"),
+    (0x1EEA1, "Arab"),
+    (0x1EEA4, "Zzzz"),
+    (0x1EEA5, "Arab"),
+    (0x1EEAA, "Zzzz"),
+    (0x1EEAB, "Arab"),
+    (0x1EEBC, "Zzzz"),
+    (0x1EEF0, "Arab"),
+    (0x1EEF2, "Zzzz"),
+    (0x1F000, "Zyyy"),
+    (0x1F02C, "Zzzz"),
+    (0x1F030, "Zyyy"),
+    (0x1F094, "Zzzz"),
+    (0x1F0A0, "Zyyy"),
+    (0x1F0AF, "Zzzz"),
+    (0x1F0B1, "Zyyy"),
+    (0x1F0C0, "Zzzz"),
+    (0x1F0C1, "Zyyy"),
+    (0x1F0D0, "Zzzz"),
+    (0x1F0D1, "Zyyy"),
+    (0x1F0F6, "Zzzz"),
+    (0x1F100, "Zyyy"),
+    (0x1F1AE, "Zzzz"),
+    (0x1F1E6, "Zyyy"),
+    (0x1F200, "Hira"),
+    (0x1F201, "Zyyy"),
+    (0x1F203, "Zzzz"),
+    (0x1F210, "Zyyy"),
+    (0x1F23C, "Zzzz"),
+    (0x1F240, "Zyyy"),
+    (0x1F249, "Zzzz"),
+    (0x1F250, "Zyyy"),
+    (0x1F252, "Zzzz"),
+    (0x1F260, "Zyyy"),
+    (0x1F266, "Zzzz"),
+    (0x1F300, "Zyyy"),
+    (0x1F6D8, "Zzzz"),
+    (0x1F6DD, "Zyyy"),
+    (0x1F6ED, "Zzzz"),
+    (0x1F6F0, "Zyyy"),
+    (0x1F6FD, "Zzzz"),
+    (0x1F700, "Zyyy"),
+    (0x1F774, "Zzzz"),
+    (0x1F780, "Zyyy"),
+    (0x1F7D9, "Zzzz"),
+    (0x1F7E0, "Zyyy"),
+    (0x1F7EC, "Zzzz"),
+    (0x1F7F0, "Zyyy"),
+    (0x1F7F1, "Zzzz"),
+    (0x1F800, "Zyyy"),
+    (0x1F80C, "Zzzz"),
+    (0x1F810, "Zyyy"),
+    (0x1F848, "Zzzz"),
+    (0x1F850, "Zyyy"),
+    (0x1F85A, "Zzzz"),
+    (0x1F860, "Zyyy"),
+    (0x1F888, "Zzzz"),
+    (0x1F890, "Zyyy"),
+    (0x1F8AE, "Zzzz"),
+    (0x1F8B0, "Zyyy"),
+    (0x1F8B2, "Zzzz"),
+    (0x1F900, "Zyyy"),
+    (0x1FA54, "Zzzz"),
+    (0x1FA60, "Zyyy"),
+    (0x1FA6E, "Zzzz"),
+    (0x1FA70, "Zyyy"),
+    (0x1FA75, "Zzzz"),
+    (0x1FA78, "Zyyy"),
+    (0x1FA7D, "Zzzz"),
+    (0x1FA80, "Zyyy"),
+    (0x1FA87, "Zzzz"),
+    (0x1FA90, "Zyyy"),
+    (0x1FAAD, "Zzzz"),
+    (0x1FAB0, "Zyyy"),
+    (0x1FABB, "Zzzz"),
+    (0x1FAC0, "Zyyy"),
+    (0x1FAC6, "Zzzz"),
+    (0x1FAD0, "Zyyy"),
+    (0x1FADA, "Zzzz"),
+    (0x1FAE0, "Zyyy"),
+    (0x1FAE8, "Zzzz"),
+    (0x1FAF0, "Zyyy"),
+    (0x1FAF7, "Zzzz"),
+    (0x1FB00, "Zyyy"),
+    (0x1FB93, "Zzzz"),
+    (0x1FB94, "Zyyy"),
+    (0x1FBCB, "Zzzz"),
+    (0x1FBF0, "Zyyy"),
+    (0x1FBFA, "Zzzz"),
+    (0x20000, "Hani"),
+    (0x2A6E0, "Zzzz"),
+    (0x2A700, "Hani"),
+    (0x2B739, "Zzzz"),
+    (0x2B740, "Hani"),
+    (0x2B81E, "Zzzz"),
+    (0x2B820, "Hani"),
+    (0x2CEA2, "Zzzz"),
+    (0x2CEB0, "Hani"),
+    (0x2EBE1, "Zzzz"),
+    (0x2F800, "Hani"),
+    (0x2FA1E, "Zzzz"),
+    (0x30000, "Hani"),
+    (0x3134B, "Zzzz"),
+    (0xE0001, "Zyyy"),
+    (0xE0002, "Zzzz"),
+    (0xE0020, "Zyyy"),
+    (0xE0080, "Zzzz"),
+    (0xE0100, "Zinh"),
+    (0xE01F0, "Zzzz"),
+];
+
+#[rustfmt::skip]
+pub(super) static JOINING_TYPE: &[(u32, JoiningType)] = &[
+    (0x0000, NonJoining),
+    (0x00AD, Transparent),
+    (0x00AE, NonJoining),
+    (0x0300, Transparent),
+    (0x0370, NonJoining),
+    (0x0483, Transparent),
+    (0x048A, NonJoining),
+    (0x0591, Transparent),
+    (0x05BE, NonJoining),
+    (0x05BF, Transparent),
+    (0x05C0, NonJoining),
+    (0x05C1, Transparent),
+    (0x05C3, NonJoining),
+    (0x05C4, Transparent),
+    (0x05C6, NonJoining),
+    (0x05C7, Transparent),
+    (0x05C8, NonJoining),
+    (0x0610, Transparent),
+    (0x061B, NonJoining),
+    (0x061C, Transparent),
+    (0x061D, NonJoining),
+    (0x0620, DualJoining),
+    (0x0621, NonJoining),
+    (0x0622, RightJoining),
+    (0x0626, DualJoining),
+    (0x0627, RightJoining),
+    (0x0628, DualJoining),
+    (0x0629, RightJoining),
+    (0x062A, DualJoining),
+    (0x062F, RightJoining),
+    (0x0633, DualJoining),
+    (0x0640, JoinCausing),
+    (0x0641, DualJoining),
+    (0x0648, RightJoining),
+    (0x0649, DualJoining),
+    (0x064B, Transparent),
+    (0x0660, NonJoining),
+    (0x066E, DualJoining),
+    (0x0670, Transparent),
+    (0x0671, RightJoining),
+    (0x0674, NonJoining),
+    (0x0675, RightJoining),
+    (0x0678, DualJoining),
+    (0x0688, RightJoining),
+    (0x069A, DualJoining),
+    (0x06C0, RightJoining),
+    (0x06C1, DualJoining),
+    (0x06C3, RightJoining),
+    (0x06CC, DualJoining),
+    (0x06CD, RightJoining),
+    (0x06CE, DualJoining),
+    (0x06CF, RightJoining),
+    (0x06D0, DualJoining),
+    (0x06D2, RightJoining),
+    (0x06D4, NonJoining),
+    (0x06D5, RightJoining),
+    (0x06D6, Transparent),
+    (0x06DD, NonJoining),
+    (0x06DF, Transparent),
+    (0x06E5, NonJoining),
+    (0x06E7, Transparent),
+    (0x06E9, NonJoining),
+    (0x06EA, Transparent),
+    (0x06EE, RightJoining),
+    (0x06F0, NonJoining),
+    (0x06FA, DualJoining),
+    (0x06FD, NonJoining),
+    (0x06FF, DualJoining),
+    (0x0700, NonJoining),
+    (0x070F, Transparent),
+    (0x0710, RightJoining),
+    (0x0711, Transparent),
+    (0x0712, DualJoining),
+    (0x0715, RightJoining),
+    (0x071A, DualJoining),
+    (0x071E, RightJoining),
+    (0x071F, DualJoining),
+    (0x0728, RightJoining),
+    (0x0729, DualJoining),
+    (0x072A, RightJoining),
+    (0x072B, DualJoining),
+    (0x072C, RightJoining),
+    (0x072D, DualJoining),
+    (0x072F, RightJoining),
+    (0x0730, Transparent),
+    (0x074B, NonJoining),
+    (0x074D, RightJoining),
+    (0x074E, DualJoining),
+    (0x0759, RightJoining),
+    (0x075C, DualJoining),
+    (0x076B, RightJoining),
+    (0x076D, DualJoining),
+    (0x0771, RightJoining),
+    (0x0772, DualJoining),
+    (0x0773, RightJoining),
+    (0x0775, DualJoining),
+    (0x0778, RightJoining),
+    (0x077A, DualJoining),
+    (0x0780, NonJoining),
+    (0x07A6, Transparent),
+    (0x07B1, NonJoining),
+    (0x07CA, DualJoining),
+    (0x07EB, Transparent),
+    (0x07F4, NonJoining),
+    (0x07FA, JoinCausing),
+    (0x07FB, NonJoining),
+    (0x07FD, Transparent),
+    (0x07FE, NonJoining),
+    (0x0816, Transparent),
+    (0x081A, NonJoining),
+    (0x081B, Transparent),
+    (0x0824, NonJoining),
+    (0x0825, Transparent),
+    (0x0828, NonJoining),
+    (0x0829, Transparent),
+    (0x082E, NonJoining),
+    (0x0840, RightJoining),
+    (0x0841, DualJoining),
+    (0x0846, RightJoining),
+    (0x0848, DualJoining),
+    (0x0849, RightJoining),
+    (0x084A, DualJoining),
+    (0x0854, RightJoining),
+    (0x0855, DualJoining),
+    (0x0856, RightJoining),
+    (0x0859, Transparent),
+    (0x085C, NonJoining),
+    (0x0860, DualJoining),
+    (0x0861, NonJoining),
+    (0x0862, DualJoining),
+    (0x0866, NonJoining),
+    (0x0867, RightJoining),
+    (0x0868, DualJoining),
+    (0x0869, RightJoining),
+    (0x086B, NonJoining),
+    (0x0870, RightJoining),
+    (0x0883, JoinCausing),
+    (0x0886, DualJoining),
+    (0x0887, NonJoining),
+    (0x0889, DualJoining),
+    (0x088E, RightJoining),
+    (0x088F, NonJoining),
+    (0x0898, Transparent),
+    (0x08A0, DualJoining),
+    (0x08AA, RightJoining),
+    (0x08AD, NonJoining),
+    (0x08AE, RightJoining),
+    (0x08AF, DualJoining),
+    (0x08B1, RightJoining),
+    (0x08B3, DualJoining),
+    (0x08B9, RightJoining),
+    (0x08BA, DualJoining),
+    (0x08C9, NonJoining),
+    (0x08CA, Transparent),
+    (0x08E2, NonJoining),
+    (0x08E3, Transparent),
+    (0x0903, NonJoining),
+    (0x093A, Transparent),
+    (0x093B, NonJoining),
+    (0x093C, Transparent),
+    (0x093D, NonJoining),
+    (0x0941, Transparent),
+    (0x0949, NonJoining),
+    (0x094D, Transparent),
+    (0x094E, NonJoining),
+    (0x0951, Transparent),
+    (0x0958, NonJoining),
+    (0x0962, Transparent),
+    (0x0964, NonJoining),
+    (0x0981, Transparent),
+    (0x0982, NonJoining),
+    (0x09BC, Transparent),
+    (0x09BD, NonJoining),
+    (0x09C1, Transparent),
+    (0x09C5, NonJoining),
+    (0x09CD, Transparent),
+    (0x09CE, NonJoining),
+    (0x09E2, Transparent),
+    (0x09E4, NonJoining),
+    (0x09FE, Transparent),
+    (0x09FF, NonJoining),
+    (0x0A01, Transparent),
+    (0x0A03, NonJoining),
+    (0x0A3C, Transparent),
+    (0x0A3D, NonJoining),
+    (0x0A41, Transparent),
+    (0x0A43, NonJoining),
+    (0x0A47, Transparent),
+    (0x0A49, NonJoining),
+    (0x0A4B, Transparent),
+    (0x0A4E, NonJoining),
+    (0x0A51, Transparent),
+    (0x0A52, NonJoining),
+    (0x0A70, Transparent),
+    (0x0A72, NonJoining),
+    (0x0A75, Transparent),
+    (0x0A76, NonJoining),
+    (0x0A81, Transparent),
+    (0x0A83, NonJoining),
+    (0x0ABC, Transparent),
+    (0x0ABD, NonJoining),
+    (0x0AC1, Transparent),
+    (0x0AC6, NonJoining),
+    (0x0AC7, Transparent),
+    (0x0AC9, NonJoining),
+    (0x0ACD, Transparent),
+    (0x0ACE, NonJoining),
+    (0x0AE2, Transparent),
+    (0x0AE4, NonJoining),
+    (0x0AFA, Transparent),
+    (0x0B00, NonJoining),
+    (0x0B01, Transparent),
+    (0x0B02, NonJoining),
+    (0x0B3C, Transparent),
+    (0x0B3D, NonJoining),
+    (0x0B3F, Transparent),
+    (0x0B40, NonJoining),
+    (0x0B41, Transparent),
+    (0x0B45, NonJoining),
+    (0x0B4D, Transparent),
+    (0x0B4E, NonJoining),
+    (0x0B55, Transparent),
+    (0x0B57, NonJoining),
+    (0x0B62, Transparent),
+    (0x0B64, NonJoining),
+    (0x0B82, Transparent),
+    (0x0B83, NonJoining),
+    (0x0BC0, Transparent),
+    (0x0BC1, NonJoining),
+    (0x0BCD, Transparent),
+    (0x0BCE, NonJoining),
+    (0x0C00, Transparent),
+    (0x0C01, NonJoining),
+    (0x0C04, Transparent),
+    (0x0C05, NonJoining),
+    (0x0C3C, Transparent),
+    (0x0C3D, NonJoining),
+    (0x0C3E, Transparent),
+    (0x0C41, NonJoining),
+    (0x0C46, Transparent),
+    (0x0C49, NonJoining),
+    (0x0C4A, Transparent),
+    (0x0C4E, NonJoining),
+    (0x0C55, Transparent),
+    (0x0C57, NonJoining),
+    (0x0C62, Transparent),
+    (0x0C64, NonJoining),
+    (0x0C81, Transparent),
+    (0x0C82, NonJoining),
+    (0x0CBC, Transparent),
+    (0x0CBD, NonJoining),
+    (0x0CBF, Transparent),
+    (0x0CC0, NonJoining),
+    (0x0CC6, Transparent),
+    (0x0CC7, NonJoining),
+    (0x0CCC, Transparent),
+    (0x0CCE, NonJoining),
+    (0x0CE2, Transparent),
+    (0x0CE4, NonJoining),
+    (0x0D00, Transparent),
+    (0x0D02, NonJoining),
+    (0x0D3B, Transparent),
+    (0x0D3D, NonJoining),
+    (0x0D41, Transparent),
+    (0x0D45, NonJoining),
+    (0x0D4D, Transparent),
+    (0x0D4E, NonJoining),
+    (0x0D62, Transparent),
+    (0x0D64, NonJoining),
+    (0x0D81, Transparent),
+    (0x0D82, NonJoining),
+    (0x0DCA, Transparent),
+    (0x0DCB, NonJoining),
+    (0x0DD2, Transparent),
+    (0x0DD5, NonJoining),
+    (0x0DD6, Transparent),
+    (0x0DD7, NonJoining),
+    (0x0E31, Transparent),
+    (0x0E32, NonJoining),
+    (0x0E34, Transparent),
+    (0x0E3B, NonJoining),
+    (0x0E47, Transparent),
+    (0x0E4F, NonJoining),
+    (0x0EB1, Transparent),
+    (0x0EB2, NonJoining),
+    (0x0EB4, Transparent),
+    (0x0EBD, NonJoining),
+    (0x0EC8, Transparent),
+    (0x0ECE, NonJoining),
+    (0x0F18, Transparent),
+    (0x0F1A, NonJoining),
+    (0x0F35, Transparent),
+    (0x0F36, NonJoining),
+    (0x0F37, Transparent),
+    (0x0F38, NonJoining),
+    (0x0F39, Transparent),
+    (0x0F3A, NonJoining),
+    (0x0F71, Transparent),
+    (0x0F7F, NonJoining),
+    (0x0F80, Transparent),
+    (0x0F85, NonJoining),
+    (0x0F86, Transparent),
+    (0x0F88, NonJoining),
+    (0x0F8D, Transparent),
+    (0x0F98, NonJoining),
+    (0x0F99, Transparent),
+    (0x0FBD, NonJoining),
+    (0x0FC6, Transparent),
+    (0x0FC7, NonJoining),
+    (0x102D, Transparent),
+    (0x1031, NonJoining),
+    (0x1032, Transparent),
+    (0x1038, NonJoining),
+    (0x1039, Transparent),
+    (0x103B, NonJoining),
+    (0x103D, Transparent),
+    (0x103F, NonJoining),
+    (0x1058, Transparent),
+    (0x105A, NonJoining),
+    (0x105E, Transparent),
+    (0x1061, NonJoining),
+    (0x1071, Transparent),
+    (0x1075, NonJoining),
+    (0x1082, Transparent),
+    (0x1083, NonJoining),
+    (0x1085, Transparent),
+    (0x1087, NonJoining),
+    (0x108D, Transparent),
+    (0x108E, NonJoining),
+    (0x109D, Transparent),
+    (0x109E, NonJoining),
+    (0x135D, Transparent),
+    (0x1360, NonJoining),
+    (0x1712, Transparent),
+    (0x1715, NonJoining),
+    (0x1732, Transparent),
+    (0x1734, NonJoining),
+    (0x1752, Transparent),
+    (0x1754, NonJoining),
+    (0x1772, Transparent),
+    (0x1774, NonJoining),
+    (0x17B4, Transparent),
+    (0x17B6, NonJoining),
+    (0x17B7, Transparent),
+    (0x17BE, NonJoining),
+    (0x17C6, Transparent),
+    (0x17C7, NonJoining),
+    (0x17C9, Transparent),
+    (0x17D4, NonJoining),
+    (0x17DD, Transparent),
+    (0x17DE, NonJoining),
+    (0x1807, DualJoining),
+    (0x1808, NonJoining),
+    (0x180A, JoinCausing),
+    (0x180B, Transparent),
+    (0x180E, NonJoining),
+    (0x180F, Transparent),
+    (0x1810, NonJoining),
+    (0x1820, DualJoining),
+    (0x1879, NonJoining),
+    (0x1885, Transparent),
+    (0x1887, DualJoining),
+    (0x18A9, Transparent),
+    (0x18AA, DualJoining),
+    (0x18AB, NonJoining),
+    (0x1920, Transparent),
+    (0x1923, NonJoining),
+    (0x1927, Transparent),
+    (0x1929, NonJoining),
+    (0x1932, Transparent),
+    (0x1933, NonJoining),
+    (0x1939, Transparent),
+    (0x193C, NonJoining),
+    (0x1A17, Transparent),
+    (0x1A19, NonJoining),
+    (0x1A1B, Transparent),
+    (0x1A1C, NonJoining),
+    (0x1A56, Transparent),
+    (0x1A57, NonJoining),
+    (0x1A58, Transparent),
+    (0x1A5F, NonJoining),
+    (0x1A60, Transparent),
+    (0x1A61, NonJoining),
+    (0x1A62, Transparent),
+    (0x1A63, NonJoining),
+    (0x1A65, Transparent),
+    (0x1A6D, NonJoining),
+    (0x1A73, Transparent),
+    (0x1A7D, NonJoining),
+    (0x1A7F, Transparent),
+    (0x1A80, NonJoining),
+    (0x1AB0, Transparent),
+    (0x1ACF, NonJoining),
+    (0x1B00, Transparent),
+    (0x1B04, NonJoining),
+    (0x1B34, Transparent),
+    (0x1B35, NonJoining),
+    (0x1B36, Transparent),
+    (0x1B3B, NonJoining),
+    (0x1B3C, Transparent),
+    (0x1B3D, NonJoining),
+    (0x1B42, Transparent),
+    (0x1B43, NonJoining),
+    (0x1B6B, Transparent),
+    (0x1B74, NonJoining),
+    (0x1B80, Transparent),
+    (0x1B82, NonJoining),
+    (0x1BA2, Transparent),
+    (0x1BA6, NonJoining),
+    (0x1BA8, Transparent),
+    (0x1BAA, NonJoining),
+    (0x1BAB, Transparent),
+    (0x1BAE, NonJoining),
+    (0x1BE6, Transparent),
+    (0x1BE7, NonJoining),
+    (0x1BE8, Transparent),
+    (0x1BEA, NonJoining),
+    (0x1BED, Transparent),
+    (0x1BEE, NonJoining),
+    (0x1BEF, Transparent),
+    (0x1BF2, NonJoining),
+    (0x1C2C, Transparent),
+    (0x1C34, NonJoining),
+    (0x1C36, Transparent),
+    (0x1C38, NonJoining),
+    (0x1CD0, Transparent),
+    (0x1CD3, NonJoining),
+    (0x1CD4, Transparent),
+    (0x1CE1, NonJoining),
+    (0x1CE2, Transparent),
+    (0x1CE9, NonJoining),
+    (0x1CED, Transparent),
+    (0x1CEE, NonJoining),
+    (0x1CF4, Transparent),
+    (0x1CF5, NonJoining),
+    (0x1CF8, Transparent),
+    (0x1CFA, NonJoining),
+    (0x1DC0, Transparent),
+    (0x1E00, NonJoining),
+    (0x200B, Transparent),
+    (0x200C, NonJoining),
+    (0x200D, JoinCausing),
+    (0x200E, Transparent),
+    (0x2010, NonJoining),
+    (0x202A, Transparent),
+    (0x202F, NonJoining),
+    (0x2060, Transparent),
+    (0x2065, NonJoining),
+    (0x206A, Transparent),
+    (0x2070, NonJoining),
+    (0x20D0, Transparent),
+    (0x20F1, NonJoining),
+    (0x2CEF, Transparent),
+    (0x2CF2, NonJoining),
+    (0x2D7F, Transparent),
+    (0x2D80, NonJoining),
+    (0x2DE0, Transparent),
+    (0x2E00, NonJoining),
+    (0x302A, Transparent),
+    (0x302E, NonJoining),
+    (0x3099, Transparent),
+    (0x309B, NonJoining),
+    (0xA66F, Transparent),
+    (0xA673, NonJoining),
+    (0xA674, Transparent),
+    (0xA67E, NonJoining),
+    (0xA69E, Transparent),
+    (0xA6A0, NonJoining),
+    (0xA6F0, Transparent),
+    (0xA6F2, NonJoining),
+    (0xA802, Transparent),
+    (0xA803, NonJoining),
+    (0xA806, Transparent),
+    (0xA807, NonJoining),
+    (0xA80B, Transparent),
+    (0xA80C, NonJoining),
+    (0xA825, Transparent),
+    (0xA827, NonJoining),
+    (0xA82C, Transparent),
+    (0xA82D, NonJoining),
+    (0xA840, DualJoining),
+    (0xA872, LeftJoining),
+    (0xA873, NonJoining),
+    (0xA8C4, Transparent),
+    (0xA8C6, NonJoining),
+    (0xA8E0, Transparent),
+    (0xA8F2, NonJoining),
+    (0xA8FF, Transparent),
+    (0xA900, NonJoining),
+    (0xA926, Transparent),
+    (0xA92E, NonJoining),
+    (0xA947, Transparent),
+    (0xA952, NonJoining),
+    (0xA980, Transparent),
+    (0xA983, NonJoining),
+    (0xA9B3, Transparent),
+    (0xA9B4, NonJoining),
+    (0xA9B6, Transparent),
+    (0xA9BA, NonJoining),
+    (0xA9BC, Transparent),
+    (0xA9BE, NonJoining),
+    (0xA9E5, Transparent),
+    (0xA9E6, NonJoining),
+    (0xAA29, Transparent),
+    (0xAA2F, NonJoining),
+    (0xAA31, Transparent),
+    (0xAA33, NonJoining),
+    (0xAA35, Transparent),
+    (0xAA37, NonJoining),
+    (0xAA43, Transparent),
+    (0xAA44, NonJoining),
+    (0xAA4C, Transparent),
+    (0xAA4D, NonJoining),
+    (0xAA7C, Transparent),
+    (0xAA7D, NonJoining),
+    (0xAAB0, Transparent),
+    (0xAAB1, NonJoining),
+    (0xAAB2, Transparent),
+    (0xAAB5, NonJoining),
+    (0xAAB7, Transparent),
+    (0xAAB9, NonJoining),
+    (0xAABE, Transparent),
+    (0xAAC0, NonJoining),
+    (0xAAC1, Transparent),
+    (0xAAC2, NonJoining),
+    (0xAAEC, Transparent),
+    (0xAAEE, NonJoining),
+    (0xAAF6, Transparent),
+    (0xAAF7, NonJoining),
+    (0xABE5, Transparent),
+    (0xABE6, NonJoining),
+    (0xABE8, Transparent),
+    (0xABE9, NonJoining),
+    (0xABED, Transparent),
+    (0xABEE, NonJoining),
+    (0xFB1E, Transparent),
+    (0xFB1F, NonJoining),
+    (0xFE00, Transparent),
+    (0xFE10, NonJoining),
+    (0xFE20, Transparent),
+    (0xFE30, NonJoining),
+    (0xFEFF, Transparent),
+    (0xFF00, NonJoining),
+    (0xFFF9, Transparent),
+    (0xFFFC, NonJoining),
+    (0x101FD, Transparent),
+    (0x101FE, NonJoining),
+    (0x102E0, Transparent),
+    (0x102E1, NonJoining),
+    (0x10376, Transparent),
+    (0x1037B, NonJoining),
+    (0x10A01, Transparent),
+    (0x10A04, NonJoining),
+    (0x10A05, Transparent),
+    (0x10A07, NonJoining),
+    (0x10A0C, Transparent),
+    (0x10A10, NonJoining),
+    (0x10A38, Transparent),
+    (0x10A3B, NonJoining),
+    (0x10A3F, Transparent),
+    (0x10A40, NonJoining),
+    (0x10AC0, DualJoining),
+    (0x10AC5, RightJoining),
+    (0x10AC6, NonJoining),
+    (0x10AC7, RightJoining),
+    (0x10AC8, NonJoining),
+    (0x10AC9, RightJoining),
+    (0x10ACB, NonJoining),
+    (0x10ACD, LeftJoining),
+    (0x10ACE, RightJoining),
+    (0x10AD3, DualJoining),
+    (0x10AD7, LeftJoining),
+    (0x10AD8, DualJoining),
+    (0x10ADD, RightJoining),
+    (0x10ADE, DualJoining),
+    (0x10AE1, RightJoining),
+    (0x10AE2, NonJoining),
+    (0x10AE4, RightJoining),
+    (0x10AE5, Transparent),
+    (0x10AE7, NonJoining),
+    (0x10AEB, DualJoining),
+    (0x10AEF, RightJoining),
+    (0x10AF0, NonJoining),
+    (0x10B80, DualJoining),
+    (0x10B81, RightJoining),
+    (0x10B82, DualJoining),
+    (0x10B83, RightJoining),
+    (0x10B86, DualJoining),
+    (0x10B89, RightJoining),
+    (0x10B8A, DualJoining),
+    (0x10B8C, RightJoining),
+    (0x10B8D, DualJoining),
+    (0x10B8E, RightJoining),
+    (0x10B90, DualJoining),
+    (0x10B91, RightJoining),
+    (0x10B92, NonJoining),
+    (0x10BA9, RightJoining),
+    (0x10BAD, DualJoining),
+    (0x10BAF, NonJoining),
+    (0x10D00, LeftJoining),
+    (0x10D01, DualJoining),
+    (0x10D22, RightJoining),
+    (0x10D23, DualJoining),
+    (0x10D24, Transparent),
+    (0x10D28, NonJoining),
+    (0x10EAB, Transparent),
+    (0x10EAD, NonJoining),
+    (0x10F30, DualJoining),
+    (0x10F33, RightJoining),
+    (0x10F34, DualJoining),
+    (0x10F45, NonJoining),
+    (0x10F46, Transparent),
+    (0x10F51, DualJoining),
+    (0x10F54, RightJoining),
+    (0x10F55, NonJoining),
+    (0x10F70, DualJoining),
+    (0x10F74, RightJoining),
+    (0x10F76, DualJoining),
+    (0x10F82, Transparent),
+    (0x10F86, NonJoining),
+    (0x10FB0, DualJoining),
+    (0x10FB1, NonJoining),
+    (0x10FB2, DualJoining),
+    (0x10FB4, RightJoining),
+    (0x10FB7, NonJoining),
+    (0x10FB8, DualJoining),
+    (0x10FB9, RightJoining),
+    (0x10FBB, DualJoining),
+    (0x10FBD, RightJoining),
+    (0x10FBE, DualJoining),
+    (0x10FC0, NonJoining),
+    (0x10FC1, DualJoining),
+    (0x10FC2, RightJoining),
+    (0x10FC4, DualJoining),
+    (0x10FC5, NonJoining),
+    (0x10FC9, RightJoining),
+    (0x10FCA, DualJoining),
+    (0x10FCB, LeftJoining),
+    (0x10FCC, NonJoining),
+    (0x11001, Transparent),
+    (0x11002, NonJoining),
+    (0x11038, Transparent),
+    (0x11047, NonJoining),
+    (0x11070, Transparent),
+    (0x11071, NonJoining),
+    (0x11073, Transparent),
+    (0x11075, NonJoining),
+    (0x1107F, Transparent),
+    (0x11082, NonJoining),
+    (0x110B3, Transparent),
+    (0x110B7, NonJoining),
+    (0x110B9, Transparent),
+    (0x110BB, NonJoining),
+    (0x110C2, Transparent),
+    (0x110C3, NonJoining),
+    (0x11100, Transparent),
+    (0x11103, NonJoining),
+    (0x11127, Transparent),
+    (0x1112C, NonJoining),
+    (0x1112D, Transparent),
+    (0x11135, NonJoining),
+    (0x11173, Transparent),
+    (0x11174, NonJoining),
+    (0x11180, Transparent),
+    (0x11182, NonJoining),
+    (0x111B6, Transparent),
+    (0x111BF, NonJoining),
+    (0x111C9, Transparent),
+    (0x111CD, NonJoining),
+    (0x111CF, Transparent),
+    (0x111D0, NonJoining),
+    (0x1122F, Transparent),
+    (0x11232, NonJoining),
+    (0x11234, Transparent),
+    (0x11235, NonJoining),
+    (0x11236, Transparent),
+    (0x11238, NonJoining),
+    (0x1123E, Transparent),
+    (0x1123F, NonJoining),
+    (0x112DF, Transparent),
+    (0x112E0, NonJoining),
+    (0x112E3, Transparent),
+    (0x112EB, NonJoining),
+    (0x11300, Transparent),
+    (0x11302, NonJoining),
+    (0x1133B, Transparent),
+    (0x1133D, NonJoining),
+    (0x11340, Transparent),
+    (0x11341, NonJoining),
+    (0x11366, Transparent),
+    (0x1136D, NonJoining),
+    (0x11370, Transparent),
+    (0x11375, NonJoining),
+    (0x11438, Transparent),
+    (0x11440, NonJoining),
+    (0x11442, Transparent),
+    (0x11445, NonJoining),
+    (0x11446, Transparent),
+    (0x11447, NonJoining),
+    (0x1145E, Transparent),
+    (0x1145F, NonJoining),
+    (0x114B3, Transparent),
+    (0x114B9, NonJoining),
+    (0x114BA, Transparent),
+    (0x114BB, NonJoining),
+    (0x114BF, Transparent),
+    (0x114C1, NonJoining),
+    (0x114C2, Transparent),
+    (0x114C4, NonJoining),
+    (0x115B2, Transparent),
+    (0x115B6, NonJoining),
+    (0x115BC, Transparent),
+    (0x115BE, NonJoining),
+    (0x115BF, Transparent),
+    (0x115C1, NonJoining),
+    (0x115DC, Transparent),
+    (0x115DE, NonJoining),
+    (0x11633, Transparent),
+    (0x1163B, NonJoining),
+    (0x1163D, Transparent),
+    (0x1163E, NonJoining),
+    (0x1163F, Transparent),
+    (0x11641, NonJoining),
+    (0x116AB, Transparent),
+    (0x116AC, NonJoining),
+    (0x116AD, Transparent),
+    (0x116AE, NonJoining),
+    (0x116B0, Transparent),
+    (0x116B6, NonJoining),
+    (0x116B7, Transparent),
+    (0x116B8, NonJoining),
+    (0x1171D, Transparent),
+    (0x11720, NonJoining),
+    (0x11722, Transparent),
+    (0x11726, NonJoining),
+    (0x11727, Transparent),
+    (0x1172C, NonJoining),
+    (0x1182F, Transparent),
+    (0x11838, NonJoining),
+    (0x11839, Transparent),
+    (0x1183B, NonJoining),
+    (0x1193B, Transparent),
+    (0x1193D, NonJoining),
+    (0x1193E, Transparent),
+    (0x1193F, NonJoining),
+    (0x11943, Transparent),
+    (0x11944, NonJoining),
+    (0x119D4, Transparent),
+    (0x119D8, NonJoining),
+    (0x119DA, Transparent),
+    (0x119DC, NonJoining),
+    (0x119E0, Transparent),
+    (0x119E1, NonJoining),
+    (0x11A01, Transparent),
+    (0x11A0B, NonJoining),
+    (0x11A33, Transparent),
+    (0x11A39, NonJoining),
+    (0x11A3B, Transparent),
+    (0x11A3F, NonJoining),
+    (0x11A47, Transparent),
+    (0x11A48, NonJoining),
+    (0x11A51, Transparent),
+    (0x11A57, NonJoining),
+    (0x11A59, Transparent),
+    (0x11A5C, NonJoining),
+    (0x11A8A, Transparent),
+    (0x11A97, NonJoining),
+    (0x11A98, Transparent),
+    (0x11A9A, NonJoining),
+    (0x11C30, Transparent),
+    (0x11C37, NonJoining),
+    (0x11C38, Transparent),
+    (0x11C3E, NonJoining),
+    (0x11C3F, Transparent),
+    (0x11C40, NonJoining),
+    (0x11C92, Transparent),
+    (0x11CA8, NonJoining),
+    (0x11CAA, Transparent),
+    (0x11CB1, NonJoining),
+    (0x11CB2, Transparent),
+    (0x11CB4, NonJoining),
+    (0x11CB5, Transparent),
+    (0x11CB7, NonJoining),
+    (0x11D31, Transparent),
+    (0x11D37, NonJoining),
+    (0x11D3A, Transparent),
+    (0x11D3B, NonJoining),
+    (0x11D3C, Transparent),
+    (0x11D3E, NonJoining),
+    (0x11D3F, Transparent),
+    (0x11D46, NonJoining),
+    (0x11D47, Transparent),
+    (0x11D48, NonJoining),
+    (0x11D90, Transparent),
+    (0x11D92, NonJoining),
+    (0x11D95, Transparent),
+    (0x11D96, NonJoining),
+    (0x11D97, Transparent),
+    (0x11D98, NonJoining),
+    (0x11EF3, Transparent),
+    (0x11EF5, NonJoining),
+    (0x13430, Transparent),
+    (0x13439, NonJoining),
+    (0x16AF0, Transparent),
+    (0x16AF5, NonJoining),
+    (0x16B30, Transparent),
+    (0x16B37, NonJoining),
+    (0x16F4F, Transparent),
+    (0x16F50, NonJoining),
+    (0x16F8F, Transparent),
+    (0x16F93, NonJoining),
+    (0x16FE4, Transparent),
+    (0x16FE5, NonJoining),
+    (0x1BC9D, Transparent),
+    (0x1BC9F, NonJoining),
+    (0x1BCA0, Transparent),
+    (0x1BCA4, NonJoining),
+    (0x1CF00, Transparent),
+    (0x1CF2E, NonJoining),
+    (0x1CF30, Transparent),
+    (0x1CF47, NonJoining),
+    (0x1D167, Transparent),
+    (0x1D16A, NonJoining),
+    (0x1D173, Transparent),
+    (0x1D183, NonJoining),
+    (0x1D185, Transparent),
+    (0x1D18C, NonJoining),
+    (0x1D1AA, Transparent),
+    (0x1D1AE, NonJoining),
+    (0x1D242, Transparent),
+    (0x1D245, NonJoining),
+    (0x1DA00, Transparent),
+    (0x1DA37, NonJoining),
+    (0x1DA3B, Transparent),
+    (0x1DA6D, NonJoining),
+    (0x1DA75, Transparent),
+    (0x1DA76, NonJoining),
+    (0x1DA84, Transparent),
+    (0x1DA85, NonJoining),
+    (0x1DA9B, Transparent),
+    (0x1DAA0, NonJoining),
+    (0x1DAA1, Transparent),
+    (0x1DAB0, NonJoining),
+    (0x1E000, Transparent),
+    (0x1E007, NonJoining),
+    (0x1E008, Transparent),
+    (0x1E019, NonJoining),
+    (0x1E01B, Transparent),
+    (0x1E022, NonJoining),
+    (0x1E023, Transparent),
+    (0x1E025, NonJoining),
+    (0x1E026, Transparent),
+    (0x1E02B, NonJoining),
+    (0x1E130, Transparent),
+    (0x1E137, NonJoining),
+    (0x1E2AE, Transparent),
+    (0x1E2AF, NonJoining),
+    (0x1E2EC, Transparent),
+    (0x1E2F0, NonJoining),
+    (0x1E8D0, Transparent),
+    (0x1E8D7, NonJoining),
+    (0x1E900, DualJoining),
+    (0x1E944, Transparent),
+    (0x1E94C, NonJoining),
+    (0xE0001, Transparent),
+    (0xE0002, NonJoining),
+    (0xE0020, Transparent),
+    (0xE0080, NonJoining),
+    (0xE0100, Transparent),
+    (0xE01F0, NonJoining),
+];
+
+#[rustfmt::skip]
+pub(super) static JOINING_GROUP: &[(u32, Option<&str>)] = &[
+    (0x0000, None),
+    (0x0620, Some("Yeh")),
+    (0x0621, None),
+    (0x0622, Some("Alef")),
+    (0x0624, Some("Waw")),
+    (0x0625, Some("Alef")),
+    (0x0626, Some("Yeh")),
+    (0x0627, Some("Alef")),
+    (0x0628, Some("Beh")),
+    (0x0629, Some("Teh_Marbuta")),
+    (0x062A, Some("Beh")),
+    (0x062C, Some("Hah")),
+    (0x062F, Some("Dal")),
+    (0x0631, Some("Reh")),
+    (0x0633, Some("Seen")),
+    (0x0635, Some("Sad")),
+    (0x0637, Some("Tah")),
+    (0x0639, Some("Ain")),
+    (0x063B, Some("Gaf")),
+    (0x063D, Some("Farsi_Yeh")),
+    (0x0640, None),
+    (0x0641, Some("Feh")),
+    (0x0642, Some("Qaf")),
+    (0x0643, Some("Kaf")),
+    (0x0644, Some("Lam")),
+    (0x0645, Some("Meem")),
+    (0x0646, Some("Noon")),
+    (0x0647, Some("Heh")),
+    (0x0648, Some("Waw")),
+    (0x0649, Some("Yeh")),
+    (0x064B, None),
+    (0x066E, Some("Beh")),
+    (0x066F, Some("Qaf")),
+    (0x0670, None),
+    (0x0671, Some("Alef")),
+    (0x0674, None),
+    (0x0675, Some("Alef")),
+    (0x0676, Some("Waw")),
+    (0x0678, Some("Yeh")),
+    (0x0679, Some("Beh")),
+    (0x0681, Some("Hah")),
+    (0x0688, Some("Dal")),
+    (0x0691, Some("Reh")),
+    (0x069A, Some("Seen")),
+    (0x069D, Some("Sad")),
+    (0x069F, Some("Tah")),
+    (0x06A0, Some("Ain")),
+    (0x06A1, Some("Feh")),
+    (0x06A7, Some("Qaf")),
+    (0x06A9, Some("Gaf")),
+    (0x06AA, Some("Swash_Kaf")),
+    (0x06AB, Some("Gaf")),
+    (0x06AC, Some("Kaf")),
+    (0x06AF, Some("Gaf")),
+    (0x06B5, Some("Lam")),
+    (0x06B9, Some("Noon")),
+    (0x06BD, Some("Nya")),
+    (0x06BE, Some("Knotted_Heh")),
+    (0x06BF, Some("Hah")),
+    (0x06C0, Some("Teh_Marbuta")),
+    (0x06C1, Some("Heh_Goal")),
+    (0x06C3, Some("Teh_Marbuta_Goal")),
+    (0x06C4, Some("Waw")),
+    (0x06CC, Some("Farsi_Yeh")),
+    (0x06CD, Some("Yeh_With_Tail")),
+    (0x06CE, Some("Farsi_Yeh")),
+    (0x06CF, Some("Waw")),
+    (0x06D0, Some("Yeh")),
+    (0x06D2, Some("Yeh_Barree")),
+    (0x06D4, None),
+    (0x06D5, Some("Teh_Marbuta")),
+    (0x06D6, None),
+    (0x06EE, Some("Dal")),
+    (0x06EF, Some("Reh")),
+    (0x06F0, None),
+    (0x06FA, Some("Seen")),
+    (0x06FB, Some("Sad")),
+    (0x06FC, Some("Ain")),
+    (0x06FD, None),
+    (0x06FF, Some("Knotted_Heh")),
+    (0x0700, None),
+    (0x0710, Some("Alaph")),
+    (0x0711, None),
+    (0x0712, Some("Beth")),
+    (0x0713, Some("Gamal")),
+    (0x0715, Some("Dalath_Rish")),
+    (0x0717, Some("He")),
+    (0x0718, Some("Syriac_Waw")),
+    (0x0719, Some("Zain")),
+    (0x071A, Some("Heth")),
+    (0x071B, Some("Teth")),
+    (0x071D, Some("Yudh")),
+    (0x071E, Some("Yudh_He")),
+    (0x071F, Some("Kaph")),
+    (0x0720, Some("Lamadh")),
+    (0x0721, Some("Mim")),
+    (0x0722, Some("Nun")),
+    (0x0723, Some("Semkath")),
+    (0x0724, Some("Final_Semkath")),
+    (0x0725, Some("E")),
+    (0x0726, Some("Pe")),
+    (0x0727, Some("Reversed_Pe")),
+    (0x0728, Some("Sadhe")),
+    (0x0729, Some("Qaph")),
+    (0x072A, Some("Dalath_Rish")),
+    (0x072B, Some("Shin")),
+    (0x072C, Some("Taw")),
+    (0x072D, Some("Beth")),
+    (0x072E, Some("Gamal")),
+    (0x072F, Some("Dalath_Rish")),
+    (0x0730, None),
+    (0x074D, Some("Zhain")),
+    (0x074E, Some("Khaph")),
+    (0x074F, Some("Fe")),
+    (0x0750, Some("Beh")),
+    (0x0757, Some("Hah")),
+    (0x0759, Some("Dal")),
+    (0x075B, Some("Reh")),
+    (0x075C, Some("Seen")),
+    (0x075D, Some("Ain")),
+    (0x0760, Some("Feh")),
+    (0x0762, Some("Gaf")),
+    (0x0765, Some("Meem")),
+    (0x0767, Some("Noon")),
+    (0x076A, Some("Lam")),
+    (0x076B, Some("Reh")),
+    (0x076D, Some("Seen")),
+    (0x076E, Some("Hah")),
+    (0x0770, Some("Seen")),
+    (0x0771, Some("Reh")),
+    (0x0772, Some("Hah")),
+    (0x0773, Some("Alef")),
+    (0x0775, Some("Farsi_Yeh")),
+    (0x0777, Some("Yeh")),
+    (0x0778, Some("Waw")),
+    (0x077A, Some("Burushaski_Yeh_Barree")),
+    (0x077C, Some("Hah")),
+    (0x077D, Some("Seen")),
+    (0x077F, Some("Kaf")),
+    (0x0780, None),
+    (0x0860, Some("Malayalam_Nga")),
+    (0x0861, Some("Malayalam_Ja")),
+    (0x0862, Some("Malayalam_Nya")),
+    (0x0863, Some("Malayalam_Tta")),
+    (0x0864, Some("Malayalam_Nna")),
+    (0x0865, Some("Malayalam_Nnna")),
+    (0x0866, Some("Malayalam_Bha")),
+    (0x0867, Some("Malayalam_Ra")),
+    (0x0868, Some("Malayalam_Lla")),
+    (0x0869, Some("Malayalam_Llla")),
+    (0x086A, Some("Malayalam_Ssa")),
+    (0x086B, None),
+    (0x0870, Some("Alef")),
+    (0x0883, None),
+    (0x0886, Some("Thin_Yeh")),
+    (0x0887, None),
+    (0x0889, Some("Noon")),
+    (0x088A, Some("Hah")),
+    (0x088B, Some("Tah")),
+    (0x088D, Some("Gaf")),
+    (0x088E, Some("Vertical_Tail")),
+    (0x088F, None),
+    (0x08A0, Some("Beh")),
+    (0x08A2, Some("Hah")),
+    (0x08A3, Some("Tah")),
+    (0x08A4, Some("Feh")),
+    (0x08A5, Some("Qaf")),
+    (0x08A6, Some("Lam")),
+    (0x08A7, Some("Meem")),
+    (0x08A8, Some("Yeh")),
+    (0x08AA, Some("Reh")),
+    (0x08AB, Some("Waw")),
+    (0x08AC, Some("Rohingya_Yeh")),
+    (0x08AD, None),
+    (0x08AE, Some("Dal")),
+    (0x08AF, Some("Sad")),
+    (0x08B0, Some("Gaf")),
+    (0x08B1, Some("Straight_Waw")),
+    (0x08B2, Some("Reh")),
+    (0x08B3, Some("Ain")),
+    (0x08B4, Some("Kaf")),
+    (0x08B5, Some("Qaf")),
+    (0x08B6, Some("Beh")),
+    (0x08B9, Some("Reh")),
+    (0x08BA, Some("Yeh")),
+    (0x08BB, Some("African_Feh")),
+    (0x08BC, Some("African_Qaf")),
+    (0x08BD, Some("African_Noon")),
+    (0x08BE, Some("Beh")),
+    (0x08C1, Some("Hah")),
+    (0x08C2, Some("Gaf")),
+    (0x08C3, Some("Ain")),
+    (0x08C4, Some("African_Qaf")),
+    (0x08C5, Some("Hah")),
+    (0x08C7, Some("Lam")),
+    (0x08C8, Some("Gaf")),
+    (0x08C9, None),
+    (0x10AC0, Some("Manichaean_Aleph")),
+    (0x10AC1, Some("Manichaean_Beth")),
+    (0x10AC3, Some("Manichaean_Gimel")),
+    (0x10AC5, Some("Manichaean_Daleth")),
+    (0x10AC6, None),
+    (0x10AC7, Some("Manichaean_Waw")),
+    (0x10AC8, None),
+    (0x10AC9, Some("Manichaean_Zayin")),
+    (0x10ACB, None),
+    (0x10ACD, Some("Manichaean_Heth")),
+    (0x10ACE, Some("Manichaean_Teth")),
+    (0x10ACF, Some("Manichaean_Yodh")),
+    (0x10AD0, Some("Manichaean_Kaph")),
+    (0x10AD3, Some("Manichaean_Lamedh")),
+    (0x10AD4, Some("Manichaean_Dhamedh")),
+    (0x10AD5, Some("Manichaean_Thamedh")),
+    (0x10AD6, Some("Manichaean_Mem")),
+    (0x10AD7, Some("Manichaean_Nun")),
+    (0x10AD8, Some("Manichaean_Samekh")),
+    (0x10AD9, Some("Manichaean_Ayin")),
+    (0x10ADB, Some("Manichaean_Pe")),
+    (0x10ADD, Some("Manichaean_Sadhe")),
+    (0x10ADE, Some("Manichaean_Qoph")),
+    (0x10AE1, Some("Manichaean_Resh")),
+    (0x10AE2, None),
+    (0x10AE4, Some("Manichaean_Taw")),
+    (0x10AE5, None),
+    (0x10AEB, Some("Manichaean_One")),
+    (0x10AEC, Some("Manichaean_Five")),
+    (0x10AED, Some("Manichaean_Ten")),
+    (0x10AEE, Some("Manichaean_Twenty")),
+    (0x10AEF, Some("Manichaean_Hundred")),
+    (0x10AF0, None),
+    (0x10D02, Some("Hanifi_Rohingya_Pa")),
+    (0x10D03, None),
+    (0x10D09, Some("Hanifi_Rohingya_Pa")),
+    (0x10D0A, None),
+    (0x10D19, Some("Hanifi_Rohingya_Kinna_Ya")),
+    (0x10D1A, None),
+    (0x10D1C, Some("Hanifi_Rohingya_Pa")),
+    (0x10D1D, None),
+    (0x10D1E, Some("Hanifi_Rohingya_Kinna_Ya")),
+    (0x10D1F, None),
+    (0x10D20, Some("Hanifi_Rohingya_Kinna_Ya")),
+    (0x10D21, None),
+    (0x10D23, Some("Hanifi_Rohingya_Kinna_Ya")),
+    (0x10D24, None),
+];
+
+#[rustfmt::skip]
+pub(super) static BIDI_CLASS: &[(u32, BidiClass)] = &[
+    (0x0000, BN),
+    (0x0009, S),
+    (0x000A, B),
+    (0x000B, S),
+    (0x000C, WS),
+    (0x000D, B),
+    (0x000E, BN),
+    (0x001C, B),
+    (0x001F, S),
+    (0x0020, WS),
+    (0x0021, ON),
+    (0x0023, ET),
+    (0x0026, ON),
+    (0x002B, ES),
+    (0x002C, CS),
+    (0x002D, ES),
+    (0x002E, CS),
+    (0x0030, EN),
+    (0x003A, CS),
+    (0x003B, ON),
+    (0x0041, L),
+    (0x005B, ON),
+    (0x0061, L),
+    (0x007B, ON),
+    (0x007F, BN),
+    (0x0085, B),
+    (0x0086, BN),
+    (0x00A0, CS),
+    (0x00A1, ON),
+    (0x00A2, ET),
+    (0x00A6, ON),
+    (0x00AA, L),
+    (0x00AB, ON),
+    (0x00AD, BN),
+    (0x00AE, ON),
+    (0x00B0, ET),
+    (0x00B2, EN),
+    (0x00B4, ON),
+    (0x00B5, L),
+    (0x00B6, ON),
+    (0x00B9, EN),
+    (0x00BA, L),
+    (0x00BB, ON),
+    (0x00C0, L),
+    (0x00D7, ON),
+    (0x00D8, L),
+    (0x00F7, ON),
+    (0x00F8, L),
+    (0x02B9, ON),
+    (0x02BB, L),
+    (0x02C2, ON),
+    (0x02D0, L),
+    (0x02D2, ON),
+    (0x02E0, L),
+    (0x02E5, ON),
+    (0x02EE, L),
+    (0x02EF, ON),
+    (0x0300, NSM),
+    (0x0370, L),
+    (0x0374, ON),
+    (0x0376, L),
+    (0x037E, ON),
+    (0x037F, L),
+    (0x0384, ON),
+    (0x0386, L),
+    (0x0387, ON),
+    (0x0388, L),
+    (0x03F6, ON),
+    (0x03F7, L),
+    (0x0483, NSM),
+    (0x048A, L),
+    (0x058A, ON),
+    (0x058B, L),
+    (0x058D, ON),
+    (0x058F, ET),
+    (0x0590, R),
+    (0x0591, NSM),
+    (0x05BE, R),
+    (0x05BF, NSM),
+    (0x05C0, R),
+    (0x05C1, NSM),
+    (0x05C3, R),
+    (0x05C4, NSM),
+    (0x05C6, R),
+    (0x05C7, NSM),
+    (0x05C8, R),
+    (0x0600, AN),
+    (0x0606, ON),
+    (0x0608, AL),
+    (0x0609, ET),
+    (0x060B, AL),
+    (0x060C, CS),
+    (0x060D, AL),
+    (0x060E, ON),
+    (0x0610, NSM),
+    (0x061B, AL),
+    (0x064B, NSM),
+    (0x0660, AN),
+    (0x066A, ET),
+    (0x066B, AN),
+    (0x066D, AL),
+    (0x0670, NSM),
+    (0x0671, AL),
+    (0x06D6, NSM),
+    (0x06DD, AN),
+    (0x06DE, ON),
+    (0x06DF, NSM),
+    (0x06E5, AL),
+    (0x06E7, NSM),
+    (0x06E9, ON),
+    (0x06EA, NSM),
+    (0x06EE, AL),
+    (0x06F0, EN),
+    (0x06FA, AL),
+    (0x0711, NSM),
+    (0x0712, AL),
+    (0x0730, NSM),
+    (0x074B, AL),
+    (0x07A6, NSM),
+    (0x07B1, AL),
+    (0x07C0, R),
+    (0x07EB, NSM),
+    (0x07F4, R),
+    (0x07F6, ON),
+    (0x07FA, R),
+    (0x07FD, NSM),
+    (0x07FE, R),
+    (0x0816, NSM),
+    (0x081A, R),
+    (0x081B, NSM),
+    (0x0824, R),
+    (0x0825, NSM),
+    (0x0828, R),
+    (0x0829, NSM),
+    (0x082E, R),
+    (0x0859, NSM),
+    (0x085C, R),
+    (0x0860, AL),
+    (0x0890, AN),
+    (0x0892, AL),
+    (0x0898, NSM),
+    (0x08A0, AL),
+    (0x08CA, NSM),
+    (0x08E2, AN),
+    (0x08E3, NSM),
+    (0x0903, L),
+    (0x093A, NSM),
+    (0x093B, L),
+    (0x093C, NSM),
+    (0x093D, L),
+    (0x0941, NSM),
+    (0x0949, L),
+    (0x094D, NSM),
+    (0x094E, L),
+    (0x0951, NSM),
+    (0x0958, L),
+    (0x0962, NSM),
+    (0x0964, L),
+    (0x0981, NSM),
+    (0x0982, L),
+    (0x09BC, NSM),
+    (0x09BD, L),
+    (0x09C1, NSM),
+    (0x09C5, L),
+    (0x09CD, NSM),
+    (0x09CE, L),
+    (0x09E2, NSM),
+    (0x09E4, L),
+    (0x09F2, ET),
+    (0x09F4, L),
+    (0x09FB, ET),
+    (0x09FC, L),
+    (0x09FE, NSM),
+    (0x09FF, L),
+    (0x0A01, NSM),
+    (0x0A03, L),
+    (0x0A3C, NSM),
+    (0x0A3D, L),
+    (0x0A41, NSM),
+    (0x0A43, L),
+    (0x0A47, NSM),
+    (0x0A49, L),
+    (0x0A4B, NSM),
+    (0x0A4E, L),
+    (0x0A51, NSM),
+    (0x0A52, L),
+    (0x0A70, NSM),
+    (0x0A72, L),
+    (0x0A75, NSM),
+    (0x0A76, L),
+    (0x0A81, NSM),
+    (0x0A83, L),
+    (0x0ABC, NSM),
+    (0x0ABD, L),
+    (0x0AC1, NSM),
+    (0x0AC6, L),
+    (0x0AC7, NSM),
+    (0x0AC9, L),
+    (0x0ACD, NSM),
+    (0x0ACE, L),
+    (0x0AE2, NSM),
+    (0x0AE4, L),
+    (0x0AF1, ET),
+    (0x0AF2, L),
+    (0x0AFA, NSM),
+    (0x0B00, L),
+    (0x0B01, NSM),
+    (0x0B02, L),
+    (0x0B3C, NSM),
+    (0x0B3D, L),
+    (0x0B3F, NSM),
+    (0x0B40, L),
+    (0x0B41, NSM),
+    (0x0B45, L),
+    (0x0B4D, NSM),
+    (0x0B4E, L),
+    (0x0B55, NSM),
+    (0x0B57, L),
+    (0x0B62, NSM),
+    (0x0B64, L),
+    (0x0B82, NSM),
+    (0x0B83, L),
+    (0x0BC0, NSM),
+    (0x0BC1, L),
+    (0x0BCD, NSM),
+    (0x0BCE, L),
+    (0x0BF3, ON),
+    (0x0BF9, ET),
+    (0x0BFA, ON),
+    (0x0BFB, L),
+    (0x0C00, NSM),
+    (0x0C01, L),
+    (0x0C04, NSM),
+    (0x0C05, L),
+    (0x0C3C, NSM),
+    (0x0C3D, L),
+    (0x0C3E, NSM),
+    (0x0C41, L),
+    (0x0C46, NSM),
+    (0x0C49, L),
+    (0x0C4A, NSM),
+    (0x0C4E, L),
+    (0x0C55, NSM),
+    (0x0C57, L),
+    (0x0C62, NSM),
+    (0x0C64, L),
+    (0x0C78, ON),
+    (0x0C7F, L),
+    (0x0C81, NSM),
+    (0x0C82, L),
+    (0x0CBC, NSM),
+    (0x0CBD, L),
+    (0x0CCC, NSM),
+    (0x0CCE, L),
+    (0x0CE2, NSM),
+    (0x0CE4, L),
+    (0x0D00, NSM),
+    (0x0D02, L),
+    (0x0D3B, NSM),
+    (0x0D3D, L),
+    (0x0D41, NSM),
+    (0x0D45, L),
+    (0x0D4D, NSM),
+    (0x0D4E, L),
+    (0x0D62, NSM),
+    (0x0D64, L),
+    (0x0D81, NSM),
+    (0x0D82, L),
+    (0x0DCA, NSM),
+    (0x0DCB, L),
+    (0x0DD2, NSM),
+    (0x0DD5, L),
+    (0x0DD6, NSM),
+    (0x0DD7, L),
+    (0x0E31, NSM),
+    (0x0E32, L),
+    (0x0E34, NSM),
+    (0x0E3B, L),
+    (0x0E3F, ET),
+    (0x0E40, L),
+    (0x0E47, NSM),
+    (0x0E4F, L),
+    (0x0EB1, NSM),
+    (0x0EB2, L),
+    (0x0EB4, NSM),
+    (0x0EBD, L),
+    (0x0EC8, NSM),
+    (0x0ECE, L),
+    (0x0F18, NSM),
+    (0x0F1A, L),
+    (0x0F35, NSM),
+    (0x0F36, L),
+    (0x0F37, NSM),
+    (0x0F38, L),
+    (0x0F39, NSM),
+    (0x0F3A, ON),
+    (0x0F3E, L),
+    (0x0F71, NSM),
+    (0x0F7F, L),
+    (0x0F80, NSM),
+    (0x0F85, L),
+    (0x0F86, NSM),
+    (0x0F88, L),
+    (0x0F8D, NSM),
+    (0x0F98, L),
+    (0x0F99, NSM),
+    (0x0FBD, L),
+    (0x0FC6, NSM),
+    (0x0FC7, L),
+    (0x102D, NSM),
+    (0x1031, L),
+    (0x1032, NSM),
+    (0x1038, L),
+    (0x1039, NSM),
+    (0x103B, L),
+    (0x103D, NSM),
+    (0x103F, L),
+    (0x1058, NSM),
+    (0x105A, L),
+    (0x105E, NSM),
+    (0x1061, L),
+    (0x1071, NSM),
+    (0x1075, L),
+    (0x1082, NSM),
+    (0x1083, L),
+    (0x1085, NSM),
+    (0x1087, L),
+    (0x108D, NSM),
+    (0x108E, L),
+    (0x109D, NSM),
+    (0x109E, L),
+    (0x135D, NSM),
+    (0x1360, L),
+    (0x1390, ON),
+    (0x139A, L),
+    (0x1400, ON),
+    (0x1401, L),
+    (0x1680, WS),
+    (0x1681, L),
+    (0x169B, ON),
+    (0x169D, L),
+    (0x1712, NSM),
+    (0x1715, L),
+    (0x1732, NSM),
+    (0x1734, L),
+    (0x1752, NSM),
+    (0x1754, L),
+    (0x1772, NSM),
+    (0x1774, L),
+    (0x17B4, NSM),
+    (0x17B6, L),
+    (0x17B7, NSM),
+    (0x17BE, L),
+    (0x17C6, NSM),
+    (0x17C7, L),
+    (0x17C9, NSM),
+    (0x17D4, L),
+    (0x17DB, ET),
+    (0x17DC, L),
+    (0x17DD, NSM),
+    (0x17DE, L),
+    (0x17F0, ON),
+    (0x17FA, L),
+    (0x1800, ON),
+    (0x180B, NSM),
+    (0x180E, BN),
+    (0x180F, NSM),
+    (0x1810, L),
+    (0x1885, NSM),
+    (0x1887, L),
+    (0x18A9, NSM),
+    (0x18AA, L),
+    (0x1920, NSM),
+    (0x1923, L),
+    (0x1927, NSM),
+    (0x1929, L),
+    (0x1932, NSM),
+    (0x1933, L),
+    (0x1939, NSM),
+    (0x193C, L),
+    (0x1940, ON),
+    (0x1941, L),
+    (0x1944, ON),
+    (0x1946, L),
+    (0x19DE, ON),
+    (0x1A00, L),
+    (0x1A17, NSM),
+    (0x1A19, L),
+    (0x1A1B, NSM),
+    (0x1A1C, L),
+    (0x1A56, NSM),
+    (0x1A57, L),
+    (0x1A58, NSM),
+    (0x1A5F, L),
+    (0x1A60, NSM),
+    (0x1A61, L),
+    (0x1A62, NSM),
+    (0x1A63, L),
+    (0x1A65, NSM),
+    (0x1A6D, L),
+    (0x1A73, NSM),
+    (0x1A7D, L),
+    (0x1A7F, NSM),
+    (0x1A80, L),
+    (0x1AB0, NSM),
+    (0x1ACF, L),
+    (0x1B00, NSM),
+    (0x1B04, L),
+    (0x1B34, NSM),
+    (0x1B35, L),
+    (0x1B36, NSM),
+    (0x1B3B, L),
+    (0x1B3C, NSM),
+    (0x1B3D, L),
+    (0x1B42, NSM),
+    (0x1B43, L),
+    (0x1B6B, NSM),
+    (0x1B74, L),
+    (0x1B80, NSM),
+    (0x1B82, L),
+    (0x1BA2, NSM),
+    (0x1BA6, L),
+    (0x1BA8, NSM),
+    (0x1BAA, L),
+    (0x1BAB, NSM),
+    (0x1BAE, L),
+    (0x1BE6, NSM),
+    (0x1BE7, L),
+    (0x1BE8, NSM),
+    (0x1BEA, L),
+    (0x1BED, NSM),
+    (0x1BEE, L),
+    (0x1BEF, NSM),
+    (0x1BF2, L),
+    (0x1C2C, NSM),
+    (0x1C34, L),
+    (0x1C36, NSM),
+    (0x1C38, L),
+    (0x1CD0, NSM),
+    (0x1CD3, L),
+    (0x1CD4, NSM),
+    (0x1CE1, L),
+    (0x1CE2, NSM),
+    (0x1CE9, L),
+    (0x1CED, NSM),
+    (0x1CEE, L),
+    (0x1CF4, NSM),
+    (0x1CF5, L),
+    (0x1CF8, NSM),
+    (0x1CFA, L),
+    (0x1DC0, NSM),
+    (0x1E00, L),
+    (0x1FBD, ON),
+    (0x1FBE, L),
+    (0x1FBF, ON),
+    (0x1FC2, L),
+    (0x1FCD, ON),
+    (0x1FD0, L),
+    (0x1FDD, ON),
+    (0x1FE0, L),
+    (0x1FED, ON),
+    (0x1FF0, L),
+    (0x1FFD, ON),
+    (0x1FFF, L),
+    (0x2000, WS),
+    (0x200B, BN),
+    (0x200E, L),
+    (0x200F, R),
+    (0x2010, ON),
+    (0x2028, WS),
+    (0x2029, B),
+    (0x202A, LRE),
+    (0x202B, RLE),
+    (0x202C, PDF),
+    (0x202D, LRO),
+    (0x202E, RLO),
+    (0x202F, CS),
+    (0x2030, ET),
+    (0x2035, ON),
+    (0x2044, CS),
+    (0x2045, ON),
+    (0x205F, WS),
+    (0x2060, BN),
+    (0x2066, LRI),
+    (0x2067, RLI),
+    (0x2068, FSI),
+    (0x2069, PDI),
+    (0x206A, BN),
+    (0x2070, EN),
+    (0x2071, L),
+    (0x2074, EN),
+    (0x207A, ES),
+    (0x207C, ON),
+    (0x207F, L),
+    (0x2080, EN),
+    (0x208A, ES),
+    (0x208C, ON),
+    (0x208F, L),
+    (0x20A0, ET),
+    (0x20D0, NSM),
+    (0x20F1, L),
+    (0x2100, ON),
+    (0x2102, L),
+    (0x2103, ON),
+    (0x2107, L),
+    (0x2108, ON),
+    (0x210A, L),
+    (0x2114, ON),
+    (0x2115, L),
+    (0x2116, ON),
+    (0x2119, L),
+    (0x211E, ON),
+    (0x2124, L),
+    (0x2125, ON),
+    (0x2126, L),
+    (0x2127, ON),
+    (0x2128, L),
+    (0x2129, ON),
+    (0x212A, L),
+    (0x212E, ET),
+    (0x212F, L),
+    (0x213A, ON),
+    (0x213C, L),
+    (0x2140, ON),
+    (0x2145, L),
+    (0x214A, ON),
+    (0x214E, L),
+    (0x2150, ON),
+    (0x2160, L),
+    (0x2189, ON),
+    (0x218C, L),
+    (0x2190, ON),
+    (0x2212, ES),
+    (0x2213, ET),
+    (0x2214, ON),
+    (0x2336, L),
+    (0x237B, ON),
+    (0x2395, L),
+    (0x2396, ON),
+    (0x2427, L),
+    (0x2440, ON),
+    (0x244B, L),
+    (0x2460, ON),
+    (0x2488, EN),
+    (0x249C, L),
+    (0x24EA, ON),
+    (0x26AC, L),
+    (0x26AD, ON),
+    (0x2800, L),
+    (0x2900, ON),
+    (0x2B74, L),
+    (0x2B76, ON),
+    (0x2B96, L),
+    (0x2B97, ON),
+    (0x2C00, L),
+    (0x2CE5, ON),
+    (0x2CEB, L),
+    (0x2CEF, NSM),
+    (0x2CF2, L),
+    (0x2CF9, ON),
+    (0x2D00, L),
+    (0x2D7F, NSM),
+    (0x2D80, L),
+    (0x2DE0, NSM),
+    (0x2E00, ON),
+    (0x2E5E, L),
+    (0x2E80, ON),
+    (0x2E9A, L),
+    (0x2E9B, ON),
+    (0x2EF4, L),
+    (0x2F00, ON),
+    (0x2FD6, L),
+    (0x2FF0, ON),
+    (0x2FFC, L),
+    (0x3000, WS),
+    (0x3001, ON),
+    (0x3005, L),
+    (0x3008, ON),
+    (0x3021, L),
+    (0x302A, NSM),
+    (0x302E, L),
+    (0x3030, ON),
+    (0x3031, L),
+    (0x3036, ON),
+    (0x3038, L),
+    (0x303D, ON),
+    (0x3040, L),
+    (0x3099, NSM),
+    (0x309B, ON),
+    (0x309D, L),
+    (0x30A0, ON),
+    (0x30A1, L),
+    (0x30FB, ON),
+    (0x30FC, L),
+    (0x31C0, ON),
+    (0x31E4, L),
+    (0x321D, ON),
+    (0x321F, L),
+    (0x3250, ON),
+    (0x3260, L),
+    (0x327C, ON),
+    (0x327F, L),
+    (0x32B1, ON),
+    (0x32C0, L),
+    (0x32CC, ON),
+    (0x32D0, L),
+    (0x3377, ON),
+    (0x337B, L),
+    (0x33DE, ON),
+    (0x33E0, L),
+    (0x33FF, ON),
+    (0x3400, L),
+    (0x4DC0, ON),
+    (0x4E00, L),
+    (0xA490, ON),
+    (0xA4C7, L),
+    (0xA60D, ON),
+    (0xA610, L),
+    (0xA66F, NSM),
+    (0xA673, ON),
+    (0xA674, NSM),
+    (0xA67E, ON),
+    (0xA680, L),
+    (0xA69E, NSM),
+    (0xA6A0, L),
+    (0xA6F0, NSM),
+    (0xA6F2, L),
+    (0xA700, ON),
+    (0xA722, L),
+    (0xA788, ON),
+    (0xA789, L),
+    (0xA802, NSM),
+    (0xA803, L),
+    (0xA806, NSM),
+    (0xA807, L),
+    (0xA80B, NSM),
+    (0xA80C, L),
+    (0xA825, NSM),
+    (0xA827, L),
+    (0xA828, ON),
+    (0xA82C, NSM),
+    (0xA82D, L),
+    (0xA838, ET),
+    (0xA83A, L),
+    (0xA874, ON),
+    (0xA878, L),
+    (0xA8C4, NSM),
+    (0xA8C6, L),
+    (0xA8E0, NSM),
+    (0xA8F2, L),
+    (0xA8FF, NSM),
+    (0xA900, L),
+    (0xA926, NSM),
+    (0xA92E, L),
+    (0xA947, NSM),
+    (0xA952, L),
+    (0xA980, NSM),
+    (0xA983, L),
+    (0xA9B3, NSM),
+    (0xA9B4, L),
+    (0xA9B6, NSM),
+    (0xA9BA, L),
+    (0xA9BC, NSM),
+    (0xA9BE, L),
+    (0xA9E5, NSM),
+    (0xA9E6, L),
+    (0xAA29, NSM),
+    (0xAA2F, L),
+    (0xAA31, NSM),
+    (0xAA33, L),
+    (0xAA35, NSM),
+    (0xAA37, L),
+    (0xAA43, NSM),
+    (0xAA44, L),
+    (0xAA4C, NSM),
+    (0xAA4D, L),
+    (0xAA7C, NSM),
+    (0xAA7D, L),
+    (0xAAB0, NSM),
+    (0xAAB1, L),
+    (0xAAB2, NSM),
+    (0xAAB5, L),
+    (0xAAB7, NSM),
+    (0xAAB9, L),
+    (0xAABE, NSM),
+    (0xAAC0, L),
+    (0xAAC1, NSM),
+    (0xAAC2, L),
+    (0xAAEC, NSM),
+    (0xAAEE, L),
+    (0xAAF6, NSM),
+    (0xAAF7, L),
+    (0xAB6A, ON),
+    (0xAB6C, L),
+    (0xABE5, NSM),
+    (0xABE6, L),
+    (0xABE8, NSM),
+    (0xABE9, L),
+    (0xABED, NSM),
+    (0xABEE, L),
+    (0xFB1D, R),
+    (0xFB1E, NSM),
+    (0xFB1F, R),
+    (0xFB29, ES),
+    (0xFB2A, R),
+    (0xFB50, AL),
+    (0xFD3E, ON),
+    (0xFD50, AL),
+    (0xFDCF, ON),
+    (0xFDD0, BN),
+    (0xFDF0, AL),
+    (0xFDFD, ON),
+    (0xFE00, NSM),
+    (0xFE10, ON),
+    (0xFE1A, L),
+    (0xFE20, NSM),
+    (0xFE30, ON),
+    (0xFE50, CS),
+    (0xFE51, ON),
+    (0xFE52, CS),
+    (0xFE53, L),
+    (0xFE54, ON),
+    (0xFE55, CS),
+    (0xFE56, ON),
+    (0xFE5F, ET),
+    (0xFE60, ON),
+    (0xFE62, ES),
+    (0xFE64, ON),
+    (0xFE67, L),
+    (0xFE68, ON),
+    (0xFE69, ET),
+    (0xFE6B, ON),
+    (0xFE6C, L),
+    (0xFE70, AL),
+    (0xFEFF, BN),
+    (0xFF00, L),
+    (0xFF01, ON),
+    (0xFF03, ET),
+    (0xFF06, ON),
+    (0xFF0B, ES),
+    (0xFF0C, CS),
+    (0xFF0D, ES),
+    (0xFF0E, CS),
+    (0xFF10, EN),
+    (0xFF1A, CS),
+    (0xFF1B, ON),
+    (0xFF21, L),
+    (0xFF3B, ON),
+    (0xFF41, L),
+    (0xFF5B, ON),
+    (0xFF66, L),
+    (0xFFE0, ET),
+    (0xFFE2, ON),
+    (0xFFE5, ET),
+    (0xFFE7, L),
+    (0xFFE8, ON),
+    (0xFFEF, L),
+    (0xFFF0, BN),
+    (0xFFF9, ON),
+    (0xFFFE, BN),
+    (0x10000, L),
+    (0x10101, ON),
+    (0x10102, L),
+    (0x10140, ON),
+    (0x1018D, L),
+    (0x10190, ON),
+    (0x1019D, L),
+    (0x101A0, ON),
+    (0x101A1, L),
+    (0x101FD, NSM),
+    (0x101FE, L),
+    (0x102E0, NSM),
+    (0x102E1, EN),
+    (0x102FC, L),
+    (0x10376, NSM),
+    (0x1037B, L),
+    (0x10800, R),
+    (0x1091F, ON),
+    (0x10920, R),
+    (0x10A01, NSM),
+    (0x10A04, R),
+    (0x10A05, NSM),
+    (0x10A07, R),
+    (0x10A0C, NSM),
+    (0x10A10, R),
+    (0x10A38, NSM),
+    (0x10A3B, R),
+    (0x10A3F, NSM),
+    (0x10A40, R),
+    (0x10AE5, NSM),
+    (0x10AE7, R),
+    (0x10B39, ON),
+    (0x10B40, R),
+    (0x10D00, AL),
+    (0x10D24, NSM),
+    (0x10D28, AL),
+    (0x10D30, AN),
+    (0x10D3A, AL),
+    (0x10D40, R),
+    (0x10E60, AN),
+    (0x10E7F, R),
+    (0x10EAB, NSM),
+    (0x10EAD, R),
+    (0x10F30, AL),
+    (0x10F46, NSM),
+    (0x10F51, AL),
+    (0x10F70, R),
+    (0x10F82, NSM),
+    (0x10F86, R),
+    (0x11000, L),
+    (0x11001, NSM),
+    (0x11002, L),
+    (0x11038, NSM),
+    (0x11047, L),
+    (0x11052, ON),
+    (0x11066, L),
+    (0x11070, NSM),
+    (0x11071, L),
+    (0x11073, NSM),
+    (0x11075, L),
+    (0x1107F, NSM),
+    (0x11082, L),
+    (0x110B3, NSM),
+    (0x110B7, L),
+    (0x110B9, NSM),
+    (0x110BB, L),
+    (0x110C2, NSM),
+    (0x110C3, L),
+    (0x11100, NSM),
+    (0x11103, L),
+    (0x11127, NSM),
+    (0x1112C, L),
+    (0x1112D, NSM),
+    (0x11135, L),
+    (0x11173, NSM),
+    (0x11174, L),
+    (0x11180, NSM),
+    (0x11182, L),
+    (0x111B6, NSM),
+    (0x111BF, L),
+    (0x111C9, NSM),
+    (0x111CD, L),
+    (0x111CF, NSM),
+    (0x111D0, L),
+    (0x1122F, NSM),
+    (0x11232, L),
+    (0x11234, NSM),
+    (0x11235, L),
+    (0x11236, NSM),
+    (0x11238, L),
+    (0x1123E, NSM),
+    (0x1123F, L),
+    (0x112DF, NSM),
+    (0x112E0, L),
+    (0x112E3, NSM),
+    (0x112EB, L),
+    (0x11300, NSM),
+    (0x11302, L),
+    (0x1133B, NSM),
+    (0x1133D, L),
+    (0x11340, NSM),
+    (0x11341, L),
+    (0x11366, NSM),
+    (0x1136D, L),
+    (0x11370, NSM),
+    (0x11375, L),
+    (0x11438, NSM),
+    (0x11440, L),
+    (0x11442, NSM),
+    (0x11445, L),
+    (0x11446, NSM),
+    (0x11447, L),
+    (0x1145E, NSM),
+    (0x1145F, L),
+    (0x114B3, NSM),
+    (0x114B9, L),
+    (0x114BA, NSM),
+    (0x114BB, L),
+    (0x114BF, NSM),
+    (0x114C1, L),
+    (0x114C2, NSM),
+    (0x114C4, L),
+    (0x115B2, NSM),
+    (0x115B6, L),
+    (0x115BC, NSM),
+    (0x115BE, L),
+    (0x115BF, NSM),
+    (0x115C1, L),
+    (0x115DC, NSM),
+    (0x115DE, L),
+    (0x11633, NSM),
+    (0x1163B, L),
+    (0x1163D, NSM),
+    (0x1163E, L),
+    (0x1163F, NSM),
+    (0x11641, L),
+    (0x11660, ON),
+    (0x1166D, L),
+    (0x116AB, NSM),
+    (0x116AC, L),
+    (0x116AD, NSM),
+    (0x116AE, L),
+    (0x116B0, NSM),
+    (0x116B6, L),
+    (0x116B7, NSM),
+    (0x116B8, L),
+    (0x1171D, NSM),
+    (0x11720, L),
+    (0x11722, NSM),
+    (0x11726, L),
+    (0x11727, NSM),
+    (0x1172C, L),
+    (0x1182F, NSM),
+    (0x11838, L),
+    (0x11839, NSM),
+    (0x1183B, L),
+    (0x1193B, NSM),
+    (0x1193D, L),
+    (0x1193E, NSM),
+    (0x1193F, L),
+    (0x11943, NSM),
+    (0x11944, L),
+    (0x119D4, NSM),
+    (0x119D8, L),
+    (0x119DA, NSM),
+    (0x119DC, L),
+    (0x119E0, NSM),
+    (0x119E1, L),
+    (0x11A01, NSM),
+    (0x11A07, L),
+    (0x11A09, NSM),
+    (0x11A0B, L),
+    (0x11A33, NSM),
+    (0x11A39, L),
+    (0x11A3B, NSM),
+    (0x11A3F, L),
+    (0x11A47, NSM),
+    (0x11A48, L),
+    (0x11A51, NSM),
+    (0x11A57, L),
+    (0x11A59, NSM),
+    (0x11A5C, L),
+    (0x11A8A, NSM),
+    (0x11A97, L),
+    (0x11A98, NSM),
+    (0x11A9A, L),
+    (0x11C30, NSM),
+    (0x11C37, L),
+    (0x11C38, NSM),
+    (0x11C3E, L),
+    (0x11C92, NSM),
+    (0x11CA8, L),
+    (0x11CAA, NSM),
+    (0x11CB1, L),
+    (0x11CB2, NSM),
+    (0x11CB4, L),
+    (0x11CB5, NSM),
+    (0x11CB7, L),
+    (0x11D31, NSM),
+    (0x11D37, L),
+    (0x11D3A, NSM),
+    (0x11D3B, L),
+    (0x11D3C, NSM),
+    (0x11D3E, L),
+    (0x11D3F, NSM),
+    (0x11D46, L),
+    (0x11D47, NSM),
+    (0x11D48, L),
+    (0x11D90, NSM),
+    (0x11D92, L),
+    (0x11D95, NSM),
+    (0x11D96, L),
+    (0x11D97, NSM),
+    (0x11D98, L),
+    (0x11EF3, NSM),
+    (0x11EF5, L),
+    (0x11FD5, ON),
+    (0x11FDD, ET),
+    (0x11FE1, ON),
+    (0x11FF2, L),
+    (0x16AF0, NSM),
+    (0x16AF5, L),
+    (0x16B30, NSM),
+    (0x16B37, L),
+    (0x16F4F, NSM),
+    (0x16F50, L),
+    (0x16F8F, NSM),
+    (0x16F93, L),
+    (0x16FE2, ON),
+    (0x16FE3, L),
+    (0x16FE4, NSM),
+    (0x16FE5, L),
+    (0x1BC9D, NSM),
+    (0x1BC9F, L),
+    (0x1BCA0, BN),
+    (0x1BCA4, L),
+    (0x1CF00, NSM),
+    (0x1CF2E, L),
+    (0x1CF30, NSM),
+    (0x1CF47, L),
+    (0x1D167, NSM),
+    (0x1D16A, L),
+    (0x1D173, BN),
+    (0x1D17B, NSM),
+    (0x1D183, L),
+    (0x1D185, NSM),
+    (0x1D18C, L),
+    (0x1D1AA, NSM),
+    (0x1D1AE, L),
+    (0x1D1E9, ON),
+    (0x1D1EB, L),
+    (0x1D200, ON),
+    (0x1D242, NSM),
+    (0x1D245, ON),
+    (0x1D246, L),
+    (0x1D300, ON),
+    (0x1D357, L),
+    (0x1D6DB, ON),
+    (0x1D6DC, L),
+    (0x1D715, ON),
+    (0x1D716, L),
+    (0x1D74F, ON),
+    (0x1D750, L),
+    (0x1D789, ON),
+    (0x1D78A, L),
+    (0x1D7C3, ON),
+    (0x1D7C4, L),
+    (0x1D7CE, EN),
+    (0x1D800, L),
+    (0x1DA00, NSM),
+    (0x1DA37, L),
+    (0x1DA3B, NSM),
+    (0x1DA6D, L),
+    (0x1DA75, NSM),
+    (0x1DA76, L),
+    (0x1DA84, NSM),
+    (0x1DA85, L),
+    (0x1DA9B, NSM),
+    (0x1DAA0, L),
+    (0x1DAA1, NSM),
+    (0x1DAB0, L),
+    (0x1E000, NSM),
+    (0x1E007, L),
+    (0x1E008, NSM),
+    (0x1E019, L),
+    (0x1E01B, NSM),
+    (0x1E022, L),
+    (0x1E023, NSM),
+    (0x1E025, L),
+    (0x1E026, NSM),
+    (0x1E02B, L),
+    (0x1E130, NSM),
+    (0x1E137, L),
+    (0x1E2AE, NSM),
+    (0x1E2AF, L),
+    (0x1E2EC, NSM),
+    (0x1E2F0, L),
+    (0x1E2FF, ET),
+    (0x1E300, L),
+    (0x1E800, R),
+    (0x1E8D0, NSM),
+    (0x1E8D7, R),
+    (0x1E944, NSM),
+    (0x1E94B, R),
+    (0x1EC70, AL),
+    (0x1ECC0, R),
+    (0x1ED00, AL),
+    (0x1ED50, R),
+    (0x1EE00, AL),
+    (0x1EEF0, ON),
+    (0x1EEF2, AL),
+    (0x1EF00, R),
+    (0x1F000, ON),
+    (0x1F02C, L),
+    (0x1F030, ON),
+    (0x1F094, L),
+    (0x1F0A0, ON),
+    (0x1F0AF, L),
+    (0x1F0B1, ON),
+    (0x1F0C0, L),
+    (0x1F0C1, ON),
+    (0x1F0D0, L),
+    (0x1F0D1, ON),
+    (0x1F0F6, L),
+    (0x1F100, EN),
+    (0x1F10B, ON),
+    (0x1F110, L),
+    (0x1F12F, ON),
+    (0x1F130, L),
+    (0x1F16A, ON),
+    (0x1F170, L),
+    (0x1F1AD, ON),
+    (0x1F1AE, L),
+    (0x1F260, ON),
+    (0x1F266, L),
+    (0x1F300, ON),
+    (0x1F6D8, L),
+    (0x1F6DD, ON),
+    (0x1F6ED, L),
+    (0x1F6F0, ON),
+    (0x1F6FD, L),
+    (0x1F700, ON),
+    (0x1F774, L),
+    (0x1F780, ON),
+    (0x1F7D9, L),
+    (0x1F7E0, ON),
+    (0x1F7EC, L),
+    (0x1F7F0, ON),
+    (0x1F7F1, L),
+    (0x1F800, ON),
+    (0x1F80C, L),
+    (0x1F810, ON),
+    (0x1F848, L),
+    (0x1F850, ON),
+    (0x1F85A, L),
+    (0x1F860, ON),
+    (0x1F888, L),
+    (0x1F890, ON),
+    (0x1F8AE, L),
+    (0x1F8B0, ON),
+    (0x1F8B2, L),
+    (0x1F900, ON),
+    (0x1FA54, L),
+    (0x1FA60, ON),
+    (0x1FA6E, L),
+    (0x1FA70, ON),
+    (0x1FA75, L),
+    (0x1FA78, ON),
+    (0x1FA7D, L),
+    (0x1FA80, ON),
+    (0x1FA87, L),
+    (0x1FA90, ON),
+    (0x1FAAD, L),
+    (0x1FAB0, ON),
+    (0x1FABB, L),
+    (0x1FAC0, ON),
+    (0x1FAC6, L),
+    (0x1FAD0, ON),
+    (0x1FADA, L),
+    (0x1FAE0, ON),
+    (0x1FAE8, L),
+    (0x1FAF0, ON),
+    (0x1FAF7, L),
+    (0x1FB00, ON),
+    (0x1FB93, L),
+    (0x1FB94, ON),
+    (0x1FBCB, L),
+    (0x1FBF0, EN),
+    (0x1FBFA, L),
+    (0x1FFFE, BN),
+    (0x20000, L),
+    (0x2FFFE, BN),
+    (0x30000, L),
+    (0x3FFFE, BN),
+    (0x40000, L),
+    (0x4FFFE, BN),
+    (0x50000, L),
+    (0x5FFFE, BN),
+    (0x60000, L),
+    (0x6FFFE, BN),
+    (0x70000, L),
+    (0x7FFFE, BN),
+    (0x80000, L),
+    (0x8FFFE, BN),
+    (0x90000, L),
+    (0x9FFFE, BN),
+    (0xA0000, L),
+    (0xAFFFE, BN),
+    (0xB0000, L),
+    (0xBFFFE, BN),
+    (0xC0000, L),
+    (0xCFFFE, BN),
+    (0xD0000, L),
+    (0xDFFFE, BN),
+    (0xE0100, NSM),
+    (0xE01F0, BN),
+    (0xE1000, L),
+    (0xEFFFE, BN),
+    (0xF0000, L),
+    (0xFFFFE, BN),
+    (0x100000, L),
+    (0x10FFFE, BN),
+    (0x110000, L),
+];
+
+#[rustfmt::skip]
+pub(super) static COMBINING_CLASS: &[(u32, u8)] = &[
+    (0x0000, 0),
+    (0x0300, 230),
+    (0x0315, 232),
+    (0x0316, 220),
+    (0x031A, 232),
+    (0x031B, 216),
+    (0x031C, 220),
+    (0x0321, 202),
+    (0x0323, 220),
+    (0x0327, 202),
+    (0x0329, 220),
+    (0x0334, 1),
+    (0x0339, 220),
+    (0x033D, 230),
+    (0x0345, 240),
+    (0x0346, 230),
+    (0x0347, 220),
+    (0x034A, 230),
+    (0x034D, 220),
+    (0x034F, 0),
+    (0x0350, 230),
+    (0x0353, 220),
+    (0x0357, 230),
+    (0x0358, 232),
+    (0x0359, 220),
+    (0x035B, 230),
+    (0x035C, 233),
+    (0x035D, 234),
+    (0x035F, 233),
+    (0x0360, 234),
+    (0x0362, 233),
+    (0x0363, 230),
+    (0x0370, 0),
+    (0x0483, 230),
+    (0x0488, 0),
+    (0x0591, 220),
+    (0x0592, 230),
+    (0x0596, 220),
+    (0x0597, 230),
+    (0x059A, 222),
+    (0x059B, 220),
+    (0x059C, 230),
+    (0x05A2, 220),
+    (0x05A8, 230),
+    (0x05AA, 220),
+    (0x05AB, 230),
+    (0x05AD, 222),
+    (0x05AE, 228),
+    (0x05AF, 230),
+    (0x05B0, 10),
+    (0x05B1, 11),
+    (0x05B2, 12),
+    (0x05B3, 13),
+    (0x05B4, 14),
+    (0x05B5, 15),
+    (0x05B6, 16),
+    (0x05B7, 17),
+    (0x05B8, 18),
+    (0x05B9, 19),
+    (0x05BB, 20),
+    (0x05BC, 21),
+    (0x05BD, 22),
+    (0x05BE, 0),
+    (0x05BF, 23),
+    (0x05C0, 0),
+    (0x05C1, 24),
+    (0x05C2, 25),
+    (0x05C3, 0),
+    (0x05C4, 230),
+    (0x05C5, 220),
+    (0x05C6, 0),
+    (0x05C7, 18),
+    (0x05C8, 0),
+    (0x0610, 230),
+    (0x0618, 30),
+    (0x0619, 31),
+    (0x061A, 32),
+    (0x061B, 0),
+    (0x064B, 27),
+    (0x064C, 28),
+    (0x064D, 29),
+    (0x064E, 30),
+    (0x064F, 31),
+    (0x0650, 32),
+    (0x0651, 33),
+    (0x0652, 34),
+    (0x0653, 230),
+    (0x0655, 220),
+    (0x0657, 230),
+    (0x065C, 220),
+    (0x065D, 230),
+    (0x065F, 220),
+    (0x0660, 0),
+    (0x0670, 35),
+    (0x0671, 0),
+    (0x06D6, 230),
+    (0x06DD, 0),
+    (0x06DF, 230),
+    (0x06E3, 220),
+    (0x06E4, 230),
+    (0x06E5, 0),
+    (0x06E7, 230),
+    (0x06E9, 0),
+    (0x06EA, 220),
+    (0x06EB, 230),
+    (0x06ED, 220),
+    (0x06EE, 0),
+    (0x0711, 36),
+    (0x0712, 0),
+    (0x0730, 230),
+    (0x0731, 220),
+    (0x0732, 230),
+    (0x0734, 220),
+    (0x0735, 230),
+    (0x0737, 220),
+    (0x073A, 230),
+    (0x073B, 220),
+    (0x073D, 230),
+    (0x073E, 220),
+    (0x073F, 230),
+    (0x0742, 220),
+    (0x0743, 230),
+    (0x0744, 220),
+    (0x0745, 230),
+    (0x0746, 220),
+    (0x0747, 230),
+    (0x0748, 220),
+    (0x0749, 230),
+    (0x074B, 0),
+    (0x07EB, 230),
+    (0x07F2, 220),
+    (0x07F3, 230),
+    (0x07F4, 0),
+    (0x07FD, 220),
+    (0x07FE, 0),
+    (0x0816, 230),
+    (0x081A, 0),
+    (0x081B, 230),
+    (0x0824, 0),
+    (0x0825, 230),
+    (0x0828, 0),
+    (0x0829, 230),
+    (0x082E, 0),
+    (0x0859, 220),
+    (0x085C, 0),
+    (0x0898, 230),
+    (0x0899, 220),
+    (0x089C, 230),
+    (0x08A0, 0),
+    (0x08CA, 230),
+    (0x08CF, 220),
+    (0x08D4, 230),
+    (0x08E2, 0),
+    (0x08E3, 220),
+    (0x08E4, 230),
+    (0x08E6, 220),
+    (0x08E7, 230),
+    (0x08E9, 220),
+    (0x08EA, 230),
+    (0x08ED, 220),
+    (0x08F0, 27),
+    (0x08F1, 28),
+    (0x08F2, 29),
+    (0x08F3, 230),
+    (0x08F6, 220),
+    (0x08F7, 230),
+    (0x08F9, 220),
+    (0x08FB, 230),
+    (0x0900, 0),
+    (0x093C, 7),
+    (0x093D, 0),
+    (0x094D, 9),
+    (0x094E, 0),
+    (0x0951, 230),
+    (0x0952, 220),
+    (0x0953, 230),
+    (0x0955, 0),
+    (0x09BC, 7),
+    (0x09BD, 0),
+    (0x09CD, 9),
+    (0x09CE, 0),
+    (0x09FE, 230),
+    (0x09FF, 0),
+    (0x0A3C, 7),
+    (0x0A3D, 0),
+    (0x0A4D, 9),
+    (0x0A4E, 0),
+    (0x0ABC, 7),
+    (0x0ABD, 0),
+    (0x0ACD, 9),
+    (0x0ACE, 0),
+    (0x0B3C, 7),
+    (0x0B3D, 0),
+    (0x0B4D, 9),
+    (0x0B4E, 0),
+    (0x0BCD, 9),
+    (0x0BCE, 0),
+    (0x0C3C, 7),
+    (0x0C3D, 0),
+    (0x0C4D, 9),
+    (0x0C4E, 0),
+    (0x0C55, 84),
+    (0x0C56, 91),
+    (0x0C57, 0),
+    (0x0CBC, 7),
+    (0x0CBD, 0),
+    (0x0CCD, 9),
+    (0x0CCE, 0),
+    (0x0D3B, 9),
+    (0x0D3D, 0),
+    (0x0D4D, 9),
+    (0x0D4E, 0),
+    (0x0DCA, 9),
+    (0x0DCB, 0),
+    (0x0E38, 103),
+    (0x0E3A, 9),
+    (0x0E3B, 0),
+    (0x0E48, 107),
+    (0x0E4C, 0),
+    (0x0EB8, 118),
+    (0x0EBA, 9),
+    (0x0EBB, 0),
+    (0x0EC8, 122),
+    (0x0ECC, 0),
+    (0x0F18, 220),
+    (0x0F1A, 0),
+    (0x0F35, 220),
+    (0x0F36, 0),
+    (0x0F37, 220),
+    (0x0F38, 0),
+    (0x0F39, 216),
+    (0x0F3A, 0),
+    (0x0F71, 129),
+    (0x0F72, 130),
+    (0x0F73, 0),
+    (0x0F74, 132),
+    (0x0F75, 0),
+    (0x0F7A, 130),
+    (0x0F7E, 0),
+    (0x0F80, 130),
+    (0x0F81, 0),
+    (0x0F82, 230),
+    (0x0F84, 9),
+    (0x0F85, 0),
+    (0x0F86, 230),
+    (0x0F88, 0),
+    (0x0FC6, 220),
+    (0x0FC7, 0),
+    (0x1037, 7),
+    (0x1038, 0),
+    (0x1039, 9),
+    (0x103B, 0),
+    (0x108D, 220),
+    (0x108E, 0),
+    (0x135D, 230),
+    (0x1360, 0),
+    (0x1714, 9),
+    (0x1716, 0),
+    (0x1734, 9),
+    (0x1735, 0),
+    (0x17D2, 9),
+    (0x17D3, 0),
+    (0x17DD, 230),
+    (0x17DE, 0),
+    (0x18A9, 228),
+    (0x18AA, 0),
+    (0x1939, 222),
+    (0x193A, 230),
+    (0x193B, 220),
+    (0x193C, 0),
+    (0x1A17, 230),
+    (0x1A18, 220),
+    (0x1A19, 0),
+    (0x1A60, 9),
+    (0x1A61, 0),
+    (0x1A75, 230),
+    (0x1A7D, 0),
+    (0x1A7F, 220),
+    (0x1A80, 0),
+    (0x1AB0, 230),
+    (0x1AB5, 220),
+    (0x1ABB, 230),
+    (0x1ABD, 220),
+    (0x1ABE, 0),
+    (0x1ABF, 220),
+    (0x1AC1, 230),
+    (0x1AC3, 220),
+    (0x1AC5, 230),
+    (0x1ACA, 220),
+    (0x1ACB, 230),
+    (0x1ACF, 0),
+    (0x1B34, 7),
+    (0x1B35, 0),
+    (0x1B44, 9),
+    (0x1B45, 0),
+    (0x1B6B, 230),
+    (0x1B6C, 220),
+    (0x1B6D, 230),
+    (0x1B74, 0),
+    (0x1BAA, 9),
+    (0x1BAC, 0),
+    (0x1BE6, 7),
+    (0x1BE7, 0),
+    (0x1BF2, 9),
+    (0x1BF4, 0),
+    (0x1C37, 7),
+    (0x1C38, 0),
+    (0x1CD0, 230),
+    (0x1CD3, 0),
+    (0x1CD4, 1),
+    (0x1CD5, 220),
+    (0x1CDA, 230),
+    (0x1CDC, 220),
+    (0x1CE0, 230),
+    (0x1CE1, 0),
+    (0x1CE2, 1),
+    (0x1CE9, 0),
+    (0x1CED, 220),
+    (0x1CEE, 0),
+    (0x1CF4, 230),
+    (0x1CF5, 0),
+    (0x1CF8, 230),
+    (0x1CFA, 0),
+    (0x1DC0, 230),
+    (0x1DC2, 220),
+    (0x1DC3, 230),
+    (0x1DCA, 220),
+    (0x1DCB, 230),
+    (0x1DCD, 234),
+    (0x1DCE, 214),
+    (0x1DCF, 220),
+    (0x1DD0, 202),
+    (0x1DD1, 230),
+    (0x1DF6, 232),
+    (0x1DF7, 228),
+    (0x1DF9, 220),
+    (0x1DFA, 218),
+    (0x1DFB, 230),
+    (0x1DFC, 233),
+    (0x1DFD, 220),
+    (0x1DFE, 230),
+    (0x1DFF, 220),
+    (0x1E00, 0),
+    (0x20D0, 230),
+    (0x20D2, 1),
+    (0x20D4, 230),
+    (0x20D8, 1),
+    (0x20DB, 230),
+    (0x20DD, 0),
+    (0x20E1, 230),
+    (0x20E2, 0),
+    (0x20E5, 1),
+    (0x20E7, 230),
+    (0x20E8, 220),
+    (0x20E9, 230),
+    (0x20EA, 1),
+    (0x20EC, 220),
+    (0x20F0, 230),
+    (0x20F1, 0),
+    (0x2CEF, 230),
+    (0x2CF2, 0),
+    (0x2D7F, 9),
+    (0x2D80, 0),
+    (0x2DE0, 230),
+    (0x2E00, 0),
+    (0x302A, 218),
+    (0x302B, 228),
+    (0x302C, 232),
+    (0x302D, 222),
+    (0x302E, 224),
+    (0x3030, 0),
+    (0x3099, 8),
+    (0x309B, 0),
+    (0xA66F, 230),
+    (0xA670, 0),
+    (0xA674, 230),
+    (0xA67E, 0),
+    (0xA69E, 230),
+    (0xA6A0, 0),
+    (0xA6F0, 230),
+    (0xA6F2, 0),
+    (0xA806, 9),
+    (0xA807, 0),
+    (0xA82C, 9),
+    (0xA82D, 0),
+    (0xA8C4, 9),
+    (0xA8C5, 0),
+    (0xA8E0, 230),
+    (0xA8F2, 0),
+    (0xA92B, 220),
+    (0xA92E, 0),
+    (0xA953, 9),
+    (0xA954, 0),
+    (0xA9B3, 7),
+    (0xA9B4, 0),
+    (0xA9C0, 9),
+    (0xA9C1, 0),
+    (0xAAB0, 230),
+    (0xAAB1, 0),
+    (0xAAB2, 230),
+    (0xAAB4, 220),
+    (0xAAB5, 0),
+    (0xAAB7, 230),
+    (0xAAB9, 0),
+    (0xAABE, 230),
+    (0xAAC0, 0),
+    (0xAAC1, 230),
+    (0xAAC2, 0),
+    (0xAAF6, 9),
+    (0xAAF7, 0),
+    (0xABED, 9),
+    (0xABEE, 0),
+    (0xFB1E, 26),
+    (0xFB1F, 0),
+    (0xFE20, 230),
+    (0xFE27, 220),
+    (0xFE2E, 230),
+    (0xFE30, 0),
+    (0x101FD, 220),
+    (0x101FE, 0),
+    (0x102E0, 220),
+    (0x102E1, 0),
+    (0x10376, 230),
+    (0x1037B, 0),
+    (0x10A0D, 220),
+    (0x10A0E, 0),
+    (0x10A0F, 230),
+    (0x10A10, 0),
+    (0x10A38, 230),
+    (0x10A39, 1),
+    (0x10A3A, 220),
+    (0x10A3B, 0),
+    (0x10A3F, 9),
+    (0x10A40, 0),
+    (0x10AE5, 230),
+    (0x10AE6, 220),
+    (0x10AE7, 0),
+    (0x10D24, 230),
+    (0x10D28, 0),
+    (0x10EAB, 230),
+    (0x10EAD, 0),
+    (0x10F46, 220),
+    (0x10F48, 230),
+    (0x10F4B, 220),
+    (0x10F4C, 230),
+    (0x10F4D, 220),
+    (0x10F51, 0),
+    (0x10F82, 230),
+    (0x10F83, 220),
+    (0x10F84, 230),
+    (0x10F85, 220),
+    (0x10F86, 0),
+    (0x11046, 9),
+    (0x11047, 0),
+    (0x11070, 9),
+    (0x11071, 0),
+    (0x1107F, 9),
+    (0x11080, 0),
+    (0x110B9, 9),
+    (0x110BA, 7),
+    (0x110BB, 0),
+    (0x11100, 230),
+    (0x11103, 0),
+    (0x11133, 9),
+    (0x11135, 0),
+    (0x11173, 7),
+    (0x11174, 0),
+    (0x111C0, 9),
+    (0x111C1, 0),
+    (0x111CA, 7),
+    (0x111CB, 0),
+    (0x11235, 9),
+    (0x11236, 7),
+    (0x11237, 0),
+    (0x112E9, 7),
+    (0x112EA, 9),
+    (0x112EB, 0),
+    (0x1133B, 7),
+    (0x1133D, 0),
+    (0x1134D, 9),
+    (0x1134E, 0),
+    (0x11366, 230),
+    (0x1136D, 0),
+    (0x11370, 230),
+    (0x11375, 0),
+    (0x11442, 9),
+    (0x11443, 0),
+    (0x11446, 7),
+    (0x11447, 0),
+    (0x1145E, 230),
+    (0x1145F, 0),
+    (0x114C2, 9),
+    (0x114C3, 7),
+    (0x114C4, 0),
+    (0x115BF, 9),
+    (0x115C0, 7),
+    (0x115C1, 0),
+    (0x1163F, 9),
+    (0x11640, 0),
+    (0x116B6, 9),
+    (0x116B7, 7),
+    (0x116B8, 0),
+    (0x1172B, 9),
+    (0x1172C, 0),
+    (0x11839, 9),
+    (0x1183A, 7),
+    (0x1183B, 0),
+    (0x1193D, 9),
+    (0x1193F, 0),
+    (0x11943, 7),
+    (0x11944, 0),
+    (0x119E0, 9),
+    (0x119E1, 0),
+    (0x11A34, 9),
+    (0x11A35, 0),
+    (0x11A47, 9),
+    (0x11A48, 0),
+    (0x11A99, 9),
+    (0x11A9A, 0),
+    (0x11C3F, 9),
+    (0x11C40, 0),
+    (0x11D42, 7),
+    (0x11D43, 0),
+    (0x11D44, 9),
+    (0x11D46, 0),
+    (0x11D97, 9),
+    (0x11D98, 0),
+    (0x16AF0, 1),
+    (0x16AF5, 0),
+    (0x16B30, 230),
+    (0x16B37, 0),
+    (0x16FF0, 6),
+    (0x16FF2, 0),
+    (0x1BC9E, 1),
+    (0x1BC9F, 0),
+    (0x1D165, 216),
+    (0x1D167, 1),
+    (0x1D16A, 0),
+    (0x1D16D, 226),
+    (0x1D16E, 216),
+    (0x1D173, 0),
+    (0x1D17B, 220),
+    (0x1D183, 0),
+    (0x1D185, 230),
+    (0x1D18A, 220),
+    (0x1D18C, 0),
+    (0x1D1AA, 230),
+    (0x1D1AE, 0),
+    (0x1D242, 230),
+    (0x1D245, 0),
+    (0x1E000, 230),
+    (0x1E007, 0),
+    (0x1E008, 230),
+    (0x1E019, 0),
+    (0x1E01B, 230),
+    (0x1E022, 0),
+    (0x1E023, 230),
+    (0x1E025, 0),
+    (0x1E026, 230),
+    (0x1E02B, 0),
+    (0x1E130, 230),
+    (0x1E137, 0),
+    (0x1E2AE, 230),
+    (0x1E2AF, 0),
+    (0x1E2EC, 230),
+    (0x1E2F0, 0),
+    (0x1E8D0, 220),
+    (0x1E8D7, 0),
+    (0x1E944, 230),
+    (0x1E94A, 7),
+    (0x1E94B, 0),
+];
+
+#[rustfmt::skip]
+pub(super) static MIRRORED: &[(u32, bool)] = &[
+    (0x0000, false),
+    (0x0028, true),
+    (0x002A, false),
+    (0x003C, true),
+    (0x003D, false),
+    (0x003E, true),
+    (0x003F, false),
+    (0x005B, true),
+    (0x005C, false),
+    (0x005D, true),
+    (0x005E, false),
+    (0x007B, true),
+    (0x007C, false),
+    (0x007D, true),
+    (0x007E, false),
+    (0x00AB, true),
+    (0x00AC, false),
+    (0x00BB, true),
+    (0x00BC, false),
+    (0x0F3A, true),
+    (0x0F3E, false),
+    (0x169B, true),
+    (0x169D, false),
+    (0x2039, true),
+    (0x203B, false),
+    (0x2045, true),
+    (0x2047, false),
+    (0x207D, true),
+    (0x207F, false),
+    (0x208D, true),
+    (0x208F, false),
+    (0x2140, true),
+    (0x2141, false),
+    (0x2201, true),
+    (0x2205, false),
+    (0x2208, true),
+    (0x220E, false),
+    (0x2211, true),
+    (0x2212, false),
+    (0x2215, true),
+    (0x2217, false),
+    (0x221A, true),
+    (0x221E, false),
+    (0x221F, true),
+    (0x2223, false),
+    (0x2224, true),
+    (0x2225, false),
+    (0x2226, true),
+    (0x2227, false),
+    (0x222B, true),
+    (0x2234, false),
+    (0x2239, true),
+    (0x223A, false),
+    (0x223B, true),
+    (0x224D, false),
+    (0x2252, true),
+    (0x2256, false),
+    (0x225F, true),
+    (0x2261, false),
+    (0x2262, true),
+    (0x2263, false),
+    (0x2264, true),
+    (0x226C, false),
+    (0x226E, true),
+    (0x228D, false),
+    (0x228F, true),
+    (0x2293, false),
+    (0x2298, true),
+    (0x2299, false),
+    (0x22A2, true),
+    (0x22A4, false),
+    (0x22A6, true),
+    (0x22B9, false),
+    (0x22BE, true),
+    (0x22C0, false),
+    (0x22C9, true),
+    (0x22CE, false),
+    (0x22D0, true),
+    (0x22D2, false),
+    (0x22D6, true),
+    (0x22EE, false),
+    (0x22F0, true),
+    (0x2300, false),
+    (0x2308, true),
+    (0x230C, false),
+    (0x2320, true),
+    (0x2322, false),
+    (0x2329, true),
+    (0x232B, false),
+    (0x2768, true),
+    (0x2776, false),
+    (0x27C0, true),
+    (0x27C1, false),
+    (0x27C3, true),
+    (0x27C7, false),
+    (0x27C8, true),
+    (0x27CA, false),
+    (0x27CB, true),
+    (0x27CE, false),
+    (0x27D3, true),
+    (0x27D7, false),
+    (0x27DC, true),
+    (0x27DF, false),
+    (0x27E2, true),
+    (0x27F0, false),
+    (0x2983, true),
+    (0x2999, false),
+    (0x299B, true),
+    (0x29A1, false),
+    (0x29A2, true),
+    (0x29B0, false),
+    (0x29B8, true),
+    (0x29B9, false),
+    (0x29C0, true),
+    (0x29C6, false),
+    (0x29C9, true),
+    (0x29CA, false),
+    (0x29CE, true),
+    (0x29D3, false),
+    (0x29D4, true),
+    (0x29D6, false),
+    (0x29D8, true),
+    (0x29DD, false),
+    (0x29E1, true),
+    (0x29E2, false),
+    (0x29E3, true),
+    (0x29E6, false),
+    (0x29E8, true),
+    (0x29EA, false),
+    (0x29F4, true),
+    (0x29FA, false),
+    (0x29FC, true),
+    (0x29FE, false),
+    (0x2A0A, true),
+    (0x2A1D, false),
+    (0x2A1E, true),
+    (0x2A22, false),
+    (0x2A24, true),
+    (0x2A25, false),
+    (0x2A26, true),
+    (0x2A27, false),
+    (0x2A29, true),
+    (0x2A2A, false),
+    (0x2A2B, true),
+    (0x2A2F, false),
+    (0x2A34, true),
+    (0x2A36, false),
+    (0x2A3C, true),
+    (0x2A3F, false),
+    (0x2A57, true),
+    (0x2A59, false),
+    (0x2A64, true),
+    (0x2A66, false),
+    (0x2A6A, true),
+    (0x2A6E, false),
+    (0x2A6F, true),
+    (0x2A71, false),
+    (0x2A73, true),
+    (0x2A75, false),
+    (0x2A79, true),
+    (0x2AA4, false),
+    (0x2AA6, true),
+    (0x2AAE, false),
+    (0x2AAF, true),
+    (0x2AD7, false),
+    (0x2ADC, true),
+    (0x2ADD, false),
+    (0x2ADE, true),
+    (0x2ADF, false),
+    (0x2AE2, true),
+    (0x2AE7, false),
+    (0x2AEC, true),
+    (0x2AEF, false),
+    (0x2AF3, true),
+    (0x2AF4, false),
+    (0x2AF7, true),
+    (0x2AFC, false),
+    (0x2AFD, true),
+    (0x2AFE, false),
+    (0x2BFE, true),
+    (0x2BFF, false),
+    (0x2E02, true),
+    (0x2E06, false),
+    (0x2E09, true),
+    (0x2E0B, false),
+    (0x2E0C, true),
+    (0x2E0E, false),
+    (0x2E1C, true),
+    (0x2E1E, false),
+    (0x2E20, true),
+    (0x2E2A, false),
+    (0x2E55, true),
+    (0x2E5D, false),
+    (0x3008, true),
+    (0x3012, false),
+    (0x3014, true),
+    (0x301C, false),
+    (0xFE59, true),
+    (0xFE5F, false),
+    (0xFE64, true),
+    (0xFE66, false),
+    (0xFF08, true),
+    (0xFF0A, false),
+    (0xFF1C, true),
+    (0xFF1D, false),
+    (0xFF1E, true),
+    (0xFF1F, false),
+    (0xFF3B, true),
+    (0xFF3C, false),
+    (0xFF3D, true),
+    (0xFF3E, false),
+    (0xFF5B, true),
+    (0xFF5C, false),
+    (0xFF5D, true),
+    (0xFF5E, false),
+    (0xFF5F, true),
+    (0xFF61, false),
+    (0xFF62, true),
+    (0xFF64, false),
+    (0x1D6DB, true),
+    (0x1D6DC, false),
+    (0x1D715, true),
+    (0x1D716, false),
+    (0x1D74F, true),
+    (0x1D750, false),
+    (0x1D789, true),
+    (0x1D78A, false),
+    (0x1D7C3, true),
+    (0x1D7C4, false),
+];
+
+#[rustfmt::skip]
+pub(super) static MIRRORING_GLYPH: &[(u32, u32)] = &[
+    (0x0028, 0x0029),
+    (0x0029, 0x0028),
+    (0x003C, 0x003E),
+    (0x003E, 0x003C),
+    (0x005B, 0x005D),
+    (0x005D, 0x005B),
+    (0x007B, 0x007D),
+    (0x007D, 0x007B),
+    (0x00AB, 0x00BB),
+    (0x00BB, 0x00AB),
+    (0x0F3A, 0x0F3B),
+    (0x0F3B, 0x0F3A),
+    (0x0F3C, 0x0F3D),
+    (0x0F3D, 0x0F3C),
+    (0x169B, 0x169C),
+    (0x169C, 0x169B),
+    (0x2039, 0x203A),
+    (0x203A, 0x2039),
+    (0x2045, 0x2046),
+    (0x2046, 0x2045),
+    (0x207D, 0x207E),
+    (0x207E, 0x207D),
+    (0x208D, 0x208E),
+    (0x208E, 0x208D),
+    (0x2208, 0x220B),
+    (0x2209, 0x220C),
+    (0x220A, 0x220D),
+    (0x220B, 0x2208),
+    (0x220C, 0x2209),
+    (0x220D, 0x220A),
+    (0x2215, 0x29F5),
+    (0x221F, 0x2BFE),
+    (0x2220, 0x29A3),
+    (0x2221, 0x299B),
+    (0x2222, 0x29A0),
+    (0x2224, 0x2AEE),
+    (0x223C, 0x223D),
+    (0x223D, 0x223C),
+    (0x2243, 0x22CD),
+    (0x2245, 0x224C),
+    (0x224C, 0x2245),
+    (0x2252, 0x2253),
+    (0x2253, 0x2252),
+    (0x2254, 0x2255),
+    (0x2255, 0x2254),
+    (0x2264, 0x2265),
+    (0x2265, 0x2264),
+    (0x2266, 0x2267),
+    (0x2267, 0x2266),
+    (0x2268, 0x2269),
+    (0x2269, 0x2268),
+    (0x226A, 0x226B),
+    (0x226B, 0x226A),
+    (0x226E, 0x226F),
+    (0x226F, 0x226E),
+    (0x2270, 0x2271),
+    (0x2271, 0x2270),
+    (0x2272, 0x2273),
+    (0x2273, 0x2272),
+    (0x2274, 0x2275),
+    (0x2275, 0x2274),
+    (0x2276, 0x2277),
+    (0x2277, 0x2276),
+    (0x2278, 0x2279),
+    (0x2279, 0x2278),
+    (0x227A, 0x227B),
+    (0x227B, 0x227A),
+    (0x227C, 0x227D),
+    (0x227D, 0x227C),
+    (0x227E, 0x227F),
+    (0x227F, 0x227E),
+    (0x2280, 0x2281),
+    (0x2281, 0x2280),
+    (0x2282, 0x2283),
+    (0x2283, 0x2282),
+    (0x2284, 0x2285),
+    (0x2285, 0x2284),
+    (0x2286, 0x2287),
+    (0x2287, 0x2286),
+    (0x2288, 0x2289),
+    (0x2289, 0x2288),
+    (0x228A, 0x228B),
+    (0x228B, 0x228A),
+    (0x228F, 0x2290),
+    (0x2290, 0x228F),
+    (0x2291, 0x2292),
+    (0x2292, 0x2291),
+    (0x2298, 0x29B8),
+    (0x22A2, 0x22A3),
+    (0x22A3, 0x22A2),
+    (0x22A6, 0x2ADE),
+    (0x22A8, 0x2AE4),
+    (0x22A9, 0x2AE3),
+    (0x22AB, 0x2AE5),
+    (0x22B0, 0x22B1),
+    (0x22B1, 0x22B0),
+    (0x22B2, 0x22B3),
+    (0x22B3, 0x22B2),
+    (0x22B4, 0x22B5),
+    (0x22B5, 0x22B4),
+    (0x22B6, 0x22B7),
+    (0x22B7, 0x22B6),
+    (0x22B8, 0x27DC),
+    (0x22C9, 0x22CA),
+    (0x22CA, 0x22C9),
+    (0x22CB, 0x22CC),
+    (0x22CC, 0x22CB),
+    (0x22CD, 0x2243),
+    (0x22D0, 0x22D1),
+    (0x22D1, 0x22D0),
+    (0x22D6, 0x22D7),
+    (0x22D7, 0x22D6),
+    (0x22D8, 0x22D9),
+    (0x22D9, 0x22D8),
+    (0x22DA, 0x22DB),
+    (0x22DB, 0x22DA),
+    (0x22DC, 0x22DD),
+    (0x22DD, 0x22DC),
+    (0x22DE, 0x22DF),
+    (0x22DF, 0x22DE),
+    (0x22E0, 0x22E1),
+    (0x22E1, 0x22E0),
+    (0x22E2, 0x22E3),
+    (0x22E3, 0x22E2),
+    (0x22E4, 0x22E5),
+    (0x22E5, 0x22E4),
+    (0x22E6, 0x22E7),
+    (0x22E7, 0x22E6),
+    (0x22E8, 0x22E9),
+    (0x22E9, 0x22E8),
+    (0x22EA, 0x22EB),
+    (0x22EB, 0x22EA),
+    (0x22EC, 0x22ED),
+    (0x22ED, 0x22EC),
+    (0x22F0, 0x22F1),
+    (0x22F1, 0x22F0),
+    (0x22F2, 0x22FA),
+    (0x22F3, 0x22FB),
+    (0x22F4, 0x22FC),
+    (0x22F6, 0x22FD),
+    (0x22F7, 0x22FE),
+    (0x22FA, 0x22F2),
+    (0x22FB, 0x22F3),
+    (0x22FC, 0x22F4),
+    (0x22FD, 0x22F6),
+    (0x22FE, 0x22F7),
+    (0x2308, 0x2309),
+    (0x2309, 0x2308),
+    (0x230A, 0x230B),
+    (0x230B, 0x230A),
+    (0x2329, 0x232A),
+    (0x232A, 0x2329),
+    (0x2768, 0x2769),
+    (0x2769, 0x2768),
+    (0x276A, 0x276B),
+    (0x276B, 0x276A),
+    (0x276C, 0x276D),
+    (0x276D, 0x276C),
+    (0x276E, 0x276F),
+    (0x276F, 0x276E),
+    (0x2770, 0x2771),
+    (0x2771, 0x2770),
+    (0x2772, 0x2773),
+    (0x2773, 0x2772),
+    (0x2774, 0x2775),
+    (0x2775, 0x2774),
+    (0x27C3, 0x27C4),
+    (0x27C4, 0x27C3),
+    (0x27C5, 0x27C6),
+    (0x27C6, 0x27C5),
+    (0x27C8, 0x27C9),
+    (0x27C9, 0x27C8),
+    (0x27CB, 0x27CD),
+    (0x27CD, 0x27CB),
+    (0x27D5, 0x27D6),
+    (0x27D6, 0x27D5),
+    (0x27DC, 0x22B8),
+    (0x27DD, 0x27DE),
+    (0x27DE, 0x27DD),
+    (0x27E2, 0x27E3),
+    (0x27E3, 0x27E2),
+    (0x27E4, 0x27E5),
+    (0x27E5, 0x27E4),
+    (0x27E6, 0x27E7),
+    (0x27E7, 0x27E6),
+    (0x27E8, 0x27E9),
+    (0x27E9, 0x27E8),
+    (0x27EA, 0x27EB),
+    (0x27EB, 0x27EA),
+    (0x27EC, 0x27ED),
+    (0x27ED, 0x27EC),
+    (0x27EE, 0x27EF),
+    (0x27EF, 0x27EE),
+    (0x2983, 0x2984),
+    (0x2984, 0x2983),
+    (0x2985, 0x2986),
+    (0x2986, 0x2985),
+    (0x2987, 0x2988),
+    (0x2988, 0x2987),
+    (0x2989, 0x298A),
+    (0x298A, 0x2989),
+    (0x298B, 0x298C),
+    (0x298C, 0x298B),
+    (0x298D, 0x2990),
+    (0x298E, 0x298F),
+    (0x298F, 0x298E),
+    (0x2990, 0x298D),
+    (0x2991, 0x2992),
+    (0x2992, 0x2991),
+    (0x2993, 0x2994),
+    (0x2994, 0x2993),
+    (0x2995, 0x2996),
+    (0x2996, 0x2995),
+    (0x2997, 0x2998),
+    (0x2998, 0x2997),
+    (0x299B, 0x2221),
+    (0x29A0, 0x2222),
+    (0x29A3, 0x2220),
+    (0x29A4, 0x29A5),
+    (0x29A5, 0x29A4),
+    (0x29A8, 0x29A9),
+    (0x29A9, 0x29A8),
+    (0x29AA, 0x29AB),
+    (0x29AB, 0x29AA),
+    (0x29AC, 0x29AD),
+    (0x29AD, 0x29AC),
+    (0x29AE, 0x29AF),
+    (0x29AF, 0x29AE),
+    (0x29B8, 0x2298),
+    (0x29C0, 0x29C1),
+    (0x29C1, 0x29C0),
+    (0x29C4, 0x29C5),
+    (0x29C5, 0x29C4),
+    (0x29CF, 0x29D0),
+    (0x29D0, 0x29CF),
+    (0x29D1, 0x29D2),
+    (0x29D2, 0x29D1),
+    (0x29D4, 0x29D5),
+    (0x29D5, 0x29D4),
+    (0x29D8, 0x29D9),
+    (0x29D9, 0x29D8),
+    (0x29DA, 0x29DB),
+    (0x29DB, 0x29DA),
+    (0x29E8, 0x29E9),
+    (0x29E9, 0x29E8),
+    (0x29F5, 0x2215),
+    (0x29F8, 0x29F9),
+    (0x29F9, 0x29F8),
+    (0x29FC, 0x29FD),
+    (0x29FD, 0x29FC),
+    (0x2A2B, 0x2A2C),
+    (0x2A2C, 0x2A2B),
+    (0x2A2D, 0x2A2E),
+    (0x2A2E, 0x2A2D),
+    (0x2A34, 0x2A35),
+    (0x2A35, 0x2A34),
+    (0x2A3C, 0x2A3D),
+    (0x2A3D, 0x2A3C),
+    (0x2A64, 0x2A65),
+    (0x2A65, 0x2A64),
+    (0x2A79, 0x2A7A),
+    (0x2A7A, 0x2A79),
+    (0x2A7B, 0x2A7C),
+    (0x2A7C, 0x2A7B),
+    (0x2A7D, 0x2A7E),
+    (0x2A7E, 0x2A7D),
+    (0x2A7F, 0x2A80),
+    (0x2A80, 0x2A7F),
+    (0x2A81, 0x2A82),
+    (0x2A82, 0x2A81),
+    (0x2A83, 0x2A84),
+    (0x2A84, 0x2A83),
+    (0x2A85, 0x2A86),
+    (0x2A86, 0x2A85),
+    (0x2A87, 0x2A88),
+    (0x2A88, 0x2A87),
+    (0x2A89, 0x2A8A),
+    (0x2A8A, 0x2A89),
+    (0x2A8B, 0x2A8C),
+    (0x2A8C, 0x2A8B),
+    (0x2A8D, 0x2A8E),
+    (0x2A8E, 0x2A8D),
+    (0x2A8F, 0x2A90),
+    (0x2A90, 0x2A8F),
+    (0x2A91, 0x2A92),
+    (0x2A92, 0x2A91),
+    (0x2A93, 0x2A94),
+    (0x2A94, 0x2A93),
+    (0x2A95, 0x2A96),
+    (0x2A96, 0x2A95),
+    (0x2A97, 0x2A98),
+    (0x2A98, 0x2A97),
+    (0x2A99, 0x2A9A),
+    (0x2A9A, 0x2A99),
+    (0x2A9B, 0x2A9C),
+    (0x2A9C, 0x2A9B),
+    (0x2A9D, 0x2A9E),
+    (0x2A9E, 0x2A9D),
+    (0x2A9F, 0x2AA0),
+    (0x2AA0, 0x2A9F),
+    (0x2AA1, 0x2AA2),
+    (0x2AA2, 0x2AA1),
+    (0x2AA6, 0x2AA7),
+    (0x2AA7, 0x2AA6),
+    (0x2AA8, 0x2AA9),
+    (0x2AA9, 0x2AA8),
+    (0x2AAA, 0x2AAB),
+    (0x2AAB, 0x2AAA),
+    (0x2AAC, 0x2AAD),
+    (0x2AAD, 0x2AAC),
+    (0x2AAF, 0x2AB0),
+    (0x2AB0, 0x2AAF),
+    (0x2AB1, 0x2AB2),
+    (0x2AB2, 0x2AB1),
+    (0x2AB3, 0x2AB4),
+    (0x2AB4, 0x2AB3),
+    (0x2AB5, 0x2AB6),
+    (0x2AB6, 0x2AB5),
+    (0x2AB7, 0x2AB8),
+    (0x2AB8, 0x2AB7),
+    (0x2AB9, 0x2ABA),
+    (0x2ABA, 0x2AB9),
+    (0x2ABB, 0x2ABC),
+    (0x2ABC, 0x2ABB),
+    (0x2ABD, 0x2ABE),
+    (0x2ABE, 0x2ABD),
+    (0x2ABF, 0x2AC0),
+    (0x2AC0, 0x2ABF),
+    (0x2AC1, 0x2AC2),
+    (0x2AC2, 0x2AC1),
+    (0x2AC3, 0x2AC4),
+    (0x2AC4, 0x2AC3),
+    (0x2AC5, 0x2AC6),
+    (0x2AC6, 0x2AC5),
+    (0x2AC7, 0x2AC8),
+    (0x2AC8, 0x2AC7),
+    (0x2AC9, 0x2ACA),
+    (0x2ACA, 0x2AC9),
+    (0x2ACB, 0x2ACC),
+    (0x2ACC, 0x2ACB),
+    (0x2ACD, 0x2ACE),
+    (0x2ACE, 0x2ACD),
+    (0x2ACF, 0x2AD0),
+    (0x2AD0, 0x2ACF),
+    (0x2AD1, 0x2AD2),
+    (0x2AD2, 0x2AD1),
+    (0x2AD3, 0x2AD4),
+    (0x2AD4, 0x2AD3),
+    (0x2AD5, 0x2AD6),
+    (0x2AD6, 0x2AD5),
+    (0x2ADE, 0x22A6),
+    (0x2AE3, 0x22A9),
+    (0x2AE4, 0x22A8),
+    (0x2AE5, 0x22AB),
+    (0x2AEC, 0x2AED),
+    (0x2AED, 0x2AEC),
+    (0x2AEE, 0x2224),
+    (0x2AF7, 0x2AF8),
+    (0x2AF8, 0x2AF7),
+    (0x2AF9, 0x2AFA),
+    (0x2AFA, 0x2AF9),
+    (0x2BFE, 0x221F),
+    (0x2E02, 0x2E03),
+    (0x2E03, 0x2E02),
+    (0x2E04, 0x2E05),
+    (0x2E05, 0x2E04),
+    (0x2E09, 0x2E0A),
+    (0x2E0A, 0x2E09),
+    (0x2E0C, 0x2E0D),
+    (0x2E0D, 0x2E0C),
+    (0x2E1C, 0x2E1D),
+    (0x2E1D, 0x2E1C),
+    (0x2E20, 0x2E21),
+    (0x2E21, 0x2E20),
+    (0x2E22, 0x2E23),
+    (0x2E23, 0x2E22),
+    (0x2E24, 0x2E25),
+    (0x2E25, 0x2E24),
+    (0x2E26, 0x2E27),
+    (0x2E27, 0x2E26),
+    (0x2E28, 0x2E29),
+    (0x2E29, 0x2E28),
+    (0x2E55, 0x2E56),
+    (0x2E56, 0x2E55),
+    (0x2E57, 0x2E58),
+    (0x2E58, 0x2E57),
+    (0x2E59, 0x2E5A),
+    (0x2E5A, 0x2E59),
+    (0x2E5B, 0x2E5C),
+    (0x2E5C, 0x2E5B),
+    (0x3008, 0x3009),
+    (0x3009, 0x3008),
+    (0x300A, 0x300B),
+    (0x300B, 0x300A),
+    (0x300C, 0x300D),
+    (0x300D, 0x300C),
+    (0x300E, 0x300F),
+    (0x300F, 0x300E),
+    (0x3010, 0x3011),
+    (0x3011, 0x3010),
+    (0x3014, 0x3015),
+    (0x3015, 0x3014),
+    (0x3016, 0x3017),
+    (0x3017, 0x3016),
+    (0x3018, 0x3019),
+    (0x3019, 0x3018),
+    (0x301A, 0x301B),
+    (0x301B, 0x301A),
+    (0xFE59, 0xFE5A),
+    (0xFE5A, 0xFE59),
+    (0xFE5B, 0xFE5C),
+    (0xFE5C, 0xFE5B),
+    (0xFE5D, 0xFE5E),
+    (0xFE5E, 0xFE5D),
+    (0xFE64, 0xFE65),
+    (0xFE65, 0xFE64),
+    (0xFF08, 0xFF09),
+    (0xFF09, 0xFF08),
+    (0xFF1C, 0xFF1E),
+    (0xFF1E, 0xFF1C),
+    (0xFF3B, 0xFF3D),
+    (0xFF3D, 0xFF3B),
+    (0xFF5B, 0xFF5D),
+    (0xFF5D, 0xFF5B),
+    (0xFF5F, 0xFF60),
+    (0xFF60, 0xFF5F),
+    (0xFF62, 0xFF63),
+    (0xFF63, 0xFF62),
+];
@@ -0,0 +1,71 @@
+use harfbuzz_wasm::abi::{host_function, HOST_FUNCTIONS, SHAPE_EXPORT};
+
+// The functions the crate imports from Harfbuzz, with how many
+// parameters each takes and whether it returns anything, read from
+// the `extern "C"` block in lib.rs.
+fn imports() -> Vec<(String, usize, bool)> {
+    let source = include_str!("../src/lib.rs");
+    let start = source.find("extern \"C\" {").unwrap();
+    let end = start + source[start..].find("\n}").unwrap();
+    source[start..end]
+        .split("fn ")
+        .skip(1)
+        .map(|decl| {
+            let decl = decl.split(';').next().unwrap();
+            let name = decl.split('(').next().unwrap().trim().to_string();
+            let params = decl[decl.find('(').unwrap()..decl.rfind(')').unwrap()]
+                .matches(':')
+                .count();
+            (name, params, decl.contains("->"))
+        })
+        .collect()
+}
+
+#[test]
+fn imports_are_provided_by_harfbuzz() {
+    for (name, params, returns) in imports() {
+        let f = host_function(&name).unwrap_or_else(|| panic!("Harfbuzz doesn't provide {}", name));
+        assert_eq!((f.params, f.returns), (params, returns), "{}", name);
+    }
+}
+
+#[test]
+fn signatures_are_well_formed() {
+    for (ix, f) in HOST_FUNCTIONS.iter().enumerate() {
+        assert!(
+            HOST_FUNCTIONS[..ix].iter().all(|g| g.name != f.name),
+            "{} is listed twice",
+            f.name
+        );
+        let (params, result) = f.signature[1..].split_once(')').unwrap();
+        assert!(f.signature.starts_with('('), "{}", f.name);
+        assert!(params.chars().all(|c| "i$*~".contains(c)), "{}", f.name);
+        assert_eq!(params.matches('*').count(), params.matches("*~").count());
+        assert!(result.is_empty() || result == "i", "{}", f.name);
+        assert_eq!((f.params, f.returns), (params.len(), !result.is_empty()));
+    }
+    assert_eq!((SHAPE_EXPORT.params, SHAPE_EXPORT.returns), (5, true));
+}
+
+#[test]
+fn only_harfbuzz_functions_are_listed() {
+    for name in [
+        "buffer_reverse",
+        "buffer_reverse_clusters",
+        "buffer_contents_free",
+        "buffer_contents_realloc",
+        "blob_free",
+        "glyph_outline_free",
+    ] {
+        assert!(host_function(name).is_some(), "{}", name);
+    }
+    for name in [
+        "buffer_set_direction",
+        "buffer_get_language",
+        "buffer_get_flags",
+        "buffer_copy_context",
+        "malloc",
+    ] {
+        assert!(host_function(name).is_none(), "{}", name);
+    }
+}
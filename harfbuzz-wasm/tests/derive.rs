@@ -0,0 +1,91 @@
+#![cfg(feature = "derive")]
+use harfbuzz_wasm::{BufferItem, CGlyphInfo, CGlyphPosition, ClusterItem, PositionedItem};
+
+#[derive(BufferItem)]
+struct Plain {
+    codepoint: u32,
+    cluster: u32,
+    x_advance: i32,
+    y_offset: i32,
+    name: String,
+}
+
+#[derive(BufferItem)]
+struct RoundTrip {
+    codepoint: u32,
+    x_advance: i32,
+    width: f32,
+    #[buffer_item(info)]
+    info: CGlyphInfo,
+    #[buffer_item(position)]
+    position: CGlyphPosition,
+}
+
+#[derive(BufferItem)]
+struct Placed {
+    codepoint: u32,
+    x_advance: i32,
+    y_advance: i32,
+    x_offset: i32,
+    y_offset: i32,
+}
+
+fn sample() -> (CGlyphInfo, CGlyphPosition) {
+    let info = CGlyphInfo {
+        codepoint: 42,
+        mask: 0x3,
+        cluster: 7,
+        var1: 11,
+        var2: 12,
+    };
+    let pos = CGlyphPosition {
+        x_advance: 500,
+        y_advance: 0,
+        x_offset: 10,
+        y_offset: -20,
+        var: 13,
+    };
+    (info, pos)
+}
+
+#[test]
+fn named_fields_are_mapped() {
+    let (info, pos) = sample();
+    let mut glyph = Plain::from_c(info, pos);
+    assert_eq!(glyph.codepoint, 42);
+    assert_eq!(glyph.cluster, 7);
+    assert_eq!(glyph.x_advance, 500);
+    assert_eq!(glyph.y_offset, -20);
+    assert_eq!(glyph.name, "");
+    glyph.set_cluster(3);
+    assert_eq!(glyph.cluster(), 3);
+
+    let (info, pos) = glyph.to_c();
+    assert_eq!((info.codepoint, info.cluster), (42, 3));
+    assert_eq!((info.mask, info.var1, info.var2), (0, 0, 0));
+    assert_eq!((pos.x_advance, pos.x_offset, pos.y_offset), (500, 0, -20));
+    assert_eq!(pos.var, 0);
+}
+
+#[test]
+fn raw_data_round_trips() {
+    let (info, pos) = sample();
+    let mut glyph = RoundTrip::from_c(info, pos);
+    assert_eq!(glyph.width, 0.0);
+    glyph.x_advance = 600;
+
+    let (info, pos) = glyph.to_c();
+    assert_eq!((info.codepoint, info.cluster, info.mask), (42, 7, 0x3));
+    assert_eq!((info.var1, info.var2), (11, 12));
+    assert_eq!((pos.x_advance, pos.x_offset, pos.y_offset), (600, 10, -20));
+    assert_eq!(pos.var, 13);
+}
+
+#[test]
+fn positions_are_derived() {
+    let (info, pos) = sample();
+    let glyph = Placed::from_c(info, pos);
+    assert_eq!(glyph.glyph_id(), 42);
+    assert_eq!(glyph.advance(), (500, 0));
+    assert_eq!(glyph.offset(), (10, -20));
+}
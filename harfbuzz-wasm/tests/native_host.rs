@@ -0,0 +1,558 @@
+#![cfg(feature = "native-host")]
+use harfbuzz_wasm::{
+    native, run_shaper, Direction, Feature, FeatureList, Font, Glyph, GlyphBuffer, HbWasmError,
+    RawOutline, ShaperCache,
+};
+use std::rc::Rc;
+
+fn open_sans() -> u32 {
+    native::load_font(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../base-fonts/OpenSans-Regular.ttf"
+    ))
+    .unwrap()
+}
+
+fn recursive() -> u32 {
+    native::load_font(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../base-fonts/Recursive-subset.ttf"
+    ))
+    .unwrap()
+}
+
+#[test]
+fn font_queries() {
+    let font = Font::from_ref(open_sans());
+    let glyph = font.get_glyph('A' as u32, 0);
+    assert_ne!(glyph, 0);
+    assert_eq!(font.get_glyph_name(glyph), "A");
+    assert_eq!(font.get_face().get_upem(), 2048);
+    assert!(font.get_glyph_h_advance(glyph) > 0);
+    assert!(font.get_glyph_extents(glyph).height < 0);
+    assert_eq!(font.get_face().reference_table("head").length, 54);
+}
+
+#[test]
+fn shape_with_maps_and_advances() {
+    let font_ref = open_sans();
+    let buf_ref = native::create_buffer("abc");
+    let font = Font::from_ref(font_ref);
+    font.shape_with(buf_ref, "ot");
+    let buffer = GlyphBuffer::from_ref(buf_ref);
+    assert_eq!(buffer.direction(), Direction::LeftToRight);
+    let clusters: Vec<u32> = buffer.glyphs.iter().map(|g| g.cluster).collect();
+    assert_eq!(clusters, vec![0, 1, 2]);
+    assert!(buffer.glyphs.iter().all(|g| g.x_advance > 0));
+}
+
+#[test]
+fn right_to_left_is_reversed() {
+    let font = Font::from_ref(open_sans());
+    let buf_ref = native::create_buffer("\u{05D0}\u{05D1}");
+    font.shape_with(buf_ref, "ot");
+    let clusters: Vec<u32> = native::buffer_glyphs(buf_ref)
+        .iter()
+        .map(|g| g.cluster)
+        .collect();
+    assert_eq!(clusters, vec![2, 0]);
+}
+
+#[test]
+fn buffer_is_written_back_on_drop() {
+    let buf_ref = native::create_buffer("ab");
+    {
+        let mut buffer = GlyphBuffer::from_ref(buf_ref);
+        buffer.glyphs.pop();
+    }
+    assert_eq!(native::buffer_glyphs(buf_ref).len(), 1);
+}
+
+#[test]
+fn features_pass_through() {
+    let (ptr, count) = native::features_ref(&[Feature::new("kern", 0)]);
+    let features = FeatureList::from_ref(ptr, count);
+    assert!(features.is_disabled("kern", 0..3));
+    assert!(!features.is_disabled("liga", 0..3));
+}
+
+#[test]
+fn missing_table_is_an_error() {
+    let face = Font::from_ref(open_sans()).get_face();
+    assert_eq!(
+        face.try_reference_table("Font").err(),
+        Some(HbWasmError::TableNotFound("Font".to_string()))
+    );
+}
+
+#[test]
+fn failed_shaper_falls_back_to_opentype() {
+    let font_ref = open_sans();
+    let buf_ref = native::create_buffer("ab");
+    let result = run_shaper(
+        1,
+        font_ref,
+        buf_ref,
+        0,
+        0,
+        |_plan, _font, buf_ref, _features| {
+            let mut buffer = GlyphBuffer::from_ref(buf_ref);
+            buffer.glyphs.clear();
+            Err("no glyphs left".into())
+        },
+    );
+    assert_eq!(result, 1);
+    let glyphs = native::buffer_glyphs(buf_ref);
+    assert_eq!(glyphs.len(), 2);
+    assert!(glyphs.iter().all(|g| g.x_advance > 0));
+    assert!(native::take_debug_log()
+        .iter()
+        .any(|l| l.contains("no glyphs left")));
+}
+
+#[test]
+fn shapers_can_keep_state_per_shape_plan() {
+    let font_ref = open_sans();
+    let cache: ShaperCache<String> = ShaperCache::new();
+    let shape = |plan, text| {
+        let buf_ref = native::create_buffer(text);
+        run_shaper(
+            plan,
+            font_ref,
+            buf_ref,
+            0,
+            0,
+            |plan, font, _buf_ref, _features| {
+                cache.get_or_insert_with_plan(plan, font, || text.to_string());
+                Ok(())
+            },
+        )
+    };
+    assert_eq!(shape(1, "ab"), 1);
+    assert_eq!(shape(2, "cd"), 1);
+    assert_eq!(shape(1, "ef"), 1);
+    let font = Font::from_ref(font_ref);
+    assert_eq!(*cache.get_or_insert_with_plan(1, &font, String::new), "ab");
+    assert_eq!(*cache.get_or_insert_with_plan(2, &font, String::new), "cd");
+}
+
+#[test]
+fn glyphs_by_name() {
+    let font = Font::from_ref(open_sans());
+    assert_eq!(font.get_face().glyph_count(), 938);
+    let a = font.get_glyph('A' as u32, 0);
+    assert_eq!(font.get_glyph_from_name("A"), Some(a));
+    assert_eq!(font.get_glyph_from_name("no.such.glyph"), None);
+}
+
+#[test]
+fn cache_is_rebuilt_when_the_font_changes() {
+    let font_ref = native::load_font(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../base-fonts/Recursive-subset.ttf"
+    ))
+    .unwrap();
+    let font = Font::from_ref(font_ref);
+    let cache: ShaperCache<u32> = ShaperCache::new();
+    let mut builds = 0;
+    let first = cache.get_or_insert_with(&font, || {
+        builds += 1;
+        builds
+    });
+    let before = native::outstanding_allocations();
+    let second = cache.get_or_insert_with(&font, || unreachable!());
+    assert!(Rc::ptr_eq(&first, &second));
+    // Checking the coordinates doesn't leave a copy behind.
+    assert_eq!(native::outstanding_allocations(), before);
+    let mut coords = font.get_var_coords();
+    coords[0] = 0.5;
+    font.set_var_coords(&coords);
+    assert_eq!(*cache.get_or_insert_with(&font, || 2), 2);
+
+    let scaled: ShaperCache<i32> = ShaperCache::new();
+    assert_eq!(*scaled.get_or_insert_with_scale(&font, || 1), 1);
+    assert_eq!(*scaled.get_or_insert_with_scale(&font, || 2), 1);
+    native::set_font_scale(font_ref, 2000, 2000);
+    assert_eq!(*scaled.get_or_insert_with_scale(&font, || 3), 3);
+    assert_eq!(*cache.get_or_insert_with(&font, || unreachable!()), 2);
+}
+
+#[cfg(feature = "tables")]
+#[test]
+fn tables_can_be_parsed() {
+    use harfbuzz_wasm::tables::{head, os2};
+    let face = Font::from_ref(open_sans()).get_face();
+    let head: head::Table = face.reference_table("head").parse().unwrap();
+    assert_eq!(head.units_per_em, 2048);
+    let blob = face.reference_table("OS/2");
+    let os2: os2::Table = blob.parse().unwrap();
+    assert!(os2.x_height().unwrap() > 0);
+    let hhea = face.reference_table("hhea");
+    assert_eq!(
+        hhea.parse::<os2::Table>().err(),
+        Some(HbWasmError::TableMalformed("OS/2".to_string()))
+    );
+}
+
+#[cfg(feature = "tables")]
+#[test]
+fn axes_in_user_space() {
+    let font = Font::from_ref(
+        native::load_font(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../base-fonts/Recursive-subset.ttf"
+        ))
+        .unwrap(),
+    );
+    let tags: Vec<String> = font.get_face().axes().into_iter().map(|a| a.tag).collect();
+    assert_eq!(tags, vec!["wght", "slnt", "CRSV"]);
+    // fvar and avar are only copied from the host once.
+    let before = native::outstanding_allocations();
+    font.get_face().axes();
+    font.axis_value("wght");
+    assert_eq!(native::outstanding_allocations(), before);
+    assert_eq!(font.axis_value("wght"), Some(300.0));
+    font.set_axis_value("wght", 500.0).unwrap();
+    assert!((font.axis_value("wght").unwrap() - 500.0).abs() < 0.5);
+    assert_eq!(font.axis_value("slnt"), Some(0.0));
+    assert_eq!(
+        font.set_axis_value("XXXX", 1.0),
+        Err(HbWasmError::AxisNotFound("XXXX".to_string()))
+    );
+}
+
+#[cfg(feature = "tables")]
+#[test]
+fn glyph_definitions() {
+    use harfbuzz_wasm::GlyphClass;
+    let font = Font::from_ref(
+        native::load_font(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../base-fonts/NotoNastaliqUrdu-VF.ttf"
+        ))
+        .unwrap(),
+    );
+    let above = font.get_glyph_from_name("OneDotAboveNS").unwrap();
+    let below = font.get_glyph_from_name("OneDotBelowNS").unwrap();
+    assert_eq!(font.glyph_class(above), Some(GlyphClass::Mark));
+    assert!(font.is_mark(below));
+    assert_eq!(font.mark_attachment_class(above), 2);
+    assert_eq!(font.mark_attachment_class(below), 0);
+    assert!(font.is_in_mark_glyph_set(above, 1));
+    assert!(!font.is_in_mark_glyph_set(above, 2));
+    assert!(font.is_in_mark_glyph_set(below, 2));
+    assert!(font.ligature_carets(above).is_empty());
+
+    // Open Sans has no glyph classes.
+    let open_sans = Font::from_ref(open_sans());
+    assert_eq!(
+        open_sans.glyph_class(open_sans.get_glyph('a' as u32, 0)),
+        None
+    );
+
+    let recursive = native::load_font(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../base-fonts/Recursive-subset.ttf"
+    ))
+    .unwrap();
+    let font = Font::from_ref(recursive);
+    assert_eq!(font.ligature_carets(256), vec![400]);
+    assert_eq!(font.ligature_carets(257), vec![400, 800]);
+    native::set_font_scale(recursive, 500, 500);
+    assert_eq!(font.ligature_carets(257), vec![200, 400]);
+}
+
+#[cfg(feature = "tables")]
+#[test]
+fn glyph_positioning() {
+    use harfbuzz_wasm::{Adjustment, Anchor};
+    let nastaliq = native::load_font(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../base-fonts/NotoNastaliqUrdu-VF.ttf"
+    ))
+    .unwrap();
+    let font = Font::from_ref(nastaliq);
+    let id = |name| font.get_glyph_from_name(name).unwrap();
+    let medial = font.cursive_anchors(id("BehxMed")).unwrap();
+    assert_eq!(medial.entry, Some(Anchor { x: 199, y: 103 }));
+    assert_eq!(medial.exit, Some(Anchor { x: 0, y: 0 }));
+    assert_eq!(medial.rise(), -103);
+    let alef = font.cursive_anchors(id("AlefFin")).unwrap();
+    assert_eq!(alef.exit, None);
+    assert_eq!(font.cursive_anchors(id("OneDotAboveNS")), None);
+    assert_eq!(
+        font.cursive_anchors_for(&[id("BehxMed"), id("OneDotAboveNS"), id("AlefFin")]),
+        vec![Some(medial), None, Some(alef)]
+    );
+
+    let attachment = font
+        .mark_attachment(id("BehxIni"), id("OneDotAboveNS"))
+        .unwrap();
+    assert_eq!(attachment.base, Anchor { x: 147, y: 514 });
+    assert_eq!(attachment.offset(), (147, -406));
+    assert_eq!(
+        font.mark_attachment(id("OneDotAboveNS"), id("BehxIni")),
+        None
+    );
+
+    // Anchors follow the variation coordinates and the scale.
+    font.set_axis_value("wght", 700.0).unwrap();
+    let bold = font.cursive_anchors(id("AlefFin")).unwrap();
+    assert_eq!(bold.entry, Some(Anchor { x: 267, y: 0 }));
+    let entry_at = |scale| {
+        native::set_font_scale(nastaliq, scale, scale);
+        font.cursive_anchors(id("BehxMed")).unwrap().entry.unwrap()
+    };
+    let (small, large) = (entry_at(1000), entry_at(2000));
+    assert!((small.x * 2 - large.x).abs() <= 1);
+
+    let font = Font::from_ref(
+        native::load_font(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../base-fonts/ArefRuqaa-Regular.otf"
+        ))
+        .unwrap(),
+    );
+    let id = |name| font.get_glyph_from_name(name).unwrap();
+    let (first, second) = font
+        .pair_adjustment(id("alef-ar.fina"), id("hah-ar"))
+        .unwrap();
+    assert_eq!(first.x_advance, -193);
+    assert_eq!(second, Adjustment::default());
+    assert_eq!(font.pair_kerning(id("alef-ar.fina"), id("hah-ar")), -193);
+    assert_eq!(font.pair_kerning(id("hah-ar"), id("alef-ar.fina")), 0);
+}
+
+// A Wcfg table with one axis and one region, peaking at the axis
+// maximum: kern_distance is 300.0 (+50), max_tightening is 1000
+// (-100), and dot_glyph is "a".
+fn config_table() -> Vec<u8> {
+    let mut table = vec![];
+    for value in [1u16, 0, 1, 1, 3, 2, 0, 16384, 16384] {
+        table.extend(value.to_be_bytes());
+    }
+    let mut entry = |key: (u16, u16), kind: u16, value: u32, deltas: (u16, u16)| {
+        for v in [key.0, key.1, kind] {
+            table.extend(v.to_be_bytes());
+        }
+        table.extend(value.to_be_bytes());
+        table.extend(deltas.0.to_be_bytes());
+        table.extend(deltas.1.to_be_bytes());
+    };
+    entry((0, 9), 3, 9 << 16 | 1, (0, 0));
+    entry((10, 13), 2, 300 << 16, (0, 1));
+    entry((23, 14), 1, 1000, (1, 1));
+    for delta in [50 << 16, -100i32] {
+        table.extend(0u16.to_be_bytes());
+        table.extend(delta.to_be_bytes());
+    }
+    table.extend(b"dot_glyphakern_distancemax_tightening");
+    table
+}
+
+#[test]
+fn config_from_the_font() {
+    use harfbuzz_wasm::{Config, ConfigValue};
+    let font = Font::from_ref(recursive());
+    assert_eq!(
+        font.try_config(),
+        Err(HbWasmError::TableNotFound("Wcfg".to_string()))
+    );
+    assert_eq!(font.config().float("kern_distance", 12.5), 12.5);
+
+    // Faces don't change, so each table needs a font of its own.
+    let font_ref = recursive();
+    native::set_font_table(font_ref, "Wcfg", &config_table());
+    let font = Font::from_ref(font_ref);
+    let config = font.config();
+    let keys: Vec<&str> = config.keys().collect();
+    assert_eq!(keys, vec!["dot_glyph", "kern_distance", "max_tightening"]);
+    assert_eq!(
+        config.get("kern_distance"),
+        Some(&ConfigValue::Float(300.0))
+    );
+    assert_eq!(config.int("max_tightening", 0), 1000);
+    assert_eq!(config.float("max_tightening", 0.0), 1000.0);
+    assert_eq!(config.string("dot_glyph", ""), "a");
+    // This subset has no glyph names, so "a" can't be found.
+    assert_eq!(config.glyph(&font, "dot_glyph", 5), 5);
+    assert_eq!(config.glyph(&font, "max_tightening", 5), 1000);
+    assert_eq!(config.int("missing", 7), 7);
+    assert_eq!(config.string("kern_distance", "none"), "none");
+
+    // The table is only parsed again when the coordinates change.
+    assert!(Rc::ptr_eq(&font.config(), &config));
+
+    // Values vary along the first axis only.
+    font.set_var_coords(&[0.5, 0.0, 0.0]);
+    let config = font.config();
+    assert_eq!(config.float("kern_distance", 0.0), 325.0);
+    assert_eq!(config.int("max_tightening", 0), 950);
+    font.set_var_coords(&[1.0, -1.0, 1.0]);
+    assert_eq!(font.config().float("kern_distance", 0.0), 350.0);
+    font.set_var_coords(&[-1.0, 0.0, 0.0]);
+    assert_eq!(font.config().float("kern_distance", 0.0), 300.0);
+
+    let font_ref = recursive();
+    native::set_font_table(font_ref, "Wcfg", &[0, 2, 0, 0]);
+    let font = Font::from_ref(font_ref);
+    assert_eq!(
+        font.try_config(),
+        Err(HbWasmError::TableMalformed("Wcfg".to_string()))
+    );
+    assert_eq!(*font.config(), Config::default());
+}
+
+#[test]
+fn negative_glyph_ids_in_the_config_are_ignored() {
+    use harfbuzz_wasm::Config;
+    let font = Font::from_ref(recursive());
+    let mut table = vec![];
+    // One entry and no variations: "g" is the integer -5.
+    for value in [1u16, 0, 0, 0, 1, 0, 0, 1, 1] {
+        table.extend(value.to_be_bytes());
+    }
+    table.extend((-5i32).to_be_bytes());
+    table.extend([0, 0, 0, 0]);
+    table.extend(b"g");
+    let config = Config::from_bytes(&table, &[]).unwrap();
+    assert_eq!(config.int("g", 0), -5);
+    assert_eq!(config.glyph(&font, "g", 7), 7);
+}
+
+#[test]
+fn editing_keeps_clusters_in_order() {
+    let font = Font::from_ref(open_sans());
+    let buf_ref = native::create_buffer("abcd");
+    font.shape_with(buf_ref, "ot");
+    let mut buffer = GlyphBuffer::from_ref(buf_ref);
+    let glyph = |codepoint| Glyph {
+        codepoint,
+        ..Default::default()
+    };
+    buffer.ligate(1..3, glyph(100));
+    let clusters = |b: &GlyphBuffer| b.glyphs.iter().map(|g| g.cluster).collect::<Vec<_>>();
+    assert_eq!(clusters(&buffer), vec![0, 1, 3]);
+    buffer.decompose(0, vec![glyph(101), glyph(102)]);
+    assert_eq!(clusters(&buffer), vec![0, 0, 1, 3]);
+    buffer.insert_after(2, glyph(103));
+    assert_eq!(clusters(&buffer), vec![0, 0, 1, 1, 3]);
+    buffer.merge_clusters(1..3);
+    assert_eq!(clusters(&buffer), vec![0, 0, 0, 0, 3]);
+    assert_eq!(buffer.validate_clusters(), Ok(()));
+    buffer.glyphs.swap(0, 4);
+    assert_eq!(
+        buffer.validate_clusters(),
+        Err(HbWasmError::ClustersOutOfOrder(1))
+    );
+}
+
+#[test]
+fn shape_buffer_syncs_both_ways() {
+    let font = Font::from_ref(open_sans());
+    let buf_ref = native::create_buffer("xab");
+    let mut buffer = GlyphBuffer::from_ref(buf_ref);
+    buffer.glyphs.remove(0);
+    font.shape_buffer(&mut buffer, "ot", &[]).unwrap();
+    assert_eq!(buffer.glyphs.len(), 2);
+    assert_eq!(buffer.glyphs[0].codepoint, font.get_glyph('a' as u32, 0));
+    buffer.glyphs[0].x_offset = 10;
+    drop(buffer);
+    assert_eq!(native::buffer_glyphs(buf_ref)[0].x_offset, 10);
+}
+
+#[test]
+fn text_maps_back_to_clusters() {
+    let buf_ref = native::create_buffer("1×2=");
+    let buffer = GlyphBuffer::from_ref(buf_ref);
+    let text = buffer.text_map();
+    assert_eq!(text.text, "1×2=");
+    let times = text.text.find('×').unwrap();
+    assert_eq!(text.cluster_at(times), Some(1));
+    assert_eq!(text.cluster_at(times + 1), Some(1));
+    assert_eq!(text.cluster_at(times + 2), Some(3));
+    assert_eq!(text.glyph_range(times..text.text.len()), 1..4);
+}
+
+#[cfg(feature = "kurbo")]
+#[test]
+fn glyphs_are_placed_in_visual_order() {
+    use harfbuzz_wasm::geometry::OutlineCache;
+    let font = Font::from_ref(open_sans());
+    let outlines = OutlineCache::new();
+    for text in ["ab", "\u{05D0}\u{05D1}"] {
+        let buf_ref = native::create_buffer(text);
+        font.shape_with(buf_ref, "ot");
+        let buffer = GlyphBuffer::from_ref(buf_ref);
+        let pens = buffer.pen_positions();
+        assert_eq!(pens.len(), 3);
+        assert_eq!(pens[1].x, buffer.glyphs[0].x_advance as f64);
+        let total: i32 = buffer.glyphs.iter().map(|g| g.x_advance).sum();
+        assert_eq!(pens[2].x, total as f64);
+
+        // The first glyph is always the leftmost.
+        let boxes = buffer.bounding_boxes(&font);
+        assert!(boxes[0].center().x < boxes[1].center().x);
+        let placed = buffer.positioned_outlines(&font, &outlines);
+        for (outline, bounds) in placed.iter().zip(boxes) {
+            let drawn = outline.bounding_box();
+            assert!((drawn.x0 - bounds.x0).abs() < 1.0);
+            assert!((drawn.y1 - bounds.y1).abs() < 1.0);
+        }
+        let paths = placed[1].paths();
+        assert_eq!(paths.len(), placed[1].outline.paths.len());
+    }
+    let a = font.get_glyph('a' as u32, 0);
+    assert!(Rc::ptr_eq(&outlines.get(&font, a), &outlines.get(&font, a)));
+}
+
+#[test]
+fn outlines_without_kurbo() {
+    let font = Font::from_ref(open_sans());
+    let contours = |c: char| {
+        font.get_raw_outline(font.get_glyph(c as u32, 0))
+            .contours
+            .len()
+    };
+    assert_eq!(contours('l'), 1);
+    assert_eq!(contours('o'), 2);
+    assert_eq!(contours('B'), 3);
+    assert_eq!(contours(' '), 0);
+
+    // The extreme points agree with the glyph's extents.
+    let o = font.get_glyph('o' as u32, 0);
+    let outline = font.get_raw_outline(o);
+    let extents = font.get_glyph_extents(o);
+    let left = outline
+        .on_curve_points()
+        .map(|p| p.x)
+        .fold(f32::MAX, f32::min);
+    let top = outline
+        .on_curve_points()
+        .map(|p| p.y)
+        .fold(f32::MIN, f32::max);
+    assert!((left - extents.x_bearing as f32).abs() <= 1.0);
+    assert!((top - extents.y_bearing as f32).abs() <= 1.0);
+    assert_eq!(
+        outline.contours().map(|c| c.len()).sum::<usize>(),
+        outline.points.len()
+    );
+    #[cfg(feature = "kurbo")]
+    assert_eq!(font.get_outline(o).len(), 2);
+}
+
+#[test]
+fn outlines_are_freed() {
+    let font = Font::from_ref(open_sans());
+    let o = font.get_glyph('o' as u32, 0);
+    let before = native::outstanding_allocations();
+    for _ in 0..100 {
+        assert_eq!(font.try_draw_glyph(o, &mut RawOutline::default()), Ok(()));
+    }
+    assert_eq!(native::outstanding_allocations(), before);
+    // A font reference the host doesn't know about
+    assert_eq!(
+        Font::from_ref(9999).try_draw_glyph(o, &mut RawOutline::default()),
+        Err(HbWasmError::OutlineCopyFailed(o))
+    );
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "unicode")]
+use harfbuzz_wasm::unicode::*;
+
+#[test]
+fn character_properties() {
+    assert_eq!(general_category('A'), GeneralCategory::UppercaseLetter);
+    assert_eq!(general_category('\u{10FFFF}'), GeneralCategory::Unassigned);
+    assert_eq!(script('ب'), "Arab");
+    assert_eq!(script('\u{064E}'), "Zinh");
+    assert_eq!(script('1'), "Zyyy");
+    assert_eq!(joining_type('ب'), JoiningType::DualJoining);
+    assert_eq!(joining_type('ا'), JoiningType::RightJoining);
+    assert_eq!(joining_type('\u{0640}'), JoiningType::JoinCausing);
+    assert_eq!(joining_group('ے'), Some("Yeh_Barree"));
+    assert_eq!(joining_group('A'), None);
+    assert_eq!(bidi_class('ب'), BidiClass::AL);
+    assert_eq!(bidi_class('\u{0661}'), BidiClass::AN);
+    assert_eq!(combining_class('\u{0301}'), 230);
+    assert!(is_mirrored('('));
+    assert_eq!(mirror('('), Some(')'));
+    assert_eq!(mirror('a'), None);
+    assert_eq!(
+        egyptian_format_control('\u{13430}'),
+        Some(EgyptianFormatControl::VerticalJoiner)
+    );
+    assert_eq!(script('\u{13438}'), "Egyp");
+    assert_eq!(egyptian_format_control('\u{13439}'), None);
+    assert_eq!(general_category('\u{13439}'), GeneralCategory::Unassigned);
+}
@@ -1,22 +1,25 @@
 use crate::ast::Expr;
-use harfbuzz_wasm::{debug, Buffer, Font, Glyph, GlyphBuffer};
+use harfbuzz_wasm::{
+    debug, register_shaper, Buffer, FeatureList, Font, Glyph, GlyphBuffer, ShapeResult, ShaperCache,
+};
 use std::collections::BTreeMap;
+use std::rc::Rc;
 
 use lalrpop_util::lalrpop_mod;
-use wasm_bindgen::prelude::*;
 mod tokenizer;
 use tokenizer::{Lexer, Tok};
 lalrpop_mod!(pub parser);
 pub mod ast;
 
+// The size of each quadrat, unless the font sets "advance" in its
+// Wcfg table.
 const ADVANCE: f32 = 1500_f32;
 
 struct LayoutEngine<'a> {
     font: &'a Font,
-    scale_factor: f32,
+    advance: i32,
     glyphs: Vec<Glyph>,
-    half_map: BTreeMap<u32, u32>,
-    quarter_map: BTreeMap<u32, u32>,
+    small_glyphs: Rc<SmallGlyphs>,
     width: i32,
     height: i32,
     x_offset: i32,
@@ -26,36 +29,51 @@ struct LayoutEngine<'a> {
     is_first_glyph: bool,
 }
 
+// IDs of the smaller versions of each glyph, which only need
+// working out once per font.
+struct SmallGlyphs {
+    half: BTreeMap<u32, u32>,
+    quarter: BTreeMap<u32, u32>,
+}
+
+impl SmallGlyphs {
+    fn new(font: &Font) -> Self {
+        let mut half: BTreeMap<u32, u32> = BTreeMap::new();
+        let mut quarter: BTreeMap<u32, u32> = BTreeMap::new();
+        for id in 0..font.get_face().glyph_count() {
+            let name = font.get_glyph_name(id);
+            if let Some(half_id) = font.get_glyph_from_name(&(name.to_owned() + ".half")) {
+                half.insert(id, half_id);
+            }
+            if let Some(quarter_id) = font.get_glyph_from_name(&(name + ".quarter")) {
+                quarter.insert(id, quarter_id);
+            }
+        }
+        Self { half, quarter }
+    }
+}
+
+thread_local! {
+    static SMALL_GLYPHS: ShaperCache<SmallGlyphs> = const { ShaperCache::new() };
+}
+
 impl<'a> LayoutEngine<'a> {
     fn new(font: &'a Font) -> Self {
-        let names_map: BTreeMap<String, u32> = (0..3210_u32)
-            .map(|id| (font.get_glyph_name(id), id))
-            .collect();
-        let mut half_map: BTreeMap<u32, u32> = BTreeMap::new();
-        let mut quarter_map: BTreeMap<u32, u32> = BTreeMap::new();
         let (x_scale, _y_scale) = font.get_scale();
         let face = font.get_face();
         let upem = face.get_upem();
         let scale_factor: f32 = x_scale as f32 / upem as f32;
-
-        // Find IDs for small versions of glyphs
-        for (name, &id) in names_map.iter() {
-            if let Some(half_id) = names_map.get(&(name.to_owned() + ".half")) {
-                half_map.insert(id, *half_id);
-            }
-            if let Some(quarter_id) = names_map.get(&(name.to_owned() + ".quarter")) {
-                quarter_map.insert(id, *quarter_id);
-            }
-        }
+        let advance = (font.config().float("advance", ADVANCE) * scale_factor) as i32;
+        let small_glyphs =
+            SMALL_GLYPHS.with(|cache| cache.get_or_insert_with(font, || SmallGlyphs::new(font)));
 
         Self {
             font,
             glyphs: vec![],
-            half_map,
-            quarter_map,
-            scale_factor,
-            width: (ADVANCE * scale_factor) as i32,
-            height: (ADVANCE * scale_factor) as i32,
+            small_glyphs,
+            advance,
+            width: advance,
+            height: advance,
             x_offset: 0,
             y_offset: 0,
             cluster: 0,
@@ -64,8 +82,8 @@ impl<'a> LayoutEngine<'a> {
         }
     }
     fn layout_cluster(&mut self, expr: &Expr) {
-        self.width = (ADVANCE * self.scale_factor) as i32;
-        self.height = (ADVANCE * self.scale_factor) as i32;
+        self.width = self.advance;
+        self.height = self.advance;
         self.x_offset = 0;
         self.y_offset = 0;
         self.depth = 0;
@@ -101,11 +119,11 @@ impl<'a> LayoutEngine<'a> {
         // improving.
 
         // if self.depth > 2 {
-        //     glyph_id = *self.quarter_map.get(&glyph_id).unwrap_or(&glyph_id);
+        //     glyph_id = *self.small_glyphs.quarter.get(&glyph_id).unwrap_or(&glyph_id);
         // } else
         //
         if self.depth > 1 {
-            glyph_id = *self.half_map.get(&glyph_id).unwrap_or(&glyph_id);
+            glyph_id = *self.small_glyphs.half.get(&glyph_id).unwrap_or(&glyph_id);
         }
 
         // let h_advance = self.font.get_glyph_h_advance(glyph_id);
@@ -122,19 +140,20 @@ impl<'a> LayoutEngine<'a> {
             codepoint: glyph_id,
             cluster: self.cluster,
             x_advance: if self.is_first_glyph {
-                (ADVANCE * self.scale_factor) as i32
+                self.advance
             } else {
                 0
             },
             y_advance: 0,
             x_offset: if !self.is_first_glyph {
-                -(ADVANCE * self.scale_factor) as i32
+                -self.advance
             } else {
                 0
             } + self.x_offset
                 + centering_x,
             y_offset: self.y_offset + centering_y,
             flags: 0,
+            ..Default::default()
         });
         self.is_first_glyph = false
     }
@@ -253,27 +272,28 @@ impl<'a> LayoutEngine<'a> {
     }
 }
 
-#[wasm_bindgen]
-pub fn shape(
+fn shape_hieroglyphs(
     _shape_plan: u32,
-    font_ref: u32,
+    font: &Font,
     buf_ref: u32,
-    _features: u32,
-    _num_features: u32,
-) -> i32 {
-    let font = Font::from_ref(font_ref);
+    _features: &FeatureList,
+) -> ShapeResult {
     // Get all glyph names
     let mut buffer = GlyphBuffer::from_ref(buf_ref);
     // Turn the buffer into a nested structure
     let codepoints: Vec<u32> = buffer.glyphs.iter().map(|item| item.codepoint).collect();
     let lexer = Lexer::new(&codepoints);
     let parser = parser::FragmentParser::new();
-    let expr: Vec<Box<Expr>> = parser.parse(lexer).unwrap();
-    let mut engine = LayoutEngine::new(&font);
+    let expr: Vec<Box<Expr>> = parser
+        .parse(lexer)
+        .map_err(|e| format!("Couldn't parse hieroglyphs: {:?}", e))?;
+    let mut engine = LayoutEngine::new(font);
     debug(&format!("Expression was {:?}", expr));
     for exp in expr.iter() {
         engine.layout_cluster(exp);
     }
     buffer.glyphs = engine.glyphs;
-    1
+    Ok(())
 }
+
+register_shaper!(shape_hieroglyphs);
@@ -1,3 +1,5 @@
+use harfbuzz_wasm::unicode::{egyptian_format_control, script, EgyptianFormatControl::*};
+
 #[derive(Debug, Clone)]
 pub enum Tok {
     Sign(u32),
@@ -37,21 +39,22 @@ impl<'input> Iterator for Lexer<'input> {
 
     fn next(&mut self) -> Option<Self::Item> {
         match self.chars.next() {
-            Some((i, 0x13430)) => Some(Ok((i, Tok::Vert, i + 1))),
-            Some((i, 0x13431)) => Some(Ok((i, Tok::Hor, i + 1))),
-            Some((i, 0x13432)) => Some(Ok((i, Tok::St, i + 1))),
-            Some((i, 0x13433)) => Some(Ok((i, Tok::Sb, i + 1))),
-            Some((i, 0x13434)) => Some(Ok((i, Tok::Et, i + 1))),
-            Some((i, 0x13435)) => Some(Ok((i, Tok::Eb, i + 1))),
-            Some((i, 0x13436)) => Some(Ok((i, Tok::Overlay, i + 1))),
-            Some((i, 0x13437)) => Some(Ok((i, Tok::Begin, i + 1))),
-            Some((i, 0x13438)) => Some(Ok((i, Tok::End, i + 1))),
             Some((i, &cp)) => {
-                if (0x13000..=0x1342F).contains(&cp) {
-                    Some(Ok((i, Tok::Sign(cp), i + 1)))
-                } else {
-                    Some(Ok((i, Tok::Other(cp), i + 1)))
-                }
+                let c = char::from_u32(cp).unwrap_or(char::REPLACEMENT_CHARACTER);
+                let tok = match egyptian_format_control(c) {
+                    Some(VerticalJoiner) => Tok::Vert,
+                    Some(HorizontalJoiner) => Tok::Hor,
+                    Some(InsertAtTopStart) => Tok::St,
+                    Some(InsertAtBottomStart) => Tok::Sb,
+                    Some(InsertAtTopEnd) => Tok::Et,
+                    Some(InsertAtBottomEnd) => Tok::Eb,
+                    Some(OverlayMiddle) => Tok::Overlay,
+                    Some(BeginSegment) => Tok::Begin,
+                    Some(EndSegment) => Tok::End,
+                    None if script(c) == "Egyp" => Tok::Sign(cp),
+                    None => Tok::Other(cp),
+                };
+                Some(Ok((i, tok, i + 1)))
             }
             None => None, // End of file
         }
@@ -0,0 +1,25 @@
+use harfbuzz_wasm::native;
+use hieroglyphs::shape;
+
+fn font() -> u32 {
+    native::load_font(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/Hieroglyphs-Regular.ttf"
+    ))
+    .unwrap()
+}
+
+#[test]
+fn vertical_group_shares_a_quadrat() {
+    let font = font();
+    // t : t, followed by a lone sign
+    let buffer = native::create_buffer("\u{133CF}\u{13430}\u{133CF}\u{13000}");
+    assert_eq!(shape(0, font, buffer, 0, 0), 1);
+    let glyphs = native::buffer_glyphs(buffer);
+    assert_eq!(glyphs.len(), 3);
+    assert_eq!(glyphs[0].cluster, glyphs[1].cluster);
+    assert_ne!(glyphs[1].cluster, glyphs[2].cluster);
+    assert!(glyphs[0].x_advance > 0);
+    assert_eq!(glyphs[1].x_advance, 0);
+    assert!(glyphs[0].y_offset != glyphs[1].y_offset);
+}
@@ -0,0 +1,8 @@
+// Look up the space and pixel glyphs. These are in the outer font,
+// which the Makefile builds from Inception.glyphs; BASE_FONT is the
+// font inside it.
+fn main() {
+    glyph_ids::GlyphIds::from_makefile(&["TARGET_SOURCE"])
+        .glyphs(&["space", "pixel", "pixel2", "pixel3"])
+        .build("glyphs.rs");
+}
@@ -8,7 +8,7 @@
 use ab_glyph_rasterizer::Rasterizer;
 // harfbuzz_wasm provides access to structures related to Harfbuzz
 // shaping.
-use harfbuzz_wasm::{Buffer, CGlyphExtents, Font, Glyph, GlyphBuffer};
+use harfbuzz_wasm::{debug, Buffer, CGlyphExtents, FeatureList, Font, Glyph, GlyphBuffer};
 // And kurbo is a library which helps manipulate curve structures.
 use kurbo::{
     Affine, BezPath,
@@ -17,6 +17,12 @@ use kurbo::{
 // Finally this will give us the connection to the WASM engine.
 use wasm_bindgen::prelude::*;
 
+// The IDs of the space and pixel glyphs in the outer font, which
+// build.rs looks up by name when the shaper is built.
+mod glyphs {
+    include!(concat!(env!("OUT_DIR"), "/glyphs.rs"));
+}
+
 // Get the bezier paths of a glyph, by ID, and apply a scaling transform.
 fn get_scaled_outline(font: &Font, glyph: u32, scale_factor: f64) -> Vec<BezPath> {
     let mut paths = font.get_outline(glyph);
@@ -77,11 +83,11 @@ fn rasterize(
         // light grey, dark grey) based on the coverage of
         // this pixel.
         let color = if alpha > 0.5 {
-            2
+            glyphs::PIXEL
         } else if alpha > 0.3 {
-            3
+            glyphs::PIXEL2
         } else {
-            4
+            glyphs::PIXEL3
         };
         // Create an output glyph representing this pixel, at
         // this color and this position.
@@ -93,6 +99,7 @@ fn rasterize(
             flags: 0,
             x_offset: ((x as f32) * pixel_size) as i32,
             y_offset: ((y as f32) * pixel_size) as i32,
+            ..Default::default()
         });
     });
     glyphs
@@ -103,32 +110,44 @@ pub fn shape(
     _shape_plan: u32,
     font_ref: u32,
     buf_ref: u32,
-    _features: u32,
-    _num_features: u32,
+    features: u32,
+    num_features: u32,
 ) -> i32 {
     let font = Font::from_ref(font_ref);
     let face = font.get_face();
+    let features = FeatureList::from_ref(features, num_features);
 
     // There's a table inside the font called "Font", so read
-    // that, and turn it into a Harfbuzz font structure.
-    let inner_font_blob = face.reference_table("Font");
-    let inner_face = inner_font_blob.into_face(0);
+    // that, and turn it into a Harfbuzz font structure. If it
+    // isn't there, or isn't a font, just shape the text normally
+    // rather than bringing the whole shaper down.
+    let inner_face = match face
+        .try_reference_table("Font")
+        .and_then(|b| b.try_into_face(0))
+    {
+        Ok(inner_face) => inner_face,
+        Err(e) => {
+            debug(&e.to_string());
+            font.shape_with_features(buf_ref, "ot", &features.features);
+            return 1;
+        }
+    };
     let inner_font = inner_face.create_font();
 
     // Find the size of the pixel. This will already have been
     // affected by variation on the opsz axis.
-    let pixel_size = -font.get_glyph_extents(2).height as f32;
+    let pixel_size = -font.get_glyph_extents(glyphs::PIXEL).height as f32;
 
     // Take all the other variation axis settings and apply
-    // them to the inner font.
-    let coords = font.get_var_coords();
-    if coords.len() > 1 {
-        inner_font.set_var_coords(&coords[2..]);
+    // them to the inner font, matching the axes up by tag.
+    if let Err(e) = inner_font.copy_axis_values_from(&font) {
+        debug(&e.to_string());
     }
 
     // Run ordinary OpenType shaping on the inner font, using
-    // the current buffer, and get the result.
-    inner_font.shape_with(buf_ref, "ot");
+    // the current buffer and the user's feature settings, and
+    // get the result.
+    inner_font.shape_with_features(buf_ref, "ot", &features.features);
     let mut buffer: GlyphBuffer = Buffer::from_ref(buf_ref);
 
     let mut new_glyphs: Vec<Glyph> = vec![];
@@ -145,12 +164,13 @@ pub fn shape(
         let x_advance = (item.x_advance * face.get_upem() as i32) / (inner_face.get_upem() as i32);
         new_glyphs.push(Glyph {
             cluster: item.cluster,
-            codepoint: 1, // space,
+            codepoint: glyphs::SPACE,
             x_advance,
             y_advance: 0,
             flags: 0,
             x_offset: 0,
             y_offset: 0,
+            ..Default::default()
         });
     }
     // Send our new buffer back to the shaping engine.
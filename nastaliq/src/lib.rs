@@ -1,80 +1,93 @@
-mod dist;
 mod glyph;
 
-// Auto-kerning routine, look in dist.rs for this.
-use dist::determine_kern;
 // Routines for interfacing with Harfbuzz
-use harfbuzz_wasm::{debug, Font};
+use harfbuzz_wasm::geometry::OutlineCache;
+use harfbuzz_wasm::{debug, FeatureList, Font, ShaperCache};
 // With the Harfbuzz interface, we can choose how we want
 // to represent a glyph. Here we use our own custom glyph
 // representation so we can do clever things with it.
 use glyph::GulzarBuffer;
-// Kurbo is a library for doing mathematics on bezier curves.
-use kurbo::{Affine, BezPath, Shape};
+// Auto-kerning and collision detection on glyph outlines.
+use outline_distance::{kern_to_distance, Outline};
+use std::cell::RefCell;
 use std::collections::BTreeMap;
 
 use wasm_bindgen::prelude::*;
 
-// In a serious Nastaliq shaper these values would be read
-// from the font.
+// Defaults for the settings the font can override in its Wcfg
+// table (as kern_distance, bari_ye_dot_position and
+// dot_avoidance_delta).
 const KERN_DISTANCE: f32 = 300.0;
 const BARI_YE_DOT_POSITION: f32 = -150.0;
 const DOT_AVOIDANCE_DELTA: f32 = 50.0; // How much to move a colliding dot. Affects rendering speed.
+const MAXIMUM_TIGHTENING: f32 = 1000.0;
+// How many times dot avoidance may move a dot before giving up, in
+// case the dots can't be moved clear of everything else.
+const MAXIMUM_DOT_MOVES: usize = 200;
 
-// Return a slightly scaled-up copy of a glyph's outline.
-// We create this slightly bigger copy of the glyphs so that
-// when we do collision tests between glyphs, they have a bit
-// of breathing space around them.
-fn get_scaled_outline(font: &Font, glyph: u32) -> Vec<BezPath> {
-    let mut paths = font.get_outline(glyph);
-    // Find a bounding box which encompasses all the paths by
-    // taking the union of all of their bounding boxes.
-    if let Some(big_bounds) = paths
-        .iter()
-        .map(|x| x.bounding_box())
-        .reduce(|a, b| a.union(b))
-    {
-        // Scale up 10% around the center.
-        let center_vec = big_bounds.center().to_vec2();
-        let affine = Affine::translate(center_vec * -1.0)
-            * Affine::scale(1.12)
-            * Affine::translate(center_vec);
-        for path in paths.iter_mut() {
-            path.apply_affine(affine);
-        }
+// How much to scale up glyph outlines. We use slightly bigger
+// copies of the glyphs so that when we do collision tests between
+// glyphs, they have a bit of breathing space around them.
+const OUTLINE_PADDING: f64 = 1.12;
+
+// The font's GDEF mark glyph sets for marks above and below the
+// letters, which is where we look for dots.
+const MARKS_ABOVE: u16 = 1;
+const MARKS_BELOW: u16 = 2;
+
+// Normally Harfbuzz buffers give you the advance for each glyph
+// but it turns out to be quite useful for us to keep the pen
+// position in the data structure representing each glyph.
+fn set_pen_positions(buffer: &mut GulzarBuffer) {
+    let pens = buffer.pen_positions();
+    for (item, pen) in buffer.glyphs.iter_mut().zip(pens) {
+        item.pen = pen;
     }
-    paths
 }
 
-// Normally Harfbuzz buffers give you the advance for each glyph
-// but it turns out to be quite useful for us to keep a running
-// total in the data structure representing each glyph.
-// We compute this total x advance by summing the advances.
-fn set_total_advance(buffer: &mut GulzarBuffer) {
-    let mut total_advance = 0;
-    for mut item in buffer.glyphs.iter_mut() {
-        // Fill total advances
-        item.x_total_advance = total_advance;
-        total_advance += item.x_advance;
+// Glyph names and outlines are slow to get from Harfbuzz, so we
+// keep them from one call to the next, until the font's size or
+// variation changes.
+struct GlyphData {
+    names: RefCell<BTreeMap<u32, String>>,
+    outlines: OutlineCache<Outline>,
+}
+
+impl GlyphData {
+    fn new() -> Self {
+        GlyphData {
+            names: RefCell::new(BTreeMap::new()),
+            outlines: OutlineCache::with_padding(OUTLINE_PADDING),
+        }
+    }
+
+    fn name(&self, font: &Font, glyph: u32) -> String {
+        self.names
+            .borrow_mut()
+            .entry(glyph)
+            .or_insert_with(|| font.get_glyph_name(glyph))
+            .clone()
     }
 }
 
-// We want to know three things: the name of each glyph,
-// their paths, and the running total advance, so this routine
-// just gets that information ready in the buffer to help us
-// for later.
+thread_local! {
+    static GLYPH_DATA: ShaperCache<GlyphData> = const { ShaperCache::new() };
+}
+
+// We want to know four things: the name of each glyph, whether
+// it is a mark above or below, their outlines, and the pen
+// positions, so this routine just gets that information ready in
+// the buffer to help us for later. Glyphs with the same ID share
+// an outline.
 fn prepare_buffer(buffer: &mut GulzarBuffer, font: &Font) {
-    let mut cache: BTreeMap<u32, Vec<BezPath>> = BTreeMap::new();
-    for mut item in buffer.glyphs.iter_mut() {
-        item.name = font.get_glyph_name(item.codepoint);
-    }
-    for mut item in buffer.glyphs.iter_mut() {
-        item.paths = cache
-            .entry(item.codepoint)
-            .or_insert_with(|| get_scaled_outline(font, item.codepoint))
-            .clone(); // This clone is bad code, I know.
+    let data = GLYPH_DATA.with(|cache| cache.get_or_insert_with_scale(font, GlyphData::new));
+    for item in buffer.glyphs.iter_mut() {
+        item.name = data.name(font, item.codepoint);
+        item.mark_above = font.is_in_mark_glyph_set(item.codepoint, MARKS_ABOVE);
+        item.mark_below = font.is_in_mark_glyph_set(item.codepoint, MARKS_BELOW);
+        item.outline = data.outlines.get(font, item.codepoint);
     }
-    set_total_advance(buffer)
+    set_pen_positions(buffer)
 }
 
 #[wasm_bindgen]
@@ -82,15 +95,16 @@ pub fn shape(
     _shape_plan: u32,
     font_ref: u32,
     buf_ref: u32,
-    _features: u32,
-    _num_features: u32,
+    features: u32,
+    num_features: u32,
 ) -> i32 {
     // OK, this is the main shaping routine. First, get hold
     // of Harfbuzz's copy of the font and use OpenType shaping.
     // This just gives glyph selection, cursive attachment and
     // mark positioning. No kerning or collision mitigations yet.
     let font = Font::from_ref(font_ref);
-    font.shape_with(buf_ref, "ot");
+    let features = FeatureList::from_ref(features, num_features);
+    font.shape_with_features(buf_ref, "ot", &features.features);
 
     let mut bari_ye_counter: Option<i32> = None;
 
@@ -100,6 +114,12 @@ pub fn shape(
     let upem = face.get_upem();
     let scale_factor: f32 = x_scale as f32 / upem as f32;
 
+    // And how the font would like us to space things.
+    let config = font.config();
+    let kern_distance = config.float("kern_distance", KERN_DISTANCE);
+    let bari_ye_dot_position = config.float("bari_ye_dot_position", BARI_YE_DOT_POSITION);
+    let dot_avoidance_delta = config.float("dot_avoidance_delta", DOT_AVOIDANCE_DELTA);
+
     // Get the Buffer from Harfbuzz and fill in the information
     // we need.
     let mut buffer = GulzarBuffer::from_ref(buf_ref);
@@ -179,10 +199,15 @@ pub fn shape(
             continue;
         }
 
-        // Now we have a left glyph and a right glyph.
+        // Now we have a left glyph and a right glyph. If the user
+        // has turned kerning off here, leave them alone.
         if let Some(to_kern_with) = to_kern_with {
-            let mut left_paths = this_item.positioned_paths();
-            let other_paths = &buffer.glyphs[to_kern_with].positioned_paths();
+            let (c1, c2) = (this_item.cluster, buffer.glyphs[to_kern_with].cluster);
+            if features.is_disabled("kern", c1.min(c2)..c1.max(c2) + 1) {
+                continue;
+            }
+            let mut left = vec![this_item.placed()];
+            let right = [buffer.glyphs[to_kern_with].placed()];
             // We're actually going to extend those paths with
             // some more context on the left side,
             // to deal with things like بلی - the choti ye is part
@@ -201,29 +226,28 @@ pub fn shape(
                     if next.is_dot_below() || next.is_dot_above() {
                         continue;
                     }
-                    left_paths.extend_from_slice(&next.positioned_paths());
+                    left.push(next.placed());
                     counter += 1;
                 } else {
                     break;
                 }
             }
-            if left_paths.is_empty() || other_paths.is_empty() {
+            if left.iter().all(|p| p.outline.is_empty()) || right[0].outline.is_empty() {
                 continue;
             }
 
             // OK, we found everything we want. Work out the
-            // kern. If we saw a space, loosen things a little.
-            let kern_required = determine_kern(
-                &left_paths,
-                other_paths,
-                KERN_DISTANCE * scale_factor,
-                0.0,
-                scale_factor,
-            ) + if seen_space {
-                480.0 * scale_factor
-            } else {
-                0.0
-            };
+            // kern, but don't pull things closer than the tightest
+            // kern we allow. If we saw a space, loosen things a little.
+            let tightest = -MAXIMUM_TIGHTENING * scale_factor;
+            let kern_required =
+                kern_to_distance(&left, &right, (kern_distance * scale_factor) as f64)
+                    .map_or(tightest, |kern| (kern as f32).max(tightest))
+                    + if seen_space {
+                        480.0 * scale_factor
+                    } else {
+                        0.0
+                    };
             // debug(&format!(
             //     "Kern between {} and {}: {}",
             //     this_item.name, buffer.glyphs[to_kern_with].name, kern_required,
@@ -241,7 +265,7 @@ pub fn shape(
         buffer.glyphs[ix].x_advance += kern_required;
     }
     // We changed the advances so we should recompute.
-    set_total_advance(&mut buffer);
+    set_pen_positions(&mut buffer);
 
     // Drop dots within bari ye.
     let mut last_bari_ye_ix = 0;
@@ -263,11 +287,13 @@ pub fn shape(
     for ix in bari_ye_dots {
         let mut item = &mut buffer.glyphs[ix];
         let extents = font.get_glyph_extents(item.codepoint);
-        item.y_offset = extents.height - (BARI_YE_DOT_POSITION * scale_factor) as i32;
+        item.y_offset = extents.height - (bari_ye_dot_position * scale_factor) as i32;
     }
 
-    // Let's do dot avoidance. Part one, dots below.
-    loop {
+    // Let's do dot avoidance. Part one, dots below. The delta comes
+    // from the font, so make sure each move gets somewhere.
+    let lower_by = ((dot_avoidance_delta * scale_factor) as i32).max(1);
+    for _ in 0..MAXIMUM_DOT_MOVES {
         let mut to_lower: Option<usize> = None;
         // Walk backwards along the buffer, finding dots below
         for i in (0..buffer_len).rev() {
@@ -308,12 +334,13 @@ pub fn shape(
         }
         // Otherwise, fix this dot and go check again.
         let to_lower = to_lower.unwrap();
-        buffer.glyphs[to_lower].y_offset -= (DOT_AVOIDANCE_DELTA * scale_factor) as i32;
+        buffer.glyphs[to_lower].y_offset -= lower_by;
     }
 
     // And this is basically the same thing but looking at
-    // dots above.
-    loop {
+    // dots above, moving them by the same step.
+    let raise_by = lower_by;
+    for _ in 0..MAXIMUM_DOT_MOVES {
         let mut to_raise: Option<usize> = None;
         for i in (0..buffer_len).rev() {
             if !buffer.glyphs[i].is_dot_above() {
@@ -336,7 +363,7 @@ pub fn shape(
             break;
         }
         let to_raise = to_raise.unwrap();
-        buffer.glyphs[to_raise].y_offset += (50.0 * scale_factor) as i32;
+        buffer.glyphs[to_raise].y_offset += raise_by;
     }
 
     // And we are done. Hand the buffer back to harfbuzz.
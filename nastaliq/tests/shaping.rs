@@ -0,0 +1,129 @@
+use gulzar_shaper::shape;
+use harfbuzz_wasm::{native, Font};
+
+fn font() -> u32 {
+    native::load_font(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/NotoNastaliq-Wasm.ttf"
+    ))
+    .unwrap()
+}
+
+#[test]
+fn shapes_a_word() {
+    let font = font();
+    let buffer = native::create_buffer("سلام");
+    assert_eq!(shape(0, font, buffer, 0, 0), 1);
+    let glyphs = native::buffer_glyphs(buffer);
+    assert_eq!(glyphs.len(), 4);
+    assert!(glyphs.iter().all(|g| g.codepoint != 0));
+    // Right-to-left text comes back in visual order.
+    assert_eq!(glyphs[0].cluster, 6);
+}
+
+// The native host only maps characters through the `cmap` table, so
+// it never gives the shaper the contextual forms and separate dots
+// which OpenType shaping would. Instead, give the font a `cmap` which
+// maps the private use area straight onto glyph IDs, so we can ask
+// for exactly the glyphs we want.
+fn font_with_glyph_cmap() -> u32 {
+    let mut data = std::fs::read(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/NotoNastaliq-Wasm.ttf"
+    ))
+    .unwrap();
+    let glyph_count = Font::from_ref(font()).get_face().glyph_count();
+    let mut cmap = vec![];
+    // One Windows Unicode full repertoire subtable...
+    for value in [0u16, 1, 3, 10] {
+        cmap.extend(value.to_be_bytes());
+    }
+    cmap.extend(12u32.to_be_bytes());
+    // ...in format 12, with a single group starting at U+E000.
+    cmap.extend(12u16.to_be_bytes());
+    cmap.extend(0u16.to_be_bytes());
+    for value in [28, 0, 1, 0xE000, 0xE000 + glyph_count - 1, 0] {
+        cmap.extend(u32::to_be_bytes(value));
+    }
+
+    while data.len() % 4 != 0 {
+        data.push(0);
+    }
+    let offset = data.len() as u32;
+    let num_tables = u16::from_be_bytes([data[4], data[5]]) as usize;
+    for record in (12..12 + num_tables * 16).step_by(16) {
+        if &data[record..record + 4] == b"cmap" {
+            data[record + 8..record + 12].copy_from_slice(&offset.to_be_bytes());
+            data[record + 12..record + 16].copy_from_slice(&(cmap.len() as u32).to_be_bytes());
+        }
+    }
+    data.extend(cmap);
+    native::font_from_data(data, 0).unwrap()
+}
+
+#[test]
+fn moves_dots_in_the_sample_text() {
+    // پَچھے جر پیل, as in comparison.png, written out in roughly the
+    // glyphs OpenType shaping picks and in the order Harfbuzz
+    // returns them.
+    let font_ref = font_with_glyph_cmap();
+    let font = Font::from_ref(font_ref);
+    let glyphs = [
+        "LamFin",
+        "TwoDotsBelowNS",
+        "BehxMed",
+        "ThreeDotsDownBelowNS",
+        "BehxIni",
+        "space",
+        "RehFin",
+        "OneDotBelowNS",
+        "HahIni",
+        "space",
+        "YehBarreeFin",
+        "HehDoMed",
+        "ThreeDotsDownBelowNS",
+        "HahMed",
+        "FathaNS",
+        "ThreeDotsDownBelowNS",
+        "BehxIni",
+    ];
+    let text: String = glyphs
+        .iter()
+        .map(|name| char::from_u32(0xE000 + font.get_glyph_from_name(name).unwrap()).unwrap())
+        .collect();
+    let buffer = native::create_buffer(&text);
+    assert_eq!(shape(0, font_ref, buffer, 0, 0), 1);
+
+    let shaped: Vec<_> = native::buffer_glyphs(buffer)
+        .iter()
+        .map(|g| (font.get_glyph_name(g.codepoint), g.x_advance, g.y_offset))
+        .collect();
+    // Dots which would collide are lowered until they don't, and
+    // the initial pe is widened to clear the tail of the bari ye.
+    let expected = [
+        ("LamFin", 848, 0),
+        ("TwoDotsBelowNS", 0, -300),
+        ("BehxMed", 200, 0),
+        ("ThreeDotsDownBelowNS", 0, 0),
+        ("BehxIni", 236, 0),
+        ("space", 132, 0),
+        ("RehFin", 382, 0),
+        ("OneDotBelowNS", 0, 0),
+        ("HahIni", 494, 0),
+        ("space", 132, 0),
+        ("YehBarreeFin", 356, 0),
+        ("HehDoMed", 328, 0),
+        ("ThreeDotsDownBelowNS", 0, -542),
+        ("HahMed", 99, 0),
+        ("FathaNS", 0, 0),
+        ("ThreeDotsDownBelowNS", 0, -192),
+        ("BehxIni", 727, 0),
+    ];
+    assert_eq!(
+        shaped,
+        expected
+            .iter()
+            .map(|&(name, x_advance, y_offset)| (name.to_string(), x_advance, y_offset))
+            .collect::<Vec<_>>()
+    );
+}
@@ -40,6 +40,7 @@ pub fn shape(
             y_offset: 0,
             cluster: item.cluster,
             flags: 0,
+            ..Default::default()
         });
         let rand1 = rng.rand_range_i32(-2, 2);
         let rand2 = rng.rand_range_i32(-2, 2);
@@ -51,6 +52,7 @@ pub fn shape(
             y_offset: rand2 * step,
             cluster: item.cluster,
             flags: 0,
+            ..Default::default()
         });
         let rand1 = rng.rand_range_i32(-2, 2);
         let rand2 = rng.rand_range_i32(-2, 2);
@@ -63,6 +65,7 @@ pub fn shape(
 
             cluster: item.cluster,
             flags: 0,
+            ..Default::default()
         });
     }
     buffer.glyphs = newglyphs;
@@ -0,0 +1,116 @@
+use kurbo::{Affine, PathSeg, Rect, Vec2};
+
+use crate::rect_distance;
+use crate::segment::{self, hull};
+
+// Segments per leaf of the tree.
+const LEAF_SIZE: usize = 4;
+
+#[derive(Debug, Clone)]
+enum Node {
+    // A range of `Bvh::segments`.
+    Leaf(usize, usize),
+    // Indices of two child nodes.
+    Split(usize, usize),
+}
+
+/// A bounding volume hierarchy: the segments of an outline, grouped
+/// into a tree where each node has a box around everything below it.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Bvh {
+    segments: Vec<(PathSeg, Rect)>,
+    nodes: Vec<(Rect, Node)>,
+}
+
+impl Bvh {
+    pub(crate) fn new(segments: Vec<PathSeg>) -> Self {
+        let mut bvh = Bvh {
+            segments: segments.into_iter().map(|s| (s, hull(&s))).collect(),
+            nodes: vec![],
+        };
+        if !bvh.segments.is_empty() {
+            bvh.build(0, bvh.segments.len());
+        }
+        bvh
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    pub(crate) fn bounds(&self) -> Rect {
+        self.nodes.first().map(|n| n.0).unwrap_or_default()
+    }
+
+    // Add the node for a range of segments, and everything below it,
+    // returning its index. The root is always node zero.
+    fn build(&mut self, start: usize, end: usize) -> usize {
+        let bounds = self.segments[start..end]
+            .iter()
+            .map(|s| s.1)
+            .reduce(|a, b| a.union(b))
+            .unwrap_or_default();
+        let ix = self.nodes.len();
+        self.nodes.push((bounds, Node::Leaf(start, end)));
+        if end - start <= LEAF_SIZE {
+            return ix;
+        }
+        // Split across the longer side, at the median.
+        let centre = |r: &Rect| {
+            if bounds.width() > bounds.height() {
+                r.center().x
+            } else {
+                r.center().y
+            }
+        };
+        self.segments[start..end].sort_by(|a, b| centre(&a.1).total_cmp(&centre(&b.1)));
+        let mid = (start + end) / 2;
+        let left = self.build(start, mid);
+        let right = self.build(mid, end);
+        self.nodes[ix].1 = Node::Split(left, right);
+        ix
+    }
+}
+
+/// Shrink `best` to the distance between two trees, each moved by
+/// an offset, if they are closer than that.
+pub(crate) fn closest(a: &Bvh, a_offset: Vec2, b: &Bvh, b_offset: Vec2, best: &mut f64) {
+    let (a_move, b_move) = (Affine::translate(a_offset), Affine::translate(b_offset));
+    let mut stack = vec![(0, 0)];
+    while let Some((i, j)) = stack.pop() {
+        let (a_bounds, a_node) = &a.nodes[i];
+        let (b_bounds, b_node) = &b.nodes[j];
+        if rect_distance(*a_bounds + a_offset, *b_bounds + b_offset) >= *best {
+            continue;
+        }
+        match (a_node, b_node) {
+            (Node::Leaf(a0, a1), Node::Leaf(b0, b1)) => {
+                for (s1, r1) in &a.segments[*a0..*a1] {
+                    for (s2, r2) in &b.segments[*b0..*b1] {
+                        if rect_distance(*r1 + a_offset, *r2 + b_offset) < *best {
+                            segment::closest(a_move * *s1, b_move * *s2, best);
+                        }
+                    }
+                }
+            }
+            // Open up the bigger of the two nodes.
+            (Node::Split(l, r), Node::Leaf(..)) => {
+                stack.push((*l, j));
+                stack.push((*r, j));
+            }
+            (Node::Leaf(..), Node::Split(l, r)) => {
+                stack.push((i, *l));
+                stack.push((i, *r));
+            }
+            (Node::Split(l, r), Node::Split(bl, br)) => {
+                if a_bounds.area() >= b_bounds.area() {
+                    stack.push((*l, j));
+                    stack.push((*r, j));
+                } else {
+                    stack.push((i, *bl));
+                    stack.push((i, *br));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,231 @@
+#![warn(missing_docs)]
+//! Distances and collisions between glyph outlines.
+//!
+//! Shapers which space glyphs by their outlines, rather than by
+//! their advance widths, keep asking the same three questions: how
+//! far apart are these two sets of paths, how far do I need to move
+//! this one so they are a given distance apart, and do they
+//! overlap? This crate answers them.
+//!
+//! An [`Outline`] is built once for each glyph, and keeps its
+//! segments in a tree of bounding boxes so that the parts of two
+//! outlines which are far apart can be skipped without looking at
+//! their curves. It can then be placed anywhere without being copied,
+//! with [`Outline::at`].
+//!
+//! ```rust,ignore
+//! use outline_distance::{kern_to_distance, Outline};
+//!
+//! let left = Outline::new(&left_paths);
+//! let right = Outline::new(&right_paths);
+//! // How far to move `right` so it is 300 units from `left`
+//! let kern = kern_to_distance(&[left.at(left_origin)], &[right.at(right_origin)], 300.0);
+//! ```
+mod bvh;
+mod segment;
+mod sweep;
+
+use kurbo::{BezPath, ParamCurve, PathEl, Point, Rect, Shape, Vec2};
+
+use bvh::Bvh;
+
+/// How closely distances are measured, in font units.
+///
+/// Curves are split until they are within this distance of
+/// straight, so distances are accurate to about twice this.
+pub const TOLERANCE: f64 = 0.25;
+
+/// The outline of a glyph, or any other set of closed paths, made
+/// ready for measuring.
+#[derive(Debug, Clone, Default)]
+pub struct Outline {
+    paths: Vec<BezPath>,
+    bvh: Bvh,
+    // The outline flattened to straight lines, for sweeping.
+    lines: Vec<kurbo::Line>,
+}
+
+impl Outline {
+    /// Make an outline from a list of paths.
+    pub fn new(paths: &[BezPath]) -> Self {
+        let segments = paths.iter().flat_map(|p| p.segments()).collect();
+        let mut lines = vec![];
+        for path in paths {
+            let mut start = Point::ZERO;
+            let mut last = Point::ZERO;
+            path.flatten(TOLERANCE, |el| match el {
+                PathEl::MoveTo(p) => {
+                    start = p;
+                    last = p;
+                }
+                PathEl::LineTo(p) => {
+                    lines.push(kurbo::Line::new(last, p));
+                    last = p;
+                }
+                PathEl::ClosePath => {
+                    if last != start {
+                        lines.push(kurbo::Line::new(last, start));
+                    }
+                    last = start;
+                }
+                _ => {}
+            });
+        }
+        Self {
+            paths: paths.to_vec(),
+            bvh: Bvh::new(segments),
+            lines,
+        }
+    }
+
+    /// The paths this outline was made from.
+    pub fn paths(&self) -> &[BezPath] {
+        &self.paths
+    }
+
+    /// Does the outline have no segments at all, like a space?
+    pub fn is_empty(&self) -> bool {
+        self.bvh.is_empty()
+    }
+
+    /// A box containing the whole outline.
+    pub fn bounds(&self) -> Rect {
+        self.bvh.bounds()
+    }
+
+    /// Place the outline with its origin at `offset`.
+    pub fn at(&self, offset: impl Into<Vec2>) -> Placed<'_> {
+        Placed {
+            outline: self,
+            offset: offset.into(),
+        }
+    }
+
+    // Is a point inside the outline, by the non-zero winding rule?
+    fn contains(&self, pt: Point) -> bool {
+        self.paths.iter().map(|p| p.winding(pt)).sum::<i32>() != 0
+    }
+
+    // Any point on the outline.
+    fn first_point(&self) -> Option<Point> {
+        self.paths
+            .iter()
+            .flat_map(|p| p.segments())
+            .next()
+            .map(|s| s.start())
+    }
+}
+
+impl From<Vec<BezPath>> for Outline {
+    fn from(paths: Vec<BezPath>) -> Self {
+        Self::new(&paths)
+    }
+}
+
+/// An [`Outline`] moved to a position.
+#[derive(Debug, Clone, Copy)]
+pub struct Placed<'a> {
+    /// The outline
+    pub outline: &'a Outline,
+    /// Where the outline's origin is
+    pub offset: Vec2,
+}
+
+impl Placed<'_> {
+    /// A box containing the placed outline.
+    pub fn bounds(&self) -> Rect {
+        self.outline.bounds() + self.offset
+    }
+
+    /// The same outline moved a little further.
+    pub fn moved(&self, by: impl Into<Vec2>) -> Self {
+        Self {
+            outline: self.outline,
+            offset: self.offset + by.into(),
+        }
+    }
+}
+
+// The distance between two rectangles; zero if they touch.
+pub(crate) fn rect_distance(a: Rect, b: Rect) -> f64 {
+    let dx = (b.x0 - a.x1).max(a.x0 - b.x1).max(0.0);
+    let dy = (b.y0 - a.y1).max(a.y0 - b.y1).max(0.0);
+    dx.hypot(dy)
+}
+
+// Shrink `best` to the distance between two placed outlines, if
+// they are closer than that.
+fn closest(a: &Placed, b: &Placed, best: &mut f64) {
+    if a.outline.is_empty() || b.outline.is_empty() {
+        return;
+    }
+    if rect_distance(a.bounds(), b.bounds()) >= *best {
+        return;
+    }
+    bvh::closest(&a.outline.bvh, a.offset, &b.outline.bvh, b.offset, best)
+}
+
+/// The smallest distance between any part of the outlines in `a`
+/// and any part of the outlines in `b`, or `None` if either is
+/// empty.
+///
+/// This is the distance between the paths themselves, so it is zero
+/// if they cross, but not if one is wholly inside the other; use
+/// [`collides`] to check for overlaps.
+pub fn min_distance(a: &[Placed], b: &[Placed]) -> Option<f64> {
+    let mut best = f64::INFINITY;
+    for p1 in a {
+        for p2 in b {
+            closest(p1, p2, &mut best);
+        }
+    }
+    best.is_finite().then_some(best)
+}
+
+/// Do two placed outlines overlap or touch?
+pub fn collides(a: &Placed, b: &Placed) -> bool {
+    if a.outline.is_empty() || b.outline.is_empty() {
+        return false;
+    }
+    if rect_distance(a.bounds(), b.bounds()) > 0.0 {
+        return false;
+    }
+    let mut best = TOLERANCE;
+    closest(a, b, &mut best);
+    if best < TOLERANCE {
+        return true;
+    }
+    // The paths don't cross, but one might be inside the other.
+    let inside = |p: &Placed, q: &Placed| {
+        p.outline
+            .first_point()
+            .is_some_and(|pt| q.outline.contains(pt + p.offset - q.offset))
+    };
+    inside(a, b) || inside(b, a)
+}
+
+/// How far to move the outlines in `right` horizontally so that
+/// they are `target` units from the outlines in `left` at their
+/// closest point.
+///
+/// A negative result moves them to the left. `right` should be
+/// to the right of `left` once moved; this is the usual case for
+/// kerning, and lets parts of one glyph tuck under another.
+/// Returns `None` if the outlines have no heights in common, so
+/// that however far `right` is moved they never come within
+/// `target` of each other.
+pub fn kern_to_distance(left: &[Placed], right: &[Placed], target: f64) -> Option<f64> {
+    let mut kern = sweep::kern(left, right, target)?;
+    // The sweep works on strips of the outlines, so correct it
+    // with an exact measurement. Moving the outlines apart changes
+    // their distance by at most as much as they were moved.
+    if target > 0.0 {
+        let moved: Vec<Placed> = right.iter().map(|p| p.moved((kern, 0.0))).collect();
+        if let Some(distance) = min_distance(left, &moved) {
+            if distance > 0.0 {
+                kern += target - distance;
+            }
+        }
+    }
+    Some(kern)
+}
@@ -0,0 +1,86 @@
+use kurbo::{Line, ParamCurve, ParamCurveNearest, PathSeg, Point, Rect};
+
+use crate::{rect_distance, TOLERANCE};
+
+// Beyond this many splits, a curve is treated as straight.
+const MAX_DEPTH: u32 = 24;
+
+/// A box containing a segment's control points, and so the whole
+/// segment.
+pub(crate) fn hull(seg: &PathSeg) -> Rect {
+    match *seg {
+        PathSeg::Line(l) => Rect::from_points(l.p0, l.p1),
+        PathSeg::Quad(q) => Rect::from_points(q.p0, q.p2).union_pt(q.p1),
+        PathSeg::Cubic(c) => Rect::from_points(c.p0, c.p3).union_pt(c.p1).union_pt(c.p2),
+    }
+}
+
+// How far the segment's control points stray from the straight
+// line between its ends.
+fn flatness(seg: &PathSeg) -> f64 {
+    let chord = Line::new(seg.start(), seg.end());
+    let away = |p: Point| chord.nearest(p, 0.0).distance_sq.sqrt();
+    match *seg {
+        PathSeg::Line(_) => 0.0,
+        PathSeg::Quad(q) => away(q.p1),
+        PathSeg::Cubic(c) => away(c.p1).max(away(c.p2)),
+    }
+}
+
+// Which side of the line a->b is c on?
+fn orientation(a: Point, b: Point, c: Point) -> f64 {
+    (b - a).cross(c - a)
+}
+
+fn lines_cross(l1: Line, l2: Line) -> bool {
+    let d1 = orientation(l2.p0, l2.p1, l1.p0);
+    let d2 = orientation(l2.p0, l2.p1, l1.p1);
+    let d3 = orientation(l1.p0, l1.p1, l2.p0);
+    let d4 = orientation(l1.p0, l1.p1, l2.p1);
+    d1 * d2 <= 0.0 && d3 * d4 <= 0.0 && !(d1 == 0.0 && d2 == 0.0 && d3 == 0.0 && d4 == 0.0)
+}
+
+/// The distance between two straight lines.
+pub(crate) fn line_distance(l1: Line, l2: Line) -> f64 {
+    if lines_cross(l1, l2) {
+        return 0.0;
+    }
+    let a = l1.nearest(l2.p0, 0.0).distance_sq;
+    let b = l1.nearest(l2.p1, 0.0).distance_sq;
+    let c = l2.nearest(l1.p0, 0.0).distance_sq;
+    let d = l2.nearest(l1.p1, 0.0).distance_sq;
+    a.min(b).min(c).min(d).sqrt()
+}
+
+/// Shrink `best` to the distance between two segments, if they are
+/// closer than that.
+///
+/// Both segments are split in half until the halves are nearly
+/// straight, skipping any pair of pieces whose boxes are already
+/// further apart than the best distance found so far.
+pub(crate) fn closest(s1: PathSeg, s2: PathSeg, best: &mut f64) {
+    let mut stack = vec![(s1, s2, 0)];
+    while let Some((a, b, depth)) = stack.pop() {
+        if rect_distance(hull(&a), hull(&b)) >= *best {
+            continue;
+        }
+        // Any two points give an upper bound, which lets more
+        // pieces be skipped.
+        *best = best.min(a.start().distance(b.start()));
+        let (fa, fb) = (flatness(&a), flatness(&b));
+        if depth >= MAX_DEPTH || (fa <= TOLERANCE && fb <= TOLERANCE) {
+            let d = line_distance(Line::new(a.start(), a.end()), Line::new(b.start(), b.end()));
+            *best = best.min(d);
+            continue;
+        }
+        if fa >= fb {
+            let (a1, a2) = a.subdivide();
+            stack.push((a1, b, depth + 1));
+            stack.push((a2, b, depth + 1));
+        } else {
+            let (b1, b2) = b.subdivide();
+            stack.push((a, b1, depth + 1));
+            stack.push((a, b2, depth + 1));
+        }
+    }
+}
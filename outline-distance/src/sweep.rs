@@ -0,0 +1,86 @@
+//! Kerning by sweeping one set of outlines towards another.
+//!
+//! The outlines are cut into horizontal strips. In each strip we
+//! find the rightmost point of the left outlines and the leftmost
+//! point of the right outlines; the distance needed between any two
+//! strips then follows from Pythagoras, and the kern is whatever
+//! the tightest pair of strips allows.
+use crate::{Placed, TOLERANCE};
+
+// How many strips to cut the target distance into.
+const STRIPS_PER_TARGET: f64 = 16.0;
+
+// For each strip, the furthest point of the outlines in one
+// direction: rightmost if `rightmost`, else leftmost.
+fn profile(
+    placed: &[Placed],
+    y0: f64,
+    step: f64,
+    strips: usize,
+    rightmost: bool,
+) -> Vec<Option<f64>> {
+    let mut edge: Vec<Option<f64>> = vec![None; strips];
+    let mut extend = |strip: usize, x: f64| {
+        let e = &mut edge[strip];
+        *e = Some(match *e {
+            None => x,
+            Some(old) if rightmost => old.max(x),
+            Some(old) => old.min(x),
+        });
+    };
+    let strip_of = |y: f64| (((y - y0) / step).floor().max(0.0) as usize).min(strips - 1);
+    for p in placed {
+        for line in &p.outline.lines {
+            let (a, b) = (line.p0 + p.offset, line.p1 + p.offset);
+            let (lo, hi) = if a.y <= b.y { (a, b) } else { (b, a) };
+            for strip in strip_of(lo.y)..=strip_of(hi.y) {
+                // The part of the line inside this strip
+                let top = (y0 + (strip as f64) * step).max(lo.y);
+                let bottom = (y0 + (strip + 1) as f64 * step).min(hi.y);
+                if hi.y == lo.y {
+                    extend(strip, lo.x);
+                    extend(strip, hi.x);
+                } else {
+                    let x_at = |y: f64| lo.x + (hi.x - lo.x) * (y - lo.y) / (hi.y - lo.y);
+                    extend(strip, x_at(top));
+                    extend(strip, x_at(bottom));
+                }
+            }
+        }
+    }
+    edge
+}
+
+pub(crate) fn kern(left: &[Placed], right: &[Placed], target: f64) -> Option<f64> {
+    let bounds = left
+        .iter()
+        .chain(right)
+        .filter(|p| !p.outline.is_empty())
+        .map(|p| p.bounds())
+        .reduce(|a, b| a.union(b))?;
+    let step = (target / STRIPS_PER_TARGET).max(TOLERANCE * 4.0);
+    let strips = ((bounds.height() / step).ceil() as usize).max(1);
+    let left_edge = profile(left, bounds.y0, step, strips, true);
+    let right_edge = profile(right, bounds.y0, step, strips, false);
+    // How many strips apart can two points be and still be closer
+    // than the target?
+    let reach = (target / step).floor() as usize;
+    let mut kern: Option<f64> = None;
+    for (i, l) in left_edge.iter().enumerate() {
+        let Some(l) = l else { continue };
+        let nearby = i.saturating_sub(reach)..(i + reach + 1).min(strips);
+        for (j, r) in right_edge
+            .iter()
+            .enumerate()
+            .take(nearby.end)
+            .skip(nearby.start)
+        {
+            let Some(r) = r else { continue };
+            let dy = (i.abs_diff(j) as f64) * step;
+            let gap = (target * target - dy * dy).max(0.0).sqrt();
+            let needed = l - r + gap;
+            kern = Some(kern.map_or(needed, |k| k.max(needed)));
+        }
+    }
+    kern
+}
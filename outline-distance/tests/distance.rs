@@ -0,0 +1,88 @@
+use kurbo::{BezPath, Circle, Rect, Shape};
+use outline_distance::{collides, kern_to_distance, min_distance, Outline, TOLERANCE};
+
+fn circle(x: f64, y: f64, r: f64) -> Outline {
+    Outline::new(&[Circle::new((x, y), r).to_path(0.01)])
+}
+
+fn square(x: f64, y: f64, size: f64) -> Outline {
+    Outline::new(&[Rect::new(x, y, x + size, y + size).to_path(0.01)])
+}
+
+#[test]
+fn curves_are_measured_exactly() {
+    // Circles are drawn with cubics, so this catches curves being
+    // measured by their end points.
+    let a = circle(0.0, 0.0, 100.0);
+    let b = circle(300.0, 40.0, 50.0);
+    let expected = (300.0_f64).hypot(40.0) - 150.0;
+    let d = min_distance(&[a.at((0.0, 0.0))], &[b.at((0.0, 0.0))]).unwrap();
+    assert!(
+        (d - expected).abs() < 2.0 * TOLERANCE,
+        "{} != {}",
+        d,
+        expected
+    );
+
+    // Moving an outline doesn't need a new one.
+    let d = min_distance(&[a.at((0.0, 0.0))], &[b.at((100.0, 0.0))]).unwrap();
+    let expected = (400.0_f64).hypot(40.0) - 150.0;
+    assert!((d - expected).abs() < 2.0 * TOLERANCE);
+
+    let curve = Outline::new(&[BezPath::from_svg("M0,0 Q100,200 200,0 Z").unwrap()]);
+    let d = min_distance(
+        &[curve.at((0.0, 0.0))],
+        &[square(90.0, 120.0, 20.0).at((0.0, 0.0))],
+    );
+    assert!((d.unwrap() - 20.0).abs() < 2.0 * TOLERANCE);
+
+    assert_eq!(
+        min_distance(&[Outline::default().at((0.0, 0.0))], &[b.at((0.0, 0.0))]),
+        None
+    );
+}
+
+#[test]
+fn collisions() {
+    let big = square(0.0, 0.0, 100.0);
+    let small = square(0.0, 0.0, 10.0);
+    // Crossing
+    assert!(collides(&big.at((0.0, 0.0)), &small.at((95.0, 50.0))));
+    // Wholly inside
+    assert!(collides(&big.at((0.0, 0.0)), &small.at((45.0, 45.0))));
+    assert!(collides(&small.at((45.0, 45.0)), &big.at((0.0, 0.0))));
+    // Apart
+    assert!(!collides(&big.at((0.0, 0.0)), &small.at((101.0, 50.0))));
+    assert!(!collides(
+        &circle(0.0, 0.0, 50.0).at((0.0, 0.0)),
+        &small.at((40.0, 40.0))
+    ));
+}
+
+#[test]
+fn kerning_reaches_the_target_distance() {
+    let a = circle(0.0, 0.0, 100.0);
+    let b = circle(0.0, 0.0, 100.0);
+    let left = [a.at((0.0, 0.0))];
+    let right = [b.at((500.0, 0.0))];
+    let kern = kern_to_distance(&left, &right, 50.0).unwrap();
+    assert!((kern - -250.0).abs() < 1.0, "{}", kern);
+
+    // Overlapping outlines are pushed apart.
+    let right = [b.at((150.0, 0.0))];
+    let kern = kern_to_distance(&left, &right, 0.0).unwrap();
+    assert!((kern - 50.0).abs() < 1.0, "{}", kern);
+
+    // Outlines at different heights can come closer horizontally.
+    let big = square(0.0, 0.0, 100.0);
+    let small = square(0.0, 0.0, 10.0);
+    let kern = kern_to_distance(&[big.at((0.0, 0.0))], &[small.at((200.0, 105.0))], 10.0);
+    let x = 200.0 + kern.unwrap();
+    assert!((x - (100.0 + 75.0_f64.sqrt())).abs() < 1.0, "{}", x);
+
+    // Nothing at the same height
+    assert_eq!(
+        kern_to_distance(&left, &[b.at((500.0, 1000.0))], 50.0),
+        None
+    );
+}
@@ -1,64 +1,60 @@
-mod dist;
 mod glyph;
 
-use dist::_determine_kern;
 use glyph::GulzarBuffer;
-use harfbuzz_wasm::{debug, Font};
-use kurbo::{Affine, BezPath, Shape};
-use std::collections::BTreeMap;
+use harfbuzz_wasm::geometry::OutlineCache;
+use harfbuzz_wasm::{
+    debug, register_shaper, CursiveAnchors, FeatureList, Font, ShapeResult, ShaperCache,
+};
+use outline_distance::{kern_to_distance, Outline};
+use std::ops::RangeInclusive;
 
-use wasm_bindgen::prelude::*;
+// How much to enlarge outlines by, to leave room between glyphs.
+const OUTLINE_PADDING: f64 = 1.1;
 
-fn get_scaled_outline(font: &Font, glyph: u32) -> Vec<BezPath> {
-    let mut paths = font.get_outline(glyph);
-    if let Some(big_bounds) = paths
-        .iter()
-        .map(|x| x.bounding_box())
-        .reduce(|a, b| a.union(b))
-    {
-        let center_vec = big_bounds.center().to_vec2();
-        let affine = Affine::translate(center_vec * -1.0)
-            * Affine::scale(1.1)
-            * Affine::translate(center_vec);
-        for path in paths.iter_mut() {
-            path.apply_affine(affine);
-        }
-    }
-    paths
+thread_local! {
+    // Outlines are kept from one call to the next, until the font's
+    // coordinates or size change.
+    static OUTLINES: ShaperCache<OutlineCache<Outline>> = const { ShaperCache::new() };
 }
 
-fn set_total_advance(buffer: &mut GulzarBuffer) {
-    let mut total_advance = 0;
-    for mut item in buffer.glyphs.iter_mut() {
-        // Fill total advances
-        item.x_total_advance = total_advance;
-        total_advance += item.x_advance;
+fn set_pen_positions(buffer: &mut GulzarBuffer) {
+    let pens = buffer.pen_positions();
+    for (item, pen) in buffer.glyphs.iter_mut().zip(pens) {
+        item.pen = pen;
     }
 }
 fn prepare_buffer(buffer: &mut GulzarBuffer, font: &Font) {
-    let mut cache: BTreeMap<u32, Vec<BezPath>> = BTreeMap::new();
-    for mut item in buffer.glyphs.iter_mut() {
+    let outlines = OUTLINES.with(|cache| {
+        cache.get_or_insert_with_scale(font, || OutlineCache::with_padding(OUTLINE_PADDING))
+    });
+    for item in buffer.glyphs.iter_mut() {
         item.name = font.get_glyph_name(item.codepoint);
+        item.mark = font.is_mark(item.codepoint);
+        item.outline = outlines.get(font, item.codepoint);
     }
-    for mut item in buffer.glyphs.iter_mut() {
-        item.paths = cache
-            .entry(item.codepoint)
-            .or_insert_with(|| get_scaled_outline(font, item.codepoint))
-            .clone();
-    }
-    set_total_advance(buffer)
+    set_pen_positions(buffer)
 }
 
-#[wasm_bindgen]
-pub fn shape(
-    _shape_plan: u32,
-    font_ref: u32,
-    buf_ref: u32,
-    _features: u32,
-    _num_features: u32,
+// How far the end of a word is above its start, by adding up the
+// difference between the cursive entry and exit anchors of each
+// letter. Marks aren't part of the cursive chain. `anchors` holds
+// the anchors of each glyph in the buffer.
+fn word_rise(
+    buffer: &GulzarBuffer,
+    anchors: &[Option<CursiveAnchors>],
+    word: RangeInclusive<usize>,
 ) -> i32 {
-    let font = Font::from_ref(font_ref);
-    font.shape_with(buf_ref, "ot");
+    buffer.glyphs[word.clone()]
+        .iter()
+        .zip(&anchors[word])
+        .filter(|(item, _)| !item.is_dot())
+        .filter_map(|(_, anchors)| anchors.as_ref())
+        .map(|anchors| anchors.rise())
+        .sum()
+}
+
+fn shape_ruqaa(_shape_plan: u32, font: &Font, buf_ref: u32, features: &FeatureList) -> ShapeResult {
+    font.shape_with_features(buf_ref, "ot", &features.features);
     let face = font.get_face();
     let (x_scale, _y_scale) = font.get_scale();
     let upem = face.get_upem();
@@ -66,7 +62,7 @@ pub fn shape(
     // debug(&format!("Scale factor: {:}", scale_factor));
 
     let mut buffer = GulzarBuffer::from_ref(buf_ref);
-    prepare_buffer(&mut buffer, &font);
+    prepare_buffer(&mut buffer, font);
 
     // Kerning
     let buffer_len = buffer.glyphs.len();
@@ -96,13 +92,17 @@ pub fn shape(
         }
         if let Some(to_kern_with) = to_kern_with {
             let other_item = &buffer.glyphs[to_kern_with];
-            let kern_required = _determine_kern(
-                &this_item.positioned_paths(),
-                &other_item.positioned_paths(),
-                100.0 * scale_factor,
-                0.0,
-                scale_factor,
-            );
+            let (c1, c2) = (this_item.cluster, other_item.cluster);
+            if features.is_disabled("kern", c1.min(c2)..c1.max(c2) + 1) {
+                continue;
+            }
+            let tightest = -1000.0 * scale_factor;
+            let kern_required = kern_to_distance(
+                &[this_item.placed()],
+                &[other_item.placed()],
+                (100.0 * scale_factor) as f64,
+            )
+            .map_or(tightest, |kern| (kern as f32).max(tightest));
             debug(&format!(
                 "Kern between {} and {}: {}",
                 this_item.name, other_item.name, kern_required,
@@ -115,10 +115,12 @@ pub fn shape(
         buffer.glyphs[ix].x_advance += kern_required;
     }
 
-    set_total_advance(&mut buffer);
+    set_pen_positions(&mut buffer);
 
     // Vertical positioning
-    let mut start_of_word = buffer_len - 1;
+    let codepoints: Vec<u32> = buffer.glyphs.iter().map(|item| item.codepoint).collect();
+    let anchors = font.cursive_anchors_for(&codepoints);
+    let mut start_of_word = buffer_len.saturating_sub(1);
     let mut words = vec![];
     while start_of_word > 0 {
         let this_item = &buffer.glyphs[start_of_word];
@@ -130,10 +132,10 @@ pub fn shape(
             "Word start {:}: {:} @{:}",
             start_of_word, this_item.name, this_item.y_offset
         ));
-        let mut ix = start_of_word;
-        while !buffer.glyphs[ix].name.contains(".fina") {
-            ix -= 1;
-        }
+        let ix = (0..start_of_word)
+            .rev()
+            .find(|&ix| buffer.glyphs[ix].name.contains(".fina"))
+            .ok_or_else(|| format!("No final form after {}", this_item.name))?;
         debug(&format!(
             "Word end {:}: {:} @{:}",
             ix, buffer.glyphs[ix].name, buffer.glyphs[ix].y_offset
@@ -142,7 +144,7 @@ pub fn shape(
         words.push((
             start_of_word,
             ix,
-            -(buffer.glyphs[start_of_word].y_offset) / 2,
+            word_rise(&buffer, &anchors, ix..=start_of_word) / 2,
         ));
         start_of_word = ix;
     }
@@ -156,5 +158,7 @@ pub fn shape(
             buffer.glyphs[ix].y_offset += shift;
         }
     }
-    1
+    Ok(())
 }
+
+register_shaper!(shape_ruqaa);
@@ -0,0 +1,7 @@
+// Look up the shadow layer of each glyph in the font, so that the
+// shaper doesn't depend on the order of the glyphs.
+fn main() {
+    glyph_ids::GlyphIds::from_makefile(&["BASE_FONT"])
+        .suffix("shadow")
+        .build("glyphs.rs");
+}
@@ -1,7 +1,12 @@
-use harfbuzz_wasm::{Font, GlyphBuffer};
+use harfbuzz_wasm::{Font, Glyph, GlyphBuffer};
 
 use wasm_bindgen::prelude::*;
 
+// The shadow layer of each glyph, generated from the font by build.rs.
+mod glyphs {
+    include!(concat!(env!("OUT_DIR"), "/glyphs.rs"));
+}
+
 #[wasm_bindgen]
 pub fn shape(
     _shape_plan: u32,
@@ -11,26 +16,45 @@ pub fn shape(
     _num_features: u32,
 ) -> i32 {
     let font = Font::from_ref(font_ref);
+    // The font can say in its Wcfg table that each shadow glyph is
+    // this many glyph IDs after its glyph, instead of using the map.
+    let config = font.config();
+    let shadow_offset = config
+        .contains("shadow_offset")
+        .then(|| config.int("shadow_offset", 0) as u32);
     let mut buffer = GlyphBuffer::from_ref(buf_ref);
-    let mut total_width = 0;
 
     for mut item in buffer.glyphs.iter_mut() {
         // Map character to glyph
         item.codepoint = font.get_glyph(item.codepoint, 0);
         // Set advance width
         item.x_advance = font.get_glyph_h_advance(item.codepoint);
-        total_width += item.x_advance;
     }
 
-    let mut cloned = buffer.glyphs.clone();
-    buffer.glyphs.last_mut().unwrap().x_advance -= total_width;
-    for item in buffer.glyphs.iter_mut() {
-        item.codepoint += 26;
+    // We're not calling the OpenType shaper, so it's up to us to
+    // put right-to-left text into visual order.
+    if buffer.direction().is_backward() {
+        buffer.glyphs.reverse();
     }
-    for item in cloned.iter_mut() {
-        item.cluster += buffer.glyphs.len() as u32;
+
+    // Draw a shadow glyph, with no advance of its own, underneath
+    // each glyph which has one, keeping both in the glyph's cluster.
+    for ix in (0..buffer.glyphs.len()).rev() {
+        let item = buffer.glyphs[ix];
+        let shadow = match shadow_offset {
+            Some(offset) => Some(item.codepoint + offset),
+            None => glyphs::lookup(glyphs::SHADOW, item.codepoint),
+        };
+        let Some(shadow) = shadow else {
+            continue;
+        };
+        let shadow = Glyph {
+            codepoint: shadow,
+            x_advance: 0,
+            ..item
+        };
+        buffer.decompose(ix, vec![shadow, item]);
     }
-    buffer.glyphs.extend(cloned);
 
     // Buffer is written back to HB on drop
     1